@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+//! The database handle shared by the requests served by bpfman-rpc.
+
+use std::{
+    sync::{Arc, Mutex, Weak},
+    time::{Duration, Instant},
+};
+
+use bpfman::{config::Config, errors::BpfmanError, setup};
+use log::debug;
+use sled::Db;
+use tokio::{sync::broadcast, task::spawn_blocking};
+
+// How often to check whether the database has been idle for long enough to
+// be closed.
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The configuration and database used by a request.
+pub(crate) struct BpfmanState {
+    pub(crate) config: Config,
+    pub(crate) root_db: Db,
+}
+
+#[derive(Default)]
+struct Inner {
+    // The open database, if any request is still using it.
+    open: Weak<BpfmanState>,
+    // Keeps the database open between requests until it has been idle for
+    // long enough.
+    keepalive: Option<Arc<BpfmanState>>,
+    last_used: Option<Instant>,
+}
+
+/// SharedDatabase opens the database once for all the requests in progress,
+/// rather than once per request.
+///
+/// The database can only be opened by one process at a time, so it is closed
+/// again once bpfman-rpc has been idle for `millisec_idle` from the
+/// `[database]` config section. This lets `bpfman` commands run on the host
+/// in between requests. The config is read again every time the database is
+/// opened.
+#[derive(Clone, Default)]
+pub(crate) struct SharedDatabase {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl SharedDatabase {
+    /// Returns the open database, opening it if needed. Opening the database
+    /// may block while another process holds it, so this must be called from
+    /// a blocking task.
+    pub(crate) fn get(&self) -> Result<Arc<BpfmanState>, BpfmanError> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.last_used = Some(Instant::now());
+        if let Some(state) = inner.open.upgrade() {
+            inner.keepalive = Some(state.clone());
+            return Ok(state);
+        }
+
+        debug!("Opening shared database");
+        let (config, root_db) = setup()?;
+        let state = Arc::new(BpfmanState { config, root_db });
+        inner.open = Arc::downgrade(&state);
+        inner.keepalive = Some(state.clone());
+        Ok(state)
+    }
+
+    /// Returns a handle to a database that is already open, which is kept
+    /// open until the handle is dropped. Used by tests, which don't have the
+    /// bpfman database.
+    #[cfg(test)]
+    pub(crate) fn open(config: Config, root_db: Db) -> Self {
+        let state = Arc::new(BpfmanState { config, root_db });
+        let inner = Inner {
+            open: Arc::downgrade(&state),
+            keepalive: Some(state),
+            last_used: Some(Instant::now()),
+        };
+        Self {
+            inner: Arc::new(Mutex::new(inner)),
+        }
+    }
+
+    // Stops keeping the database open once no request has used it for
+    // `millisec_idle`, and returns it so that the caller can drop it. It is
+    // closed when the last request using it finishes.
+    fn release_idle(&self) -> Option<Arc<BpfmanState>> {
+        let mut inner = self.inner.lock().unwrap();
+        let state = inner.keepalive.as_ref()?;
+        // Requests still using the database hold references to it.
+        if Arc::strong_count(state) > 1 {
+            inner.last_used = Some(Instant::now());
+            return None;
+        }
+        let idle = Duration::from_millis(state.config.database().millisec_idle);
+        if inner.last_used.is_none_or(|t| t.elapsed() >= idle) {
+            debug!("Releasing idle shared database");
+            return inner.keepalive.take();
+        }
+        None
+    }
+
+    /// Releases the database whenever it becomes idle, until shutdown.
+    pub(crate) async fn run(&self, mut shutdown_channel: broadcast::Receiver<()>) {
+        let mut interval = tokio::time::interval(IDLE_CHECK_INTERVAL);
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    // Closing the database flushes it to disk.
+                    if let Some(state) = self.release_idle() {
+                        let _ = spawn_blocking(move || drop(state)).await;
+                    }
+                }
+                _ = shutdown_channel.recv() => {
+                    self.inner.lock().unwrap().keepalive = None;
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use std::{sync::Arc, time::Duration};
+
+use bpfman_api::{
+    v1::bpfman_server::BpfmanServer, v2::bpfman_server::BpfmanServer as BpfmanServerV2,
+};
+use log::{debug, info, warn};
+use tokio::{
+    sync::broadcast,
+    time::{error::Elapsed, interval, timeout},
+};
+use tonic::server::NamedService;
+use tonic_health::{ServingStatus, server::HealthReporter};
+
+use crate::{AsyncBpfman, rpc::BpfmanLoader};
+
+// How often the health of bpfman-rpc is checked.
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+// How long a health check can take, for example while another process holds
+// the database, before bpfman-rpc is reported as not serving.
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// HealthMonitor periodically checks that bpfman-rpc can serve requests, and
+/// reports the result through the `grpc.health.v1.Health` service. Both the
+/// `bpfman.v1.Bpfman` service and the server as a whole are reported as
+/// NOT_SERVING while the database can't be opened or the bpffs isn't mounted.
+pub struct HealthMonitor {
+    bpfman: Arc<AsyncBpfman>,
+    reporter: HealthReporter,
+    status: Option<ServingStatus>,
+}
+
+impl HealthMonitor {
+    pub fn new(bpfman: Arc<AsyncBpfman>, reporter: HealthReporter) -> Self {
+        Self {
+            bpfman,
+            reporter,
+            status: None,
+        }
+    }
+
+    pub async fn run(mut self, mut shutdown_channel: broadcast::Receiver<()>) {
+        let mut check = interval(HEALTH_CHECK_INTERVAL);
+
+        loop {
+            tokio::select! {
+                _ = check.tick() => self.check().await,
+                _ = shutdown_channel.recv() => {
+                    debug!("Health Monitor: Received shutdown signal");
+                    break;
+                }
+            }
+        }
+        self.set_status(ServingStatus::NotServing).await;
+        info!("Shutdown Health Monitor");
+    }
+
+    async fn check(&mut self) {
+        let result = timeout(HEALTH_CHECK_TIMEOUT, self.bpfman.check_health()).await;
+        self.report(result).await;
+    }
+
+    // Reports the outcome of a health check, which is an `Elapsed` error if
+    // the check did not complete in time.
+    async fn report(&mut self, result: Result<anyhow::Result<()>, Elapsed>) {
+        let status = match result {
+            Ok(Ok(())) => ServingStatus::Serving,
+            Ok(Err(e)) => {
+                warn!("Health check failed: {e}");
+                ServingStatus::NotServing
+            }
+            Err(_) => {
+                warn!(
+                    "Health check did not complete within {} seconds",
+                    HEALTH_CHECK_TIMEOUT.as_secs()
+                );
+                ServingStatus::NotServing
+            }
+        };
+        self.set_status(status).await;
+    }
+
+    async fn set_status(&mut self, status: ServingStatus) {
+        if self.status == Some(status) {
+            return;
+        }
+        info!("Reporting health status {status:?}");
+        // The empty service name is the health of the server as a whole.
+        for service in [
+            "",
+            BpfmanServer::<BpfmanLoader>::NAME,
+            BpfmanServerV2::<BpfmanLoader>::NAME,
+        ] {
+            self.reporter.set_service_status(service, status).await;
+        }
+        self.status = Some(status);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use anyhow::anyhow;
+    use tokio::net::TcpListener;
+    use tokio_stream::wrappers::TcpListenerStream;
+    use tonic::transport::{Channel, Endpoint, Server};
+    use tonic_health::{
+        pb::{
+            HealthCheckRequest, health_check_response::ServingStatus as RpcServingStatus,
+            health_client::HealthClient,
+        },
+        server::health_reporter,
+    };
+
+    use super::*;
+
+    // Returns the status reported for each service of bpfman-rpc.
+    async fn statuses(client: &mut HealthClient<Channel>) -> Vec<RpcServingStatus> {
+        let mut statuses = Vec::new();
+        for service in [
+            "",
+            BpfmanServer::<BpfmanLoader>::NAME,
+            BpfmanServerV2::<BpfmanLoader>::NAME,
+        ] {
+            let response = client
+                .check(HealthCheckRequest {
+                    service: service.to_string(),
+                })
+                .await
+                .unwrap();
+            statuses.push(response.into_inner().status());
+        }
+        statuses
+    }
+
+    #[tokio::test]
+    async fn test_report() {
+        let (reporter, health) = health_reporter();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (shutdown_tx, mut shutdown_rx) = broadcast::channel(1);
+        let handle = tokio::spawn(
+            Server::builder()
+                .add_service(health)
+                .serve_with_incoming_shutdown(TcpListenerStream::new(listener), async move {
+                    let _ = shutdown_rx.recv().await;
+                }),
+        );
+        let channel = Endpoint::from_shared(format!("http://{address}"))
+            .unwrap()
+            .connect_timeout(Duration::from_secs(5))
+            .connect()
+            .await
+            .unwrap();
+        let mut client = HealthClient::new(channel);
+
+        let mut monitor = HealthMonitor::new(Arc::new(AsyncBpfman::new()), reporter);
+        monitor
+            .report(Ok(Err(anyhow!("bpffs is not mounted"))))
+            .await;
+        assert_eq!(
+            statuses(&mut client).await,
+            vec![RpcServingStatus::NotServing; 3]
+        );
+
+        monitor.report(Ok(Ok(()))).await;
+        assert_eq!(
+            statuses(&mut client).await,
+            vec![RpcServingStatus::Serving; 3]
+        );
+
+        shutdown_tx.send(()).unwrap();
+        handle.await.unwrap().unwrap();
+    }
+}
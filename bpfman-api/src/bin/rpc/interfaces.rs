@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use std::{collections::HashSet, path::PathBuf, sync::Arc, thread, time::Duration};
+
+use bpfman::netlink::NetlinkManager;
+use log::{debug, error, info, warn};
+use tokio::{
+    sync::{broadcast, mpsc},
+    time::interval,
+};
+
+use crate::AsyncBpfman;
+
+// How often the network namespaces containing links are looked up, so that
+// namespaces used by new links are watched.
+const NETNS_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+// How long a watcher waits for an interface event before checking whether its
+// network namespace still exists.
+const NETNS_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// InterfaceMonitor watches for interfaces being added and removed in every
+/// network namespace that contains an XDP, TC or TCX link, and updates the
+/// links to match. Links are marked as detached when their interface is
+/// removed, and links with the `by-name` reattach policy are attached again
+/// when an interface with the same name is created.
+pub struct InterfaceMonitor {
+    bpfman: Arc<AsyncBpfman>,
+}
+
+impl InterfaceMonitor {
+    pub fn new(bpfman: Arc<AsyncBpfman>) -> Self {
+        Self { bpfman }
+    }
+
+    pub async fn run(self, mut shutdown_channel: broadcast::Receiver<()>) {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut watched = HashSet::new();
+        let mut refresh = interval(NETNS_REFRESH_INTERVAL);
+
+        loop {
+            tokio::select! {
+                _ = refresh.tick() => self.watch_namespaces(&mut watched, &tx).await,
+                Some(netns) = rx.recv() => self.reconcile(netns).await,
+                _ = shutdown_channel.recv() => {
+                    debug!("Interface Monitor: Received shutdown signal");
+                    break;
+                }
+            }
+        }
+        info!("Shutdown Interface Monitor");
+    }
+
+    // Starts a watcher for each network namespace containing links that is not
+    // already watched. The links in a newly watched namespace are reconciled
+    // straight away, as interfaces may have changed while nothing was watching.
+    async fn watch_namespaces(
+        &self,
+        watched: &mut HashSet<Option<PathBuf>>,
+        tx: &mpsc::UnboundedSender<Option<PathBuf>>,
+    ) {
+        // Watchers exit once their network namespace is deleted.
+        watched.retain(|netns| netns.as_ref().is_none_or(|n| n.exists()));
+
+        let namespaces = match self.bpfman.get_link_namespaces().await {
+            Ok(namespaces) => namespaces,
+            Err(e) => {
+                error!("Unable to get the network namespaces of links: {e}");
+                return;
+            }
+        };
+
+        for netns in namespaces {
+            if watched.contains(&netns) {
+                continue;
+            }
+            let monitor =
+                match NetlinkManager::new_link_monitor(netns.as_deref(), NETNS_CHECK_INTERVAL) {
+                    Ok(monitor) => monitor,
+                    Err(e) => {
+                        warn!("Unable to watch interfaces in network namespace {netns:?}: {e}");
+                        continue;
+                    }
+                };
+            debug!("Watching interfaces in network namespace {netns:?}");
+            let thread_netns = netns.clone();
+            let thread_tx = tx.clone();
+            thread::spawn(move || watch_namespace(monitor, thread_netns, thread_tx));
+            watched.insert(netns.clone());
+            self.reconcile(netns).await;
+        }
+    }
+
+    async fn reconcile(&self, netns: Option<PathBuf>) {
+        if let Err(e) = self.bpfman.reconcile_interfaces(netns.clone()).await {
+            warn!("Unable to reconcile links in network namespace {netns:?}: {e}");
+        }
+    }
+}
+
+// Forwards interface events from a network namespace until the namespace is
+// deleted or the monitor shuts down.
+fn watch_namespace(
+    monitor: NetlinkManager,
+    netns: Option<PathBuf>,
+    tx: mpsc::UnboundedSender<Option<PathBuf>>,
+) {
+    loop {
+        match monitor.wait_for_link_change() {
+            Ok(true) => {
+                if tx.send(netns.clone()).is_err() {
+                    return;
+                }
+            }
+            Ok(false) => {
+                if tx.is_closed() || netns.as_ref().is_some_and(|n| !n.exists()) {
+                    return;
+                }
+            }
+            Err(e) => {
+                warn!("Stopped watching interfaces in network namespace {netns:?}: {e}");
+                return;
+            }
+        }
+    }
+}
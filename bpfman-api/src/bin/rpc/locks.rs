@@ -0,0 +1,346 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+//! Locks that serialise mutations of the same resource while letting
+//! requests that touch different resources, and all reads, run concurrently.
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    os::unix::fs::MetadataExt,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use bpfman::{
+    errors::BpfmanError,
+    get_link,
+    types::{AttachInfo, Link, METADATA_ALIAS_TAG, METADATA_APPLICATION_TAG, Program},
+};
+use sled::Db;
+use tokio::sync::{
+    Mutex as AsyncMutex, OwnedMutexGuard, OwnedRwLockReadGuard, OwnedRwLockWriteGuard, RwLock,
+};
+
+// The network namespace used when a request doesn't name one.
+const DEFAULT_NETNS: &str = "/proc/self/ns/net";
+
+/// A resource that mutations are serialised on.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum ResourceKey {
+    /// A loaded program, including the maps it owns.
+    Program(u32),
+    /// The dispatchers of an interface in a network namespace, identified by
+    /// the inode of the namespace so that different paths to the same
+    /// namespace share a lock.
+    Interface { iface: String, netns: u64 },
+    /// The aliases of all programs and links, which are checked to be unused
+    /// before they are given.
+    Alias,
+    /// The programs of an application, which are counted against the policy
+    /// before more programs of the application are loaded.
+    Application(String),
+}
+
+impl ResourceKey {
+    pub(crate) fn interface(iface: &str, netns: Option<&Path>) -> Self {
+        let netns = std::fs::metadata(netns.unwrap_or(Path::new(DEFAULT_NETNS)))
+            .map(|m| m.ino())
+            .unwrap_or_default();
+        ResourceKey::Interface {
+            iface: iface.to_string(),
+            netns,
+        }
+    }
+}
+
+/// The locks held by a request. They are released when the guard is dropped.
+pub(crate) struct ResourceGuard {
+    _global: OwnedRwLockReadGuard<()>,
+    guards: Vec<OwnedMutexGuard<()>>,
+    keys: Vec<ResourceKey>,
+    locks: Arc<Mutex<HashMap<ResourceKey, Arc<AsyncMutex<()>>>>>,
+}
+
+impl ResourceGuard {
+    /// Returns whether the guard holds the locks of all of `keys`.
+    pub(crate) fn covers(&self, keys: &[ResourceKey]) -> bool {
+        keys.iter().all(|key| self.keys.binary_search(key).is_ok())
+    }
+}
+
+impl Drop for ResourceGuard {
+    fn drop(&mut self) {
+        // Release the resource locks first so that unused entries can be
+        // removed from the map.
+        self.guards.clear();
+        let mut locks = self.locks.lock().unwrap();
+        for key in &self.keys {
+            if locks.get(key).is_some_and(|l| Arc::strong_count(l) == 1) {
+                locks.remove(key);
+            }
+        }
+    }
+}
+
+/// The resource locks of bpfman-rpc.
+///
+/// Mutations lock the resources they change, such as a program or the
+/// dispatchers of an interface, and share a global lock with each other.
+/// Operations that can change any resource, such as reconciling interfaces,
+/// take the global lock exclusively instead. Reads don't take any lock.
+#[derive(Default)]
+pub(crate) struct ResourceLocks {
+    global: Arc<RwLock<()>>,
+    locks: Arc<Mutex<HashMap<ResourceKey, Arc<AsyncMutex<()>>>>>,
+}
+
+impl ResourceLocks {
+    /// Locks the given resources. The locks are always acquired in the same
+    /// order so that requests locking several resources can't deadlock.
+    pub(crate) async fn lock(&self, keys: impl IntoIterator<Item = ResourceKey>) -> ResourceGuard {
+        let global = self.global.clone().read_owned().await;
+        let keys: Vec<ResourceKey> = keys
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        // The guard is created first so that it removes unused entries from
+        // the map even if the request is cancelled while waiting.
+        let mut guard = ResourceGuard {
+            _global: global,
+            guards: Vec::with_capacity(keys.len()),
+            keys,
+            locks: self.locks.clone(),
+        };
+        let mutexes: Vec<Arc<AsyncMutex<()>>> = {
+            let mut locks = self.locks.lock().unwrap();
+            guard
+                .keys
+                .iter()
+                .map(|key| locks.entry(key.clone()).or_default().clone())
+                .collect()
+        };
+        for mutex in mutexes {
+            guard.guards.push(mutex.lock_owned().await);
+        }
+        guard
+    }
+
+    /// Locks every resource, waiting for the mutations in progress to finish.
+    pub(crate) async fn lock_all(&self) -> OwnedRwLockWriteGuard<()> {
+        self.global.clone().write_owned().await
+    }
+}
+
+/// Returns the resources changed by attaching program `id`.
+pub(crate) fn attach_keys(id: u32, attach_info: &AttachInfo) -> Vec<ResourceKey> {
+    let mut keys = vec![ResourceKey::Program(id)];
+    match attach_info {
+        AttachInfo::Xdp { iface, netns, .. }
+        | AttachInfo::Tc { iface, netns, .. }
+        | AttachInfo::Tcx { iface, netns, .. } => {
+            keys.push(ResourceKey::interface(iface, netns.as_deref()))
+        }
+        _ => {}
+    }
+    if attach_info.metadata().contains_key(METADATA_ALIAS_TAG) {
+        keys.push(ResourceKey::Alias);
+    }
+    keys
+}
+
+/// Returns the resources changed by detaching, or otherwise updating, `link`.
+pub(crate) fn link_keys(link: &Link) -> Result<Vec<ResourceKey>, BpfmanError> {
+    let mut keys = vec![ResourceKey::Program(link.get_program_id()?)];
+    let interface = match link {
+        Link::Xdp(l) => Some((l.get_iface()?, l.get_netns()?)),
+        Link::Tc(l) => Some((l.get_iface()?, l.get_netns()?)),
+        Link::Tcx(l) => Some((l.get_iface()?, l.get_netns()?)),
+        _ => None,
+    };
+    if let Some((iface, netns)) = interface {
+        keys.push(ResourceKey::interface(&iface, netns.as_deref()));
+    }
+    Ok(keys)
+}
+
+/// Returns the resources changed by unloading `program`, which detaches all
+/// of its links.
+pub(crate) fn unload_keys(
+    root_db: &Db,
+    program: &Program,
+) -> Result<Vec<ResourceKey>, BpfmanError> {
+    let data = program.get_data();
+    let mut keys = vec![ResourceKey::Program(data.get_id()?)];
+    if let Some(owner) = data.get_map_owner_id()? {
+        keys.push(ResourceKey::Program(owner));
+    }
+    for id in data.get_link_ids()? {
+        keys.extend(link_keys(&get_link(root_db, id)?)?);
+    }
+    Ok(keys)
+}
+
+/// Returns the resources changed by loading `programs`, which are the
+/// programs whose maps they share, and the aliases if they are given one.
+/// If `count_applications` is set, because the policy limits the number of
+/// programs of an application, the applications of the programs are included.
+pub(crate) fn load_keys(
+    programs: &[Program],
+    count_applications: bool,
+) -> Result<Vec<ResourceKey>, BpfmanError> {
+    let mut keys = vec![];
+    for program in programs {
+        let data = program.get_data();
+        if let Some(owner) = data.get_map_owner_id()? {
+            keys.push(ResourceKey::Program(owner));
+        }
+        if data.get_alias().is_some() {
+            keys.push(ResourceKey::Alias);
+        }
+        if count_applications && let Some(app) = data.get_metadata()?.get(METADATA_APPLICATION_TAG)
+        {
+            keys.push(ResourceKey::Application(app.clone()));
+        }
+    }
+    Ok(keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bpfman::types::{Location, ProgramData, TracepointProgram};
+    use tokio::time::timeout;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resource_locks() {
+        let locks = ResourceLocks::default();
+        let wait = Duration::from_millis(50);
+
+        let guard = locks
+            .lock([
+                ResourceKey::Program(1),
+                ResourceKey::interface("eth0", None),
+            ])
+            .await;
+        assert!(guard.covers(&[ResourceKey::Program(1)]));
+        assert!(!guard.covers(&[ResourceKey::Program(1), ResourceKey::Program(2)]));
+        // Other resources can be locked while the first ones are held.
+        assert!(
+            timeout(wait, locks.lock([ResourceKey::Program(2)]))
+                .await
+                .is_ok()
+        );
+        assert!(
+            timeout(wait, locks.lock([ResourceKey::interface("eth0", None)]))
+                .await
+                .is_err()
+        );
+        assert!(timeout(wait, locks.lock_all()).await.is_err());
+
+        drop(guard);
+        assert!(
+            timeout(wait, locks.lock([ResourceKey::Program(1)]))
+                .await
+                .is_ok()
+        );
+        assert!(locks.locks.lock().unwrap().is_empty());
+
+        let all = locks.lock_all().await;
+        assert!(
+            timeout(wait, locks.lock([ResourceKey::Program(1)]))
+                .await
+                .is_err()
+        );
+        drop(all);
+    }
+
+    #[tokio::test]
+    async fn test_alias_lock() {
+        let locks = ResourceLocks::default();
+        let wait = Duration::from_millis(50);
+        let tracepoint = |metadata: HashMap<String, String>| AttachInfo::Tracepoint {
+            tracepoint: "syscalls/sys_enter_openat".to_string(),
+            metadata,
+        };
+
+        assert_eq!(
+            attach_keys(1, &tracepoint(HashMap::new())),
+            [ResourceKey::Program(1)]
+        );
+        let aliased = tracepoint(HashMap::from([(
+            METADATA_ALIAS_TAG.to_string(),
+            "openat".to_string(),
+        )]));
+        assert_eq!(
+            attach_keys(1, &aliased),
+            [ResourceKey::Program(1), ResourceKey::Alias]
+        );
+
+        // Requests giving aliases to different programs are serialised, so
+        // that the alias is checked and given atomically.
+        let _guard = locks.lock(attach_keys(1, &aliased)).await;
+        assert!(
+            timeout(wait, locks.lock(attach_keys(2, &aliased)))
+                .await
+                .is_err()
+        );
+        assert!(
+            timeout(
+                wait,
+                locks.lock(attach_keys(2, &tracepoint(HashMap::new())))
+            )
+            .await
+            .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_application_lock() {
+        let locks = ResourceLocks::default();
+        let wait = Duration::from_millis(50);
+        let program = |app: &str| {
+            let data = ProgramData::new(
+                Location::File("/tmp/prog.o".to_string()),
+                "prog".to_string(),
+                HashMap::from([(METADATA_APPLICATION_TAG.to_string(), app.to_string())]),
+                HashMap::new(),
+                None,
+            )
+            .unwrap();
+            vec![Program::Tracepoint(TracepointProgram::new(data).unwrap())]
+        };
+
+        // Applications are only locked if the policy counts their programs.
+        assert!(load_keys(&program("stats"), false).unwrap().is_empty());
+        assert_eq!(
+            load_keys(&program("stats"), true).unwrap(),
+            [ResourceKey::Application("stats".to_string())]
+        );
+
+        // Concurrent loads of the same application are serialised, so that
+        // each one counts the programs the others loaded.
+        let _guard = locks
+            .lock(load_keys(&program("stats"), true).unwrap())
+            .await;
+        assert!(
+            timeout(
+                wait,
+                locks.lock(load_keys(&program("stats"), true).unwrap())
+            )
+            .await
+            .is_err()
+        );
+        assert!(
+            timeout(
+                wait,
+                locks.lock(load_keys(&program("trace"), true).unwrap())
+            )
+            .await
+            .is_ok()
+        );
+    }
+}
@@ -2,25 +2,45 @@
 // Copyright Authors of bpfman
 use std::{env, fs::create_dir_all, path::PathBuf, str::FromStr, sync::Arc};
 
-use anyhow::Context;
+use anyhow::{Context, bail};
 use bpfman::{
-    add_programs, attach_program,
+    ProgramPage, add_programs, attach_link_group, attach_program, attach_program_batch,
     config::Config,
-    detach,
+    detach, detach_batch, detach_link_group, disable_link, enable_link,
     errors::BpfmanError,
-    get_program, list_programs, pull_bytecode, remove_program, setup,
-    types::{AttachInfo, BytecodeImage, Link, ListFilter, Program},
+    get_event_revision, get_events, get_kernel_link_info, get_link, get_link_group,
+    get_link_namespaces, get_program, list_links, list_programs, list_programs_page, pull_bytecode,
+    reconcile_interfaces, remove_program, resolve_link, resolve_program, select_links,
+    select_programs, set_link_priority, set_link_reattach_policy,
+    types::{
+        AttachInfo, BytecodeImage, Drift, Event, IdOrAlias, InterfaceSelector, KernelLinkInfo,
+        Link, LinkGroup, LinkListFilter, ListFilter, Program, ReattachPolicy,
+    },
+    utils::{is_bpffs_mounted, open_config_file},
+    validate_attach, validate_programs, verify_with,
 };
 use clap::{Args, Parser};
 use log::debug;
+use sled::Db;
 use systemd_journal_logger::{JournalLog, connected_to_journal};
-use tokio::{sync::Mutex, task::spawn_blocking};
+use tokio::task::spawn_blocking;
 
-use crate::serve::serve;
+use crate::{
+    database::SharedDatabase,
+    locks::{
+        ResourceGuard, ResourceKey, ResourceLocks, attach_keys, link_keys, load_keys, unload_keys,
+    },
+    serve::serve,
+};
 
+mod database;
+mod health;
+mod interfaces;
+mod locks;
 mod rpc;
 mod serve;
 mod storage;
+mod v2;
 
 const BPFMAN_ENV_LOG_LEVEL: &str = "RUST_LOG";
 
@@ -93,70 +113,411 @@ fn initialize_rpc(csi_support: bool) -> anyhow::Result<()> {
     Ok(())
 }
 
-pub struct AsyncBpfman {}
+/// AsyncBpfman runs bpfman library calls on blocking tasks, against a
+/// database shared by all the requests in progress.
+///
+/// Reads run concurrently with everything else. Mutations lock the resources
+/// they change, see [`ResourceLocks`], and so only wait for other mutations of
+/// the same program or interface, for other mutations giving an alias, or for
+/// other loads of the same application if the policy limits its programs.
+/// Image pulls don't take any locks.
+#[derive(Default)]
+pub struct AsyncBpfman {
+    database: SharedDatabase,
+    locks: ResourceLocks,
+}
+
 impl AsyncBpfman {
     pub(crate) fn new() -> Self {
-        Self {}
+        Self::default()
     }
 
-    fn setup(&self) -> anyhow::Result<(Config, sled::Db)> {
-        setup().map_err(|e| e.into())
+    #[cfg(test)]
+    pub(crate) fn with_database(database: SharedDatabase) -> Self {
+        Self {
+            database,
+            locks: ResourceLocks::default(),
+        }
+    }
+
+    pub(crate) fn database(&self) -> &SharedDatabase {
+        &self.database
+    }
+
+    async fn run<T, F>(&self, f: F) -> anyhow::Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Config, &Db) -> Result<T, BpfmanError> + Send + 'static,
+    {
+        self.run_locked((), f).await
+    }
+
+    /// Runs `f` while holding `guard`. The guard is moved into the blocking
+    /// task and only dropped once `f` returns, so the locks are kept even if
+    /// the request is cancelled while `f` runs.
+    async fn run_locked<G, T, F>(&self, guard: G, f: F) -> anyhow::Result<T>
+    where
+        G: Send + 'static,
+        T: Send + 'static,
+        F: FnOnce(&Config, &Db) -> Result<T, BpfmanError> + Send + 'static,
+    {
+        let database = self.database.clone();
+        match spawn_blocking(move || {
+            let result = database
+                .get()
+                .and_then(|state| f(&state.config, &state.root_db));
+            drop(guard);
+            result
+        })
+        .await
+        {
+            Ok(result) => result.map_err(|e| e.into()),
+            Err(e) => Err(BpfmanError::InternalError(e.to_string()).into()),
+        }
     }
 
     pub(crate) async fn add_programs(
         &self,
         programs: Vec<Program>,
     ) -> anyhow::Result<Vec<Program>> {
-        let (config, root_db) = self.setup()?;
-        match spawn_blocking(move || add_programs(&config, &root_db, programs)).await {
-            Ok(result) => result.map_err(|e| e.into()),
-            Err(e) => Err(BpfmanError::InternalError(e.to_string()).into()),
-        }
+        // Loads of an application only need to be serialised if the policy
+        // limits the number of programs it may have.
+        let count_applications = self
+            .run(|config, _| {
+                Ok(config
+                    .policy()
+                    .resolve()?
+                    .max_programs_per_application
+                    .is_some())
+            })
+            .await?;
+        let guard = self
+            .locks
+            .lock(load_keys(&programs, count_applications)?)
+            .await;
+        self.run_locked(guard, move |config, root_db| {
+            add_programs(config, root_db, programs)
+        })
+        .await
+    }
+
+    pub(crate) async fn validate_programs(
+        &self,
+        programs: Vec<Program>,
+    ) -> anyhow::Result<Vec<Program>> {
+        self.run(move |config, root_db| validate_programs(config, root_db, programs))
+            .await
+    }
+
+    pub(crate) async fn validate_attach(
+        &self,
+        id: u32,
+        attach_info: AttachInfo,
+    ) -> anyhow::Result<()> {
+        self.run(move |config, root_db| validate_attach(config, root_db, id, attach_info))
+            .await
     }
 
     pub(crate) async fn attach(&self, id: u32, attach_info: AttachInfo) -> anyhow::Result<Link> {
-        let (config, root_db) = self.setup()?;
-        match spawn_blocking(move || attach_program(&config, &root_db, id, attach_info)).await {
-            Ok(result) => result.map_err(|e| e.into()),
-            Err(e) => Err(BpfmanError::InternalError(e.to_string()).into()),
+        let guard = self.locks.lock(attach_keys(id, &attach_info)).await;
+        self.run_locked(guard, move |config, root_db| {
+            attach_program(config, root_db, id, attach_info)
+        })
+        .await
+    }
+
+    pub(crate) async fn attach_link_group(
+        &self,
+        id: u32,
+        name: String,
+        selector: InterfaceSelector,
+        attach_info: AttachInfo,
+    ) -> anyhow::Result<LinkGroup> {
+        // The interfaces that are attached to are only known once the
+        // selector is applied.
+        let guard = self.locks.lock_all().await;
+        self.run_locked(guard, move |config, root_db| {
+            attach_link_group(config, root_db, id, &name, selector, attach_info)
+        })
+        .await
+    }
+
+    pub(crate) async fn detach_link_group(&self, name: String) -> anyhow::Result<()> {
+        let group = name.clone();
+        let guard = self
+            .lock_keys(move |root_db| {
+                let mut keys = vec![];
+                for member in get_link_group(root_db, &group)?.get_members()? {
+                    if let Some(link_id) = member.link_id {
+                        keys.extend(link_keys(&get_link(root_db, link_id)?)?);
+                    }
+                }
+                Ok(keys)
+            })
+            .await?;
+        self.run_locked(guard, move |config, root_db| {
+            detach_link_group(config, root_db, &name)
+        })
+        .await
+    }
+
+    pub(crate) async fn get_link_group(&self, name: String) -> anyhow::Result<LinkGroup> {
+        self.run(move |_, root_db| get_link_group(root_db, &name))
+            .await
+    }
+
+    pub(crate) async fn attach_batch(
+        &self,
+        requests: Vec<(u32, AttachInfo)>,
+    ) -> anyhow::Result<Vec<Result<Link, BpfmanError>>> {
+        let keys: Vec<ResourceKey> = requests
+            .iter()
+            .flat_map(|(id, attach_info)| attach_keys(*id, attach_info))
+            .collect();
+        let guard = self.locks.lock(keys).await;
+        self.run_locked(guard, move |config, root_db| {
+            Ok(attach_program_batch(config, root_db, requests))
+        })
+        .await
+    }
+
+    pub(crate) async fn detach_batch(
+        &self,
+        link_ids: Vec<u32>,
+    ) -> anyhow::Result<Vec<Result<(), BpfmanError>>> {
+        let ids = link_ids.clone();
+        // Links that can't be found fail on their own when they are detached.
+        let guard = self
+            .lock_keys(move |root_db| {
+                Ok(ids
+                    .iter()
+                    .filter_map(|id| get_link(root_db, *id).and_then(|l| link_keys(&l)).ok())
+                    .flatten()
+                    .collect())
+            })
+            .await?;
+        self.run_locked(guard, move |config, root_db| {
+            Ok(detach_batch(config, root_db, &link_ids))
+        })
+        .await
+    }
+
+    /// Locks the resources of link `link_id`.
+    async fn lock_link(&self, link_id: u32) -> anyhow::Result<ResourceGuard> {
+        self.lock_keys(move |root_db| link_keys(&get_link(root_db, link_id)?))
+            .await
+    }
+
+    /// Locks the resources that `keys` reads from the database. Another
+    /// request may change them while this one waits for the locks, such as
+    /// by attaching a link to a program that is being unloaded, so they are
+    /// read again once they are locked, and locked again if they changed.
+    async fn lock_keys<F>(&self, keys: F) -> anyhow::Result<ResourceGuard>
+    where
+        F: Fn(&Db) -> Result<Vec<ResourceKey>, BpfmanError> + Send + Sync + 'static,
+    {
+        let keys = Arc::new(keys);
+        let read = || {
+            let keys = keys.clone();
+            self.run(move |_, root_db| keys(root_db))
+        };
+        let mut guard = self.locks.lock(read().await?).await;
+        loop {
+            let current = read().await?;
+            if guard.covers(&current) {
+                return Ok(guard);
+            }
+            drop(guard);
+            guard = self.locks.lock(current).await;
         }
     }
 
     pub(crate) async fn detach(&self, link_id: u32) -> anyhow::Result<()> {
-        let (config, root_db) = self.setup()?;
-        match spawn_blocking(move || detach(&config, &root_db, link_id)).await {
-            Ok(result) => result.map_err(|e| e.into()),
-            Err(e) => Err(BpfmanError::InternalError(e.to_string()).into()),
-        }
+        let guard = self.lock_link(link_id).await?;
+        self.run_locked(guard, move |config, root_db| {
+            detach(config, root_db, link_id)
+        })
+        .await
+    }
+
+    pub(crate) async fn disable_link(&self, link_id: u32) -> anyhow::Result<Link> {
+        let guard = self.lock_link(link_id).await?;
+        self.run_locked(guard, move |config, root_db| {
+            disable_link(config, root_db, link_id)
+        })
+        .await
+    }
+
+    pub(crate) async fn enable_link(&self, link_id: u32) -> anyhow::Result<Link> {
+        let guard = self.lock_link(link_id).await?;
+        self.run_locked(guard, move |config, root_db| {
+            enable_link(config, root_db, link_id)
+        })
+        .await
+    }
+
+    pub(crate) async fn set_link_priority(
+        &self,
+        link_id: u32,
+        priority: i32,
+    ) -> anyhow::Result<Link> {
+        let guard = self.lock_link(link_id).await?;
+        self.run_locked(guard, move |config, root_db| {
+            set_link_priority(config, root_db, link_id, priority)
+        })
+        .await
+    }
+
+    pub(crate) async fn set_link_reattach_policy(
+        &self,
+        link_id: u32,
+        policy: ReattachPolicy,
+    ) -> anyhow::Result<Link> {
+        let guard = self.lock_link(link_id).await?;
+        self.run_locked(guard, move |_, root_db| {
+            set_link_reattach_policy(root_db, link_id, policy)
+        })
+        .await
     }
 
     pub(crate) async fn get_program(&self, id: u32) -> anyhow::Result<Program> {
-        let (_, root_db) = self.setup()?;
-        match spawn_blocking(move || get_program(&root_db, id)).await {
-            Ok(result) => result.map_err(|e| e.into()),
-            Err(e) => Err(BpfmanError::InternalError(e.to_string()).into()),
-        }
+        self.run(move |_, root_db| get_program(root_db, id)).await
     }
 
     pub(crate) async fn list_programs(&self, filter: ListFilter) -> anyhow::Result<Vec<Program>> {
-        let (_, root_db) = self.setup()?;
-        match spawn_blocking(move || list_programs(&root_db, filter)).await {
-            Ok(result) => result.map_err(|e| e.into()),
-            Err(e) => Err(BpfmanError::InternalError(e.to_string()).into()),
-        }
+        self.run(move |_, root_db| list_programs(root_db, filter))
+            .await
+    }
+
+    pub(crate) async fn list_programs_page(
+        &self,
+        filter: ListFilter,
+    ) -> anyhow::Result<ProgramPage> {
+        self.run(move |_, root_db| list_programs_page(root_db, filter))
+            .await
+    }
+
+    pub(crate) async fn list_links(
+        &self,
+        filter: LinkListFilter,
+    ) -> anyhow::Result<Vec<(Link, Option<KernelLinkInfo>)>> {
+        self.run(move |_, root_db| {
+            list_links(root_db, filter)?
+                .into_iter()
+                .map(|link| {
+                    let kernel_info = get_kernel_link_info(root_db, &link)?;
+                    Ok((link, kernel_info))
+                })
+                .collect()
+        })
+        .await
+    }
+
+    pub(crate) async fn get_link(&self, id: u32) -> anyhow::Result<(Link, Option<KernelLinkInfo>)> {
+        self.run(move |_, root_db| {
+            let link = get_link(root_db, id)?;
+            let kernel_info = get_kernel_link_info(root_db, &link)?;
+            Ok((link, kernel_info))
+        })
+        .await
     }
 
     pub(crate) async fn remove_program(&self, id: u32) -> anyhow::Result<()> {
-        let (config, root_db) = self.setup()?;
-        match spawn_blocking(move || remove_program(&config, &root_db, id)).await {
-            Ok(result) => result.map_err(|e| e.into()),
-            Err(e) => Err(BpfmanError::InternalError(e.to_string()).into()),
+        let guard = self
+            .lock_keys(move |root_db| unload_keys(root_db, &get_program(root_db, id)?))
+            .await?;
+        self.run_locked(guard, move |config, root_db| {
+            remove_program(config, root_db, id)
+        })
+        .await
+    }
+
+    /// Returns the ID of the program that `program` refers to, by its ID or
+    /// its alias.
+    pub(crate) async fn resolve_program(&self, program: IdOrAlias) -> anyhow::Result<u32> {
+        self.run(move |_, root_db| resolve_program(root_db, &program))
+            .await
+    }
+
+    pub(crate) async fn select_programs(&self, selector: String) -> anyhow::Result<Vec<Program>> {
+        self.run(move |_, root_db| select_programs(root_db, &selector))
+            .await
+    }
+
+    pub(crate) async fn select_links(&self, selector: String) -> anyhow::Result<Vec<Link>> {
+        self.run(move |_, root_db| select_links(root_db, &selector))
+            .await
+    }
+
+    pub(crate) async fn resolve_link(&self, link: IdOrAlias) -> anyhow::Result<u32> {
+        self.run(move |_, root_db| resolve_link(root_db, &link))
+            .await
+    }
+
+    /// Locks program `id` for the caller, which changes the program outside
+    /// of the bpfman library.
+    pub(crate) async fn lock_program(&self, id: u32) -> ResourceGuard {
+        self.locks.lock([ResourceKey::Program(id)]).await
+    }
+
+    pub(crate) async fn get_link_namespaces(&self) -> anyhow::Result<Vec<Option<PathBuf>>> {
+        self.run(move |_, root_db| get_link_namespaces(root_db))
+            .await
+    }
+
+    pub(crate) async fn reconcile_interfaces(&self, netns: Option<PathBuf>) -> anyhow::Result<()> {
+        // Reconciling can detach and reattach any link in the namespace.
+        let guard = self.locks.lock_all().await;
+        self.run_locked(guard, move |config, root_db| {
+            reconcile_interfaces(config, root_db, netns)
+        })
+        .await
+    }
+
+    /// Verifies the bpfman state, repairing only the drifts that change
+    /// programs `may_repair` accepts.
+    pub(crate) async fn verify(
+        &self,
+        repair: bool,
+        may_repair: impl Fn(&Program) -> bool + Send + 'static,
+    ) -> anyhow::Result<Vec<Drift>> {
+        // Repairing can change any program or link.
+        let guard = if repair {
+            Some(self.locks.lock_all().await)
+        } else {
+            None
+        };
+        self.run_locked(guard, move |config, root_db| {
+            verify_with(config, root_db, repair, may_repair)
+        })
+        .await
+    }
+
+    pub(crate) async fn get_events(&self, revision: u64) -> anyhow::Result<Vec<Event>> {
+        self.run(move |_, root_db| get_events(root_db, revision))
+            .await
+    }
+
+    pub(crate) async fn get_event_revision(&self) -> anyhow::Result<u64> {
+        self.run(move |_, root_db| get_event_revision(root_db))
+            .await
+    }
+
+    /// Checks that bpfman can open its database and that the bpffs is mounted.
+    pub(crate) async fn check_health(&self) -> anyhow::Result<()> {
+        // Opening the database mounts the bpffs if it is not mounted yet.
+        let mounted = self
+            .run(move |_, _| is_bpffs_mounted().map_err(|e| BpfmanError::Error(e.to_string())))
+            .await?;
+        if !mounted {
+            bail!("bpffs is not mounted");
         }
+        Ok(())
     }
 
     pub(crate) async fn pull_bytecode(&self, image: BytecodeImage) -> anyhow::Result<()> {
-        let (_, root_db) = self.setup()?;
-        match spawn_blocking(move || pull_bytecode(&root_db, image)).await {
+        let database = self.database.clone();
+        match spawn_blocking(move || pull_bytecode(&database.get()?.root_db, image)).await {
             Ok(result) => result,
             Err(e) => Err(BpfmanError::InternalError(e.to_string()).into()),
         }
@@ -166,18 +527,57 @@ impl AsyncBpfman {
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let args = Rpc::parse();
-    let async_bpfman = AsyncBpfman::new();
-    let bpfman_lock: Arc<Mutex<_>> = Arc::new(Mutex::new(async_bpfman));
+    let async_bpfman = Arc::new(AsyncBpfman::new());
 
     initialize_rpc(args.csi_support)?;
+    let config = open_config_file();
     //TODO https://github.com/bpfman/bpfman/issues/881
     serve(
-        bpfman_lock,
+        async_bpfman,
         args.csi_support,
         args.timeout,
         &args.socket_path,
+        &config,
     )
     .await?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use bpfman::config::Config;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_lock_keys_changed() {
+        let root_db = sled::Config::default().temporary(true).open().unwrap();
+        let bpfman = AsyncBpfman::with_database(SharedDatabase::open(Config::default(), root_db));
+
+        // The keys change between the first read and the read once they are
+        // locked, as if another request attached a link in between.
+        let reads = Arc::new(AtomicU32::new(0));
+        let guard = bpfman
+            .lock_keys({
+                let reads = reads.clone();
+                move |_| {
+                    let read = reads.fetch_add(1, Ordering::SeqCst);
+                    let mut keys = vec![ResourceKey::Program(1)];
+                    if read > 0 {
+                        keys.push(ResourceKey::interface("eth0", None));
+                    }
+                    Ok(keys)
+                }
+            })
+            .await
+            .unwrap();
+        assert!(guard.covers(&[
+            ResourceKey::Program(1),
+            ResourceKey::interface("eth0", None)
+        ]));
+        assert_eq!(reads.load(Ordering::SeqCst), 3);
+    }
+}
@@ -1,59 +1,476 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright Authors of bpfman
 
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    pin::Pin,
+    sync::Arc,
+    time::Duration,
+};
 
-use anyhow::{anyhow, bail};
-use bpfman::types::{
-    AttachInfo, FentryProgram, FexitProgram, KprobeProgram, ListFilter, Location, Program,
-    ProgramData, TcProceedOn, TcProgram, TcxProgram, TracepointProgram, UprobeProgram,
-    XdpProceedOn, XdpProgram,
+use anyhow::Context;
+use bpfman::{
+    config::{AuthorizationConfig, Caller, METADATA_OWNER_TAG, Operation, Target},
+    errors::BpfmanError,
+    types::{
+        AttachInfo, BpfProgType, Event, FentryProgram, FexitProgram, IdOrAlias, InterfaceSelector,
+        KprobeProgram, LinkListFilter, ListFilter, Location, MetadataRequirement, Program,
+        ProgramData, ReattachPolicy, TcProceedOn, TcProgram, TcxProgram, TracepointProgram,
+        UprobeProgram, XdpProceedOn, XdpProgram,
+    },
+};
+use bpfman_api::{
+    bytecode_image, kernel_link_info, kernel_program_info, link_group_info, link_info,
+    program_info,
+    v1::{
+        AttachBatchRequest, AttachBatchResponse, AttachInfo as RpcAttachInfo, AttachRequest,
+        AttachResponse, BpfmanProgramType, DetachBatchRequest, DetachBatchResponse, DetachRequest,
+        DetachResponse, DisableLinkRequest, DisableLinkResponse, EnableLinkRequest,
+        EnableLinkResponse, GetLinkGroupRequest, GetLinkGroupResponse, GetLinkRequest,
+        GetLinkResponse, GetRequest, GetResponse, ListLinksRequest, ListLinksResponse, ListRequest,
+        ListResponse, LoadRequest, LoadResponse, LoadResponseInfo, ProgSpecificInfo,
+        PullBytecodeRequest, PullBytecodeResponse, SetLinkPriorityRequest, SetLinkPriorityResponse,
+        SetLinkReattachPolicyRequest, SetLinkReattachPolicyResponse, UnloadRequest, UnloadResponse,
+        VerifyRequest, VerifyResponse, WatchRequest, WatchResponse,
+        attach_batch_response::Result as AttachBatchResult, attach_info::Info,
+        bpfman_server::Bpfman, bytecode_location::Location as RpcLocation,
+        detach_batch_response::Result as DetachBatchResult,
+        list_links_response::ListResult as ListLinksResult, list_response::ListResult,
+        unload_response::Result as UnloadResult, verify_response::Drift as RpcDrift,
+    },
+    watch_response,
 };
-use bpfman_api::v1::{
-    AttachRequest, AttachResponse, BpfmanProgramType, DetachRequest, DetachResponse, GetRequest,
-    GetResponse, ListRequest, ListResponse, LoadRequest, LoadResponse, LoadResponseInfo,
-    ProgSpecificInfo, PullBytecodeRequest, PullBytecodeResponse, UnloadRequest, UnloadResponse,
-    attach_info::Info, bpfman_server::Bpfman, bytecode_location::Location as RpcLocation,
-    list_response::ListResult,
+use log::{error, warn};
+use tokio::{
+    select,
+    sync::{Notify, broadcast, mpsc},
+    time::sleep,
 };
-use log::error;
-use tokio::sync::Mutex;
-use tonic::{Request, Response, Status};
+use tokio_stream::{Stream, wrappers::ReceiverStream};
+use tonic::{Request, Response, Status, transport::server::UdsConnectInfo};
 
 use crate::AsyncBpfman;
 
+// How often a watch checks the event log for changes that were not made
+// through bpfman-rpc, such as by the bpfman CLI.
+const WATCH_POLL_INTERVAL: Duration = Duration::from_secs(2);
+// The number of events buffered for a watch client that is slow to read them.
+const WATCH_CHANNEL_SIZE: usize = 128;
+
+pub(crate) type WatchStream = Pin<Box<dyn Stream<Item = Result<WatchResponse, Status>> + Send>>;
+
 pub struct BpfmanLoader {
-    lock: Arc<Mutex<AsyncBpfman>>,
+    bpfman: Arc<AsyncBpfman>,
+    // Wakes up watches after bpfman-rpc made a change.
+    events: Arc<Notify>,
+    shutdown: broadcast::Sender<()>,
+    authorization: Option<AuthorizationConfig>,
 }
 
 impl BpfmanLoader {
-    pub(crate) fn new(lock: Arc<Mutex<AsyncBpfman>>) -> BpfmanLoader {
-        BpfmanLoader { lock }
+    pub(crate) fn new(
+        bpfman: Arc<AsyncBpfman>,
+        shutdown: broadcast::Sender<()>,
+        authorization: Option<AuthorizationConfig>,
+    ) -> BpfmanLoader {
+        BpfmanLoader {
+            bpfman,
+            events: Arc::new(Notify::new()),
+            shutdown,
+            authorization,
+        }
+    }
+
+    /// Returns the identity of the process that sent `request` when an
+    /// authorization policy is configured. Requests that did not arrive on the
+    /// unix socket have no caller, since TCP clients are already authenticated
+    /// by their TLS certificate.
+    fn caller<T>(&self, request: &Request<T>) -> anyhow::Result<Option<Caller>> {
+        if self.authorization.is_none() {
+            return Ok(None);
+        }
+        let Some(info) = request.extensions().get::<UdsConnectInfo>() else {
+            return Ok(None);
+        };
+        let Some(cred) = info.peer_cred else {
+            warn!("Denied request from unknown caller: unable to read peer credentials");
+            return Err(BpfmanError::PermissionDenied(
+                "unable to read peer credentials".to_string(),
+            )
+            .into());
+        };
+
+        let mut gids = vec![cred.gid()];
+        if let Some(pid) = cred.pid() {
+            gids.extend(supplementary_gids(pid));
+        }
+        Ok(Some(Caller {
+            uid: cred.uid(),
+            gids,
+            pid: cred.pid(),
+        }))
+    }
+
+    /// Checks `operation` against the authorization policy and audits denials.
+    fn authorize(
+        &self,
+        caller: Option<&Caller>,
+        operation: Operation,
+        target: Option<&Target>,
+    ) -> anyhow::Result<()> {
+        let (Some(authorization), Some(caller)) = (&self.authorization, caller) else {
+            return Ok(());
+        };
+        authorization
+            .authorize(caller, operation, target)
+            .map_err(|e| {
+                warn!(
+                    "Denied {operation} request from uid {} gids {:?} pid {}: {e}",
+                    caller.uid,
+                    caller.gids,
+                    caller
+                        .pid
+                        .map_or("unknown".to_string(), |pid| pid.to_string()),
+                );
+                e.into()
+            })
+    }
+
+    fn authorize_program(
+        &self,
+        caller: Option<&Caller>,
+        operation: Operation,
+        program: &Program,
+    ) -> anyhow::Result<()> {
+        if caller.is_none() {
+            return Ok(());
+        }
+        let metadata = program_metadata(program)?;
+        self.authorize(
+            caller,
+            operation,
+            Some(&Target {
+                program_type: &program.type_name(),
+                metadata: &metadata,
+            }),
+        )
+    }
+
+    async fn authorize_program_id(
+        &self,
+        caller: Option<&Caller>,
+        operation: Operation,
+        id: u32,
+    ) -> anyhow::Result<()> {
+        if caller.is_none() {
+            return Ok(());
+        }
+        let program = self.bpfman.get_program(id).await?;
+        self.authorize_program(caller, operation, &program)
+    }
+
+    async fn authorize_link_id(
+        &self,
+        caller: Option<&Caller>,
+        operation: Operation,
+        link_id: u32,
+    ) -> anyhow::Result<()> {
+        if caller.is_none() {
+            return Ok(());
+        }
+        let (link, _) = self.bpfman.get_link(link_id).await?;
+        self.authorize_program_id(caller, operation, link.get_program_id()?)
+            .await
+    }
+
+    // Returns the ID of the program a request identifies, by its alias if one
+    // is given.
+    async fn program_id(&self, id: u32, alias: Option<String>) -> anyhow::Result<u32> {
+        match alias {
+            Some(alias) => self.bpfman.resolve_program(IdOrAlias::Alias(alias)).await,
+            None => Ok(id),
+        }
+    }
+
+    // Returns the ID of the link a request identifies, by its alias if one is
+    // given.
+    async fn link_id(&self, link_id: u32, alias: Option<String>) -> anyhow::Result<u32> {
+        match alias {
+            Some(alias) => self.bpfman.resolve_link(IdOrAlias::Alias(alias)).await,
+            None => Ok(link_id),
+        }
+    }
+
+    /// Returns whether `caller` may list `program`, so that list results only
+    /// include the programs a caller is allowed to see.
+    fn may_list(&self, caller: Option<&Caller>, program: &Program) -> bool {
+        allows(
+            self.authorization.as_ref(),
+            caller,
+            &[Operation::List],
+            program,
+        )
+    }
+}
+
+// Returns whether the authorization policy allows `caller` every one of
+// `operations` on `program`.
+fn allows(
+    authorization: Option<&AuthorizationConfig>,
+    caller: Option<&Caller>,
+    operations: &[Operation],
+    program: &Program,
+) -> bool {
+    let (Some(authorization), Some(caller)) = (authorization, caller) else {
+        return true;
+    };
+    let Ok(metadata) = program_metadata(program) else {
+        return false;
+    };
+    let target = Target {
+        program_type: &program.type_name(),
+        metadata: &metadata,
+    };
+    operations.iter().all(|operation| {
+        authorization
+            .authorize(caller, *operation, Some(&target))
+            .is_ok()
+    })
+}
+
+// Returns whether `caller` may see `event`, which it may if it may list the
+// program the event concerns. Events that concern no program, such as image
+// pulls, only need the list operation.
+fn may_watch(
+    authorization: Option<&AuthorizationConfig>,
+    caller: Option<&Caller>,
+    event: &Event,
+) -> bool {
+    let (Some(authorization), Some(caller)) = (authorization, caller) else {
+        return true;
+    };
+    if event.program_id.is_none() {
+        return true;
+    }
+    // Events recorded before the type name was added only have the kernel
+    // program type, which rules for "tcx", "uprobe" and "fexit" never match.
+    let program_type = event.program_type_name.clone().unwrap_or_else(|| {
+        event
+            .program_type
+            .and_then(|t| BpfProgType::try_from(t).ok())
+            .map(|t| t.to_string())
+            .unwrap_or_default()
+    });
+    authorization
+        .authorize(
+            caller,
+            Operation::List,
+            Some(&Target {
+                program_type: &program_type,
+                metadata: &event.metadata,
+            }),
+        )
+        .is_ok()
+}
+
+// Returns whether `caller` may repair a drift that changes `program`, which
+// can remove the program and attach its links again.
+fn may_repair(
+    authorization: Option<&AuthorizationConfig>,
+    caller: Option<&Caller>,
+    program: &Program,
+) -> bool {
+    allows(
+        authorization,
+        caller,
+        &[Operation::Unload, Operation::Attach],
+        program,
+    )
+}
+
+fn program_metadata(program: &Program) -> anyhow::Result<HashMap<String, String>> {
+    match program {
+        // Programs not loaded by bpfman have no metadata.
+        Program::Unsupported(_) => Ok(HashMap::new()),
+        _ => Ok(program.get_data().get_metadata()?),
+    }
+}
+
+// SO_PEERCRED only reports the primary group of the caller, so read the
+// supplementary groups of the calling process from procfs.
+fn supplementary_gids(pid: i32) -> Vec<u32> {
+    std::fs::read_to_string(format!("/proc/{pid}/status"))
+        .ok()
+        .and_then(|status| {
+            status.lines().find_map(|line| {
+                line.strip_prefix("Groups:").map(|groups| {
+                    groups
+                        .split_whitespace()
+                        .filter_map(|gid| gid.parse().ok())
+                        .collect()
+                })
+            })
+        })
+        .unwrap_or_default()
+}
+
+// Converts the attach info of an attach request.
+fn attach_info(attach: Option<RpcAttachInfo>) -> anyhow::Result<AttachInfo> {
+    let Some(info) = attach else {
+        return Err(invalid_request("attach", "missing attach_info"));
+    };
+    let attach_info = match info.info {
+        Some(Info::XdpAttachInfo(i)) => AttachInfo::Xdp {
+            priority: i.priority,
+            iface: i.iface,
+            proceed_on: XdpProceedOn::from_int32s(i.proceed_on)
+                .map_err(|_| invalid_request("attach.proceed_on", "failed to parse proceed_on"))?,
+            netns: i.netns.map(PathBuf::from),
+            metadata: i.metadata,
+        },
+        Some(Info::TcAttachInfo(i)) => AttachInfo::Tc {
+            priority: i.priority,
+            iface: i.iface,
+            direction: i.direction,
+            proceed_on: TcProceedOn::from_int32s(i.proceed_on)
+                .map_err(|_| invalid_request("attach.proceed_on", "failed to parse proceed_on"))?,
+            netns: i.netns.map(PathBuf::from),
+            metadata: i.metadata,
+        },
+        Some(Info::TcxAttachInfo(i)) => AttachInfo::Tcx {
+            priority: i.priority,
+            iface: i.iface,
+            direction: i.direction,
+            netns: i.netns.map(PathBuf::from),
+            metadata: i.metadata,
+        },
+        Some(Info::TracepointAttachInfo(i)) => AttachInfo::Tracepoint {
+            tracepoint: i.tracepoint,
+            metadata: i.metadata,
+        },
+        Some(Info::KprobeAttachInfo(i)) => AttachInfo::Kprobe {
+            fn_name: i.fn_name,
+            offset: i.offset,
+            container_pid: i.container_pid,
+            metadata: i.metadata,
+        },
+        Some(Info::UprobeAttachInfo(i)) => AttachInfo::Uprobe {
+            fn_name: i.fn_name,
+            offset: i.offset,
+            target: i.target,
+            pid: i.pid,
+            container_pid: i.container_pid,
+            metadata: i.metadata,
+        },
+        Some(Info::FentryAttachInfo(i)) => AttachInfo::Fentry {
+            metadata: i.metadata,
+        },
+        Some(Info::FexitAttachInfo(i)) => AttachInfo::Fexit {
+            metadata: i.metadata,
+        },
+        None => return Err(invalid_request("attach", "missing attach_info")),
+    };
+    Ok(attach_info)
+}
+
+// Returns the name and interface selector of the link group an attach request
+// creates, if it has a selector.
+fn link_group(request: &AttachRequest) -> anyhow::Result<Option<(String, InterfaceSelector)>> {
+    let selector = match request.attach.as_ref().and_then(|a| a.info.as_ref()) {
+        Some(Info::XdpAttachInfo(i)) => i.selector.clone(),
+        Some(Info::TcAttachInfo(i)) => i.selector.clone(),
+        Some(Info::TcxAttachInfo(i)) => i.selector.clone(),
+        _ => None,
+    };
+    match (&request.group, selector) {
+        (Some(group), Some(selector)) => {
+            let selector = InterfaceSelector::try_from(selector).map_err(BpfmanError::from)?;
+            Ok(Some((group.clone(), selector)))
+        }
+        (Some(_), None) => Err(invalid_request(
+            "group",
+            "a group requires an XDP, TC or TCX attach info with a selector",
+        )),
+        (None, Some(_)) => Err(invalid_request(
+            "attach.selector",
+            "a selector requires a group",
+        )),
+        (None, None) => Ok(None),
+    }
+}
+
+/// A request with a missing or invalid field.
+#[derive(Debug, thiserror::Error)]
+#[error("{message}")]
+pub(crate) struct InvalidRequest {
+    /// The path of the field in the request.
+    pub(crate) field: String,
+    message: String,
+}
+
+pub(crate) fn invalid_request(field: &str, message: &str) -> anyhow::Error {
+    InvalidRequest {
+        field: field.to_string(),
+        message: message.to_string(),
+    }
+    .into()
+}
+
+// Converts an error into a Status, so that clients can tell a denied request
+// or a policy violation from a request that failed.
+fn error_status(e: anyhow::Error) -> Status {
+    match e.downcast_ref::<BpfmanError>() {
+        Some(BpfmanError::PermissionDenied(_)) => Status::permission_denied(format!("{e}")),
+        Some(BpfmanError::PolicyViolation { .. }) => Status::failed_precondition(format!("{e}")),
+        Some(BpfmanError::BpfParseError(_)) => Status::invalid_argument(format!("{e}")),
+        _ => Status::aborted(format!("{e}")),
     }
 }
 
 impl BpfmanLoader {
-    async fn do_load(&self, request: Request<LoadRequest>) -> anyhow::Result<LoadResponse> {
-        let request = request.into_inner();
+    /// Loads the programs of a load request. If any of the programs fails to
+    /// load, none of them is loaded and the error is a
+    /// `BpfmanError::ProgramsLoadFailure` with the failure of each program.
+    pub(crate) async fn load_programs(
+        &self,
+        request: Request<LoadRequest>,
+    ) -> anyhow::Result<Vec<Program>> {
+        let caller = self.caller(&request)?;
+        let mut request = request.into_inner();
+        // Record who loaded the programs, so that rules can limit callers to
+        // their own programs. An owner set by the client is ignored, since it
+        // would let callers claim programs for someone else.
+        if self.authorization.is_some() {
+            request.metadata.remove(METADATA_OWNER_TAG);
+        }
+        if let Some(caller) = &caller {
+            request
+                .metadata
+                .insert(METADATA_OWNER_TAG.to_string(), caller.uid.to_string());
+        }
 
         let bytecode_source = match request
             .bytecode
-            .ok_or(anyhow!("missing bytecode info"))?
+            .ok_or_else(|| invalid_request("bytecode", "missing bytecode info"))?
             .location
-            .ok_or(anyhow!("missing location"))?
+            .ok_or_else(|| invalid_request("bytecode.location", "missing location"))?
         {
-            RpcLocation::Image(i) => Location::Image(i.into()),
+            RpcLocation::Image(i) => Location::Image(bytecode_image(i)),
             RpcLocation::File(p) => Location::File(p),
         };
 
-        let programs : Vec<Result<Program, anyhow::Error>> = request.info.iter().map(|info| {
-            let data = ProgramData::new(
+        let programs : Vec<Result<Program, anyhow::Error>> = request.info.iter().enumerate().map(|(index, info)| {
+            let mut data = ProgramData::new(
                 bytecode_source.clone(),
                 info.name.clone(),
                 request.metadata.clone(),
                 request.global_data.clone(),
                 request.map_owner_id,
             )?;
+            if let Some(level) = request.verifier_log_level {
+                data.set_verifier_log_level(level)?;
+            }
 
             let program = match info.program_type() {
                 BpfmanProgramType::Xdp => Program::Xdp(XdpProgram::new(data)?),
@@ -69,7 +486,7 @@ impl BpfmanLoader {
                     {
                         Program::Fentry(FentryProgram::new(data, fentry.fn_name.clone())?)
                     } else {
-                        bail!("missing FentryInfo");
+                        return Err(invalid_request(&format!("info[{index}].info"), "missing FentryInfo"));
                     }
                 }
                 BpfmanProgramType::Fexit => {
@@ -79,7 +496,7 @@ impl BpfmanLoader {
                     {
                         Program::Fexit(FexitProgram::new(data, fexit.fn_name.clone())?)
                     } else {
-                        bail!("missing FexitInfo");
+                        return Err(invalid_request(&format!("info[{index}].info"), "missing FexitInfo"));
                     }
                 }
             };
@@ -87,21 +504,26 @@ impl BpfmanLoader {
         }).collect();
 
         // Check if any of the programs failed to be created
+        let programs = programs.into_iter().collect::<anyhow::Result<Vec<_>>>()?;
         for p in programs.iter() {
-            if let Err(e) = p {
-                bail!("{e}");
-            }
+            self.authorize_program(caller.as_ref(), Operation::Load, p)?;
         }
 
-        let bpfman_lock = self.lock.lock().await;
-        let add_prog_result = bpfman_lock
-            .add_programs(programs.into_iter().map(|p| p.unwrap()).collect())
-            .await?;
+        if request.validate_only {
+            return self.bpfman.validate_programs(programs).await;
+        }
+        let programs = self.bpfman.add_programs(programs).await?;
+        self.events.notify_waiters();
+        Ok(programs)
+    }
+
+    async fn do_load(&self, request: Request<LoadRequest>) -> anyhow::Result<LoadResponse> {
+        let add_prog_result = self.load_programs(request).await?;
 
         let mut load_program_info = vec![];
         for p in add_prog_result.iter() {
-            let info = p.try_into()?;
-            let kernel_info = p.try_into()?;
+            let info = program_info(p)?;
+            let kernel_info = kernel_program_info(p)?;
             load_program_info.push(LoadResponseInfo {
                 info: Some(info),
                 kernel_info: Some(kernel_info),
@@ -113,153 +535,587 @@ impl BpfmanLoader {
         Ok(reply_entry)
     }
 
-    async fn do_unload(&self, request: Request<UnloadRequest>) -> anyhow::Result<UnloadResponse> {
-        let reply = UnloadResponse {};
+    pub(crate) async fn do_unload(
+        &self,
+        request: Request<UnloadRequest>,
+    ) -> anyhow::Result<UnloadResponse> {
+        let caller = self.caller(&request)?;
         let request = request.into_inner();
-        let bpfman_lock = self.lock.lock().await;
-        bpfman_lock.remove_program(request.id).await?;
-        Ok(reply)
+        if let Some(selector) = request.selector {
+            return self.unload_selected(caller.as_ref(), selector).await;
+        }
+        let id = self.program_id(request.id, request.alias).await?;
+        self.authorize_program_id(caller.as_ref(), Operation::Unload, id)
+            .await?;
+        self.bpfman.remove_program(id).await?;
+        self.events.notify_waiters();
+        Ok(UnloadResponse { results: vec![] })
+    }
+
+    // Unloads every program matched by a selector that the caller may list,
+    // carrying on when one of them can't be unloaded.
+    async fn unload_selected(
+        &self,
+        caller: Option<&Caller>,
+        selector: String,
+    ) -> anyhow::Result<UnloadResponse> {
+        let mut results = vec![];
+        for program in self.bpfman.select_programs(selector).await? {
+            if !self.may_list(caller, &program) {
+                continue;
+            }
+            let id = program.get_data().get_id()?;
+            let result = match self.authorize_program(caller, Operation::Unload, &program) {
+                Ok(()) => self.bpfman.remove_program(id).await,
+                Err(e) => Err(e),
+            };
+            results.push(UnloadResult {
+                id,
+                error: result.err().map(|e| e.to_string()),
+            });
+        }
+        if results.iter().any(|r| r.error.is_none()) {
+            self.events.notify_waiters();
+        }
+        Ok(UnloadResponse { results })
     }
 
-    async fn do_get(&self, request: Request<GetRequest>) -> anyhow::Result<GetResponse> {
+    pub(crate) async fn do_get(&self, request: Request<GetRequest>) -> anyhow::Result<GetResponse> {
+        let caller = self.caller(&request)?;
         let request = request.into_inner();
-        let id = request.id;
-        let bpfman_lock = self.lock.lock().await;
-        let program = bpfman_lock.get_program(id).await?;
+        let id = self.program_id(request.id, request.alias).await?;
+        let program = self.bpfman.get_program(id).await?;
+        self.authorize_program(caller.as_ref(), Operation::List, &program)?;
 
         let reply_entry = GetResponse {
             info: if let Program::Unsupported(_) = program {
                 None
             } else {
-                Some((&program).try_into()?)
+                Some(program_info(&program)?)
             },
-            kernel_info: Some((&program).try_into()?),
+            kernel_info: Some(kernel_program_info(&program)?),
         };
         Ok(reply_entry)
     }
 
-    async fn do_list(&self, request: Request<ListRequest>) -> anyhow::Result<ListResponse> {
-        let mut reply = ListResponse { results: vec![] };
+    pub(crate) async fn do_list(
+        &self,
+        request: Request<ListRequest>,
+    ) -> anyhow::Result<ListResponse> {
+        let caller = self.caller(&request)?;
+        self.authorize(caller.as_ref(), Operation::List, None)?;
+        let request = request.into_inner();
 
-        let filter = ListFilter::new(
-            request.get_ref().program_type,
-            request.get_ref().match_metadata.clone(),
-            request.get_ref().bpfman_programs_only(),
+        let mut requirements = vec![];
+        for selector in &request.metadata_selector {
+            requirements
+                .extend(MetadataRequirement::parse_selector(selector).map_err(BpfmanError::from)?);
+        }
+        let mut filter = ListFilter::new(
+            request.program_type,
+            request.match_metadata.clone(),
+            request.bpfman_programs_only(),
+        )
+        .with_metadata_requirements(requirements)
+        .with_iface(request.iface.clone())
+        .with_no_links(request.no_links())
+        .with_sort_by(request.sort_by.try_into()?)
+        .with_page(
+            request.page_size.map(|size| size as usize),
+            request.page_token.clone(),
         );
-
-        let bpfman_lock = self.lock.lock().await;
+        if let Some(name_regex) = &request.name_regex {
+            filter = filter
+                .with_name_regex(name_regex)
+                .map_err(BpfmanError::from)?;
+        }
 
         // Await the response
-        for r in bpfman_lock
-            .list_programs(filter)
+        let page = self
+            .bpfman
+            .list_programs_page(filter)
             .await
-            .map_err(|e| Status::aborted(format!("failed to list programs: {e}")))?
-        {
+            .context("failed to list programs")?;
+        let mut reply = ListResponse {
+            results: vec![],
+            next_page_token: page.next_page_token,
+        };
+        // Programs the caller may not list are left out of the page, so a
+        // page can be shorter than the page size even if there are more.
+        for r in page.programs {
+            if !self.may_list(caller.as_ref(), &r) {
+                continue;
+            }
             // Populate the response with the Program Info and the Kernel Info.
             let reply_entry = ListResult {
                 info: if let Program::Unsupported(_) = r {
                     None
                 } else {
-                    Some((&r).try_into()?)
+                    Some(program_info(&r)?)
                 },
-                kernel_info: Some((&r).try_into()?),
+                kernel_info: Some(kernel_program_info(&r)?),
             };
             reply.results.push(reply_entry)
         }
         Ok(reply)
     }
 
-    async fn do_pull_bytecode(
+    pub(crate) async fn do_list_links(
+        &self,
+        request: Request<ListLinksRequest>,
+    ) -> anyhow::Result<ListLinksResponse> {
+        let caller = self.caller(&request)?;
+        self.authorize(caller.as_ref(), Operation::List, None)?;
+        let request = request.into_inner();
+        let filter = LinkListFilter::new(
+            request.program_id,
+            request.link_type,
+            request.iface,
+            request.match_metadata,
+        );
+        let mut reply = ListLinksResponse { results: vec![] };
+        for (link, kernel_info) in self.bpfman.list_links(filter).await? {
+            if caller.is_some() {
+                let program = self.bpfman.get_program(link.get_program_id()?).await?;
+                if !self.may_list(caller.as_ref(), &program) {
+                    continue;
+                }
+            }
+            reply.results.push(ListLinksResult {
+                info: Some(link_info(&link)?),
+                kernel_info: kernel_info.map(kernel_link_info),
+            });
+        }
+        Ok(reply)
+    }
+
+    pub(crate) async fn do_get_link(
+        &self,
+        request: Request<GetLinkRequest>,
+    ) -> anyhow::Result<GetLinkResponse> {
+        let caller = self.caller(&request)?;
+        let request = request.into_inner();
+        let link_id = self.link_id(request.link_id, request.alias).await?;
+        let (link, kernel_info) = self.bpfman.get_link(link_id).await?;
+        self.authorize_program_id(caller.as_ref(), Operation::List, link.get_program_id()?)
+            .await?;
+
+        Ok(GetLinkResponse {
+            info: Some(link_info(&link)?),
+            kernel_info: kernel_info.map(kernel_link_info),
+        })
+    }
+
+    pub(crate) async fn do_get_link_group(
+        &self,
+        request: Request<GetLinkGroupRequest>,
+    ) -> anyhow::Result<GetLinkGroupResponse> {
+        let caller = self.caller(&request)?;
+        let group = self
+            .bpfman
+            .get_link_group(request.into_inner().name)
+            .await?;
+        self.authorize_program_id(caller.as_ref(), Operation::List, group.get_program_id()?)
+            .await?;
+
+        Ok(GetLinkGroupResponse {
+            group: Some(link_group_info(&group)?),
+        })
+    }
+
+    pub(crate) async fn do_pull_bytecode(
         &self,
         request: tonic::Request<PullBytecodeRequest>,
     ) -> anyhow::Result<PullBytecodeResponse> {
+        let caller = self.caller(&request)?;
+        self.authorize(caller.as_ref(), Operation::Load, None)?;
         let request = request.into_inner();
         let image = match request.image {
-            Some(i) => i.into(),
-            None => bail!("Empty pull_bytecode request received"),
+            Some(i) => bytecode_image(i),
+            None => {
+                return Err(invalid_request(
+                    "image",
+                    "Empty pull_bytecode request received",
+                ));
+            }
         };
-        let bpfman_lock = self.lock.lock().await;
-        bpfman_lock.pull_bytecode(image).await?;
+        self.bpfman.pull_bytecode(image).await?;
+        self.events.notify_waiters();
 
         let reply = PullBytecodeResponse {};
         Ok(reply)
     }
 
-    async fn do_attach(
+    pub(crate) async fn do_attach(
         &self,
         request: tonic::Request<AttachRequest>,
     ) -> anyhow::Result<AttachResponse> {
+        let caller = self.caller(&request)?;
         let request = request.into_inner();
 
-        let attach_info = if let Some(info) = request.attach {
-            match info.info {
-                Some(Info::XdpAttachInfo(i)) => AttachInfo::Xdp {
-                    priority: i.priority,
-                    iface: i.iface,
-                    proceed_on: XdpProceedOn::from_int32s(i.proceed_on)
-                        .map_err(|_| Status::aborted("failed to parse proceed_on"))?,
-                    netns: i.netns.map(PathBuf::from),
-                    metadata: i.metadata,
-                },
-                Some(Info::TcAttachInfo(i)) => AttachInfo::Tc {
-                    priority: i.priority,
-                    iface: i.iface,
-                    direction: i.direction,
-                    proceed_on: TcProceedOn::from_int32s(i.proceed_on)
-                        .map_err(|_| Status::aborted("failed to parse proceed_on"))?,
-                    netns: i.netns.map(PathBuf::from),
-                    metadata: i.metadata,
-                },
-                Some(Info::TcxAttachInfo(i)) => AttachInfo::Tcx {
-                    priority: i.priority,
-                    iface: i.iface,
-                    direction: i.direction,
-                    netns: i.netns.map(PathBuf::from),
-                    metadata: i.metadata,
-                },
-                Some(Info::TracepointAttachInfo(i)) => AttachInfo::Tracepoint {
-                    tracepoint: i.tracepoint,
-                    metadata: i.metadata,
-                },
-                Some(Info::KprobeAttachInfo(i)) => AttachInfo::Kprobe {
-                    fn_name: i.fn_name,
-                    offset: i.offset,
-                    container_pid: i.container_pid,
-                    metadata: i.metadata,
-                },
-                Some(Info::UprobeAttachInfo(i)) => AttachInfo::Uprobe {
-                    fn_name: i.fn_name,
-                    offset: i.offset,
-                    target: i.target,
-                    pid: i.pid,
-                    container_pid: i.container_pid,
-                    metadata: i.metadata,
-                },
-                Some(Info::FentryAttachInfo(i)) => AttachInfo::Fentry {
-                    metadata: i.metadata,
-                },
-                Some(Info::FexitAttachInfo(i)) => AttachInfo::Fexit {
-                    metadata: i.metadata,
+        let link_group = link_group(&request)?;
+        let attach_info = attach_info(request.attach)?;
+
+        let id = self.program_id(request.id, request.program_alias).await?;
+        self.authorize_program_id(caller.as_ref(), Operation::Attach, id)
+            .await?;
+        if let Some((name, selector)) = link_group {
+            if request.validate_only {
+                return Err(invalid_request(
+                    "validate_only",
+                    "a link group can't be validated",
+                ));
+            }
+            let group = self
+                .bpfman
+                .attach_link_group(id, name, selector, attach_info)
+                .await?;
+            self.events.notify_waiters();
+            return Ok(AttachResponse {
+                link_id: 0,
+                link_group: Some(link_group_info(&group)?),
+            });
+        }
+        if request.validate_only {
+            self.bpfman.validate_attach(id, attach_info).await?;
+            return Ok(AttachResponse {
+                link_id: 0,
+                link_group: None,
+            });
+        }
+        let link = self.bpfman.attach(id, attach_info).await?;
+        self.events.notify_waiters();
+        let link_id = link.get_id()?;
+
+        Ok(AttachResponse {
+            link_id,
+            link_group: None,
+        })
+    }
+
+    pub(crate) async fn do_attach_batch(
+        &self,
+        request: Request<AttachBatchRequest>,
+    ) -> anyhow::Result<AttachBatchResponse> {
+        let caller = self.caller(&request)?;
+        let requests = request.into_inner().requests;
+
+        // Requests that can't be parsed or aren't authorized fail on their
+        // own, and the rest are attached together.
+        let mut results: Vec<Result<u32, String>> = vec![Err(String::new()); requests.len()];
+        let mut batch = vec![];
+        let mut indexes = vec![];
+        for (index, request) in requests.into_iter().enumerate() {
+            if request.group.is_some() || link_group(&request).is_err() {
+                results[index] = Err("link groups can't be attached in a batch".to_string());
+                continue;
+            }
+            let prepared = match attach_info(request.attach) {
+                Ok(info) => match self.program_id(request.id, request.program_alias).await {
+                    Ok(id) => self
+                        .authorize_program_id(caller.as_ref(), Operation::Attach, id)
+                        .await
+                        .map(|_| (id, info)),
+                    Err(e) => Err(e),
                 },
-                None => bail!("missing attach_info"),
+                Err(e) => Err(e),
+            };
+            match prepared {
+                Ok((id, info)) => {
+                    batch.push((id, info));
+                    indexes.push(index);
+                }
+                Err(e) => results[index] = Err(e.to_string()),
             }
-        } else {
-            bail!("missing attach_info");
-        };
+        }
 
-        let bpfman_lock = self.lock.lock().await;
-        let link = bpfman_lock.attach(request.id, attach_info).await?;
-        let link_id = link.get_id()?;
+        if !batch.is_empty() {
+            for (index, result) in indexes
+                .into_iter()
+                .zip(self.bpfman.attach_batch(batch).await?)
+            {
+                results[index] = result
+                    .and_then(|link| link.get_id())
+                    .map_err(|e| e.to_string());
+            }
+            self.events.notify_waiters();
+        }
+
+        Ok(AttachBatchResponse {
+            results: results
+                .into_iter()
+                .map(|result| match result {
+                    Ok(link_id) => AttachBatchResult {
+                        link_id: Some(link_id),
+                        error: None,
+                    },
+                    Err(error) => AttachBatchResult {
+                        link_id: None,
+                        error: Some(error),
+                    },
+                })
+                .collect(),
+        })
+    }
+
+    pub(crate) async fn do_detach_batch(
+        &self,
+        request: Request<DetachBatchRequest>,
+    ) -> anyhow::Result<DetachBatchResponse> {
+        let caller = self.caller(&request)?;
+        let link_ids = request.into_inner().link_ids;
+        Ok(DetachBatchResponse {
+            results: self.detach_links(caller.as_ref(), link_ids).await?,
+        })
+    }
+
+    // Detaches links in a batch, with an error in the result of each link
+    // that could not be detached.
+    async fn detach_links(
+        &self,
+        caller: Option<&Caller>,
+        link_ids: Vec<u32>,
+    ) -> anyhow::Result<Vec<DetachBatchResult>> {
+        let mut errors: Vec<Option<String>> = vec![None; link_ids.len()];
+        let mut batch = vec![];
+        let mut indexes = vec![];
+        for (index, link_id) in link_ids.iter().enumerate() {
+            match self
+                .authorize_link_id(caller, Operation::Detach, *link_id)
+                .await
+            {
+                Ok(()) => {
+                    batch.push(*link_id);
+                    indexes.push(index);
+                }
+                Err(e) => errors[index] = Some(e.to_string()),
+            }
+        }
+
+        if !batch.is_empty() {
+            for (index, result) in indexes
+                .into_iter()
+                .zip(self.bpfman.detach_batch(batch).await?)
+            {
+                errors[index] = result.err().map(|e| e.to_string());
+            }
+            self.events.notify_waiters();
+        }
+
+        Ok(link_ids
+            .into_iter()
+            .zip(errors)
+            .map(|(link_id, error)| DetachBatchResult { link_id, error })
+            .collect())
+    }
+
+    pub(crate) async fn do_detach(
+        &self,
+        request: Request<DetachRequest>,
+    ) -> anyhow::Result<DetachResponse> {
+        let caller = self.caller(&request)?;
+        let request = request.into_inner();
+        if let Some(name) = request.group {
+            let group = self.bpfman.get_link_group(name.clone()).await?;
+            self.authorize_program_id(caller.as_ref(), Operation::Detach, group.get_program_id()?)
+                .await?;
+            self.bpfman.detach_link_group(name).await?;
+            self.events.notify_waiters();
+            return Ok(DetachResponse { results: vec![] });
+        }
+        if let Some(selector) = request.selector {
+            // Only the links of programs the caller may list are selected.
+            let mut link_ids = vec![];
+            for link in self.bpfman.select_links(selector).await? {
+                let program = self.bpfman.get_program(link.get_program_id()?).await?;
+                if self.may_list(caller.as_ref(), &program) {
+                    link_ids.push(link.get_id()?);
+                }
+            }
+            return Ok(DetachResponse {
+                results: self.detach_links(caller.as_ref(), link_ids).await?,
+            });
+        }
+        let link_id = self.link_id(request.link_id, request.alias).await?;
+        self.authorize_link_id(caller.as_ref(), Operation::Detach, link_id)
+            .await?;
+        self.bpfman.detach(link_id).await?;
+        self.events.notify_waiters();
+
+        Ok(DetachResponse { results: vec![] })
+    }
+
+    pub(crate) async fn do_disable_link(
+        &self,
+        request: Request<DisableLinkRequest>,
+    ) -> anyhow::Result<DisableLinkResponse> {
+        let caller = self.caller(&request)?;
+        let request = request.into_inner();
+        self.authorize_link_id(caller.as_ref(), Operation::Detach, request.link_id)
+            .await?;
+        self.bpfman.disable_link(request.link_id).await?;
+        self.events.notify_waiters();
+
+        Ok(DisableLinkResponse {})
+    }
+
+    pub(crate) async fn do_enable_link(
+        &self,
+        request: Request<EnableLinkRequest>,
+    ) -> anyhow::Result<EnableLinkResponse> {
+        let caller = self.caller(&request)?;
+        let request = request.into_inner();
+        self.authorize_link_id(caller.as_ref(), Operation::Attach, request.link_id)
+            .await?;
+        self.bpfman.enable_link(request.link_id).await?;
+        self.events.notify_waiters();
+
+        Ok(EnableLinkResponse {})
+    }
+
+    pub(crate) async fn do_set_link_priority(
+        &self,
+        request: Request<SetLinkPriorityRequest>,
+    ) -> anyhow::Result<SetLinkPriorityResponse> {
+        let caller = self.caller(&request)?;
+        let request = request.into_inner();
+        self.authorize_link_id(caller.as_ref(), Operation::Attach, request.link_id)
+            .await?;
+        self.bpfman
+            .set_link_priority(request.link_id, request.priority)
+            .await?;
+        self.events.notify_waiters();
+
+        Ok(SetLinkPriorityResponse {})
+    }
+
+    pub(crate) async fn do_set_link_reattach_policy(
+        &self,
+        request: Request<SetLinkReattachPolicyRequest>,
+    ) -> anyhow::Result<SetLinkReattachPolicyResponse> {
+        let caller = self.caller(&request)?;
+        let request = request.into_inner();
+        let policy = ReattachPolicy::try_from(request.policy).map_err(BpfmanError::from)?;
+        self.authorize_link_id(caller.as_ref(), Operation::Attach, request.link_id)
+            .await?;
+        self.bpfman
+            .set_link_reattach_policy(request.link_id, policy)
+            .await?;
+        self.events.notify_waiters();
 
-        Ok(AttachResponse { link_id })
+        Ok(SetLinkReattachPolicyResponse {})
     }
 
-    async fn do_detach(&self, request: Request<DetachRequest>) -> anyhow::Result<DetachResponse> {
+    pub(crate) async fn do_verify(
+        &self,
+        request: Request<VerifyRequest>,
+    ) -> anyhow::Result<VerifyResponse> {
+        let caller = self.caller(&request)?;
         let request = request.into_inner();
-        let bpfman_lock = self.lock.lock().await;
-        bpfman_lock.detach(request.link_id).await?;
+        self.authorize(caller.as_ref(), Operation::List, None)?;
+        // Repairing can remove programs and attach links again, so each
+        // program a repair changes must allow both to the caller.
+        if request.repair {
+            self.authorize(caller.as_ref(), Operation::Unload, None)?;
+            self.authorize(caller.as_ref(), Operation::Attach, None)?;
+        }
+        // Drifts are only reported for programs the caller may list, which
+        // are found before repairs can remove them.
+        let visible: Option<HashSet<u32>> = match caller {
+            Some(_) => Some(
+                self.bpfman
+                    .list_programs(ListFilter::new(None, HashMap::new(), true))
+                    .await?
+                    .iter()
+                    .filter(|p| self.may_list(caller.as_ref(), p))
+                    .filter_map(|p| p.get_data().get_id().ok())
+                    .collect(),
+            ),
+            None => None,
+        };
+        let authorization = self.authorization.clone();
+        let drifts = self
+            .bpfman
+            .verify(request.repair, move |program| {
+                may_repair(authorization.as_ref(), caller.as_ref(), program)
+            })
+            .await?
+            .into_iter()
+            .filter(|d| visible.as_ref().is_none_or(|v| v.contains(&d.program_id)))
+            .map(|d| RpcDrift {
+                program_id: d.program_id,
+                link_id: d.link_id,
+                kind: d.kind.to_string(),
+                repair: d.repair.to_string(),
+            })
+            .collect();
+        self.events.notify_waiters();
 
-        Ok(DetachResponse {})
+        Ok(VerifyResponse { drifts })
+    }
+
+    pub(crate) async fn do_watch(
+        &self,
+        request: Request<WatchRequest>,
+    ) -> anyhow::Result<WatchStream> {
+        let caller = self.caller(&request)?;
+        self.authorize(caller.as_ref(), Operation::List, None)?;
+        let request = request.into_inner();
+        let filter = ListFilter::new(request.program_type, request.match_metadata, true);
+        let mut revision = match request.since_revision {
+            Some(revision) => revision,
+            None => self.bpfman.get_event_revision().await?,
+        };
+
+        let (tx, rx) = mpsc::channel(WATCH_CHANNEL_SIZE);
+        let authorization = self.authorization.clone();
+        let bpfman = self.bpfman.clone();
+        let events = self.events.clone();
+        let mut shutdown = self.shutdown.subscribe();
+        tokio::spawn(async move {
+            loop {
+                // Register for notifications before reading the event log so
+                // that no change is missed in between.
+                let notified = events.notified();
+                tokio::pin!(notified);
+                notified.as_mut().enable();
+
+                let result = bpfman.get_events(revision).await;
+                match result {
+                    Ok(events) => {
+                        for event in events {
+                            revision = event.revision;
+                            if filter.matches_event(&event)
+                                && may_watch(authorization.as_ref(), caller.as_ref(), &event)
+                                && tx.send(Ok(watch_response(event))).await.is_err()
+                            {
+                                return;
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        error!("Error in watch: {e}");
+                        let status = match e.downcast_ref::<BpfmanError>() {
+                            Some(BpfmanError::EventsCompacted { .. }) => {
+                                Status::out_of_range(format!("{e}"))
+                            }
+                            _ => Status::aborted(format!("{e}")),
+                        };
+                        let _ = tx.send(Err(status)).await;
+                        return;
+                    }
+                }
+
+                select! {
+                    _ = &mut notified => {}
+                    _ = sleep(WATCH_POLL_INTERVAL) => {}
+                    _ = tx.closed() => return,
+                    _ = shutdown.recv() => return,
+                }
+            }
+        });
+
+        Ok(Box::pin(ReceiverStream::new(rx)))
     }
 }
 
@@ -270,7 +1126,7 @@ impl Bpfman for BpfmanLoader {
             .await
             .map_err(|e| {
                 error!("Error in load: {e}");
-                Status::aborted(format!("{e}"))
+                error_status(e)
             })
             .map(Response::new)
     }
@@ -283,7 +1139,7 @@ impl Bpfman for BpfmanLoader {
             .await
             .map_err(|e| {
                 error!("Error in get: {e}");
-                Status::aborted(format!("{e}"))
+                error_status(e)
             })
             .map(Response::new)
     }
@@ -293,7 +1149,7 @@ impl Bpfman for BpfmanLoader {
             .await
             .map_err(|e| {
                 error!("Error in get: {e}");
-                Status::aborted(format!("{e}"))
+                error_status(e)
             })
             .map(Response::new)
     }
@@ -306,7 +1162,7 @@ impl Bpfman for BpfmanLoader {
             .await
             .map_err(|e| {
                 error!("Error in attach: {e}");
-                Status::aborted(format!("{e}"))
+                error_status(e)
             })
             .map(Response::new)
     }
@@ -319,7 +1175,113 @@ impl Bpfman for BpfmanLoader {
             .await
             .map_err(|e| {
                 error!("Error in detach: {e}");
-                Status::aborted(format!("{e}"))
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn attach_batch(
+        &self,
+        request: Request<AttachBatchRequest>,
+    ) -> Result<Response<AttachBatchResponse>, Status> {
+        self.do_attach_batch(request)
+            .await
+            .map_err(|e| {
+                error!("Error in attach_batch: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn detach_batch(
+        &self,
+        request: Request<DetachBatchRequest>,
+    ) -> Result<Response<DetachBatchResponse>, Status> {
+        self.do_detach_batch(request)
+            .await
+            .map_err(|e| {
+                error!("Error in detach_batch: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn disable_link(
+        &self,
+        request: Request<DisableLinkRequest>,
+    ) -> Result<Response<DisableLinkResponse>, Status> {
+        self.do_disable_link(request)
+            .await
+            .map_err(|e| {
+                error!("Error in disable link: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn enable_link(
+        &self,
+        request: Request<EnableLinkRequest>,
+    ) -> Result<Response<EnableLinkResponse>, Status> {
+        self.do_enable_link(request)
+            .await
+            .map_err(|e| {
+                error!("Error in enable link: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn set_link_priority(
+        &self,
+        request: Request<SetLinkPriorityRequest>,
+    ) -> Result<Response<SetLinkPriorityResponse>, Status> {
+        self.do_set_link_priority(request)
+            .await
+            .map_err(|e| {
+                error!("Error in set link priority: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn set_link_reattach_policy(
+        &self,
+        request: Request<SetLinkReattachPolicyRequest>,
+    ) -> Result<Response<SetLinkReattachPolicyResponse>, Status> {
+        self.do_set_link_reattach_policy(request)
+            .await
+            .map_err(|e| {
+                error!("Error in set link reattach policy: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn verify(
+        &self,
+        request: Request<VerifyRequest>,
+    ) -> Result<Response<VerifyResponse>, Status> {
+        self.do_verify(request)
+            .await
+            .map_err(|e| {
+                error!("Error in verify: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    type WatchStream = WatchStream;
+
+    async fn watch(
+        &self,
+        request: Request<WatchRequest>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        self.do_watch(request)
+            .await
+            .map_err(|e| {
+                error!("Error in watch: {e}");
+                error_status(e)
             })
             .map(Response::new)
     }
@@ -329,7 +1291,46 @@ impl Bpfman for BpfmanLoader {
             .await
             .map_err(|e| {
                 error!("Error in list: {e}");
-                Status::aborted(format!("{e}"))
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn list_links(
+        &self,
+        request: Request<ListLinksRequest>,
+    ) -> Result<Response<ListLinksResponse>, Status> {
+        self.do_list_links(request)
+            .await
+            .map_err(|e| {
+                error!("Error in list_links: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn get_link(
+        &self,
+        request: Request<GetLinkRequest>,
+    ) -> Result<Response<GetLinkResponse>, Status> {
+        self.do_get_link(request)
+            .await
+            .map_err(|e| {
+                error!("Error in get_link: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn get_link_group(
+        &self,
+        request: Request<GetLinkGroupRequest>,
+    ) -> Result<Response<GetLinkGroupResponse>, Status> {
+        self.do_get_link_group(request)
+            .await
+            .map_err(|e| {
+                error!("Error in get_link_group: {e}");
+                error_status(e)
             })
             .map(Response::new)
     }
@@ -342,8 +1343,93 @@ impl Bpfman for BpfmanLoader {
             .await
             .map_err(|e| {
                 error!("Error in pull: {e}");
-                Status::aborted(format!("{e}"))
+                error_status(e)
             })
             .map(Response::new)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bpfman::types::EventKind;
+    use bpfman_api::v1::XdpAttachInfo;
+
+    use super::*;
+
+    #[test]
+    fn test_may_watch() {
+        let authorization: AuthorizationConfig = toml::from_str(
+            r#"
+            [[rules]]
+            operations = ["list"]
+            program_types = ["tcx"]
+            owner_only = true
+            "#,
+        )
+        .unwrap();
+        let caller = Caller {
+            uid: 1000,
+            gids: vec![1000],
+            pid: None,
+        };
+        let event = |program_type_name: &str, owner: &str| Event {
+            revision: 1,
+            kind: EventKind::ProgramLoaded,
+            program_id: Some(1),
+            link_id: None,
+            program_type: Some(BpfProgType::Tc.into()),
+            program_type_name: Some(program_type_name.to_string()),
+            metadata: HashMap::from([(METADATA_OWNER_TAG.to_string(), owner.to_string())]),
+            detail: String::new(),
+        };
+
+        let allows = |event: &Event| may_watch(Some(&authorization), Some(&caller), event);
+        assert!(allows(&event("tcx", "1000")));
+        assert!(!allows(&event("tcx", "1001")));
+        assert!(!allows(&event("tc", "1000")));
+        assert!(allows(&Event {
+            program_id: None,
+            ..event("tc", "1001")
+        }));
+        assert!(may_watch(None, Some(&caller), &event("tc", "1001")));
+    }
+    #[test]
+    fn test_link_group() {
+        let request = |group: Option<&str>, selector: Option<&str>| AttachRequest {
+            id: 1,
+            attach: Some(RpcAttachInfo {
+                info: Some(Info::XdpAttachInfo(XdpAttachInfo {
+                    priority: 50,
+                    iface: String::new(),
+                    position: 0,
+                    proceed_on: vec![],
+                    netns: None,
+                    metadata: HashMap::new(),
+                    selector: selector.map(str::to_string),
+                })),
+            }),
+            validate_only: false,
+            program_alias: None,
+            group: group.map(str::to_string),
+        };
+        let field = |e: anyhow::Error| e.downcast::<InvalidRequest>().unwrap().field;
+
+        assert_eq!(
+            link_group(&request(Some("edge"), Some("glob:eth*"))).unwrap(),
+            Some((
+                "edge".to_string(),
+                InterfaceSelector::Glob("eth*".to_string())
+            ))
+        );
+        assert_eq!(link_group(&request(None, None)).unwrap(), None);
+        assert_eq!(
+            field(link_group(&request(Some("edge"), None)).unwrap_err()),
+            "group"
+        );
+        assert_eq!(
+            field(link_group(&request(None, Some("glob:eth*"))).unwrap_err()),
+            "attach.selector"
+        );
+        assert!(link_group(&request(Some("edge"), Some("eth*"))).is_err());
+    }
+}
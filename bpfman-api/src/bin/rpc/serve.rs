@@ -8,43 +8,104 @@ use std::{
     sync::Arc,
 };
 
-use anyhow::anyhow;
-use bpfman::utils::{SOCK_MODE, set_file_permissions};
-use bpfman_api::v1::bpfman_server::BpfmanServer;
+use anyhow::{Context, anyhow};
+use bpfman::{
+    config::{Config, RemoteConfig},
+    utils::{SOCK_MODE, set_file_permissions},
+};
+use bpfman_api::{
+    FILE_DESCRIPTOR_SET, v1::bpfman_server::BpfmanServer,
+    v2::bpfman_server::BpfmanServer as BpfmanServerV2,
+};
 use libsystemd::activation::IsType;
 use log::{debug, error, info};
 use tokio::{
     join,
     net::UnixListener,
     signal::unix::{SignalKind, signal},
-    sync::{Mutex, broadcast},
+    sync::broadcast,
     task::{JoinHandle, JoinSet},
 };
 use tokio_stream::wrappers::UnixListenerStream;
-use tonic::transport::Server;
+use tonic::transport::{Certificate, Identity, Server, ServerTlsConfig};
+use tonic_health::{
+    pb::health_server::{Health, HealthServer},
+    server::health_reporter,
+};
+use tonic_reflection::server::{
+    Builder as ReflectionBuilder, ServerReflection, ServerReflectionServer,
+};
 
-use crate::{AsyncBpfman, rpc::BpfmanLoader, storage::StorageManager};
+use crate::{
+    AsyncBpfman, health::HealthMonitor, interfaces::InterfaceMonitor, rpc::BpfmanLoader,
+    storage::StorageManager,
+};
 
 pub async fn serve(
-    db_lock: Arc<Mutex<AsyncBpfman>>,
+    bpfman: Arc<AsyncBpfman>,
     csi_support: bool,
     timeout: u64,
     socket_path: &Path,
+    config: &Config,
 ) -> anyhow::Result<()> {
     let (shutdown_tx, shutdown_rx1) = broadcast::channel(32);
     let shutdown_rx3 = shutdown_tx.subscribe();
+    let shutdown_rx4 = shutdown_tx.subscribe();
+    let shutdown_rx5 = shutdown_tx.subscribe();
+    let shutdown_rx6 = shutdown_tx.subscribe();
+    let shutdown_rx7 = shutdown_tx.subscribe();
+    let loader = Arc::new(BpfmanLoader::new(
+        bpfman.clone(),
+        shutdown_tx.clone(),
+        config.authorization().cloned(),
+    ));
     let shutdown_handle = tokio::spawn(shutdown_handler(timeout, shutdown_tx));
 
-    let loader = BpfmanLoader::new(db_lock.clone());
-    let service = BpfmanServer::new(loader);
+    // Both versions of the API are served by the same loader.
+    let service = BpfmanServer::from_arc(loader.clone());
+    let service_v2 = BpfmanServerV2::from_arc(loader);
+    let (health_reporter, health_service) = health_reporter();
 
     let mut listeners: Vec<_> = Vec::new();
 
-    let handle = serve_unix(socket_path, service.clone(), shutdown_rx1).await?;
+    let handle = serve_unix(
+        socket_path,
+        service.clone(),
+        service_v2.clone(),
+        health_service.clone(),
+        shutdown_rx1,
+    )
+    .await?;
     listeners.push(handle);
 
+    if let Some(remote) = config.remote() {
+        let handle = serve_tcp(
+            remote,
+            service.clone(),
+            service_v2.clone(),
+            health_service,
+            shutdown_rx5,
+        )?;
+        listeners.push(handle);
+    }
+
+    let health_monitor = HealthMonitor::new(bpfman.clone(), health_reporter);
+    listeners.push(tokio::spawn(async move {
+        health_monitor.run(shutdown_rx6).await
+    }));
+
+    let database = bpfman.database().clone();
+    listeners.push(tokio::spawn(
+        async move { database.run(shutdown_rx7).await },
+    ));
+
+    let interface_monitor = InterfaceMonitor::new(bpfman.clone());
+    listeners.push(tokio::spawn(async move {
+        interface_monitor.run(shutdown_rx4).await
+    }));
+
     if csi_support {
-        let storage_manager = StorageManager::new(db_lock.clone());
+        let storage_manager = StorageManager::new(bpfman.clone());
         let storage_manager_handle =
             tokio::spawn(async move { storage_manager.run(shutdown_rx3).await });
         let (_, res_storage, _) = join!(
@@ -99,9 +160,20 @@ async fn join_listeners(listeners: Vec<JoinHandle<()>>) {
     }
 }
 
+// Serves the bpfman.v1 and bpfman.v2 descriptors, so that tools such as grpcurl can discover
+// the services of bpfman-rpc.
+fn reflection_service() -> anyhow::Result<ServerReflectionServer<impl ServerReflection>> {
+    Ok(ReflectionBuilder::configure()
+        .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+        .build()?)
+}
+
 async fn serve_unix(
     path: &Path,
     service: BpfmanServer<BpfmanLoader>,
+    service_v2: BpfmanServerV2<BpfmanLoader>,
+    health: HealthServer<impl Health>,
     mut shutdown_channel: broadcast::Receiver<()>,
 ) -> anyhow::Result<JoinHandle<()>> {
     let uds_stream = if let Ok(stream) = systemd_unix_stream() {
@@ -112,6 +184,9 @@ async fn serve_unix(
 
     let serve = Server::builder()
         .add_service(service)
+        .add_service(service_v2)
+        .add_service(health)
+        .add_service(reflection_service()?)
         .serve_with_incoming_shutdown(uds_stream, async move {
             match shutdown_channel.recv().await {
                 Ok(()) => debug!("Unix Socket: Received shutdown signal"),
@@ -132,6 +207,47 @@ async fn serve_unix(
     }))
 }
 
+fn serve_tcp(
+    config: &RemoteConfig,
+    service: BpfmanServer<BpfmanLoader>,
+    service_v2: BpfmanServerV2<BpfmanLoader>,
+    health: HealthServer<impl Health>,
+    mut shutdown_channel: broadcast::Receiver<()>,
+) -> anyhow::Result<JoinHandle<()>> {
+    let read = |path: &Path| {
+        std::fs::read(path).with_context(|| format!("unable to read {}", path.display()))
+    };
+    // Clients must present a certificate signed by the client CA.
+    let tls = ServerTlsConfig::new()
+        .identity(Identity::from_pem(
+            read(&config.server_cert)?,
+            read(&config.server_key)?,
+        ))
+        .client_ca_root(Certificate::from_pem(read(&config.client_ca)?));
+
+    let address = config.address;
+    let serve = Server::builder()
+        .tls_config(tls)?
+        .add_service(service)
+        .add_service(service_v2)
+        .add_service(health)
+        .add_service(reflection_service()?)
+        .serve_with_shutdown(address, async move {
+            match shutdown_channel.recv().await {
+                Ok(()) => debug!("TCP Socket: Received shutdown signal"),
+                Err(e) => error!("Error receiving shutdown signal {:?}", e),
+            };
+        });
+
+    Ok(tokio::spawn(async move {
+        info!("Listening on {address} with mutual TLS");
+        if let Err(e) = serve.await {
+            eprintln!("Error = {e:?}");
+        }
+        info!("Shutdown TCP Handler {address}");
+    }))
+}
+
 fn systemd_unix_stream() -> anyhow::Result<UnixListenerStream> {
     let listen_fds = libsystemd::activation::receive_descriptors(true)?;
     if listen_fds.len() == 1 {
@@ -166,3 +282,210 @@ async fn std_unix_stream(path: &Path) -> anyhow::Result<UnixListenerStream> {
     info!("Using default Unix socket");
     Ok(stream)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{net::TcpListener, path::PathBuf, time::Duration};
+
+    use rcgen::{
+        BasicConstraints, Certificate as RcgenCertificate, CertificateParams,
+        ExtendedKeyUsagePurpose, IsCa, KeyPair,
+    };
+    use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
+    use tonic_health::pb::{HealthCheckRequest, health_client::HealthClient};
+    use tonic_reflection::pb::{
+        ServerReflectionRequest, server_reflection_client::ServerReflectionClient,
+        server_reflection_request::MessageRequest, server_reflection_response::MessageResponse,
+    };
+
+    use super::*;
+
+    struct Ca {
+        cert: RcgenCertificate,
+        key: KeyPair,
+    }
+
+    impl Ca {
+        fn new() -> Self {
+            let mut params = CertificateParams::new(vec![]).unwrap();
+            params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+            let key = KeyPair::generate().unwrap();
+            let cert = params.self_signed(&key).unwrap();
+            Ca { cert, key }
+        }
+
+        // Returns a PEM encoded certificate and key signed by the CA.
+        fn issue(&self, name: &str, usage: ExtendedKeyUsagePurpose) -> (String, String) {
+            let mut params = CertificateParams::new(vec![name.to_string()]).unwrap();
+            params.extended_key_usages = vec![usage];
+            let key = KeyPair::generate().unwrap();
+            let cert = params.signed_by(&key, &self.cert, &self.key).unwrap();
+            (cert.pem(), key.serialize_pem())
+        }
+    }
+
+    // Connects to a listener on `port`, optionally with a client certificate.
+    async fn connect(
+        port: u16,
+        ca: &Ca,
+        client: Option<&(String, String)>,
+    ) -> anyhow::Result<Channel> {
+        let mut tls = ClientTlsConfig::new()
+            .ca_certificate(Certificate::from_pem(ca.cert.pem()))
+            .domain_name("localhost");
+        if let Some((cert, key)) = client {
+            tls = tls.identity(Identity::from_pem(cert, key));
+        }
+        Ok(Endpoint::from_shared(format!("https://127.0.0.1:{port}"))?
+            .tls_config(tls)?
+            .connect_timeout(Duration::from_secs(5))
+            .connect()
+            .await?)
+    }
+
+    async fn health_check(
+        port: u16,
+        ca: &Ca,
+        client: Option<&(String, String)>,
+    ) -> anyhow::Result<()> {
+        HealthClient::new(connect(port, ca, client).await?)
+            .check(HealthCheckRequest {
+                service: String::new(),
+            })
+            .await?;
+        Ok(())
+    }
+
+    // A TCP listener of bpfman-rpc on a free port, which requires client
+    // certificates signed by its own client CA.
+    struct TcpServer {
+        port: u16,
+        server_ca: Ca,
+        shutdown_tx: broadcast::Sender<()>,
+        handle: JoinHandle<()>,
+        _dir: tempfile::TempDir,
+    }
+
+    impl TcpServer {
+        // Starts the listener and returns it with a client certificate and
+        // key that it accepts.
+        async fn start() -> (Self, (String, String)) {
+            let server_ca = Ca::new();
+            let client_ca = Ca::new();
+            let (server_cert, server_key) =
+                server_ca.issue("localhost", ExtendedKeyUsagePurpose::ServerAuth);
+
+            let dir = tempfile::tempdir().unwrap();
+            let write = |name: &str, contents: &str| -> PathBuf {
+                let path = dir.path().join(name);
+                std::fs::write(&path, contents).unwrap();
+                path
+            };
+            let port = TcpListener::bind("127.0.0.1:0")
+                .unwrap()
+                .local_addr()
+                .unwrap()
+                .port();
+            let config = RemoteConfig {
+                address: format!("127.0.0.1:{port}").parse().unwrap(),
+                server_cert: write("server.crt", &server_cert),
+                server_key: write("server.key", &server_key),
+                client_ca: write("ca.crt", &client_ca.cert.pem()),
+            };
+
+            let (shutdown_tx, shutdown_rx) = broadcast::channel(1);
+            let loader = Arc::new(BpfmanLoader::new(
+                Arc::new(AsyncBpfman::new()),
+                shutdown_tx.clone(),
+                None,
+            ));
+            let (_, health) = health_reporter();
+            let handle = serve_tcp(
+                &config,
+                BpfmanServer::from_arc(loader.clone()),
+                BpfmanServerV2::from_arc(loader),
+                health,
+                shutdown_rx,
+            )
+            .unwrap();
+
+            // Wait for the listener to accept connections.
+            let client = client_ca.issue("client", ExtendedKeyUsagePurpose::ClientAuth);
+            let mut accepted = health_check(port, &server_ca, Some(&client)).await;
+            for _ in 0..50 {
+                if accepted.is_ok() {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                accepted = health_check(port, &server_ca, Some(&client)).await;
+            }
+            assert!(accepted.is_ok(), "{accepted:?}");
+
+            let server = TcpServer {
+                port,
+                server_ca,
+                shutdown_tx,
+                handle,
+                _dir: dir,
+            };
+            (server, client)
+        }
+
+        async fn shutdown(self) {
+            self.shutdown_tx.send(()).unwrap();
+            self.handle.await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_serve_tcp_mutual_tls() {
+        let (server, client) = TcpServer::start().await;
+        let port = server.port;
+        let server_ca = &server.server_ca;
+        let other_ca = Ca::new();
+
+        assert!(health_check(port, server_ca, None).await.is_err());
+        let wrong = other_ca.issue("client", ExtendedKeyUsagePurpose::ClientAuth);
+        assert!(health_check(port, server_ca, Some(&wrong)).await.is_err());
+
+        // The rejected clients don't affect the next one.
+        assert!(health_check(port, server_ca, Some(&client)).await.is_ok());
+
+        server.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_serve_tcp_reflection() {
+        let (server, client) = TcpServer::start().await;
+        let channel = connect(server.port, &server.server_ca, Some(&client))
+            .await
+            .unwrap();
+
+        let request = ServerReflectionRequest {
+            host: String::new(),
+            message_request: Some(MessageRequest::ListServices(String::new())),
+        };
+        let mut responses = ServerReflectionClient::new(channel)
+            .server_reflection_info(tokio_stream::once(request))
+            .await
+            .unwrap()
+            .into_inner();
+        let response = responses.message().await.unwrap().unwrap();
+        let Some(MessageResponse::ListServicesResponse(list)) = response.message_response else {
+            panic!("unexpected reflection response {response:?}");
+        };
+        let mut services: Vec<_> = list.service.into_iter().map(|s| s.name).collect();
+        services.sort();
+        assert_eq!(
+            services,
+            vec![
+                "bpfman.v1.Bpfman",
+                "bpfman.v2.Bpfman",
+                "grpc.health.v1.Health",
+                "grpc.reflection.v1alpha.ServerReflection",
+            ]
+        );
+
+        server.shutdown().await;
+    }
+}
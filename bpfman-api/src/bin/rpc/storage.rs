@@ -31,12 +31,10 @@ use bpfman_csi::v1::{
 };
 use log::{debug, error, info, warn};
 use nix::mount::{MsFlags, mount, umount};
-use tokio::{
-    net::UnixListener,
-    sync::{Mutex, broadcast},
-};
+use tokio::{net::UnixListener, sync::broadcast};
 use tokio_stream::wrappers::UnixListenerStream;
 use tonic::{Request, Response, Status, transport::Server};
+use tonic_reflection::server::Builder as ReflectionBuilder;
 
 use crate::AsyncBpfman;
 
@@ -62,7 +60,7 @@ struct CsiIdentity {
 
 struct CsiNode {
     node_id: String,
-    db_lock: Arc<Mutex<AsyncBpfman>>,
+    bpfman: Arc<AsyncBpfman>,
 }
 
 #[async_trait]
@@ -144,7 +142,6 @@ impl Node for CsiNode {
                 fs_group: {fs_group:?}"
         );
 
-        let bpfman_lock = self.db_lock.lock().await;
         match (
             volume_context.get(MAPS_KEY),
             volume_context.get(PROGRAM_KEY),
@@ -153,7 +150,8 @@ impl Node for CsiNode {
                 let maps: Vec<&str> = m.split(',').collect();
 
                 // Find the Program with the specified *Program CRD name
-                let prog_data = bpfman_lock
+                let prog_data = self
+                    .bpfman
                     .list_programs(ListFilter::default())
                     .await
                     .map_err(|e| Status::aborted(format!("failed list programs: {e}")))?
@@ -170,6 +168,14 @@ impl Node for CsiNode {
                         format!("Bpfman Program {program_name} not found"),
                     ))?;
 
+                // Keep the program, and so its maps, from being unloaded while
+                // they are pinned for the pod.
+                let prog_id = prog_data
+                    .get_data()
+                    .get_id()
+                    .map_err(|e| Status::aborted(format!("failed to get program id: {e}")))?;
+                let _guard = self.bpfman.lock_program(prog_id).await;
+
                 let core_map_path = prog_data
                     .get_data()
                     .get_map_pin_path()
@@ -358,7 +364,7 @@ impl Node for CsiNode {
 }
 
 impl StorageManager {
-    pub fn new(db_lock: Arc<Mutex<AsyncBpfman>>) -> Self {
+    pub fn new(bpfman: Arc<AsyncBpfman>) -> Self {
         const VERSION: &str = env!("CARGO_PKG_VERSION");
         let node_id = std::env::var("KUBE_NODE_NAME")
             .expect("cannot start bpfman csi driver if KUBE_NODE_NAME not set");
@@ -368,7 +374,7 @@ impl StorageManager {
             version: VERSION.to_string(),
         };
 
-        let csi_node = CsiNode { node_id, db_lock };
+        let csi_node = CsiNode { node_id, bpfman };
 
         Self {
             csi_node,
@@ -395,9 +401,14 @@ impl StorageManager {
 
         let node_service = NodeServer::new(self.csi_node);
         let identity_service = IdentityServer::new(self.csi_identity);
+        let reflection_service = ReflectionBuilder::configure()
+            .register_encoded_file_descriptor_set(bpfman_csi::FILE_DESCRIPTOR_SET)
+            .build()
+            .expect("failed to build the CSI reflection service");
         let serve = Server::builder()
             .add_service(node_service)
             .add_service(identity_service)
+            .add_service(reflection_service)
             .serve_with_incoming_shutdown(uds_stream, async move {
                 match shutdown_channel.recv().await {
                     Ok(()) => debug!("Unix Socket: Received shutdown signal"),
@@ -0,0 +1,494 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+//! The bpfman.v2 service. It serves the same requests as bpfman.v1, but
+//! returns structured errors and the outcome of each program of a load.
+
+use bpfman::{errors::BpfmanError, types::Program};
+use bpfman_api::{
+    error_details::error_detail,
+    kernel_program_info, program_info,
+    v1::{
+        AttachBatchRequest, AttachBatchResponse, AttachRequest, AttachResponse, DetachBatchRequest,
+        DetachBatchResponse, DetachRequest, DetachResponse, DisableLinkRequest,
+        DisableLinkResponse, EnableLinkRequest, EnableLinkResponse, GetLinkRequest,
+        GetLinkResponse, GetRequest, GetResponse, ListLinksRequest, ListLinksResponse, ListRequest,
+        ListResponse, LoadRequest, LoadResponseInfo, PullBytecodeRequest, PullBytecodeResponse,
+        SetLinkPriorityRequest, SetLinkPriorityResponse, SetLinkReattachPolicyRequest,
+        SetLinkReattachPolicyResponse, UnloadRequest, UnloadResponse, VerifyRequest,
+        VerifyResponse, WatchRequest,
+    },
+    v2::{
+        ErrorDetail, ErrorReason, LoadResponse,
+        bpfman_server::Bpfman,
+        load_response::{ProgramResult, program_result::Outcome},
+    },
+};
+use log::error;
+use tonic::{Request, Response, Status};
+
+use crate::rpc::{BpfmanLoader, InvalidRequest, WatchStream};
+
+// Converts an error into a Status with the details of the error.
+fn error_status(e: anyhow::Error) -> Status {
+    let detail = if let Some(invalid) = e.downcast_ref::<InvalidRequest>() {
+        ErrorDetail {
+            field: Some(invalid.field.clone()),
+            ..ErrorDetail::new(ErrorReason::InvalidArgument, e.to_string())
+        }
+    } else if let Some(bpfman_error) = e.downcast_ref::<BpfmanError>() {
+        ErrorDetail {
+            message: e.to_string(),
+            ..error_detail(bpfman_error)
+        }
+    } else {
+        ErrorDetail::new(ErrorReason::Unspecified, e.to_string())
+    };
+    detail.into_status()
+}
+
+// Returns the outcome of each program of a load request, given the names of
+// the programs in the request and the result of loading them.
+fn load_response(
+    names: Vec<String>,
+    result: anyhow::Result<Vec<Program>>,
+) -> anyhow::Result<LoadResponse> {
+    let mut results: Vec<ProgramResult> = names
+        .into_iter()
+        .map(|name| ProgramResult {
+            name,
+            outcome: None,
+        })
+        .collect();
+
+    match result {
+        Ok(programs) => {
+            for (result, program) in results.iter_mut().zip(programs.iter()) {
+                let loaded = LoadResponseInfo {
+                    info: Some(program_info(program)?),
+                    kernel_info: Some(kernel_program_info(program)?),
+                };
+                result.outcome = Some(Outcome::Loaded(loaded));
+            }
+        }
+        Err(e) => {
+            let Some(BpfmanError::ProgramsLoadFailure(failures)) = e.downcast_ref::<BpfmanError>()
+            else {
+                return Err(e);
+            };
+            for failure in failures {
+                if let Some(result) = results.get_mut(failure.index) {
+                    result.outcome = Some(Outcome::Error(error_detail(&failure.error)));
+                }
+            }
+            // The programs that loaded were unloaded again.
+            for result in results.iter_mut().filter(|r| r.outcome.is_none()) {
+                result.outcome = Some(Outcome::Error(ErrorDetail::new(
+                    ErrorReason::Aborted,
+                    format!(
+                        "{} was not loaded because another program of the request failed to load",
+                        result.name
+                    ),
+                )));
+            }
+        }
+    }
+    Ok(LoadResponse { results })
+}
+
+#[tonic::async_trait]
+impl Bpfman for BpfmanLoader {
+    async fn load(&self, request: Request<LoadRequest>) -> Result<Response<LoadResponse>, Status> {
+        let names = request
+            .get_ref()
+            .info
+            .iter()
+            .map(|info| info.name.clone())
+            .collect();
+        let result = self.load_programs(request).await;
+        if let Err(e) = &result {
+            error!("Error in load: {e}");
+        }
+        load_response(names, result)
+            .map_err(error_status)
+            .map(Response::new)
+    }
+
+    async fn unload(
+        &self,
+        request: Request<UnloadRequest>,
+    ) -> Result<Response<UnloadResponse>, Status> {
+        self.do_unload(request)
+            .await
+            .map_err(|e| {
+                error!("Error in unload: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn get(&self, request: Request<GetRequest>) -> Result<Response<GetResponse>, Status> {
+        self.do_get(request)
+            .await
+            .map_err(|e| {
+                error!("Error in get: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn attach(
+        &self,
+        request: Request<AttachRequest>,
+    ) -> Result<Response<AttachResponse>, Status> {
+        self.do_attach(request)
+            .await
+            .map_err(|e| {
+                error!("Error in attach: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn detach(
+        &self,
+        request: Request<DetachRequest>,
+    ) -> Result<Response<DetachResponse>, Status> {
+        self.do_detach(request)
+            .await
+            .map_err(|e| {
+                error!("Error in detach: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn attach_batch(
+        &self,
+        request: Request<AttachBatchRequest>,
+    ) -> Result<Response<AttachBatchResponse>, Status> {
+        self.do_attach_batch(request)
+            .await
+            .map_err(|e| {
+                error!("Error in attach_batch: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn detach_batch(
+        &self,
+        request: Request<DetachBatchRequest>,
+    ) -> Result<Response<DetachBatchResponse>, Status> {
+        self.do_detach_batch(request)
+            .await
+            .map_err(|e| {
+                error!("Error in detach_batch: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn disable_link(
+        &self,
+        request: Request<DisableLinkRequest>,
+    ) -> Result<Response<DisableLinkResponse>, Status> {
+        self.do_disable_link(request)
+            .await
+            .map_err(|e| {
+                error!("Error in disable_link: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn enable_link(
+        &self,
+        request: Request<EnableLinkRequest>,
+    ) -> Result<Response<EnableLinkResponse>, Status> {
+        self.do_enable_link(request)
+            .await
+            .map_err(|e| {
+                error!("Error in enable_link: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn set_link_priority(
+        &self,
+        request: Request<SetLinkPriorityRequest>,
+    ) -> Result<Response<SetLinkPriorityResponse>, Status> {
+        self.do_set_link_priority(request)
+            .await
+            .map_err(|e| {
+                error!("Error in set_link_priority: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn set_link_reattach_policy(
+        &self,
+        request: Request<SetLinkReattachPolicyRequest>,
+    ) -> Result<Response<SetLinkReattachPolicyResponse>, Status> {
+        self.do_set_link_reattach_policy(request)
+            .await
+            .map_err(|e| {
+                error!("Error in set_link_reattach_policy: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn verify(
+        &self,
+        request: Request<VerifyRequest>,
+    ) -> Result<Response<VerifyResponse>, Status> {
+        self.do_verify(request)
+            .await
+            .map_err(|e| {
+                error!("Error in verify: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    type WatchStream = WatchStream;
+
+    async fn watch(
+        &self,
+        request: Request<WatchRequest>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        self.do_watch(request)
+            .await
+            .map_err(|e| {
+                error!("Error in watch: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn list(&self, request: Request<ListRequest>) -> Result<Response<ListResponse>, Status> {
+        self.do_list(request)
+            .await
+            .map_err(|e| {
+                error!("Error in list: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn list_links(
+        &self,
+        request: Request<ListLinksRequest>,
+    ) -> Result<Response<ListLinksResponse>, Status> {
+        self.do_list_links(request)
+            .await
+            .map_err(|e| {
+                error!("Error in list_links: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn get_link(
+        &self,
+        request: Request<GetLinkRequest>,
+    ) -> Result<Response<GetLinkResponse>, Status> {
+        self.do_get_link(request)
+            .await
+            .map_err(|e| {
+                error!("Error in get_link: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+
+    async fn pull_bytecode(
+        &self,
+        request: Request<PullBytecodeRequest>,
+    ) -> Result<Response<PullBytecodeResponse>, Status> {
+        self.do_pull_bytecode(request)
+            .await
+            .map_err(|e| {
+                error!("Error in pull_bytecode: {e}");
+                error_status(e)
+            })
+            .map(Response::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use bpfman::{config::Config, errors::ProgramLoadFailure};
+    use bpfman_api::v1::{AttachInfo, TracepointAttachInfo, attach_info::Info};
+    use tokio::sync::broadcast;
+
+    use super::*;
+    use crate::{AsyncBpfman, database::SharedDatabase, rpc::invalid_request};
+
+    // Returns a service backed by an empty database.
+    fn loader() -> BpfmanLoader {
+        let root_db = sled::Config::default().temporary(true).open().unwrap();
+        let database = SharedDatabase::open(Config::default(), root_db);
+        let (shutdown, _) = broadcast::channel(1);
+        BpfmanLoader::new(
+            Arc::new(AsyncBpfman::with_database(database)),
+            shutdown,
+            None,
+        )
+    }
+
+    fn assert_not_found(status: Status, field: &str) {
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        let detail = ErrorDetail::from_status(&status).unwrap();
+        assert_eq!(detail.reason(), ErrorReason::NotFound);
+        assert_eq!(detail.field.as_deref(), Some(field));
+    }
+
+    fn attach_request(validate_only: bool) -> AttachRequest {
+        AttachRequest {
+            id: 4242,
+            attach: Some(AttachInfo {
+                info: Some(Info::TracepointAttachInfo(TracepointAttachInfo {
+                    tracepoint: "syscalls/sys_enter_openat".to_string(),
+                    metadata: HashMap::new(),
+                })),
+            }),
+            validate_only,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unload_not_found() {
+        let request = UnloadRequest {
+            id: 4242,
+            ..Default::default()
+        };
+        let status = loader().unload(Request::new(request)).await.unwrap_err();
+        assert_not_found(status, "id");
+    }
+
+    #[tokio::test]
+    async fn test_attach_not_found() {
+        let status = loader()
+            .attach(Request::new(attach_request(false)))
+            .await
+            .unwrap_err();
+        assert_not_found(status, "id");
+    }
+
+    #[tokio::test]
+    async fn test_attach_validate_only_not_found() {
+        let status = loader()
+            .attach(Request::new(attach_request(true)))
+            .await
+            .unwrap_err();
+        assert_not_found(status, "id");
+    }
+
+    #[tokio::test]
+    async fn test_get_not_found() {
+        let request = GetRequest {
+            id: 4242,
+            ..Default::default()
+        };
+        let status = loader().get(Request::new(request)).await.unwrap_err();
+        assert_not_found(status, "id");
+    }
+
+    #[tokio::test]
+    async fn test_get_link_not_found() {
+        let request = GetLinkRequest {
+            link_id: 4242,
+            ..Default::default()
+        };
+        let status = loader().get_link(Request::new(request)).await.unwrap_err();
+        assert_not_found(status, "link_id");
+    }
+
+    #[tokio::test]
+    async fn test_detach_not_found() {
+        let request = DetachRequest {
+            link_id: 4242,
+            ..Default::default()
+        };
+        let status = loader().detach(Request::new(request)).await.unwrap_err();
+        assert_not_found(status, "link_id");
+    }
+
+    #[tokio::test]
+    async fn test_set_link_reattach_policy_invalid() {
+        let request = SetLinkReattachPolicyRequest {
+            link_id: 4242,
+            policy: "always".to_string(),
+        };
+        let status = loader()
+            .set_link_reattach_policy(Request::new(request))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        let detail = ErrorDetail::from_status(&status).unwrap();
+        assert_eq!(detail.reason(), ErrorReason::InvalidArgument);
+        assert_eq!(detail.field.as_deref(), Some("policy"));
+    }
+
+    #[test]
+    fn test_load_failure_response() {
+        let failure = BpfmanError::ProgramsLoadFailure(vec![ProgramLoadFailure {
+            index: 1,
+            name: "stats".to_string(),
+            error: BpfmanError::BpfFunctionNameNotValid("stats".to_string()),
+        }]);
+        let response = load_response(
+            vec!["pass".to_string(), "stats".to_string()],
+            Err(failure.into()),
+        )
+        .unwrap();
+
+        let reasons: Vec<(String, ErrorReason, Option<String>)> = response
+            .results
+            .into_iter()
+            .map(|r| match r.outcome {
+                Some(Outcome::Error(e)) => (r.name, e.reason(), e.field),
+                _ => panic!("{} should have failed", r.name),
+            })
+            .collect();
+        assert_eq!(
+            reasons,
+            vec![
+                ("pass".to_string(), ErrorReason::Aborted, None),
+                (
+                    "stats".to_string(),
+                    ErrorReason::NotFound,
+                    Some("info.name".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_error_status() {
+        let status = error_status(
+            BpfmanError::PolicyViolation {
+                rule: "allowed_registries".to_string(),
+                reason: "image is not from an allowed registry".to_string(),
+            }
+            .into(),
+        );
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+        let detail = ErrorDetail::from_status(&status).unwrap();
+        assert_eq!(detail.reason(), ErrorReason::PolicyViolation);
+        assert_eq!(detail.policy_rule.as_deref(), Some("allowed_registries"));
+
+        let status = error_status(invalid_request("attach", "missing attach_info"));
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        let detail = ErrorDetail::from_status(&status).unwrap();
+        assert_eq!(detail.field.as_deref(), Some("attach"));
+        assert_eq!(detail.message, "missing attach_info");
+    }
+}
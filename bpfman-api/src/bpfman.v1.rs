@@ -1,1051 +0,0 @@
-// This file is @generated by prost-build.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct BytecodeImage {
-    #[prost(string, tag = "1")]
-    pub url: ::prost::alloc::string::String,
-    #[prost(int32, tag = "2")]
-    pub image_pull_policy: i32,
-    #[prost(string, optional, tag = "3")]
-    pub username: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(string, optional, tag = "4")]
-    pub password: ::core::option::Option<::prost::alloc::string::String>,
-}
-/// BytecodeLocation is either:
-/// - Parameters to pull an eBPF program stored in an OCI container image.
-/// - Local file path for an image.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct BytecodeLocation {
-    #[prost(oneof = "bytecode_location::Location", tags = "2, 3")]
-    pub location: ::core::option::Option<bytecode_location::Location>,
-}
-/// Nested message and enum types in `BytecodeLocation`.
-pub mod bytecode_location {
-    #[allow(clippy::derive_partial_eq_without_eq)]
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Location {
-        #[prost(message, tag = "2")]
-        Image(super::BytecodeImage),
-        #[prost(string, tag = "3")]
-        File(::prost::alloc::string::String),
-    }
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct KernelProgramInfo {
-    /// Name as seen by the kernel. May be truncated version of user enter
-    #[prost(uint32, tag = "1")]
-    pub id: u32,
-    /// name since limited to 16 byte by kernel
-    #[prost(string, tag = "2")]
-    pub name: ::prost::alloc::string::String,
-    #[prost(uint32, tag = "3")]
-    pub program_type: u32,
-    #[prost(string, tag = "4")]
-    pub loaded_at: ::prost::alloc::string::String,
-    #[prost(string, tag = "5")]
-    pub tag: ::prost::alloc::string::String,
-    #[prost(bool, tag = "6")]
-    pub gpl_compatible: bool,
-    #[prost(uint32, repeated, tag = "7")]
-    pub map_ids: ::prost::alloc::vec::Vec<u32>,
-    #[prost(uint32, tag = "8")]
-    pub btf_id: u32,
-    #[prost(uint32, tag = "9")]
-    pub bytes_xlated: u32,
-    #[prost(bool, tag = "10")]
-    pub jited: bool,
-    #[prost(uint32, tag = "11")]
-    pub bytes_jited: u32,
-    #[prost(uint32, tag = "12")]
-    pub bytes_memlock: u32,
-    #[prost(uint32, tag = "13")]
-    pub verified_insns: u32,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ProgramInfo {
-    /// Name entered by user
-    #[prost(string, tag = "1")]
-    pub name: ::prost::alloc::string::String,
-    #[prost(message, optional, tag = "2")]
-    pub bytecode: ::core::option::Option<BytecodeLocation>,
-    #[prost(uint32, repeated, tag = "3")]
-    pub links: ::prost::alloc::vec::Vec<u32>,
-    #[prost(map = "string, bytes", tag = "4")]
-    pub global_data:
-        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::vec::Vec<u8>>,
-    #[prost(uint32, optional, tag = "5")]
-    pub map_owner_id: ::core::option::Option<u32>,
-    #[prost(string, tag = "6")]
-    pub map_pin_path: ::prost::alloc::string::String,
-    #[prost(string, repeated, tag = "7")]
-    pub map_used_by: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
-    #[prost(map = "string, string", tag = "8")]
-    pub metadata:
-        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct XdpAttachInfo {
-    #[prost(int32, tag = "1")]
-    pub priority: i32,
-    #[prost(string, tag = "2")]
-    pub iface: ::prost::alloc::string::String,
-    #[prost(int32, tag = "3")]
-    pub position: i32,
-    #[prost(int32, repeated, tag = "4")]
-    pub proceed_on: ::prost::alloc::vec::Vec<i32>,
-    #[prost(string, optional, tag = "5")]
-    pub netns: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(map = "string, string", tag = "6")]
-    pub metadata:
-        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TcAttachInfo {
-    #[prost(int32, tag = "1")]
-    pub priority: i32,
-    #[prost(string, tag = "2")]
-    pub iface: ::prost::alloc::string::String,
-    #[prost(int32, tag = "3")]
-    pub position: i32,
-    #[prost(string, tag = "4")]
-    pub direction: ::prost::alloc::string::String,
-    #[prost(int32, repeated, tag = "5")]
-    pub proceed_on: ::prost::alloc::vec::Vec<i32>,
-    #[prost(string, optional, tag = "6")]
-    pub netns: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(map = "string, string", tag = "7")]
-    pub metadata:
-        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TcxAttachInfo {
-    #[prost(int32, tag = "1")]
-    pub priority: i32,
-    #[prost(string, tag = "2")]
-    pub iface: ::prost::alloc::string::String,
-    #[prost(int32, tag = "3")]
-    pub position: i32,
-    #[prost(string, tag = "4")]
-    pub direction: ::prost::alloc::string::String,
-    #[prost(string, optional, tag = "5")]
-    pub netns: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(map = "string, string", tag = "6")]
-    pub metadata:
-        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TracepointAttachInfo {
-    #[prost(string, tag = "1")]
-    pub tracepoint: ::prost::alloc::string::String,
-    #[prost(map = "string, string", tag = "2")]
-    pub metadata:
-        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct KprobeAttachInfo {
-    #[prost(string, tag = "1")]
-    pub fn_name: ::prost::alloc::string::String,
-    #[prost(uint64, tag = "2")]
-    pub offset: u64,
-    #[prost(int32, optional, tag = "3")]
-    pub container_pid: ::core::option::Option<i32>,
-    #[prost(map = "string, string", tag = "4")]
-    pub metadata:
-        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UprobeAttachInfo {
-    #[prost(string, optional, tag = "1")]
-    pub fn_name: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(uint64, tag = "2")]
-    pub offset: u64,
-    #[prost(string, tag = "3")]
-    pub target: ::prost::alloc::string::String,
-    #[prost(int32, optional, tag = "4")]
-    pub pid: ::core::option::Option<i32>,
-    #[prost(int32, optional, tag = "5")]
-    pub container_pid: ::core::option::Option<i32>,
-    #[prost(map = "string, string", tag = "6")]
-    pub metadata:
-        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct FentryAttachInfo {
-    #[prost(map = "string, string", tag = "1")]
-    pub metadata:
-        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct FexitAttachInfo {
-    #[prost(map = "string, string", tag = "1")]
-    pub metadata:
-        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AttachInfo {
-    #[prost(oneof = "attach_info::Info", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
-    pub info: ::core::option::Option<attach_info::Info>,
-}
-/// Nested message and enum types in `AttachInfo`.
-pub mod attach_info {
-    #[allow(clippy::derive_partial_eq_without_eq)]
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Info {
-        #[prost(message, tag = "1")]
-        XdpAttachInfo(super::XdpAttachInfo),
-        #[prost(message, tag = "2")]
-        TcAttachInfo(super::TcAttachInfo),
-        #[prost(message, tag = "3")]
-        TracepointAttachInfo(super::TracepointAttachInfo),
-        #[prost(message, tag = "4")]
-        KprobeAttachInfo(super::KprobeAttachInfo),
-        #[prost(message, tag = "5")]
-        UprobeAttachInfo(super::UprobeAttachInfo),
-        #[prost(message, tag = "6")]
-        TcxAttachInfo(super::TcxAttachInfo),
-        #[prost(message, tag = "7")]
-        FentryAttachInfo(super::FentryAttachInfo),
-        #[prost(message, tag = "8")]
-        FexitAttachInfo(super::FexitAttachInfo),
-    }
-}
-/// LoadRequest represents a request to load and attach a bpf program.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct LoadRequest {
-    #[prost(message, optional, tag = "1")]
-    pub bytecode: ::core::option::Option<BytecodeLocation>,
-    #[prost(map = "string, string", tag = "5")]
-    pub metadata:
-        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
-    #[prost(map = "string, bytes", tag = "6")]
-    pub global_data:
-        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::vec::Vec<u8>>,
-    #[prost(string, optional, tag = "7")]
-    pub uuid: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(uint32, optional, tag = "8")]
-    pub map_owner_id: ::core::option::Option<u32>,
-    #[prost(message, repeated, tag = "9")]
-    pub info: ::prost::alloc::vec::Vec<LoadInfo>,
-}
-/// LoadInfo contains per-program information for LoadRequest.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct LoadInfo {
-    #[prost(string, tag = "1")]
-    pub name: ::prost::alloc::string::String,
-    #[prost(enumeration = "BpfmanProgramType", tag = "2")]
-    pub program_type: i32,
-    #[prost(message, optional, tag = "3")]
-    pub info: ::core::option::Option<ProgSpecificInfo>,
-}
-/// FentryLoadInfo contains the program-specific load information for LoadInfo.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct FentryLoadInfo {
-    #[prost(string, tag = "1")]
-    pub fn_name: ::prost::alloc::string::String,
-}
-/// FexitLoadInfo contains the program-specific load information for LoadInfo.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct FexitLoadInfo {
-    #[prost(string, tag = "1")]
-    pub fn_name: ::prost::alloc::string::String,
-}
-/// ProgSpecificInfo contains the program-specific load information for LoadInfo.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ProgSpecificInfo {
-    #[prost(oneof = "prog_specific_info::Info", tags = "1, 2")]
-    pub info: ::core::option::Option<prog_specific_info::Info>,
-}
-/// Nested message and enum types in `ProgSpecificInfo`.
-pub mod prog_specific_info {
-    #[allow(clippy::derive_partial_eq_without_eq)]
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Info {
-        #[prost(message, tag = "1")]
-        FentryLoadInfo(super::FentryLoadInfo),
-        #[prost(message, tag = "2")]
-        FexitLoadInfo(super::FexitLoadInfo),
-    }
-}
-/// LoadResponseInfo represents the state for a single eBPF program that is maintained
-/// internally by bpfman.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct LoadResponseInfo {
-    #[prost(message, optional, tag = "1")]
-    pub info: ::core::option::Option<ProgramInfo>,
-    #[prost(message, optional, tag = "2")]
-    pub kernel_info: ::core::option::Option<KernelProgramInfo>,
-}
-/// LoadResponse represents a response from loading and attaching an eBPF program.
-/// It includes all of the state kept by bpfman and the Linux kernel for the
-/// program.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct LoadResponse {
-    #[prost(message, repeated, tag = "1")]
-    pub programs: ::prost::alloc::vec::Vec<LoadResponseInfo>,
-}
-/// UnloadRequest represents a request to unload an eBPF program that was loaded
-/// and attached by bpfman. To identify a program pass in a valid kernel ID.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UnloadRequest {
-    #[prost(uint32, tag = "1")]
-    pub id: u32,
-}
-/// UnloadResponse represents a response from unloading an eBPF program.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UnloadResponse {}
-/// AttachRequest represents a request to attach an eBPF program that was loaded
-/// by bpfman. To identify a program pass in a valid kernel ID.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AttachRequest {
-    #[prost(uint32, tag = "1")]
-    pub id: u32,
-    #[prost(message, optional, tag = "2")]
-    pub attach: ::core::option::Option<AttachInfo>,
-}
-/// AttachResponse represents a response from attaching an eBPF program.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AttachResponse {
-    #[prost(uint32, tag = "1")]
-    pub link_id: u32,
-}
-/// DetachRequest represents a request to detach an eBPF program that was loaded
-/// and attached by bpfman. To identify a program pass in a valid kernel ID.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DetachRequest {
-    #[prost(uint32, tag = "1")]
-    pub link_id: u32,
-}
-/// DetachResponse represents a response from detaching an eBPF program.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DetachResponse {}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ListRequest {
-    #[prost(uint32, optional, tag = "1")]
-    pub program_type: ::core::option::Option<u32>,
-    #[prost(bool, optional, tag = "2")]
-    pub bpfman_programs_only: ::core::option::Option<bool>,
-    #[prost(map = "string, string", tag = "3")]
-    pub match_metadata:
-        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ListResponse {
-    #[prost(message, repeated, tag = "3")]
-    pub results: ::prost::alloc::vec::Vec<list_response::ListResult>,
-}
-/// Nested message and enum types in `ListResponse`.
-pub mod list_response {
-    #[allow(clippy::derive_partial_eq_without_eq)]
-    #[derive(Clone, PartialEq, ::prost::Message)]
-    pub struct ListResult {
-        #[prost(message, optional, tag = "1")]
-        pub info: ::core::option::Option<super::ProgramInfo>,
-        #[prost(message, optional, tag = "2")]
-        pub kernel_info: ::core::option::Option<super::KernelProgramInfo>,
-    }
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PullBytecodeRequest {
-    #[prost(message, optional, tag = "1")]
-    pub image: ::core::option::Option<BytecodeImage>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PullBytecodeResponse {}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetRequest {
-    #[prost(uint32, tag = "1")]
-    pub id: u32,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetResponse {
-    #[prost(message, optional, tag = "1")]
-    pub info: ::core::option::Option<ProgramInfo>,
-    #[prost(message, optional, tag = "2")]
-    pub kernel_info: ::core::option::Option<KernelProgramInfo>,
-}
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
-#[repr(i32)]
-pub enum BpfmanProgramType {
-    Xdp = 0,
-    Tc = 1,
-    Tracepoint = 2,
-    Kprobe = 3,
-    Uprobe = 4,
-    Fentry = 5,
-    Fexit = 6,
-    Tcx = 7,
-}
-impl BpfmanProgramType {
-    /// String value of the enum field names used in the ProtoBuf definition.
-    ///
-    /// The values are not transformed in any way and thus are considered stable
-    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
-    pub fn as_str_name(&self) -> &'static str {
-        match self {
-            BpfmanProgramType::Xdp => "XDP",
-            BpfmanProgramType::Tc => "TC",
-            BpfmanProgramType::Tracepoint => "TRACEPOINT",
-            BpfmanProgramType::Kprobe => "KPROBE",
-            BpfmanProgramType::Uprobe => "UPROBE",
-            BpfmanProgramType::Fentry => "FENTRY",
-            BpfmanProgramType::Fexit => "FEXIT",
-            BpfmanProgramType::Tcx => "TCX",
-        }
-    }
-    /// Creates an enum from field names used in the ProtoBuf definition.
-    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
-        match value {
-            "XDP" => Some(Self::Xdp),
-            "TC" => Some(Self::Tc),
-            "TRACEPOINT" => Some(Self::Tracepoint),
-            "KPROBE" => Some(Self::Kprobe),
-            "UPROBE" => Some(Self::Uprobe),
-            "FENTRY" => Some(Self::Fentry),
-            "FEXIT" => Some(Self::Fexit),
-            "TCX" => Some(Self::Tcx),
-            _ => None,
-        }
-    }
-}
-/// Generated client implementations.
-pub mod bpfman_client {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::http::Uri;
-    use tonic::codegen::*;
-    #[derive(Debug, Clone)]
-    pub struct BpfmanClient<T> {
-        inner: tonic::client::Grpc<T>,
-    }
-    impl<T> BpfmanClient<T>
-    where
-        T: tonic::client::GrpcService<tonic::body::BoxBody>,
-        T::Error: Into<StdError>,
-        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
-        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
-    {
-        pub fn new(inner: T) -> Self {
-            let inner = tonic::client::Grpc::new(inner);
-            Self { inner }
-        }
-        pub fn with_origin(inner: T, origin: Uri) -> Self {
-            let inner = tonic::client::Grpc::with_origin(inner, origin);
-            Self { inner }
-        }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> BpfmanClient<InterceptedService<T, F>>
-        where
-            F: tonic::service::Interceptor,
-            T::ResponseBody: Default,
-            T: tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-                Response = http::Response<
-                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
-                >,
-            >,
-            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
-                Into<StdError> + Send + Sync,
-        {
-            BpfmanClient::new(InterceptedService::new(inner, interceptor))
-        }
-        /// Compress requests with the given encoding.
-        ///
-        /// This requires the server to support it otherwise it might respond with an
-        /// error.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.send_compressed(encoding);
-            self
-        }
-        /// Enable decompressing responses.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.accept_compressed(encoding);
-            self
-        }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_decoding_message_size(limit);
-            self
-        }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_encoding_message_size(limit);
-            self
-        }
-        pub async fn load(
-            &mut self,
-            request: impl tonic::IntoRequest<super::LoadRequest>,
-        ) -> std::result::Result<tonic::Response<super::LoadResponse>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::new(
-                    tonic::Code::Unknown,
-                    format!("Service was not ready: {}", e.into()),
-                )
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Load");
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "Load"));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn unload(
-            &mut self,
-            request: impl tonic::IntoRequest<super::UnloadRequest>,
-        ) -> std::result::Result<tonic::Response<super::UnloadResponse>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::new(
-                    tonic::Code::Unknown,
-                    format!("Service was not ready: {}", e.into()),
-                )
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Unload");
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "Unload"));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn attach(
-            &mut self,
-            request: impl tonic::IntoRequest<super::AttachRequest>,
-        ) -> std::result::Result<tonic::Response<super::AttachResponse>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::new(
-                    tonic::Code::Unknown,
-                    format!("Service was not ready: {}", e.into()),
-                )
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Attach");
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "Attach"));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn detach(
-            &mut self,
-            request: impl tonic::IntoRequest<super::DetachRequest>,
-        ) -> std::result::Result<tonic::Response<super::DetachResponse>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::new(
-                    tonic::Code::Unknown,
-                    format!("Service was not ready: {}", e.into()),
-                )
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Detach");
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "Detach"));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn list(
-            &mut self,
-            request: impl tonic::IntoRequest<super::ListRequest>,
-        ) -> std::result::Result<tonic::Response<super::ListResponse>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::new(
-                    tonic::Code::Unknown,
-                    format!("Service was not ready: {}", e.into()),
-                )
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/List");
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "List"));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn pull_bytecode(
-            &mut self,
-            request: impl tonic::IntoRequest<super::PullBytecodeRequest>,
-        ) -> std::result::Result<tonic::Response<super::PullBytecodeResponse>, tonic::Status>
-        {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::new(
-                    tonic::Code::Unknown,
-                    format!("Service was not ready: {}", e.into()),
-                )
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/PullBytecode");
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "PullBytecode"));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn get(
-            &mut self,
-            request: impl tonic::IntoRequest<super::GetRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::new(
-                    tonic::Code::Unknown,
-                    format!("Service was not ready: {}", e.into()),
-                )
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Get");
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "Get"));
-            self.inner.unary(req, path, codec).await
-        }
-    }
-}
-/// Generated server implementations.
-pub mod bpfman_server {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with BpfmanServer.
-    #[async_trait]
-    pub trait Bpfman: Send + Sync + 'static {
-        async fn load(
-            &self,
-            request: tonic::Request<super::LoadRequest>,
-        ) -> std::result::Result<tonic::Response<super::LoadResponse>, tonic::Status>;
-        async fn unload(
-            &self,
-            request: tonic::Request<super::UnloadRequest>,
-        ) -> std::result::Result<tonic::Response<super::UnloadResponse>, tonic::Status>;
-        async fn attach(
-            &self,
-            request: tonic::Request<super::AttachRequest>,
-        ) -> std::result::Result<tonic::Response<super::AttachResponse>, tonic::Status>;
-        async fn detach(
-            &self,
-            request: tonic::Request<super::DetachRequest>,
-        ) -> std::result::Result<tonic::Response<super::DetachResponse>, tonic::Status>;
-        async fn list(
-            &self,
-            request: tonic::Request<super::ListRequest>,
-        ) -> std::result::Result<tonic::Response<super::ListResponse>, tonic::Status>;
-        async fn pull_bytecode(
-            &self,
-            request: tonic::Request<super::PullBytecodeRequest>,
-        ) -> std::result::Result<tonic::Response<super::PullBytecodeResponse>, tonic::Status>;
-        async fn get(
-            &self,
-            request: tonic::Request<super::GetRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status>;
-    }
-    #[derive(Debug)]
-    pub struct BpfmanServer<T: Bpfman> {
-        inner: _Inner<T>,
-        accept_compression_encodings: EnabledCompressionEncodings,
-        send_compression_encodings: EnabledCompressionEncodings,
-        max_decoding_message_size: Option<usize>,
-        max_encoding_message_size: Option<usize>,
-    }
-    struct _Inner<T>(Arc<T>);
-    impl<T: Bpfman> BpfmanServer<T> {
-        pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
-        }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            let inner = _Inner(inner);
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-                max_decoding_message_size: None,
-                max_encoding_message_size: None,
-            }
-        }
-        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
-        where
-            F: tonic::service::Interceptor,
-        {
-            InterceptedService::new(Self::new(inner), interceptor)
-        }
-        /// Enable decompressing requests with the given encoding.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.accept_compression_encodings.enable(encoding);
-            self
-        }
-        /// Compress responses with the given encoding, if the client supports it.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.send_compression_encodings.enable(encoding);
-            self
-        }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.max_decoding_message_size = Some(limit);
-            self
-        }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.max_encoding_message_size = Some(limit);
-            self
-        }
-    }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for BpfmanServer<T>
-    where
-        T: Bpfman,
-        B: Body + Send + 'static,
-        B::Error: Into<StdError> + Send + 'static,
-    {
-        type Response = http::Response<tonic::body::BoxBody>;
-        type Error = std::convert::Infallible;
-        type Future = BoxFuture<Self::Response, Self::Error>;
-        fn poll_ready(
-            &mut self,
-            _cx: &mut Context<'_>,
-        ) -> Poll<std::result::Result<(), Self::Error>> {
-            Poll::Ready(Ok(()))
-        }
-        fn call(&mut self, req: http::Request<B>) -> Self::Future {
-            let inner = self.inner.clone();
-            match req.uri().path() {
-                "/bpfman.v1.Bpfman/Load" => {
-                    #[allow(non_camel_case_types)]
-                    struct LoadSvc<T: Bpfman>(pub Arc<T>);
-                    impl<T: Bpfman> tonic::server::UnaryService<super::LoadRequest> for LoadSvc<T> {
-                        type Response = super::LoadResponse;
-                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::LoadRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move { <T as Bpfman>::load(&inner, request).await };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = LoadSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/bpfman.v1.Bpfman/Unload" => {
-                    #[allow(non_camel_case_types)]
-                    struct UnloadSvc<T: Bpfman>(pub Arc<T>);
-                    impl<T: Bpfman> tonic::server::UnaryService<super::UnloadRequest> for UnloadSvc<T> {
-                        type Response = super::UnloadResponse;
-                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::UnloadRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move { <T as Bpfman>::unload(&inner, request).await };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = UnloadSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/bpfman.v1.Bpfman/Attach" => {
-                    #[allow(non_camel_case_types)]
-                    struct AttachSvc<T: Bpfman>(pub Arc<T>);
-                    impl<T: Bpfman> tonic::server::UnaryService<super::AttachRequest> for AttachSvc<T> {
-                        type Response = super::AttachResponse;
-                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::AttachRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move { <T as Bpfman>::attach(&inner, request).await };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = AttachSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/bpfman.v1.Bpfman/Detach" => {
-                    #[allow(non_camel_case_types)]
-                    struct DetachSvc<T: Bpfman>(pub Arc<T>);
-                    impl<T: Bpfman> tonic::server::UnaryService<super::DetachRequest> for DetachSvc<T> {
-                        type Response = super::DetachResponse;
-                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::DetachRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move { <T as Bpfman>::detach(&inner, request).await };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = DetachSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/bpfman.v1.Bpfman/List" => {
-                    #[allow(non_camel_case_types)]
-                    struct ListSvc<T: Bpfman>(pub Arc<T>);
-                    impl<T: Bpfman> tonic::server::UnaryService<super::ListRequest> for ListSvc<T> {
-                        type Response = super::ListResponse;
-                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::ListRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move { <T as Bpfman>::list(&inner, request).await };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = ListSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/bpfman.v1.Bpfman/PullBytecode" => {
-                    #[allow(non_camel_case_types)]
-                    struct PullBytecodeSvc<T: Bpfman>(pub Arc<T>);
-                    impl<T: Bpfman> tonic::server::UnaryService<super::PullBytecodeRequest> for PullBytecodeSvc<T> {
-                        type Response = super::PullBytecodeResponse;
-                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::PullBytecodeRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut =
-                                async move { <T as Bpfman>::pull_bytecode(&inner, request).await };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = PullBytecodeSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/bpfman.v1.Bpfman/Get" => {
-                    #[allow(non_camel_case_types)]
-                    struct GetSvc<T: Bpfman>(pub Arc<T>);
-                    impl<T: Bpfman> tonic::server::UnaryService<super::GetRequest> for GetSvc<T> {
-                        type Response = super::GetResponse;
-                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::GetRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move { <T as Bpfman>::get(&inner, request).await };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = GetSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                _ => Box::pin(async move {
-                    Ok(http::Response::builder()
-                        .status(200)
-                        .header("grpc-status", "12")
-                        .header("content-type", "application/grpc")
-                        .body(empty_body())
-                        .unwrap())
-                }),
-            }
-        }
-    }
-    impl<T: Bpfman> Clone for BpfmanServer<T> {
-        fn clone(&self) -> Self {
-            let inner = self.inner.clone();
-            Self {
-                inner,
-                accept_compression_encodings: self.accept_compression_encodings,
-                send_compression_encodings: self.send_compression_encodings,
-                max_decoding_message_size: self.max_decoding_message_size,
-                max_encoding_message_size: self.max_encoding_message_size,
-            }
-        }
-    }
-    impl<T: Bpfman> Clone for _Inner<T> {
-        fn clone(&self) -> Self {
-            Self(Arc::clone(&self.0))
-        }
-    }
-    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            write!(f, "{:?}", self.0)
-        }
-    }
-    impl<T: Bpfman> tonic::server::NamedService for BpfmanServer<T> {
-        const NAME: &'static str = "bpfman.v1.Bpfman";
-    }
-}
@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+//! The structured errors returned by the `bpfman.v2` service. Each error is a
+//! gRPC status whose details hold a `google.rpc.Status` with a single
+//! [`ErrorDetail`], as described by the gRPC richer error model.
+
+use std::io::ErrorKind;
+
+use aya::programs::ProgramError;
+use bpfman::errors::{BpfmanError, ImageError, ParseError};
+
+use crate::v2::{ErrorDetail, ErrorReason};
+
+/// Returns the structured error for an error of the bpfman library.
+pub fn error_detail(e: &BpfmanError) -> ErrorDetail {
+    let detail = |reason| ErrorDetail::new(reason, e.to_string());
+    match e {
+        BpfmanError::Error(_) => detail(ErrorReason::Unspecified),
+        BpfmanError::BpfIOError(io) => detail(match io.kind() {
+            ErrorKind::NotFound => ErrorReason::NotFound,
+            ErrorKind::PermissionDenied => ErrorReason::PermissionDenied,
+            ErrorKind::AlreadyExists => ErrorReason::AlreadyExists,
+            _ => ErrorReason::Internal,
+        }),
+        BpfmanError::BpfProgramError(ProgramError::LoadError { verifier_log, .. }) => ErrorDetail {
+            verifier_log: Some(verifier_log.to_string()),
+            ..detail(ErrorReason::VerifierRejected)
+        },
+        BpfmanError::BpfProgramError(_) => detail(ErrorReason::Internal),
+        // The log is in its own field, so it is left out of the message.
+        BpfmanError::VerifierRejected {
+            program,
+            io_error,
+            verifier_log,
+        } => ErrorDetail {
+            verifier_log: Some(verifier_log.clone()),
+            ..ErrorDetail::new(
+                ErrorReason::VerifierRejected,
+                format!("program {program} was rejected by the verifier: {io_error}"),
+            )
+        },
+        BpfmanError::BpfLoadError(_) => detail(ErrorReason::BytecodeInvalid),
+        BpfmanError::BpfFunctionNameNotValid(_) | BpfmanError::ProgramNotFoundInBytecode { .. } => {
+            detail(ErrorReason::NotFound).with_field("info.name")
+        }
+        BpfmanError::BytecodeMetaDataMismatch { .. } => {
+            detail(ErrorReason::InvalidArgument).with_field("info.name")
+        }
+        BpfmanError::TooManyPrograms => detail(ErrorReason::ResourceExhausted),
+        BpfmanError::InvalidInterface
+        | BpfmanError::InvalidAttach(_)
+        | BpfmanError::InvalidDirection => {
+            detail(ErrorReason::InvalidArgument).with_field("attach")
+        }
+        BpfmanError::InvalidPriority(_) | BpfmanError::InvalidBundle(_) => {
+            detail(ErrorReason::InvalidArgument)
+        }
+        BpfmanError::BpfBytecodeError(image) => ErrorDetail {
+            message: e.to_string(),
+            ..image_error_detail(image)
+        },
+        BpfmanError::BpfParseError(parse) => ErrorDetail {
+            message: e.to_string(),
+            ..parse_error_detail(parse)
+        },
+        BpfmanError::ProgramsLoadFailure(failures) => match failures.first() {
+            Some(failure) => ErrorDetail {
+                message: e.to_string(),
+                ..error_detail(&failure.error)
+            },
+            None => detail(ErrorReason::Internal),
+        },
+        BpfmanError::ProgramNotFound(_) => detail(ErrorReason::NotFound).with_field("id"),
+        BpfmanError::LinkNotFound(_) => detail(ErrorReason::NotFound).with_field("link_id"),
+        BpfmanError::LinkGroupNotFound(_) => detail(ErrorReason::NotFound),
+        BpfmanError::LinkGroupExists(_) => detail(ErrorReason::AlreadyExists),
+        BpfmanError::AliasNotFound { .. } => detail(ErrorReason::NotFound).with_field("alias"),
+        BpfmanError::AliasInUse { .. } => detail(ErrorReason::AlreadyExists).with_field("metadata"),
+        BpfmanError::EventsCompacted { .. } => {
+            detail(ErrorReason::OutOfRange).with_field("since_revision")
+        }
+        BpfmanError::PermissionDenied(_) => detail(ErrorReason::PermissionDenied),
+        BpfmanError::PolicyViolation { rule, .. } => ErrorDetail {
+            policy_rule: Some(rule.clone()),
+            ..detail(ErrorReason::PolicyViolation)
+        },
+        BpfmanError::DatabaseLockError => detail(ErrorReason::Unavailable),
+        BpfmanError::UnableToPinLink(_)
+        | BpfmanError::UnableToPinProgram(_)
+        | BpfmanError::UnableToPinMap(_)
+        | BpfmanError::NotLoaded
+        | BpfmanError::DispatcherNotRequired
+        | BpfmanError::BpfmanProgramDeleteError(_)
+        | BpfmanError::RpcRecvError(_)
+        | BpfmanError::RpcSendError(_)
+        | BpfmanError::ContainerAttachError { .. }
+        | BpfmanError::DatabaseError(_, _)
+        | BpfmanError::InternalError(_)
+        | BpfmanError::BtfError(_)
+        | BpfmanError::DispatcherLoadError(_)
+        | BpfmanError::InvalidTreeName(_)
+        | BpfmanError::BpfLinkError(_) => detail(ErrorReason::Internal),
+    }
+}
+
+fn image_error_detail(e: &ImageError) -> ErrorDetail {
+    let detail = |reason| ErrorDetail::new(reason, e.to_string());
+    match e {
+        ImageError::InvalidImageUrl(_) => {
+            detail(ErrorReason::InvalidArgument).with_field("bytecode.image.url")
+        }
+        ImageError::ByteCodeImageNotfound(_) => {
+            detail(ErrorReason::NotFound).with_field("bytecode.image.url")
+        }
+        ImageError::ImageManifestPullFailure(_) | ImageError::BytecodeImagePullFailure(_) => {
+            detail(ErrorReason::ImagePullFailed)
+        }
+        ImageError::BytecodeImageExtractFailure(_)
+        | ImageError::ByteCodeImageProcessFailure(_)
+        | ImageError::BytecodeImageParseFailure(_, _) => detail(ErrorReason::BytecodeInvalid),
+        ImageError::DatabaseError(_, _) | ImageError::JoinError(_) => detail(ErrorReason::Internal),
+    }
+}
+
+fn parse_error_detail(e: &ParseError) -> ErrorDetail {
+    let field = match e {
+        ParseError::InvalidProgramType { .. } => Some("program_type"),
+        ParseError::InvalidProceedOn { .. } => Some("proceed_on"),
+        ParseError::InvalidDirection { .. } => Some("direction"),
+        ParseError::BytecodeLocationParseFailure(_)
+        | ParseError::InvalidBytecodeLocation { .. } => Some("bytecode"),
+        ParseError::InvalidBytecodeImagePullPolicy { .. } => {
+            Some("bytecode.image.image_pull_policy")
+        }
+        ParseError::InvalidMetadataSelector { .. } => Some("metadata_selector"),
+        ParseError::InvalidNameRegex { .. } => Some("name_regex"),
+        ParseError::InvalidListSortKey { .. } => Some("sort_by"),
+        ParseError::InvalidPageToken { .. } => Some("page_token"),
+        ParseError::InvalidReattachPolicy { .. } => Some("policy"),
+        // The configuration of bpfman is invalid, not the request.
+        ParseError::ConfigParseError(_) => {
+            return ErrorDetail::new(ErrorReason::Internal, e.to_string());
+        }
+        ParseError::InvalidMapType { .. }
+        | ParseError::InvalidProbeType { .. }
+        | ParseError::InvalidXdpMode { .. }
+        | ParseError::InvalidLinkAction { .. }
+        | ParseError::InvalidAttachType { .. }
+        | ParseError::InvalidInterfaceSelector { .. }
+        | ParseError::InvalidIdOrAlias { .. } => None,
+    };
+    ErrorDetail {
+        field: field.map(|f| f.to_string()),
+        ..ErrorDetail::new(ErrorReason::InvalidArgument, e.to_string())
+    }
+}
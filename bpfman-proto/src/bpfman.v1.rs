@@ -0,0 +1,2202 @@
+// This file is @generated by prost-build.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BytecodeImage {
+    #[prost(string, tag = "1")]
+    pub url: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub image_pull_policy: i32,
+    #[prost(string, optional, tag = "3")]
+    pub username: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "4")]
+    pub password: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// BytecodeLocation is either:
+/// - Parameters to pull an eBPF program stored in an OCI container image.
+/// - Local file path for an image.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BytecodeLocation {
+    #[prost(oneof = "bytecode_location::Location", tags = "2, 3")]
+    pub location: ::core::option::Option<bytecode_location::Location>,
+}
+/// Nested message and enum types in `BytecodeLocation`.
+pub mod bytecode_location {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Location {
+        #[prost(message, tag = "2")]
+        Image(super::BytecodeImage),
+        #[prost(string, tag = "3")]
+        File(::prost::alloc::string::String),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KernelProgramInfo {
+    /// Name as seen by the kernel. May be truncated version of user enter
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    /// name since limited to 16 byte by kernel
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "3")]
+    pub program_type: u32,
+    #[prost(string, tag = "4")]
+    pub loaded_at: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub tag: ::prost::alloc::string::String,
+    #[prost(bool, tag = "6")]
+    pub gpl_compatible: bool,
+    #[prost(uint32, repeated, tag = "7")]
+    pub map_ids: ::prost::alloc::vec::Vec<u32>,
+    #[prost(uint32, tag = "8")]
+    pub btf_id: u32,
+    #[prost(uint32, tag = "9")]
+    pub bytes_xlated: u32,
+    #[prost(bool, tag = "10")]
+    pub jited: bool,
+    #[prost(uint32, tag = "11")]
+    pub bytes_jited: u32,
+    #[prost(uint32, tag = "12")]
+    pub bytes_memlock: u32,
+    #[prost(uint32, tag = "13")]
+    pub verified_insns: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProgramInfo {
+    /// Name entered by user
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub bytecode: ::core::option::Option<BytecodeLocation>,
+    #[prost(uint32, repeated, tag = "3")]
+    pub links: ::prost::alloc::vec::Vec<u32>,
+    #[prost(map = "string, bytes", tag = "4")]
+    pub global_data:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, optional, tag = "5")]
+    pub map_owner_id: ::core::option::Option<u32>,
+    #[prost(string, tag = "6")]
+    pub map_pin_path: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "7")]
+    pub map_used_by: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(map = "string, string", tag = "8")]
+    pub metadata:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    /// Summary of the verification of the program when it was loaded
+    #[prost(string, optional, tag = "9")]
+    pub verifier_log_summary: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct XdpAttachInfo {
+    #[prost(int32, tag = "1")]
+    pub priority: i32,
+    #[prost(string, tag = "2")]
+    pub iface: ::prost::alloc::string::String,
+    #[prost(int32, tag = "3")]
+    pub position: i32,
+    #[prost(int32, repeated, tag = "4")]
+    pub proceed_on: ::prost::alloc::vec::Vec<i32>,
+    #[prost(string, optional, tag = "5")]
+    pub netns: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(map = "string, string", tag = "6")]
+    pub metadata:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    /// If set, the program is attached to every interface matched by this
+    /// interface selector, glob:<PATTERN>, regex:<PATTERN>, physical or
+    /// netns:<PATH>,..., as the link group named by AttachRequest.group, and
+    /// iface is ignored.
+    #[prost(string, optional, tag = "7")]
+    pub selector: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TcAttachInfo {
+    #[prost(int32, tag = "1")]
+    pub priority: i32,
+    #[prost(string, tag = "2")]
+    pub iface: ::prost::alloc::string::String,
+    #[prost(int32, tag = "3")]
+    pub position: i32,
+    #[prost(string, tag = "4")]
+    pub direction: ::prost::alloc::string::String,
+    #[prost(int32, repeated, tag = "5")]
+    pub proceed_on: ::prost::alloc::vec::Vec<i32>,
+    #[prost(string, optional, tag = "6")]
+    pub netns: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(map = "string, string", tag = "7")]
+    pub metadata:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    /// If set, the program is attached to every interface matched by this
+    /// interface selector, as with XDPAttachInfo.selector.
+    #[prost(string, optional, tag = "8")]
+    pub selector: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TcxAttachInfo {
+    #[prost(int32, tag = "1")]
+    pub priority: i32,
+    #[prost(string, tag = "2")]
+    pub iface: ::prost::alloc::string::String,
+    #[prost(int32, tag = "3")]
+    pub position: i32,
+    #[prost(string, tag = "4")]
+    pub direction: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "5")]
+    pub netns: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(map = "string, string", tag = "6")]
+    pub metadata:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    /// If set, the program is attached to every interface matched by this
+    /// interface selector, as with XDPAttachInfo.selector.
+    #[prost(string, optional, tag = "7")]
+    pub selector: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TracepointAttachInfo {
+    #[prost(string, tag = "1")]
+    pub tracepoint: ::prost::alloc::string::String,
+    #[prost(map = "string, string", tag = "2")]
+    pub metadata:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KprobeAttachInfo {
+    #[prost(string, tag = "1")]
+    pub fn_name: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub offset: u64,
+    #[prost(int32, optional, tag = "3")]
+    pub container_pid: ::core::option::Option<i32>,
+    #[prost(map = "string, string", tag = "4")]
+    pub metadata:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UprobeAttachInfo {
+    #[prost(string, optional, tag = "1")]
+    pub fn_name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, tag = "2")]
+    pub offset: u64,
+    #[prost(string, tag = "3")]
+    pub target: ::prost::alloc::string::String,
+    #[prost(int32, optional, tag = "4")]
+    pub pid: ::core::option::Option<i32>,
+    #[prost(int32, optional, tag = "5")]
+    pub container_pid: ::core::option::Option<i32>,
+    #[prost(map = "string, string", tag = "6")]
+    pub metadata:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FentryAttachInfo {
+    #[prost(map = "string, string", tag = "1")]
+    pub metadata:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FexitAttachInfo {
+    #[prost(map = "string, string", tag = "1")]
+    pub metadata:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttachInfo {
+    #[prost(oneof = "attach_info::Info", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
+    pub info: ::core::option::Option<attach_info::Info>,
+}
+/// Nested message and enum types in `AttachInfo`.
+pub mod attach_info {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Info {
+        #[prost(message, tag = "1")]
+        XdpAttachInfo(super::XdpAttachInfo),
+        #[prost(message, tag = "2")]
+        TcAttachInfo(super::TcAttachInfo),
+        #[prost(message, tag = "3")]
+        TracepointAttachInfo(super::TracepointAttachInfo),
+        #[prost(message, tag = "4")]
+        KprobeAttachInfo(super::KprobeAttachInfo),
+        #[prost(message, tag = "5")]
+        UprobeAttachInfo(super::UprobeAttachInfo),
+        #[prost(message, tag = "6")]
+        TcxAttachInfo(super::TcxAttachInfo),
+        #[prost(message, tag = "7")]
+        FentryAttachInfo(super::FentryAttachInfo),
+        #[prost(message, tag = "8")]
+        FexitAttachInfo(super::FexitAttachInfo),
+    }
+}
+/// LoadRequest represents a request to load and attach a bpf program.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LoadRequest {
+    #[prost(message, optional, tag = "1")]
+    pub bytecode: ::core::option::Option<BytecodeLocation>,
+    #[prost(map = "string, string", tag = "5")]
+    pub metadata:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    #[prost(map = "string, bytes", tag = "6")]
+    pub global_data:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::vec::Vec<u8>>,
+    #[prost(string, optional, tag = "7")]
+    pub uuid: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag = "8")]
+    pub map_owner_id: ::core::option::Option<u32>,
+    #[prost(message, repeated, tag = "9")]
+    pub info: ::prost::alloc::vec::Vec<LoadInfo>,
+    /// Log level of the kernel verifier, as a bitmask of 1 (the path that
+    /// fails verification), 2 (every instruction) and 4 (statistics). The log
+    /// is returned in the error when the verifier rejects a program, and the
+    /// end of the log is kept in verifier_log_summary for programs that are
+    /// accepted.
+    #[prost(uint32, optional, tag = "10")]
+    pub verifier_log_level: ::core::option::Option<u32>,
+    /// If set, the programs are loaded into the kernel to run the verifier and
+    /// then unloaded again, without being pinned or recorded. The response
+    /// describes the programs as they were while loaded.
+    #[prost(bool, tag = "11")]
+    pub validate_only: bool,
+}
+/// LoadInfo contains per-program information for LoadRequest.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LoadInfo {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(enumeration = "BpfmanProgramType", tag = "2")]
+    pub program_type: i32,
+    #[prost(message, optional, tag = "3")]
+    pub info: ::core::option::Option<ProgSpecificInfo>,
+}
+/// FentryLoadInfo contains the program-specific load information for LoadInfo.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FentryLoadInfo {
+    #[prost(string, tag = "1")]
+    pub fn_name: ::prost::alloc::string::String,
+}
+/// FexitLoadInfo contains the program-specific load information for LoadInfo.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FexitLoadInfo {
+    #[prost(string, tag = "1")]
+    pub fn_name: ::prost::alloc::string::String,
+}
+/// ProgSpecificInfo contains the program-specific load information for LoadInfo.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProgSpecificInfo {
+    #[prost(oneof = "prog_specific_info::Info", tags = "1, 2")]
+    pub info: ::core::option::Option<prog_specific_info::Info>,
+}
+/// Nested message and enum types in `ProgSpecificInfo`.
+pub mod prog_specific_info {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Info {
+        #[prost(message, tag = "1")]
+        FentryLoadInfo(super::FentryLoadInfo),
+        #[prost(message, tag = "2")]
+        FexitLoadInfo(super::FexitLoadInfo),
+    }
+}
+/// LoadResponseInfo represents the state for a single eBPF program that is maintained
+/// internally by bpfman.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LoadResponseInfo {
+    #[prost(message, optional, tag = "1")]
+    pub info: ::core::option::Option<ProgramInfo>,
+    #[prost(message, optional, tag = "2")]
+    pub kernel_info: ::core::option::Option<KernelProgramInfo>,
+}
+/// LoadResponse represents a response from loading and attaching an eBPF program.
+/// It includes all of the state kept by bpfman and the Linux kernel for the
+/// program.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LoadResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub programs: ::prost::alloc::vec::Vec<LoadResponseInfo>,
+}
+/// UnloadRequest represents a request to unload an eBPF program that was loaded
+/// and attached by bpfman. To identify a program pass in a valid kernel ID or
+/// its alias.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnloadRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    /// If set, the program with this alias is unloaded and id is ignored.
+    #[prost(string, optional, tag = "2")]
+    pub alias: ::core::option::Option<::prost::alloc::string::String>,
+    /// If set, every program loaded by bpfman whose metadata matches this
+    /// set-based selector is unloaded, and id and alias are ignored. The key
+    /// application matches the application the programs were loaded with. A
+    /// program that can't be unloaded does not stop the others from being
+    /// unloaded.
+    #[prost(string, optional, tag = "3")]
+    pub selector: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// UnloadResponse represents a response from unloading an eBPF program. When
+/// a selector is given, it contains one result for each program matched by the
+/// selector, with an error if the program could not be unloaded.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnloadResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<unload_response::Result>,
+}
+/// Nested message and enum types in `UnloadResponse`.
+pub mod unload_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Result {
+        #[prost(uint32, tag = "1")]
+        pub id: u32,
+        #[prost(string, optional, tag = "2")]
+        pub error: ::core::option::Option<::prost::alloc::string::String>,
+    }
+}
+/// AttachRequest represents a request to attach an eBPF program that was loaded
+/// by bpfman. To identify a program pass in a valid kernel ID or its alias.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttachRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(message, optional, tag = "2")]
+    pub attach: ::core::option::Option<AttachInfo>,
+    /// If set, the attach is checked, including that its target exists, but
+    /// the program is not attached and the response has a link_id of 0.
+    #[prost(bool, tag = "3")]
+    pub validate_only: bool,
+    /// If set, the program with this alias is attached and id is ignored.
+    #[prost(string, optional, tag = "4")]
+    pub program_alias: ::core::option::Option<::prost::alloc::string::String>,
+    /// The name of the link group created when the XDP, TC or TCX attach info
+    /// has a selector. It is required with a selector and not allowed without
+    /// one.
+    #[prost(string, optional, tag = "5")]
+    pub group: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// AttachResponse represents a response from attaching an eBPF program. When a
+/// link group is attached, link_id is 0 and link_group has a member for each
+/// interface matched by the selector.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttachResponse {
+    #[prost(uint32, tag = "1")]
+    pub link_id: u32,
+    #[prost(message, optional, tag = "2")]
+    pub link_group: ::core::option::Option<LinkGroupInfo>,
+}
+/// LinkGroupMember is the result of attaching a link group to one of the
+/// interfaces matched by its selector, with either the ID of the link or the
+/// reason the attach failed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LinkGroupMember {
+    #[prost(string, tag = "1")]
+    pub iface: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub netns: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag = "3")]
+    pub link_id: ::core::option::Option<u32>,
+    #[prost(string, optional, tag = "4")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// LinkGroupInfo represents a group of links created by attaching a program to
+/// every interface matched by an interface selector.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LinkGroupInfo {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub program_id: u32,
+    #[prost(string, tag = "3")]
+    pub selector: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub members: ::prost::alloc::vec::Vec<LinkGroupMember>,
+}
+/// DetachRequest represents a request to detach an eBPF program that was loaded
+/// and attached by bpfman. To identify a program pass in a valid kernel ID or
+/// its alias.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DetachRequest {
+    #[prost(uint32, tag = "1")]
+    pub link_id: u32,
+    /// If set, the link with this alias is detached and link_id is ignored.
+    #[prost(string, optional, tag = "2")]
+    pub alias: ::core::option::Option<::prost::alloc::string::String>,
+    /// If set, every link whose metadata matches this set-based selector is
+    /// detached, and link_id and alias are ignored. The key application matches
+    /// the application of the programs the links were attached from. A link
+    /// that can't be detached does not stop the others from being detached.
+    #[prost(string, optional, tag = "3")]
+    pub selector: ::core::option::Option<::prost::alloc::string::String>,
+    /// If set, every link of the link group with this name is detached and the
+    /// group is removed, and link_id, alias and selector are ignored.
+    #[prost(string, optional, tag = "4")]
+    pub group: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// DetachResponse represents a response from detaching an eBPF program. When a
+/// selector is given, it contains one result for each link matched by the
+/// selector, with an error if the link could not be detached.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DetachResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<detach_batch_response::Result>,
+}
+/// AttachBatchRequest represents a request to attach many eBPF programs at once.
+/// XDP and TC requests for the same interface, direction and network namespace
+/// are grouped, so that their dispatcher is rebuilt once for the whole batch.
+/// A request that fails does not stop the others from being attached.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttachBatchRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub requests: ::prost::alloc::vec::Vec<AttachRequest>,
+}
+/// AttachBatchResponse represents a response from attaching a batch of eBPF
+/// programs. It contains one result for each request, in the same order as the
+/// requests. Each result has either the ID of the new link or an error.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttachBatchResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<attach_batch_response::Result>,
+}
+/// Nested message and enum types in `AttachBatchResponse`.
+pub mod attach_batch_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Result {
+        #[prost(uint32, optional, tag = "1")]
+        pub link_id: ::core::option::Option<u32>,
+        #[prost(string, optional, tag = "2")]
+        pub error: ::core::option::Option<::prost::alloc::string::String>,
+    }
+}
+/// DetachBatchRequest represents a request to detach many links at once. XDP and
+/// TC links on the same dispatcher are removed together, so that the dispatcher
+/// is rebuilt once for the whole batch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DetachBatchRequest {
+    #[prost(uint32, repeated, tag = "1")]
+    pub link_ids: ::prost::alloc::vec::Vec<u32>,
+}
+/// DetachBatchResponse represents a response from detaching a batch of links. It
+/// contains one result for each link, in the same order as the request, with an
+/// error if the link could not be detached.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DetachBatchResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<detach_batch_response::Result>,
+}
+/// Nested message and enum types in `DetachBatchResponse`.
+pub mod detach_batch_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Result {
+        #[prost(uint32, tag = "1")]
+        pub link_id: u32,
+        #[prost(string, optional, tag = "2")]
+        pub error: ::core::option::Option<::prost::alloc::string::String>,
+    }
+}
+/// DisableLinkRequest represents a request to disable a link that was attached
+/// by bpfman without detaching it. The link keeps its ID, priority and metadata.
+/// XDP and TC programs are skipped by the dispatcher, all other link types are
+/// detached from the kernel until the link is enabled again.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DisableLinkRequest {
+    #[prost(uint32, tag = "1")]
+    pub link_id: u32,
+}
+/// DisableLinkResponse represents a response from disabling a link.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DisableLinkResponse {}
+/// EnableLinkRequest represents a request to enable a link that was previously
+/// disabled.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EnableLinkRequest {
+    #[prost(uint32, tag = "1")]
+    pub link_id: u32,
+}
+/// EnableLinkResponse represents a response from enabling a link.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EnableLinkResponse {}
+/// SetLinkPriorityRequest represents a request to change the priority of an
+/// attached XDP, TC or TCX link. The link keeps its ID and metadata, and the
+/// positions of all the programs on the interface are recomputed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetLinkPriorityRequest {
+    #[prost(uint32, tag = "1")]
+    pub link_id: u32,
+    #[prost(int32, tag = "2")]
+    pub priority: i32,
+}
+/// SetLinkPriorityResponse represents a response from changing the priority of
+/// a link.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetLinkPriorityResponse {}
+/// SetLinkReattachPolicyRequest represents a request to change what happens
+/// to an XDP, TC or TCX link when its interface is removed. The policy is
+/// either "never" or "by-name".
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetLinkReattachPolicyRequest {
+    #[prost(uint32, tag = "1")]
+    pub link_id: u32,
+    #[prost(string, tag = "2")]
+    pub policy: ::prost::alloc::string::String,
+}
+/// SetLinkReattachPolicyResponse represents a response from changing the
+/// reattach policy of a link.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetLinkReattachPolicyResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListRequest {
+    #[prost(uint32, optional, tag = "1")]
+    pub program_type: ::core::option::Option<u32>,
+    #[prost(bool, optional, tag = "2")]
+    pub bpfman_programs_only: ::core::option::Option<bool>,
+    #[prost(map = "string, string", tag = "3")]
+    pub match_metadata:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "4")]
+    pub metadata_selector: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "5")]
+    pub name_regex: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "6")]
+    pub iface: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, optional, tag = "7")]
+    pub no_links: ::core::option::Option<bool>,
+    #[prost(enumeration = "ListSortKey", tag = "8")]
+    pub sort_by: i32,
+    #[prost(uint32, optional, tag = "9")]
+    pub page_size: ::core::option::Option<u32>,
+    #[prost(string, optional, tag = "10")]
+    pub page_token: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListResponse {
+    #[prost(message, repeated, tag = "3")]
+    pub results: ::prost::alloc::vec::Vec<list_response::ListResult>,
+    #[prost(string, optional, tag = "4")]
+    pub next_page_token: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Nested message and enum types in `ListResponse`.
+pub mod list_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ListResult {
+        #[prost(message, optional, tag = "1")]
+        pub info: ::core::option::Option<super::ProgramInfo>,
+        #[prost(message, optional, tag = "2")]
+        pub kernel_info: ::core::option::Option<super::KernelProgramInfo>,
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PullBytecodeRequest {
+    #[prost(message, optional, tag = "1")]
+    pub image: ::core::option::Option<BytecodeImage>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PullBytecodeResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    /// If set, the program with this alias is returned and id is ignored.
+    #[prost(string, optional, tag = "2")]
+    pub alias: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetResponse {
+    #[prost(message, optional, tag = "1")]
+    pub info: ::core::option::Option<ProgramInfo>,
+    #[prost(message, optional, tag = "2")]
+    pub kernel_info: ::core::option::Option<KernelProgramInfo>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KernelLinkInfo {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(string, tag = "2")]
+    pub link_type: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "3")]
+    pub program_id: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LinkInfo {
+    #[prost(uint32, tag = "1")]
+    pub link_id: u32,
+    #[prost(uint32, tag = "2")]
+    pub program_id: u32,
+    #[prost(string, tag = "3")]
+    pub program_name: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub attach_info: ::core::option::Option<AttachInfo>,
+    #[prost(bool, tag = "5")]
+    pub enabled: bool,
+    #[prost(string, optional, tag = "6")]
+    pub group: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, tag = "7")]
+    pub interface_missing: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListLinksRequest {
+    #[prost(uint32, optional, tag = "1")]
+    pub program_id: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "2")]
+    pub link_type: ::core::option::Option<u32>,
+    #[prost(string, optional, tag = "3")]
+    pub iface: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(map = "string, string", tag = "4")]
+    pub match_metadata:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListLinksResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<list_links_response::ListResult>,
+}
+/// Nested message and enum types in `ListLinksResponse`.
+pub mod list_links_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ListResult {
+        #[prost(message, optional, tag = "1")]
+        pub info: ::core::option::Option<super::LinkInfo>,
+        #[prost(message, optional, tag = "2")]
+        pub kernel_info: ::core::option::Option<super::KernelLinkInfo>,
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLinkRequest {
+    #[prost(uint32, tag = "1")]
+    pub link_id: u32,
+    /// If set, the link with this alias is returned and link_id is ignored.
+    #[prost(string, optional, tag = "2")]
+    pub alias: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLinkResponse {
+    #[prost(message, optional, tag = "1")]
+    pub info: ::core::option::Option<LinkInfo>,
+    #[prost(message, optional, tag = "2")]
+    pub kernel_info: ::core::option::Option<KernelLinkInfo>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLinkGroupRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLinkGroupResponse {
+    #[prost(message, optional, tag = "1")]
+    pub group: ::core::option::Option<LinkGroupInfo>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VerifyRequest {
+    #[prost(bool, tag = "1")]
+    pub repair: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VerifyResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub drifts: ::prost::alloc::vec::Vec<verify_response::Drift>,
+}
+/// Nested message and enum types in `VerifyResponse`.
+pub mod verify_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Drift {
+        #[prost(uint32, tag = "1")]
+        pub program_id: u32,
+        #[prost(uint32, optional, tag = "2")]
+        pub link_id: ::core::option::Option<u32>,
+        #[prost(string, tag = "3")]
+        pub kind: ::prost::alloc::string::String,
+        #[prost(string, tag = "4")]
+        pub repair: ::prost::alloc::string::String,
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchRequest {
+    #[prost(uint32, optional, tag = "1")]
+    pub program_type: ::core::option::Option<u32>,
+    #[prost(map = "string, string", tag = "2")]
+    pub match_metadata:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    #[prost(uint64, optional, tag = "3")]
+    pub since_revision: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchResponse {
+    #[prost(uint64, tag = "1")]
+    pub revision: u64,
+    #[prost(enumeration = "EventType", tag = "2")]
+    pub r#type: i32,
+    #[prost(uint32, optional, tag = "3")]
+    pub program_id: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "4")]
+    pub link_id: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "5")]
+    pub program_type: ::core::option::Option<u32>,
+    #[prost(map = "string, string", tag = "6")]
+    pub metadata:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    #[prost(string, tag = "7")]
+    pub detail: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BpfmanProgramType {
+    Xdp = 0,
+    Tc = 1,
+    Tracepoint = 2,
+    Kprobe = 3,
+    Uprobe = 4,
+    Fentry = 5,
+    Fexit = 6,
+    Tcx = 7,
+}
+impl BpfmanProgramType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            BpfmanProgramType::Xdp => "XDP",
+            BpfmanProgramType::Tc => "TC",
+            BpfmanProgramType::Tracepoint => "TRACEPOINT",
+            BpfmanProgramType::Kprobe => "KPROBE",
+            BpfmanProgramType::Uprobe => "UPROBE",
+            BpfmanProgramType::Fentry => "FENTRY",
+            BpfmanProgramType::Fexit => "FEXIT",
+            BpfmanProgramType::Tcx => "TCX",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "XDP" => Some(Self::Xdp),
+            "TC" => Some(Self::Tc),
+            "TRACEPOINT" => Some(Self::Tracepoint),
+            "KPROBE" => Some(Self::Kprobe),
+            "UPROBE" => Some(Self::Uprobe),
+            "FENTRY" => Some(Self::Fentry),
+            "FEXIT" => Some(Self::Fexit),
+            "TCX" => Some(Self::Tcx),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ListSortKey {
+    Id = 0,
+    LoadTime = 1,
+    Name = 2,
+}
+impl ListSortKey {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ListSortKey::Id => "LIST_SORT_KEY_ID",
+            ListSortKey::LoadTime => "LIST_SORT_KEY_LOAD_TIME",
+            ListSortKey::Name => "LIST_SORT_KEY_NAME",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LIST_SORT_KEY_ID" => Some(Self::Id),
+            "LIST_SORT_KEY_LOAD_TIME" => Some(Self::LoadTime),
+            "LIST_SORT_KEY_NAME" => Some(Self::Name),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EventType {
+    ProgramLoaded = 0,
+    ProgramUnloaded = 1,
+    LinkAttached = 2,
+    LinkDetached = 3,
+    DispatcherRebuilt = 4,
+    ImagePulled = 5,
+    DriftDetected = 6,
+}
+impl EventType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            EventType::ProgramLoaded => "PROGRAM_LOADED",
+            EventType::ProgramUnloaded => "PROGRAM_UNLOADED",
+            EventType::LinkAttached => "LINK_ATTACHED",
+            EventType::LinkDetached => "LINK_DETACHED",
+            EventType::DispatcherRebuilt => "DISPATCHER_REBUILT",
+            EventType::ImagePulled => "IMAGE_PULLED",
+            EventType::DriftDetected => "DRIFT_DETECTED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PROGRAM_LOADED" => Some(Self::ProgramLoaded),
+            "PROGRAM_UNLOADED" => Some(Self::ProgramUnloaded),
+            "LINK_ATTACHED" => Some(Self::LinkAttached),
+            "LINK_DETACHED" => Some(Self::LinkDetached),
+            "DISPATCHER_REBUILT" => Some(Self::DispatcherRebuilt),
+            "IMAGE_PULLED" => Some(Self::ImagePulled),
+            "DRIFT_DETECTED" => Some(Self::DriftDetected),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod bpfman_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct BpfmanClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl<T> BpfmanClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> BpfmanClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            BpfmanClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn load(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LoadRequest>,
+        ) -> std::result::Result<tonic::Response<super::LoadResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Load");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "Load"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn unload(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UnloadRequest>,
+        ) -> std::result::Result<tonic::Response<super::UnloadResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Unload");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "Unload"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn attach(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AttachRequest>,
+        ) -> std::result::Result<tonic::Response<super::AttachResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Attach");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "Attach"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn detach(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DetachRequest>,
+        ) -> std::result::Result<tonic::Response<super::DetachResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Detach");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "Detach"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn attach_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AttachBatchRequest>,
+        ) -> std::result::Result<tonic::Response<super::AttachBatchResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/AttachBatch");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "AttachBatch"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn detach_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DetachBatchRequest>,
+        ) -> std::result::Result<tonic::Response<super::DetachBatchResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/DetachBatch");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "DetachBatch"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn disable_link(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DisableLinkRequest>,
+        ) -> std::result::Result<tonic::Response<super::DisableLinkResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/DisableLink");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "DisableLink"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn enable_link(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EnableLinkRequest>,
+        ) -> std::result::Result<tonic::Response<super::EnableLinkResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/EnableLink");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "EnableLink"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_link_priority(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetLinkPriorityRequest>,
+        ) -> std::result::Result<tonic::Response<super::SetLinkPriorityResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/SetLinkPriority");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "SetLinkPriority"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_link_reattach_policy(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetLinkReattachPolicyRequest>,
+        ) -> std::result::Result<tonic::Response<super::SetLinkReattachPolicyResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/SetLinkReattachPolicy");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "SetLinkReattachPolicy"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/List");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "List"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn pull_bytecode(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PullBytecodeRequest>,
+        ) -> std::result::Result<tonic::Response<super::PullBytecodeResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/PullBytecode");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "PullBytecode"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Get");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "Get"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_links(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListLinksRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListLinksResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/ListLinks");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "ListLinks"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_link(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetLinkRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetLinkResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/GetLink");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "GetLink"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_link_group(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetLinkGroupRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetLinkGroupResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/GetLinkGroup");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "GetLinkGroup"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn verify(
+            &mut self,
+            request: impl tonic::IntoRequest<super::VerifyRequest>,
+        ) -> std::result::Result<tonic::Response<super::VerifyResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Verify");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "Verify"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn watch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::WatchResponse>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Watch");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "Watch"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod bpfman_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with BpfmanServer.
+    #[async_trait]
+    pub trait Bpfman: Send + Sync + 'static {
+        async fn load(
+            &self,
+            request: tonic::Request<super::LoadRequest>,
+        ) -> std::result::Result<tonic::Response<super::LoadResponse>, tonic::Status>;
+        async fn unload(
+            &self,
+            request: tonic::Request<super::UnloadRequest>,
+        ) -> std::result::Result<tonic::Response<super::UnloadResponse>, tonic::Status>;
+        async fn attach(
+            &self,
+            request: tonic::Request<super::AttachRequest>,
+        ) -> std::result::Result<tonic::Response<super::AttachResponse>, tonic::Status>;
+        async fn detach(
+            &self,
+            request: tonic::Request<super::DetachRequest>,
+        ) -> std::result::Result<tonic::Response<super::DetachResponse>, tonic::Status>;
+        async fn attach_batch(
+            &self,
+            request: tonic::Request<super::AttachBatchRequest>,
+        ) -> std::result::Result<tonic::Response<super::AttachBatchResponse>, tonic::Status>;
+        async fn detach_batch(
+            &self,
+            request: tonic::Request<super::DetachBatchRequest>,
+        ) -> std::result::Result<tonic::Response<super::DetachBatchResponse>, tonic::Status>;
+        async fn disable_link(
+            &self,
+            request: tonic::Request<super::DisableLinkRequest>,
+        ) -> std::result::Result<tonic::Response<super::DisableLinkResponse>, tonic::Status>;
+        async fn enable_link(
+            &self,
+            request: tonic::Request<super::EnableLinkRequest>,
+        ) -> std::result::Result<tonic::Response<super::EnableLinkResponse>, tonic::Status>;
+        async fn set_link_priority(
+            &self,
+            request: tonic::Request<super::SetLinkPriorityRequest>,
+        ) -> std::result::Result<tonic::Response<super::SetLinkPriorityResponse>, tonic::Status>;
+        async fn set_link_reattach_policy(
+            &self,
+            request: tonic::Request<super::SetLinkReattachPolicyRequest>,
+        ) -> std::result::Result<tonic::Response<super::SetLinkReattachPolicyResponse>, tonic::Status>;
+        async fn list(
+            &self,
+            request: tonic::Request<super::ListRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListResponse>, tonic::Status>;
+        async fn pull_bytecode(
+            &self,
+            request: tonic::Request<super::PullBytecodeRequest>,
+        ) -> std::result::Result<tonic::Response<super::PullBytecodeResponse>, tonic::Status>;
+        async fn get(
+            &self,
+            request: tonic::Request<super::GetRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status>;
+        async fn list_links(
+            &self,
+            request: tonic::Request<super::ListLinksRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListLinksResponse>, tonic::Status>;
+        async fn get_link(
+            &self,
+            request: tonic::Request<super::GetLinkRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetLinkResponse>, tonic::Status>;
+        async fn get_link_group(
+            &self,
+            request: tonic::Request<super::GetLinkGroupRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetLinkGroupResponse>, tonic::Status>;
+        async fn verify(
+            &self,
+            request: tonic::Request<super::VerifyRequest>,
+        ) -> std::result::Result<tonic::Response<super::VerifyResponse>, tonic::Status>;
+        /// Server streaming response type for the Watch method.
+        type WatchStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::WatchResponse, tonic::Status>,
+            > + Send
+            + 'static;
+        async fn watch(
+            &self,
+            request: tonic::Request<super::WatchRequest>,
+        ) -> std::result::Result<tonic::Response<Self::WatchStream>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct BpfmanServer<T: Bpfman> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: Bpfman> BpfmanServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for BpfmanServer<T>
+    where
+        T: Bpfman,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/bpfman.v1.Bpfman/Load" => {
+                    #[allow(non_camel_case_types)]
+                    struct LoadSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::LoadRequest> for LoadSvc<T> {
+                        type Response = super::LoadResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LoadRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { <T as Bpfman>::load(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = LoadSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/Unload" => {
+                    #[allow(non_camel_case_types)]
+                    struct UnloadSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::UnloadRequest> for UnloadSvc<T> {
+                        type Response = super::UnloadResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UnloadRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { <T as Bpfman>::unload(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UnloadSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/Attach" => {
+                    #[allow(non_camel_case_types)]
+                    struct AttachSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::AttachRequest> for AttachSvc<T> {
+                        type Response = super::AttachResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AttachRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { <T as Bpfman>::attach(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AttachSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/Detach" => {
+                    #[allow(non_camel_case_types)]
+                    struct DetachSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::DetachRequest> for DetachSvc<T> {
+                        type Response = super::DetachResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DetachRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { <T as Bpfman>::detach(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DetachSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/AttachBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct AttachBatchSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::AttachBatchRequest> for AttachBatchSvc<T> {
+                        type Response = super::AttachBatchResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AttachBatchRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as Bpfman>::attach_batch(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AttachBatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/DetachBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct DetachBatchSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::DetachBatchRequest> for DetachBatchSvc<T> {
+                        type Response = super::DetachBatchResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DetachBatchRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as Bpfman>::detach_batch(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DetachBatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/DisableLink" => {
+                    #[allow(non_camel_case_types)]
+                    struct DisableLinkSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::DisableLinkRequest> for DisableLinkSvc<T> {
+                        type Response = super::DisableLinkResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DisableLinkRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as Bpfman>::disable_link(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DisableLinkSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/EnableLink" => {
+                    #[allow(non_camel_case_types)]
+                    struct EnableLinkSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::EnableLinkRequest> for EnableLinkSvc<T> {
+                        type Response = super::EnableLinkResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EnableLinkRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as Bpfman>::enable_link(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = EnableLinkSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/SetLinkPriority" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetLinkPrioritySvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::SetLinkPriorityRequest>
+                        for SetLinkPrioritySvc<T>
+                    {
+                        type Response = super::SetLinkPriorityResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetLinkPriorityRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::set_link_priority(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SetLinkPrioritySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/SetLinkReattachPolicy" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetLinkReattachPolicySvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::SetLinkReattachPolicyRequest>
+                        for SetLinkReattachPolicySvc<T>
+                    {
+                        type Response = super::SetLinkReattachPolicyResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetLinkReattachPolicyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::set_link_reattach_policy(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SetLinkReattachPolicySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/List" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::ListRequest> for ListSvc<T> {
+                        type Response = super::ListResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { <T as Bpfman>::list(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/PullBytecode" => {
+                    #[allow(non_camel_case_types)]
+                    struct PullBytecodeSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::PullBytecodeRequest> for PullBytecodeSvc<T> {
+                        type Response = super::PullBytecodeResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PullBytecodeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as Bpfman>::pull_bytecode(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PullBytecodeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/Get" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::GetRequest> for GetSvc<T> {
+                        type Response = super::GetResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { <T as Bpfman>::get(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/ListLinks" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListLinksSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::ListLinksRequest> for ListLinksSvc<T> {
+                        type Response = super::ListLinksResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListLinksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as Bpfman>::list_links(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListLinksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/GetLink" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetLinkSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::GetLinkRequest> for GetLinkSvc<T> {
+                        type Response = super::GetLinkResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetLinkRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { <T as Bpfman>::get_link(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetLinkSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/GetLinkGroup" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetLinkGroupSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::GetLinkGroupRequest> for GetLinkGroupSvc<T> {
+                        type Response = super::GetLinkGroupResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetLinkGroupRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as Bpfman>::get_link_group(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetLinkGroupSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/Verify" => {
+                    #[allow(non_camel_case_types)]
+                    struct VerifySvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::VerifyRequest> for VerifySvc<T> {
+                        type Response = super::VerifyResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::VerifyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { <T as Bpfman>::verify(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = VerifySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/Watch" => {
+                    #[allow(non_camel_case_types)]
+                    struct WatchSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::ServerStreamingService<super::WatchRequest> for WatchSvc<T> {
+                        type Response = super::WatchResponse;
+                        type ResponseStream = T::WatchStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WatchRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { <T as Bpfman>::watch(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = WatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: Bpfman> Clone for BpfmanServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: Bpfman> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: Bpfman> tonic::server::NamedService for BpfmanServer<T> {
+        const NAME: &'static str = "bpfman.v1.Bpfman";
+    }
+}
@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+//! The code generated from the bpfman protobuf definitions. It is kept apart
+//! from bpfman-api, which converts to and from the types of the bpfman
+//! library, so that the bpfman CLI can use the client without depending on
+//! bpfman-api.
+
+use prost::Message;
+use tonic::{Code, Status, codegen::Bytes};
+
+use crate::v2::{ErrorDetail, ErrorReason};
+
+#[path = "bpfman.v1.rs"]
+#[rustfmt::skip]
+#[allow(clippy::all)]
+pub mod v1;
+
+#[path = "bpfman.v2.rs"]
+#[rustfmt::skip]
+#[allow(clippy::all)]
+pub mod v2;
+
+#[path = "google.rpc.rs"]
+#[rustfmt::skip]
+#[allow(clippy::all)]
+mod google_rpc;
+
+/// The encoded protobuf descriptors of the `bpfman.v1` and `bpfman.v2`
+/// packages, used for gRPC server reflection.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("bpfman_descriptor.bin");
+
+const ERROR_DETAIL_TYPE_URL: &str = "type.googleapis.com/bpfman.v2.ErrorDetail";
+
+impl ErrorReason {
+    /// Returns the gRPC code that errors with this reason are returned with.
+    pub fn code(&self) -> Code {
+        match self {
+            ErrorReason::Unspecified => Code::Unknown,
+            ErrorReason::InvalidArgument
+            | ErrorReason::VerifierRejected
+            | ErrorReason::BytecodeInvalid => Code::InvalidArgument,
+            ErrorReason::NotFound => Code::NotFound,
+            ErrorReason::AlreadyExists => Code::AlreadyExists,
+            ErrorReason::PermissionDenied => Code::PermissionDenied,
+            ErrorReason::PolicyViolation => Code::FailedPrecondition,
+            ErrorReason::ImagePullFailed | ErrorReason::Unavailable => Code::Unavailable,
+            ErrorReason::ResourceExhausted => Code::ResourceExhausted,
+            ErrorReason::OutOfRange => Code::OutOfRange,
+            ErrorReason::Aborted => Code::Aborted,
+            ErrorReason::Internal => Code::Internal,
+        }
+    }
+}
+
+impl ErrorDetail {
+    pub fn new(reason: ErrorReason, message: String) -> Self {
+        ErrorDetail {
+            reason: reason as i32,
+            message,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the error with the request field that caused it.
+    pub fn with_field(mut self, field: &str) -> Self {
+        self.field = Some(field.to_string());
+        self
+    }
+
+    /// Returns a gRPC status with this error in its details.
+    pub fn into_status(self) -> Status {
+        let code = self.reason().code();
+        let status = google_rpc::Status {
+            code: code as i32,
+            message: self.message.clone(),
+            details: vec![prost_types::Any {
+                type_url: ERROR_DETAIL_TYPE_URL.to_string(),
+                value: self.encode_to_vec(),
+            }],
+        };
+        Status::with_details(code, self.message, Bytes::from(status.encode_to_vec()))
+    }
+
+    /// Returns the error in the details of a gRPC status returned by the
+    /// `bpfman.v2` service, if there is one.
+    pub fn from_status(status: &Status) -> Option<Self> {
+        google_rpc::Status::decode(status.details())
+            .ok()?
+            .details
+            .into_iter()
+            .find(|any| any.type_url == ERROR_DETAIL_TYPE_URL)
+            .and_then(|any| ErrorDetail::decode(any.value.as_slice()).ok())
+    }
+}
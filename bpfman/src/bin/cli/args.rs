@@ -7,10 +7,18 @@ use std::{
     str::FromStr,
 };
 
-use bpfman::types::BpfProgType;
+use bpfman::{
+    errors::ParseError,
+    types::{
+        BpfProgType, IdOrAlias, InterfaceSelector, ListSortKey, MetadataRequirement, ReattachPolicy,
+    },
+};
 use clap::{ArgGroup, Args, Parser, Subcommand};
+use clap_complete::{ArgValueCandidates, ArgValueCompleter};
 use hex::FromHex;
 
+use crate::{completions, output::OutputFormat};
+
 #[derive(Parser, Debug)]
 #[command(
     long_about = "An eBPF manager focusing on simplifying the deployment and administration of eBPF programs."
@@ -18,10 +26,45 @@ use hex::FromHex;
 #[command(name = "bpfman")]
 #[command(version = env!("BPFMAN_BUILD_INFO"))]
 pub(crate) struct Cli {
+    #[command(flatten)]
+    pub(crate) remote: RemoteArgs,
+    /// Optional: Format of the output of the load, attach, list, get, link,
+    /// inspect, doctor, export and import commands. json and yaml have a schema that is kept
+    /// stable across releases, wide adds columns when listing.
+    #[clap(long, global = true, value_enum, default_value_t, verbatim_doc_comment)]
+    pub(crate) output: OutputFormat,
     #[command(subcommand)]
     pub(crate) command: Commands,
 }
 
+#[derive(Args, Debug)]
+pub(crate) struct RemoteArgs {
+    /// Optional: Manage bpfman on a remote host through the TCP listener of its
+    /// bpfman-rpc instead of on the local host. Requires --tls-cert, --tls-key
+    /// and --tls-ca.
+    /// Format: https://<HOST>:<PORT>
+    /// Example: --remote https://node1.example.com:50051
+    #[clap(
+        long,
+        global = true,
+        verbatim_doc_comment,
+        requires_all = ["tls_cert", "tls_key", "tls_ca"]
+    )]
+    pub(crate) remote: Option<String>,
+
+    /// Optional: PEM encoded client certificate presented to the remote bpfman-rpc.
+    #[clap(long, global = true, verbatim_doc_comment, requires = "remote")]
+    pub(crate) tls_cert: Option<PathBuf>,
+
+    /// Optional: PEM encoded private key of the client certificate.
+    #[clap(long, global = true, verbatim_doc_comment, requires = "remote")]
+    pub(crate) tls_key: Option<PathBuf>,
+
+    /// Optional: PEM encoded CA certificate used to verify the remote bpfman-rpc.
+    #[clap(long, global = true, verbatim_doc_comment, requires = "remote")]
+    pub(crate) tls_ca: Option<PathBuf>,
+}
+
 #[derive(Subcommand, Debug)]
 pub(crate) enum Commands {
     /// Load an eBPF program on the system.
@@ -33,12 +76,39 @@ pub(crate) enum Commands {
     Attach(AttachArgs),
     /// Detach an eBPF program from a hook point using the Link Id.
     Detach(DetachArgs),
+    /// Manage an attached eBPF program link using the Link Id.
+    #[command(subcommand)]
+    Link(LinkSubcommand),
     /// List all loaded eBPF programs loaded or attached links.
     #[command(subcommand)]
     List(ListSubcommand),
     /// Get a loaded eBPF program or program attachment link.
     #[command(subcommand)]
     Get(GetSubcommand),
+    /// Check that loaded eBPF programs and links still match the kernel state.
+    Verify(VerifyArgs),
+    /// Show the programs, maps and global variables of eBPF bytecode without
+    /// loading it.
+    Inspect(InspectArgs),
+    /// Check that this host can run bpfman and which program and link types
+    /// its kernel supports.
+    Doctor,
+    /// Show loaded eBPF programs, links, dispatchers and maps with their run
+    /// time statistics, refreshed periodically.
+    Top(TopArgs),
+    /// Print the bpf_printk and aya-log output of a loaded eBPF program until
+    /// interrupted.
+    Trace(TraceArgs),
+    /// Write the programs and links managed by bpfman to stdout as a state
+    /// bundle that can be imported on another host, as YAML with --output yaml
+    /// and as JSON otherwise.
+    Export,
+    /// Load the programs and attach the links of a state bundle written by
+    /// bpfman export.
+    Import(ImportArgs),
+    /// Admission policy related commands.
+    #[command(subcommand)]
+    Policy(PolicySubcommand),
     /// eBPF Bytecode Image related commands.
     #[command(subcommand)]
     Image(Box<ImageSubCommand>),
@@ -50,6 +120,31 @@ pub(crate) enum Commands {
     Completions(crate::completions::Args),
 }
 
+#[derive(Args, Debug)]
+#[command(disable_version_flag = true)]
+pub(crate) struct InspectArgs {
+    /// Required: Path to a local .o file, or the URL of an eBPF bytecode image
+    /// if there is no file at that path. Local files are inspected without
+    /// privileges or network access.
+    /// Example: bpfman inspect ./bpf_x86_bpfel.o
+    /// Example: bpfman inspect quay.io/bpfman-bytecode/xdp_pass:latest
+    #[clap(verbatim_doc_comment)]
+    pub(crate) bytecode: String,
+
+    /// Optional: Registry auth for authenticating with the specified image registry.
+    /// This should be base64 encoded from the '<username>:<password>' string just like
+    /// it's stored in the docker/podman host config.
+    /// Example: --registry_auth "YnjrcKw63PhDcQodiU9hYxQ2"
+    #[clap(short, long, verbatim_doc_comment)]
+    pub(crate) registry_auth: Option<String>,
+
+    /// Optional: Pull policy for remote images.
+    ///
+    /// [possible values: Always, IfNotPresent, Never]
+    #[clap(short, long, verbatim_doc_comment, default_value = "IfNotPresent")]
+    pub(crate) pull_policy: String,
+}
+
 #[derive(Subcommand, Debug)]
 #[command(disable_version_flag = true)]
 pub(crate) enum LoadSubcommand {
@@ -104,6 +199,13 @@ pub(crate) struct LoadFileArgs {
     #[clap(short, long, verbatim_doc_comment)]
     pub(crate) application: Option<String>,
 
+    /// Optional: Unique alias of the program, which other commands accept as
+    /// name:<ALIAS> in place of the Program Id. Only used when loading a single
+    /// program.
+    /// Example: --alias edge-pass
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) alias: Option<String>,
+
     /// Optional: Specify Key/Value metadata to be attached to a program when it
     /// is loaded by bpfman.
     /// Format: <KEY>=<VALUE>
@@ -119,6 +221,20 @@ pub(crate) struct LoadFileArgs {
     /// Example: --map-owner-id 63178
     #[clap(long, verbatim_doc_comment)]
     pub(crate) map_owner_id: Option<u32>,
+
+    /// Optional: Log level of the kernel verifier, as a bitmask of 1 (log the path
+    /// that fails verification), 2 (log every instruction) and 4 (log statistics).
+    /// The log is printed if the verifier rejects a program, and the end of the
+    /// log is kept as the verifier log of programs that are accepted.
+    /// Example: --verifier-log-level 2
+    #[clap(long, verbatim_doc_comment, value_parser = clap::value_parser!(u32).range(0..=7))]
+    pub(crate) verifier_log_level: Option<u32>,
+
+    /// Optional: Check that the programs can be loaded, without keeping them.
+    /// The programs are loaded to run the kernel verifier and then unloaded,
+    /// and nothing is pinned or recorded.
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) dry_run: bool,
 }
 
 #[derive(Args, Debug)]
@@ -165,6 +281,13 @@ pub(crate) struct LoadImageArgs {
     #[clap(short, long, verbatim_doc_comment)]
     pub(crate) application: Option<String>,
 
+    /// Optional: Unique alias of the program, which other commands accept as
+    /// name:<ALIAS> in place of the Program Id. Only used when loading a single
+    /// program.
+    /// Example: --alias edge-pass
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) alias: Option<String>,
+
     /// Optional: Specify Key/Value metadata to be attached to a program when it
     /// is loaded by bpfman.
     /// Format: <KEY>=<VALUE>
@@ -180,6 +303,20 @@ pub(crate) struct LoadImageArgs {
     /// Example: --map-owner-id 63178
     #[clap(long, verbatim_doc_comment)]
     pub(crate) map_owner_id: Option<u32>,
+
+    /// Optional: Log level of the kernel verifier, as a bitmask of 1 (log the path
+    /// that fails verification), 2 (log every instruction) and 4 (log statistics).
+    /// The log is printed if the verifier rejects a program, and the end of the
+    /// log is kept as the verifier log of programs that are accepted.
+    /// Example: --verifier-log-level 2
+    #[clap(long, verbatim_doc_comment, value_parser = clap::value_parser!(u32).range(0..=7))]
+    pub(crate) verifier_log_level: Option<u32>,
+
+    /// Optional: Check that the programs can be loaded, without keeping them.
+    /// The programs are loaded to run the kernel verifier and then unloaded,
+    /// and nothing is pinned or recorded.
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) dry_run: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -195,8 +332,15 @@ pub(crate) enum AttachCommands {
     /// Install an eBPF program on the XDP hook point for a given interface.
     Xdp {
         /// Required: Interface to load program on.
-        #[clap(short, long)]
-        iface: String,
+        /// Not used when --selector is given.
+        #[clap(
+            short,
+            long,
+            verbatim_doc_comment,
+            required_unless_present = "selector",
+            add = ArgValueCandidates::new(completions::interfaces)
+        )]
+        iface: Option<String>,
 
         /// Required: Priority to run program in chain. Lower value runs first.
         /// [possible values: 1-1000]
@@ -218,6 +362,19 @@ pub(crate) enum AttachCommands {
         #[clap(short, long, verbatim_doc_comment)]
         netns: Option<PathBuf>,
 
+        /// Optional: Attach to every interface matched by a selector instead of a
+        /// single interface. This creates a link group named by --group, which can
+        /// be detached as a unit.
+        /// Format: glob:<PATTERN> | regex:<PATTERN> | physical | netns:<PATH>[,<PATH>...]
+        /// Example: --selector "glob:eth*" --group edge
+        #[clap(long, verbatim_doc_comment, conflicts_with = "iface", requires = "group",
+            value_parser = parse_interface_selector)]
+        selector: Option<InterfaceSelector>,
+
+        /// Optional: Name of the link group created when --selector is used.
+        #[clap(long, requires = "selector")]
+        group: Option<String>,
+
         /// Optional: Specify Key/Value metadata to be attached to a link when it
         /// is loaded by bpfman.
         /// Format: <KEY>=<VALUE>
@@ -238,8 +395,15 @@ pub(crate) enum AttachCommands {
         direction: String,
 
         /// Required: Interface to load program on.
-        #[clap(short, long)]
-        iface: String,
+        /// Not used when --selector is given.
+        #[clap(
+            short,
+            long,
+            verbatim_doc_comment,
+            required_unless_present = "selector",
+            add = ArgValueCandidates::new(completions::interfaces)
+        )]
+        iface: Option<String>,
 
         /// Required: Priority to run program in chain. Lower value runs first.
         /// [possible values: 1-1000]
@@ -262,6 +426,19 @@ pub(crate) enum AttachCommands {
         #[clap(short, long, verbatim_doc_comment)]
         netns: Option<PathBuf>,
 
+        /// Optional: Attach to every interface matched by a selector instead of a
+        /// single interface. This creates a link group named by --group, which can
+        /// be detached as a unit.
+        /// Format: glob:<PATTERN> | regex:<PATTERN> | physical | netns:<PATH>[,<PATH>...]
+        /// Example: --selector "glob:eth*" --group edge
+        #[clap(long, verbatim_doc_comment, conflicts_with = "iface", requires = "group",
+            value_parser = parse_interface_selector)]
+        selector: Option<InterfaceSelector>,
+
+        /// Optional: Name of the link group created when --selector is used.
+        #[clap(long, requires = "selector")]
+        group: Option<String>,
+
         /// Optional: Specify Key/Value metadata to be attached to a link when it
         /// is loaded by bpfman.
         /// Format: <KEY>=<VALUE>
@@ -283,8 +460,15 @@ pub(crate) enum AttachCommands {
         direction: String,
 
         /// Required: Interface to load program on.
-        #[clap(short, long)]
-        iface: String,
+        /// Not used when --selector is given.
+        #[clap(
+            short,
+            long,
+            verbatim_doc_comment,
+            required_unless_present = "selector",
+            add = ArgValueCandidates::new(completions::interfaces)
+        )]
+        iface: Option<String>,
 
         /// Required: Priority to run program in chain. Lower value runs first.
         /// [possible values: 1-1000]
@@ -296,6 +480,19 @@ pub(crate) enum AttachCommands {
         #[clap(short, long, verbatim_doc_comment)]
         netns: Option<PathBuf>,
 
+        /// Optional: Attach to every interface matched by a selector instead of a
+        /// single interface. This creates a link group named by --group, which can
+        /// be detached as a unit.
+        /// Format: glob:<PATTERN> | regex:<PATTERN> | physical | netns:<PATH>[,<PATH>...]
+        /// Example: --selector "glob:eth*" --group edge
+        #[clap(long, verbatim_doc_comment, conflicts_with = "iface", requires = "group",
+            value_parser = parse_interface_selector)]
+        selector: Option<InterfaceSelector>,
+
+        /// Optional: Name of the link group created when --selector is used.
+        #[clap(long, requires = "selector")]
+        group: Option<String>,
+
         /// Optional: Specify Key/Value metadata to be attached to a link when it
         /// is loaded by bpfman.
         /// Format: <KEY>=<VALUE>
@@ -311,7 +508,8 @@ pub(crate) enum AttachCommands {
     Tracepoint {
         /// Required: The tracepoint to attach to.
         /// Example: --tracepoint "sched/sched_switch"
-        #[clap(short, long, verbatim_doc_comment)]
+        #[clap(short, long, verbatim_doc_comment,
+            add = ArgValueCandidates::new(completions::tracepoints))]
         tracepoint: String,
 
         /// Optional: Specify Key/Value metadata to be attached to a link when it
@@ -328,7 +526,7 @@ pub(crate) enum AttachCommands {
     /// Install a kprobe or kretprobe eBPF probe
     Kprobe {
         /// Required: Function to attach the kprobe to.
-        #[clap(short, long)]
+        #[clap(short, long, add = ArgValueCompleter::new(completions::kernel_functions))]
         fn_name: String,
 
         /// Optional: Offset added to the address of the function for kprobe.
@@ -421,15 +619,113 @@ pub(crate) enum AttachCommands {
 #[derive(Args, Debug)]
 #[command(disable_version_flag = true)]
 pub(crate) struct UnloadArgs {
-    /// Required: Program Id to be unloaded.
-    pub(crate) program_id: u32,
+    /// Required: Program Id, or name:<ALIAS>, of the program to be unloaded.
+    /// Not used when --selector is given.
+    #[clap(verbatim_doc_comment, value_parser=parse_id_or_alias, required_unless_present = "selector",
+        add = ArgValueCandidates::new(completions::programs))]
+    pub(crate) program: Option<IdOrAlias>,
+
+    /// Optional: Unload all the programs whose metadata labels match a
+    /// set-based selector. The key application matches the --application the
+    /// programs were loaded with.
+    /// Format: <KEY>=<VALUE>, <KEY>!=<VALUE>, <KEY> in (<VALUE>,...),
+    ///         <KEY> notin (<VALUE>,...), <KEY> or !<KEY>
+    /// Example: --selector application=TestEbpfApp
+    #[clap(long, verbatim_doc_comment, value_parser=parse_selector, conflicts_with = "program")]
+    pub(crate) selector: Option<String>,
+}
+
+#[derive(Args, Debug)]
+#[command(disable_version_flag = true)]
+pub(crate) struct VerifyArgs {
+    /// Optional: Repair the drift that is found, for example by attaching a
+    /// link again or by removing a program that is no longer loaded.
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) repair: bool,
+}
+
+#[derive(Args, Debug)]
+#[command(disable_version_flag = true)]
+pub(crate) struct ImportArgs {
+    /// Required: Path to a state bundle written by bpfman export, or - to read
+    /// it from stdin. Bundles are read as YAML if the path ends with .yaml or
+    /// .yml, or if the bundle doesn't start with {, and as JSON otherwise.
+    /// Example: bpfman import state.json
+    #[clap(verbatim_doc_comment)]
+    pub(crate) path: String,
+}
+
+#[derive(Args, Debug)]
+#[command(disable_version_flag = true)]
+pub(crate) struct TopArgs {
+    /// Optional: Number of seconds between refreshes.
+    /// Example: --interval 5
+    #[clap(
+        long,
+        verbatim_doc_comment,
+        default_value_t = 2,
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    pub(crate) interval: u64,
+
+    /// Optional: Enable the kernel run time statistics of eBPF programs while
+    /// top runs, as if kernel.bpf_stats_enabled was set to 1. Without them,
+    /// run counts and run times are only collected if the sysctl is set.
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) enable_stats: bool,
+}
+
+#[derive(Args, Debug)]
+#[command(disable_version_flag = true)]
+pub(crate) struct TraceArgs {
+    /// Required: Program Id, or name:<ALIAS>, of the program to trace.
+    #[clap(verbatim_doc_comment, value_parser=parse_id_or_alias,
+        add = ArgValueCandidates::new(completions::programs))]
+    pub(crate) program: IdOrAlias,
+}
+
+#[derive(Subcommand, Debug)]
+#[command(disable_version_flag = true)]
+pub(crate) enum PolicySubcommand {
+    /// Check a manifest of programs against the admission policy without
+    /// loading or attaching anything.
+    #[clap(verbatim_doc_comment)]
+    Check(PolicyCheckArgs),
+}
+
+#[derive(Args, Debug)]
+#[command(disable_version_flag = true)]
+pub(crate) struct PolicyCheckArgs {
+    /// Required: TOML manifest of the programs to check, and where they would
+    /// be attached.
+    #[clap(short, long, verbatim_doc_comment)]
+    pub(crate) file: PathBuf,
+
+    /// Optional: bpfman configuration file with the policy to check against.
+    /// [default: /etc/bpfman/bpfman.toml]
+    #[clap(short, long, verbatim_doc_comment)]
+    pub(crate) config: Option<PathBuf>,
 }
 
 #[derive(Args, Debug)]
 #[command(disable_version_flag = true)]
 pub(crate) struct AttachArgs {
-    /// Required: Program Id to be attached.
-    pub(crate) program_id: u32,
+    /// Required: Program Id, or name:<ALIAS>, of the program to be attached.
+    #[clap(verbatim_doc_comment, value_parser=parse_id_or_alias,
+        add = ArgValueCandidates::new(completions::programs))]
+    pub(crate) program: IdOrAlias,
+
+    /// Optional: Unique alias of the link, which other commands accept as
+    /// name:<ALIAS> in place of the Link Id.
+    /// Example: --alias edge-pass-eth0
+    #[clap(long, global = true, verbatim_doc_comment)]
+    pub(crate) alias: Option<String>,
+
+    /// Optional: Check that the program can be attached, including that the
+    /// interface, tracepoint, kernel function or uprobe target exists, without
+    /// attaching it.
+    #[clap(long, global = true, verbatim_doc_comment)]
+    pub(crate) dry_run: bool,
 
     #[clap(subcommand)]
     pub(crate) command: AttachCommands,
@@ -438,10 +734,101 @@ pub(crate) struct AttachArgs {
 #[derive(Args, Debug)]
 #[command(disable_version_flag = true)]
 pub(crate) struct DetachArgs {
-    /// Required: Link Id to be detached.
+    /// Required: Link Id, or name:<ALIAS>, of the link to be detached.
+    /// Not used when --group or --selector is given.
+    #[clap(
+        verbatim_doc_comment,
+        value_parser=parse_id_or_alias,
+        required_unless_present_any = ["group", "selector"],
+        add = ArgValueCandidates::new(completions::links)
+    )]
+    pub(crate) link: Option<IdOrAlias>,
+
+    /// Optional: Detach all the links in the link group with this name.
+    /// Example: --group edge
+    #[clap(long, verbatim_doc_comment, conflicts_with_all = ["link", "selector"])]
+    pub(crate) group: Option<String>,
+
+    /// Optional: Detach all the links whose metadata labels match a set-based
+    /// selector. The key application matches the application of the programs
+    /// the links were attached from.
+    /// Format: <KEY>=<VALUE>, <KEY>!=<VALUE>, <KEY> in (<VALUE>,...),
+    ///         <KEY> notin (<VALUE>,...), <KEY> or !<KEY>
+    /// Example: --selector application=TestEbpfApp
+    #[clap(long, verbatim_doc_comment, value_parser=parse_selector, conflicts_with = "link")]
+    pub(crate) selector: Option<String>,
+}
+
+#[derive(Subcommand, Debug)]
+#[command(disable_version_flag = true)]
+pub(crate) enum LinkSubcommand {
+    /// Disable a link, bypassing the eBPF program without detaching it.
+    ///
+    /// The link keeps its Link Id, priority and metadata. XDP and TC programs
+    /// are skipped by the dispatcher, all other link types are detached from
+    /// the kernel until the link is enabled again.
+    #[clap(verbatim_doc_comment)]
+    Disable(DisableLinkArgs),
+    /// Enable a previously disabled link.
+    Enable(EnableLinkArgs),
+    /// Change the priority of an attached XDP, TC or TCX link.
+    ///
+    /// The link keeps its Link Id and metadata, and the positions of all the
+    /// programs on the interface are recomputed.
+    #[clap(verbatim_doc_comment)]
+    SetPriority(SetLinkPriorityArgs),
+    /// Set what happens to an XDP, TC or TCX link when its interface is removed.
+    ///
+    /// The link is always kept and marked as detached when its interface is
+    /// removed. With the by-name policy, bpfman-rpc attaches it again once an
+    /// interface with the same name is created in the same network namespace.
+    #[clap(verbatim_doc_comment)]
+    SetReattachPolicy(SetLinkReattachPolicyArgs),
+}
+
+#[derive(Args, Debug)]
+#[command(disable_version_flag = true)]
+pub(crate) struct DisableLinkArgs {
+    /// Required: Link Id to be disabled.
+    #[clap(add = ArgValueCandidates::new(completions::link_ids))]
     pub(crate) link_id: u32,
 }
 
+#[derive(Args, Debug)]
+#[command(disable_version_flag = true)]
+pub(crate) struct EnableLinkArgs {
+    /// Required: Link Id to be enabled.
+    #[clap(add = ArgValueCandidates::new(completions::link_ids))]
+    pub(crate) link_id: u32,
+}
+
+#[derive(Args, Debug)]
+#[command(disable_version_flag = true)]
+pub(crate) struct SetLinkPriorityArgs {
+    /// Required: Link Id to be updated.
+    #[clap(add = ArgValueCandidates::new(completions::link_ids))]
+    pub(crate) link_id: u32,
+
+    /// Required: New priority of the program in the chain. Lower value runs first.
+    /// [possible values: 1-1000]
+    #[clap(verbatim_doc_comment)]
+    pub(crate) priority: i32,
+}
+
+#[derive(Args, Debug)]
+#[command(disable_version_flag = true)]
+pub(crate) struct SetLinkReattachPolicyArgs {
+    /// Required: Link Id to be updated.
+    #[clap(add = ArgValueCandidates::new(completions::link_ids))]
+    pub(crate) link_id: u32,
+
+    /// Required: Whether to attach the link again when an interface with the
+    /// same name is created.
+    /// [possible values: never, by-name]
+    #[clap(verbatim_doc_comment, value_parser=parse_reattach_policy)]
+    pub(crate) policy: ReattachPolicy,
+}
+
 #[derive(Subcommand, Debug)]
 #[command(disable_version_flag = true)]
 pub(crate) enum ListSubcommand {
@@ -486,6 +873,46 @@ pub(crate) struct ListProgramArgs {
     #[clap(short, long, verbatim_doc_comment, value_parser=parse_key_val, value_delimiter = ',')]
     pub(crate) metadata_selector: Option<Vec<(String, String)>>,
 
+    /// Optional: List programs whose metadata labels match a set-based selector.
+    /// Requirements are separated by commas, and the option can be repeated.
+    /// Format: <KEY>=<VALUE>, <KEY>!=<VALUE>, <KEY> in (<VALUE>,...),
+    ///         <KEY> notin (<VALUE>,...), <KEY> or !<KEY>
+    /// Example: --selector 'env in (prod,staging),!deprecated'
+    #[clap(short = 'l', long, verbatim_doc_comment, value_parser=parse_selector)]
+    pub(crate) selector: Vec<String>,
+
+    /// Optional: List programs whose name matches a regular expression. The
+    /// kernel name is used for programs not loaded by bpfman.
+    /// Example: --name '^xdp_'
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) name: Option<String>,
+
+    /// Optional: List programs with a link attached to an interface.
+    /// Example: --iface eth0
+    #[clap(short, long, verbatim_doc_comment, add = ArgValueCandidates::new(completions::interfaces))]
+    pub(crate) iface: Option<String>,
+
+    /// Optional: List programs that have no links.
+    #[clap(long, verbatim_doc_comment, conflicts_with = "iface")]
+    pub(crate) no_links: bool,
+
+    /// Optional: Order of the listed programs.
+    /// [possible values: id, load-time, name]
+    #[clap(long, verbatim_doc_comment, default_value = "id", value_parser=parse_list_sort_key)]
+    pub(crate) sort_by: ListSortKey,
+
+    /// Optional: Maximum number of programs to list. The token of the next
+    /// page is printed after the programs if there are more.
+    /// Example: --limit 50
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) limit: Option<usize>,
+
+    /// Optional: List the page of programs that starts after the previous page,
+    /// using the token printed with it. The other options must be the same as
+    /// for the previous page.
+    #[clap(long, verbatim_doc_comment, requires = "limit")]
+    pub(crate) page_token: Option<String>,
+
     /// Optional: List all programs.
     #[clap(short, long, verbatim_doc_comment)]
     pub(crate) all: bool,
@@ -521,20 +948,33 @@ pub(crate) enum GetSubcommand {
     Program(GetProgramArgs),
     /// Get a loaded eBPF program's attachment using the Link Id.
     Link(GetLinkArgs),
+    /// Get a link group and the status of each of its links using the group name.
+    LinkGroup(GetLinkGroupArgs),
 }
 
 #[derive(Args, Debug)]
 #[command(disable_version_flag = true)]
 pub(crate) struct GetProgramArgs {
-    /// Required: Program Id to get.
-    pub(crate) program_id: u32,
+    /// Required: Program Id, or name:<ALIAS>, of the program to get.
+    #[clap(verbatim_doc_comment, value_parser=parse_id_or_alias,
+        add = ArgValueCandidates::new(completions::programs))]
+    pub(crate) program: IdOrAlias,
 }
 
 #[derive(Args, Debug)]
 #[command(disable_version_flag = true)]
 pub(crate) struct GetLinkArgs {
-    /// Required: Link Id to get.
-    pub(crate) link_id: u32,
+    /// Required: Link Id, or name:<ALIAS>, of the link to get.
+    #[clap(verbatim_doc_comment, value_parser=parse_id_or_alias,
+        add = ArgValueCandidates::new(completions::links))]
+    pub(crate) link: IdOrAlias,
+}
+
+#[derive(Args, Debug)]
+#[command(disable_version_flag = true)]
+pub(crate) struct GetLinkGroupArgs {
+    /// Required: Name of the link group to get.
+    pub(crate) name: String,
 }
 
 #[derive(Subcommand, Debug)]
@@ -844,6 +1284,29 @@ pub(crate) fn parse_key_val(s: &str) -> Result<(String, String), std::io::Error>
     Ok((s[..pos].to_string(), s[pos + 1..].to_string()))
 }
 
+pub(crate) fn parse_interface_selector(s: &str) -> Result<InterfaceSelector, ParseError> {
+    InterfaceSelector::try_from(s.to_string())
+}
+
+pub(crate) fn parse_reattach_policy(s: &str) -> Result<ReattachPolicy, ParseError> {
+    ReattachPolicy::try_from(s.to_string())
+}
+
+pub(crate) fn parse_id_or_alias(s: &str) -> Result<IdOrAlias, ParseError> {
+    IdOrAlias::try_from(s.to_string())
+}
+
+pub(crate) fn parse_list_sort_key(s: &str) -> Result<ListSortKey, ParseError> {
+    ListSortKey::try_from(s.to_string())
+}
+
+/// Checks a set-based metadata selector. It is kept as a string so that it
+/// can be sent as is with --remote.
+pub(crate) fn parse_selector(s: &str) -> Result<String, ParseError> {
+    MetadataRequirement::parse_selector(s)?;
+    Ok(s.to_string())
+}
+
 pub(crate) fn parse_global_arg(global_arg: &str) -> Result<GlobalArg, std::io::Error> {
     let mut parts = global_arg.split('=');
 
@@ -910,6 +1373,39 @@ mod test {
         assert_eq!(result.kind(), std::io::ErrorKind::InvalidInput);
     }
 
+    #[test]
+    fn test_parse_interface_selector() {
+        let result = parse_interface_selector("glob:eth*").unwrap();
+        assert_eq!(result, InterfaceSelector::Glob("eth*".to_string()));
+
+        let result = parse_interface_selector("netns:/var/run/netns/a,/var/run/netns/b").unwrap();
+        assert_eq!(
+            result,
+            InterfaceSelector::Netns(vec![
+                PathBuf::from("/var/run/netns/a"),
+                PathBuf::from("/var/run/netns/b")
+            ])
+        );
+
+        let result = parse_interface_selector("physical").unwrap();
+        assert_eq!(result, InterfaceSelector::Physical);
+
+        assert!(parse_interface_selector("regex:eth[").is_err());
+        assert!(parse_interface_selector("glob:").is_err());
+        assert!(parse_interface_selector("eth0").is_err());
+    }
+
+    #[test]
+    fn test_parse_reattach_policy() {
+        let result = parse_reattach_policy("never").unwrap();
+        assert_eq!(result, ReattachPolicy::Never);
+
+        let result = parse_reattach_policy("by-name").unwrap();
+        assert_eq!(result, ReattachPolicy::ByName);
+
+        assert!(parse_reattach_policy("always").is_err());
+    }
+
     #[test]
     fn test_parse_key_val() {
         let result = parse_key_val("foo=bar").unwrap();
@@ -3,36 +3,57 @@
 
 use anyhow::bail;
 use bpfman::{
-    attach_program, get_program, setup,
-    types::{AttachInfo, TcProceedOn, XdpProceedOn},
+    attach_link_group, attach_program, get_program, resolve_program, setup,
+    types::{AttachInfo, InterfaceSelector, TcProceedOn, XdpProceedOn},
+    validate_attach,
 };
 use log::warn;
 
 use crate::{
     args::{AttachArgs, AttachCommands},
-    load::parse_metadata,
-    table::ProgTable,
+    link::print_link,
+    load::{parse_metadata, with_alias},
+    output::{LinkGroupOutput, OutputFormat},
 };
 
-pub(crate) fn execute_attach(args: &AttachArgs) -> anyhow::Result<()> {
+pub(crate) fn execute_attach(args: &AttachArgs, output: OutputFormat) -> anyhow::Result<()> {
     let (config, root_db) = setup()?;
+    let program_id = resolve_program(&root_db, &args.program)?;
 
-    match get_program(&root_db, args.program_id) {
+    match get_program(&root_db, program_id) {
         Ok(program) => {
-            let link = attach_program(
-                &config,
-                &root_db,
-                args.program_id,
-                args.command
-                    .get_attach_info(&program.get_data().get_application_from_metadata())?,
+            let attach_info = args.command.get_attach_info(
+                &program.get_data().get_application_from_metadata(),
+                &args.alias,
             )?;
 
-            if let Ok(p) = ProgTable::new_link(&program, &link) {
-                p.print();
+            if args.dry_run {
+                if args.command.get_link_group().is_some() {
+                    bail!("--dry-run is not supported with --group");
+                }
+                validate_attach(&config, &root_db, program_id, attach_info)?;
+                println!("Program {} can be attached", args.program);
+                return Ok(());
+            }
+
+            if let Some((name, selector)) = args.command.get_link_group() {
+                let group = attach_link_group(
+                    &config,
+                    &root_db,
+                    program_id,
+                    name,
+                    selector.clone(),
+                    attach_info,
+                )?;
+
+                return LinkGroupOutput::try_from(&group)?.print(output);
             }
+
+            let link = attach_program(&config, &root_db, program_id, attach_info)?;
+            print_link(&root_db, &link, output)?;
         }
         Err(e) => {
-            warn!("unable to retrieve program {}: {}", args.program_id, e);
+            warn!("unable to retrieve program {}: {}", args.program, e);
             bail!(e)
         }
     }
@@ -40,9 +61,29 @@ pub(crate) fn execute_attach(args: &AttachArgs) -> anyhow::Result<()> {
 }
 
 impl AttachCommands {
+    /// Returns the link group name and interface selector if the program is to
+    /// be attached to a group of interfaces.
+    pub(crate) fn get_link_group(&self) -> Option<(&str, &InterfaceSelector)> {
+        match self {
+            AttachCommands::Xdp {
+                selector, group, ..
+            }
+            | AttachCommands::Tc {
+                selector, group, ..
+            }
+            | AttachCommands::Tcx {
+                selector, group, ..
+            } => group.as_deref().zip(selector.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the attach info of the command. The metadata of the link gets
+    /// the application of the program and the alias of the link, if any.
     pub(crate) fn get_attach_info(
         &self,
         application: &Option<String>,
+        alias: &Option<String>,
     ) -> Result<AttachInfo, anyhow::Error> {
         match self {
             AttachCommands::Xdp {
@@ -51,6 +92,7 @@ impl AttachCommands {
                 proceed_on,
                 netns,
                 metadata,
+                ..
             } => {
                 let proc_on = match XdpProceedOn::from_strings(proceed_on) {
                     Ok(p) => p,
@@ -58,10 +100,10 @@ impl AttachCommands {
                 };
                 Ok(AttachInfo::Xdp {
                     priority: *priority,
-                    iface: iface.to_string(),
+                    iface: iface.clone().unwrap_or_default(),
                     proceed_on: proc_on,
                     netns: netns.clone(),
-                    metadata: parse_metadata(metadata, application),
+                    metadata: with_alias(parse_metadata(metadata, application), alias),
                 })
             }
             AttachCommands::Tc {
@@ -71,6 +113,7 @@ impl AttachCommands {
                 proceed_on,
                 netns,
                 metadata,
+                ..
             } => {
                 match direction.as_str() {
                     "ingress" | "egress" => (),
@@ -82,11 +125,11 @@ impl AttachCommands {
                 };
                 Ok(AttachInfo::Tc {
                     priority: *priority,
-                    iface: iface.to_string(),
+                    iface: iface.clone().unwrap_or_default(),
                     direction: direction.to_string(),
                     proceed_on: proc_on,
                     netns: netns.clone(),
-                    metadata: parse_metadata(metadata, application),
+                    metadata: with_alias(parse_metadata(metadata, application), alias),
                 })
             }
             AttachCommands::Tcx {
@@ -95,6 +138,7 @@ impl AttachCommands {
                 priority,
                 netns,
                 metadata,
+                ..
             } => {
                 match direction.as_str() {
                     "ingress" | "egress" => (),
@@ -102,10 +146,10 @@ impl AttachCommands {
                 };
                 Ok(AttachInfo::Tcx {
                     priority: *priority,
-                    iface: iface.to_string(),
+                    iface: iface.clone().unwrap_or_default(),
                     direction: direction.to_string(),
                     netns: netns.clone(),
-                    metadata: parse_metadata(metadata, application),
+                    metadata: with_alias(parse_metadata(metadata, application), alias),
                 })
             }
             AttachCommands::Tracepoint {
@@ -113,7 +157,7 @@ impl AttachCommands {
                 metadata,
             } => Ok(AttachInfo::Tracepoint {
                 tracepoint: tracepoint.to_string(),
-                metadata: parse_metadata(metadata, application),
+                metadata: with_alias(parse_metadata(metadata, application), alias),
             }),
             AttachCommands::Kprobe {
                 fn_name,
@@ -129,7 +173,7 @@ impl AttachCommands {
                     fn_name: fn_name.to_string(),
                     offset,
                     container_pid: *container_pid,
-                    metadata: parse_metadata(metadata, application),
+                    metadata: with_alias(parse_metadata(metadata, application), alias),
                 })
             }
             AttachCommands::Uprobe {
@@ -147,14 +191,14 @@ impl AttachCommands {
                     target: target.to_string(),
                     pid: *pid,
                     container_pid: *container_pid,
-                    metadata: parse_metadata(metadata, application),
+                    metadata: with_alias(parse_metadata(metadata, application), alias),
                 })
             }
             AttachCommands::Fentry { metadata } => Ok(AttachInfo::Fentry {
-                metadata: parse_metadata(metadata, application),
+                metadata: with_alias(parse_metadata(metadata, application), alias),
             }),
             AttachCommands::Fexit { metadata } => Ok(AttachInfo::Fexit {
-                metadata: parse_metadata(metadata, application),
+                metadata: with_alias(parse_metadata(metadata, application), alias),
             }),
         }
     }
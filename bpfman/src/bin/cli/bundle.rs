@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use std::io::Read;
+
+use bpfman::{
+    bundle::{StateBundle, export_state, import_state},
+    setup,
+};
+
+use crate::{
+    args::ImportArgs,
+    output::{ImportOutput, ImportedIdOutput, OutputFormat},
+    table::ProgTable,
+};
+
+pub(crate) fn execute_export(output: OutputFormat) -> anyhow::Result<()> {
+    let (_, root_db) = setup()?;
+    let bundle = export_state(&root_db)?;
+    if output == OutputFormat::Yaml {
+        output.print(&bundle)
+    } else {
+        OutputFormat::Json.print(&bundle)
+    }
+}
+
+pub(crate) fn execute_import(args: &ImportArgs, output: OutputFormat) -> anyhow::Result<()> {
+    let text = if args.path == "-" {
+        let mut text = String::new();
+        std::io::stdin().read_to_string(&mut text)?;
+        text
+    } else {
+        std::fs::read_to_string(&args.path)
+            .map_err(|e| anyhow::anyhow!("unable to read {}: {e}", args.path))?
+    };
+    let bundle = parse_bundle(&args.path, &text)?;
+
+    let (config, root_db) = setup()?;
+    let imported = import_state(&config, &root_db, &bundle)?;
+
+    let to_output = |ids: Vec<(u32, u32)>| {
+        ids.into_iter()
+            .map(|(bundle_id, id)| ImportedIdOutput { bundle_id, id })
+            .collect()
+    };
+    let imported = ImportOutput {
+        programs: to_output(imported.programs),
+        links: to_output(imported.links),
+    };
+    if output.is_serialized() {
+        output.print(&imported)
+    } else {
+        ProgTable::new_import_list(&imported).print();
+        Ok(())
+    }
+}
+
+// Parses a bundle read from a path as YAML if the path has a YAML extension or
+// the bundle isn't a JSON object, and as JSON otherwise.
+fn parse_bundle(path: &str, text: &str) -> anyhow::Result<StateBundle> {
+    let yaml =
+        path.ends_with(".yaml") || path.ends_with(".yml") || !text.trim_start().starts_with('{');
+    let bundle = if yaml {
+        StateBundle::from_yaml(text)?
+    } else {
+        StateBundle::from_json(text)?
+    };
+    Ok(bundle)
+}
+
+#[cfg(test)]
+mod tests {
+    use bpfman::bundle::{
+        BundleAttach, BundleLink, BundleLocation, BundleProgram, BundleProgramType,
+        STATE_BUNDLE_VERSION,
+    };
+
+    use super::*;
+
+    fn bundle() -> StateBundle {
+        StateBundle {
+            version: STATE_BUNDLE_VERSION,
+            programs: vec![BundleProgram {
+                id: 10,
+                program_type: BundleProgramType::Xdp,
+                name: "pass".to_string(),
+                location: BundleLocation::Image {
+                    url: "quay.io/bpfman-bytecode/xdp_pass:latest".to_string(),
+                    pull_policy: "IfNotPresent".to_string(),
+                },
+                fn_name: None,
+                global_data: [("GLOBAL_u8".to_string(), "01".to_string())].into(),
+                metadata: [("owner".to_string(), "e2e".to_string())].into(),
+                map_owner_id: None,
+            }],
+            links: vec![BundleLink {
+                id: 30,
+                program_id: 10,
+                attach: BundleAttach::Xdp {
+                    iface: "eth0".to_string(),
+                    priority: 50,
+                    proceed_on: vec!["pass".to_string(), "dispatcher_return".to_string()],
+                    netns: None,
+                },
+                metadata: Default::default(),
+                enabled: false,
+                reattach_policy: Some("by-name".to_string()),
+                group: None,
+            }],
+            link_groups: vec![],
+        }
+    }
+
+    #[test]
+    fn test_bundle_round_trip() {
+        let bundle = bundle();
+
+        let json = OutputFormat::Json.serialize(&bundle).unwrap();
+        assert_eq!(parse_bundle("state.json", &json).unwrap(), bundle);
+        assert_eq!(parse_bundle("-", &json).unwrap(), bundle);
+
+        let yaml = OutputFormat::Yaml.serialize(&bundle).unwrap();
+        assert_eq!(parse_bundle("state.yaml", &yaml).unwrap(), bundle);
+        assert_eq!(parse_bundle("-", &yaml).unwrap(), bundle);
+
+        // YAML is a superset of JSON, and bundles that aren't a JSON object
+        // are read as YAML whatever their extension.
+        assert_eq!(parse_bundle("state.yml", &json).unwrap(), bundle);
+        assert_eq!(parse_bundle("state.json", &yaml).unwrap(), bundle);
+    }
+}
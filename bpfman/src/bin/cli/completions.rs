@@ -1,12 +1,26 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright Authors of bpfman
 
-use std::{ffi::OsStr, fs::create_dir_all, path::PathBuf};
+use std::{
+    collections::HashMap,
+    ffi::OsStr,
+    fs::{File, create_dir_all},
+    path::PathBuf,
+};
 
+use bpfman::{
+    errors::BpfmanError,
+    list_interfaces, list_kernel_functions, list_links, list_programs, list_tracepoints,
+    open_database,
+    types::{LinkListFilter, ListFilter},
+};
 use clap::{CommandFactory, Parser};
-use clap_complete::{Generator, Shell};
+use clap_complete::{CompletionCandidate, Generator, Shell, env::Shells};
 
-use crate::args::Cli;
+use crate::{
+    args::{Cli, RemoteArgs},
+    remote,
+};
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -17,6 +31,11 @@ pub struct Args {
     /// Optional: shell to generate completions for
     #[clap(long, short)]
     shell: Option<Shell>,
+    /// Optional: Generate scripts that ask bpfman for the completions when TAB
+    /// is pressed, which also complete program and link IDs, interfaces,
+    /// tracepoints and kernel functions.
+    #[clap(long, verbatim_doc_comment)]
+    dynamic: bool,
 }
 
 fn write_completions_file<G: Generator + Copy, P: AsRef<OsStr>>(generator: G, out_dir: P) {
@@ -25,20 +44,254 @@ fn write_completions_file<G: Generator + Copy, P: AsRef<OsStr>>(generator: G, ou
         .expect("clap complete generation failed");
 }
 
+// Writes the script that registers the dynamic completions of bpfman with a
+// shell, under the same file name as the static completions of that shell.
+fn write_registration_file<P: AsRef<OsStr>>(shell: Shell, out_dir: P) -> Result<(), anyhow::Error> {
+    let shells = Shells::builtins();
+    let completer = shells
+        .completer(&shell.to_string())
+        .ok_or_else(|| anyhow::anyhow!("dynamic completions are not supported for {shell}"))?;
+    let path = PathBuf::from(out_dir.as_ref()).join(shell.file_name("bpfman"));
+    let mut file = File::create(&path)?;
+    completer.write_registration("COMPLETE", "bpfman", "bpfman", "bpfman", &mut file)?;
+    Ok(())
+}
+
 pub fn generate(args: &Args) -> Result<(), anyhow::Error> {
-    let Args { out_dir, shell } = args;
+    let Args {
+        out_dir,
+        shell,
+        dynamic,
+    } = args;
     create_dir_all(out_dir)?;
 
-    if let Some(shell) = shell {
-        write_completions_file(*shell, out_dir);
+    let shells = match shell {
+        Some(shell) => vec![*shell],
+        None => vec![
+            Shell::Bash,
+            Shell::Elvish,
+            Shell::Fish,
+            Shell::PowerShell,
+            Shell::Zsh,
+        ],
+    };
+    for shell in &shells {
+        if *dynamic {
+            write_registration_file(*shell, out_dir)?;
+        } else {
+            write_completions_file(*shell, out_dir);
+        }
+    }
+    if shells.len() == 1 {
         eprintln!("completion script generated in {out_dir:?}");
-        return Ok(());
-    }
-    write_completions_file(Shell::Bash, out_dir);
-    write_completions_file(Shell::Elvish, out_dir);
-    write_completions_file(Shell::Fish, out_dir);
-    write_completions_file(Shell::PowerShell, out_dir);
-    write_completions_file(Shell::Zsh, out_dir);
-    eprintln!("completion scripts generated in {out_dir:?}");
+    } else {
+        eprintln!("completion scripts generated in {out_dir:?}");
+    }
     Ok(())
 }
+
+/// A program or link offered by the dynamic completions.
+pub(crate) struct IdCandidate {
+    pub(crate) id: u32,
+    pub(crate) alias: Option<String>,
+    /// The name of the program, shown next to the candidate.
+    pub(crate) name: String,
+}
+
+impl IdCandidate {
+    // Returns the completion of the ID, and of the alias as name:<ALIAS> if
+    // there is one.
+    fn into_candidates(self) -> Vec<CompletionCandidate> {
+        let mut candidates = vec![
+            CompletionCandidate::new(self.id.to_string()).help(Some(self.name.clone().into())),
+        ];
+        if let Some(alias) = self.alias {
+            candidates.push(
+                CompletionCandidate::new(format!("name:{alias}"))
+                    .help(Some(format!("{} ({})", self.name, self.id).into())),
+            );
+        }
+        candidates
+    }
+}
+
+// Returns the value of an option on the command line being completed. The
+// shell passes the words of the command line as the arguments of bpfman.
+fn completing_option(long: &str, short: Option<char>) -> Option<String> {
+    let args: Vec<String> = std::env::args().collect();
+    let long_flag = format!("--{long}");
+    let short_flag = short.map(|c| format!("-{c}"));
+    args.iter().enumerate().find_map(|(i, arg)| {
+        if *arg == long_flag || Some(arg) == short_flag.as_ref() {
+            args.get(i + 1).cloned()
+        } else {
+            arg.strip_prefix(&format!("{long_flag}="))
+                .map(|value| value.to_string())
+        }
+    })
+}
+
+// Returns the remote host given on the command line being completed, if any.
+fn completing_remote() -> Option<RemoteArgs> {
+    Some(RemoteArgs {
+        remote: Some(completing_option("remote", None)?),
+        tls_cert: completing_option("tls-cert", None).map(PathBuf::from),
+        tls_key: completing_option("tls-key", None).map(PathBuf::from),
+        tls_ca: completing_option("tls-ca", None).map(PathBuf::from),
+    })
+}
+
+// Returns the programs loaded by bpfman on the local host. While bpfman-rpc
+// runs, it holds the database lock, so the programs are listed through it.
+fn local_programs() -> Result<Vec<IdCandidate>, anyhow::Error> {
+    let root_db = match open_database() {
+        Err(BpfmanError::DatabaseLockError) => return remote::program_candidates(None),
+        root_db => root_db?,
+    };
+    let programs = list_programs(&root_db, ListFilter::new(None, HashMap::new(), true))?;
+    Ok(programs
+        .iter()
+        .filter_map(|p| {
+            let data = p.get_data();
+            Some(IdCandidate {
+                id: data.get_id().ok()?,
+                alias: data.get_alias(),
+                name: data.get_name().ok()?,
+            })
+        })
+        .collect())
+}
+
+// Returns the links managed by bpfman on the local host, through bpfman-rpc if
+// it holds the database lock.
+fn local_links() -> Result<Vec<IdCandidate>, anyhow::Error> {
+    let root_db = match open_database() {
+        Err(BpfmanError::DatabaseLockError) => return remote::link_candidates(None),
+        root_db => root_db?,
+    };
+    let links = list_links(&root_db, LinkListFilter::default())?;
+    Ok(links
+        .iter()
+        .filter_map(|l| {
+            Some(IdCandidate {
+                id: l.get_id().ok()?,
+                alias: l.get_alias(),
+                name: l.get_program_name().ok()?,
+            })
+        })
+        .collect())
+}
+
+/// Completes the IDs and aliases of the programs loaded by bpfman, on the
+/// local host or on the host given by --remote.
+pub(crate) fn programs() -> Vec<CompletionCandidate> {
+    let programs = match completing_remote() {
+        Some(remote) => remote::program_candidates(Some(&remote)),
+        None => local_programs(),
+    };
+    programs
+        .unwrap_or_default()
+        .into_iter()
+        .flat_map(IdCandidate::into_candidates)
+        .collect()
+}
+
+// Returns the links managed by bpfman, on the local host or on the host given
+// by --remote.
+fn link_candidates() -> Vec<IdCandidate> {
+    match completing_remote() {
+        Some(remote) => remote::link_candidates(Some(&remote)),
+        None => local_links(),
+    }
+    .unwrap_or_default()
+}
+
+/// Completes the IDs and aliases of the links managed by bpfman, on the local
+/// host or on the host given by --remote.
+pub(crate) fn links() -> Vec<CompletionCandidate> {
+    link_candidates()
+        .into_iter()
+        .flat_map(IdCandidate::into_candidates)
+        .collect()
+}
+
+/// Completes the IDs of the links managed by bpfman, for the commands that
+/// don't accept an alias.
+pub(crate) fn link_ids() -> Vec<CompletionCandidate> {
+    link_candidates()
+        .into_iter()
+        .map(|l| CompletionCandidate::new(l.id.to_string()).help(Some(l.name.into())))
+        .collect()
+}
+
+/// Completes the interfaces of the local host, or of the network namespace
+/// given by --netns. Nothing is offered for a remote host.
+pub(crate) fn interfaces() -> Vec<CompletionCandidate> {
+    if completing_remote().is_some() {
+        return vec![];
+    }
+    let netns = completing_option("netns", Some('n')).map(PathBuf::from);
+    list_interfaces(netns)
+        .unwrap_or_default()
+        .into_iter()
+        .map(CompletionCandidate::new)
+        .collect()
+}
+
+/// Completes the tracepoints of the local kernel as <CATEGORY>/<NAME>.
+pub(crate) fn tracepoints() -> Vec<CompletionCandidate> {
+    if completing_remote().is_some() {
+        return vec![];
+    }
+    list_tracepoints()
+        .unwrap_or_default()
+        .into_iter()
+        .map(CompletionCandidate::new)
+        .collect()
+}
+
+/// Completes the functions of the local kernel that start with `current`.
+/// Only the matching functions are read, as the kernel has too many to offer
+/// them all.
+pub(crate) fn kernel_functions(current: &OsStr) -> Vec<CompletionCandidate> {
+    if completing_remote().is_some() {
+        return vec![];
+    }
+    let Some(prefix) = current.to_str() else {
+        return vec![];
+    };
+    list_kernel_functions(prefix)
+        .unwrap_or_default()
+        .into_iter()
+        .map(CompletionCandidate::new)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_id_candidates() {
+        let candidates = IdCandidate {
+            id: 63652,
+            alias: Some("edge-pass".to_string()),
+            name: "pass".to_string(),
+        }
+        .into_candidates();
+        let values: Vec<_> = candidates.iter().map(|c| c.get_value()).collect();
+        assert_eq!(values, vec!["63652", "name:edge-pass"]);
+        assert_eq!(
+            candidates[1].get_help().map(|h| h.to_string()),
+            Some("pass (63652)".to_string())
+        );
+
+        let candidates = IdCandidate {
+            id: 63653,
+            alias: None,
+            name: "pass".to_string(),
+        }
+        .into_candidates();
+        assert_eq!(candidates.len(), 1);
+    }
+}
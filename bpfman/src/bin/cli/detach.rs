@@ -1,12 +1,24 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright Authors of bpfman
 
-use bpfman::{detach, setup};
+use bpfman::{detach, detach_link_group, resolve_link, select_links, setup};
 
-use crate::args::DetachArgs;
+use crate::{args::DetachArgs, unload::report_selected};
 
 pub(crate) fn execute_detach(args: &DetachArgs) -> Result<(), anyhow::Error> {
     let (config, root_db) = setup()?;
-    detach(&config, &root_db, args.link_id)?;
+    if let Some(group) = &args.group {
+        detach_link_group(&config, &root_db, group)?;
+    } else if let Some(selector) = &args.selector {
+        let mut results = vec![];
+        for link in select_links(&root_db, selector)? {
+            let link_id = link.get_id()?;
+            let result = detach(&config, &root_db, link_id).map_err(|e| e.to_string());
+            results.push((link_id, result));
+        }
+        report_selected("detach", "Detached", "link", results)?;
+    } else if let Some(link) = &args.link {
+        detach(&config, &root_db, resolve_link(&root_db, link)?)?;
+    }
     Ok(())
 }
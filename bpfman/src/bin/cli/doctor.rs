@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use anyhow::bail;
+use bpfman::doctor::{CheckStatus, diagnose};
+
+use crate::{
+    output::{DoctorReportOutput, OutputFormat},
+    table::ProgTable,
+};
+
+pub(crate) fn execute_doctor(output: OutputFormat) -> anyhow::Result<()> {
+    let report = diagnose();
+
+    let report_output = DoctorReportOutput::from(&report);
+    if output.is_serialized() {
+        output.print(&report_output)?;
+    } else {
+        ProgTable::new_doctor_check_list(&report_output.checks).print();
+        println!(
+            "{} passed, {} warning(s), {} failed\n",
+            report.count(CheckStatus::Pass),
+            report.count(CheckStatus::Warn),
+            report.count(CheckStatus::Fail)
+        );
+    }
+
+    let failed = report.count(CheckStatus::Fail);
+    if failed > 0 {
+        bail!("{failed} check(s) failed")
+    }
+    Ok(())
+}
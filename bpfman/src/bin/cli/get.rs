@@ -2,31 +2,44 @@
 // Copyright Authors of bpfman
 
 use anyhow::anyhow;
-use bpfman::{errors::BpfmanError, get_link, get_program, setup, types::Link};
+use bpfman::{
+    get_link, get_link_group, get_program, resolve_link, resolve_program, setup, types::Link,
+};
 use log::warn;
 
 use crate::{
-    args::{GetLinkArgs, GetProgramArgs, GetSubcommand},
+    args::{GetLinkArgs, GetLinkGroupArgs, GetProgramArgs, GetSubcommand},
+    link::print_link,
+    output::{LinkGroupOutput, OutputFormat, ProgramOutput},
     table::ProgTable,
 };
 
 impl GetSubcommand {
-    pub(crate) fn execute(&self) -> anyhow::Result<()> {
+    pub(crate) fn execute(&self, output: OutputFormat) -> anyhow::Result<()> {
         match self {
             GetSubcommand::Program(args) => {
-                execute_get_program(args).map_err(|e| anyhow!("get error: {e}"))
+                execute_get_program(args, output).map_err(|e| anyhow!("get error: {e}"))
             }
             GetSubcommand::Link(args) => {
-                execute_get_link(args).map_err(|e| anyhow!("get error: {e}"))
+                execute_get_link(args, output).map_err(|e| anyhow!("get error: {e}"))
+            }
+            GetSubcommand::LinkGroup(args) => {
+                execute_get_link_group(args, output).map_err(|e| anyhow!("get error: {e}"))
             }
         }
     }
 }
 
-pub(crate) fn execute_get_program(args: &GetProgramArgs) -> Result<(), BpfmanError> {
+pub(crate) fn execute_get_program(
+    args: &GetProgramArgs,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
     let (_, root_db) = setup()?;
-    match get_program(&root_db, args.program_id) {
+    match resolve_program(&root_db, &args.program).and_then(|id| get_program(&root_db, id)) {
         Ok(program) => {
+            if output.is_serialized() {
+                return output.print(&ProgramOutput::try_from(&program)?);
+            }
             let mut links: Vec<Link> = vec![];
             let data = program.get_data();
 
@@ -57,25 +70,32 @@ pub(crate) fn execute_get_program(args: &GetProgramArgs) -> Result<(), BpfmanErr
         }
         Err(e) => {
             warn!("BPFMAN get error: {}", e);
-            Err(e)
+            Err(e.into())
         }
     }
 }
 
-pub(crate) fn execute_get_link(args: &GetLinkArgs) -> Result<(), BpfmanError> {
+pub(crate) fn execute_get_link(args: &GetLinkArgs, output: OutputFormat) -> anyhow::Result<()> {
     let (_, root_db) = setup()?;
-    match get_link(&root_db, args.link_id) {
-        Ok(link) => {
-            let program = link.get_program(&root_db)?;
-
-            if let Ok(p) = ProgTable::new_link(&program, &link) {
-                p.print();
-            }
-            Ok(())
+    match resolve_link(&root_db, &args.link).and_then(|id| get_link(&root_db, id)) {
+        Ok(link) => print_link(&root_db, &link, output),
+        Err(e) => {
+            warn!("BPFMAN get error: {}", e);
+            Err(e.into())
         }
+    }
+}
+
+pub(crate) fn execute_get_link_group(
+    args: &GetLinkGroupArgs,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let (_, root_db) = setup()?;
+    match get_link_group(&root_db, &args.name) {
+        Ok(group) => LinkGroupOutput::try_from(&group)?.print(output),
         Err(e) => {
             warn!("BPFMAN get error: {}", e);
-            Err(e)
+            Err(e.into())
         }
     }
 }
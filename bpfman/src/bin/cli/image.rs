@@ -37,26 +37,36 @@ impl TryFrom<&PullBytecodeArgs> for BytecodeImage {
     type Error = anyhow::Error;
 
     fn try_from(value: &PullBytecodeArgs) -> Result<Self, Self::Error> {
-        let image_pull_policy: ImagePullPolicy = value.pull_policy.as_str().try_into()?;
-        let (username, password) = match &value.registry_auth {
-            Some(a) => {
-                let auth_raw = general_purpose::STANDARD.decode(a)?;
-                let auth_string = String::from_utf8(auth_raw)?;
-                let (username, password) = auth_string.split_once(':').unwrap();
-                (Some(username.to_owned()), Some(password.to_owned()))
-            }
-            None => (None, None),
-        };
-
-        Ok(BytecodeImage {
-            image_url: value.image_url.clone(),
-            image_pull_policy,
-            username,
-            password,
-        })
+        bytecode_image(&value.image_url, &value.registry_auth, &value.pull_policy)
     }
 }
 
+/// Returns the bytecode image for an image URL, with the registry auth and
+/// pull policy given on the command line.
+pub(crate) fn bytecode_image(
+    image_url: &str,
+    registry_auth: &Option<String>,
+    pull_policy: &str,
+) -> anyhow::Result<BytecodeImage> {
+    let image_pull_policy: ImagePullPolicy = pull_policy.try_into()?;
+    let (username, password) = match registry_auth {
+        Some(a) => {
+            let auth_raw = general_purpose::STANDARD.decode(a)?;
+            let auth_string = String::from_utf8(auth_raw)?;
+            let (username, password) = auth_string.split_once(':').unwrap();
+            (Some(username.to_owned()), Some(password.to_owned()))
+        }
+        None => (None, None),
+    };
+
+    Ok(BytecodeImage {
+        image_url: image_url.to_string(),
+        image_pull_policy,
+        username,
+        password,
+    })
+}
+
 /// Image builder defines the platforms and build args that must be passed to
 /// a container runtime to build an eBPF image. For single host arch images
 /// platforms will be None and build args will contain a single string.
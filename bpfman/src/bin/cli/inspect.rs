@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use std::{fs, path::Path};
+
+use anyhow::Context;
+use bpfman::{
+    inspect::{inspect_bytecode, inspect_image},
+    setup,
+};
+
+use crate::{
+    args::InspectArgs,
+    image::bytecode_image,
+    output::{BytecodeInfoOutput, OutputFormat},
+    table::ProgTable,
+};
+
+pub(crate) fn execute_inspect(args: &InspectArgs, output: OutputFormat) -> anyhow::Result<()> {
+    // Local files are parsed without calling setup(), so that they can be
+    // inspected without privileges.
+    let info = if Path::new(&args.bytecode).is_file() {
+        let bytecode = fs::read(&args.bytecode)
+            .with_context(|| format!("unable to read {}", args.bytecode))?;
+        inspect_bytecode(&bytecode)?
+    } else {
+        let image = bytecode_image(&args.bytecode, &args.registry_auth, &args.pull_policy)?;
+        let (_, root_db) = setup()?;
+        inspect_image(&root_db, &image)?
+    };
+
+    let info = BytecodeInfoOutput::from(&info);
+    if output.is_serialized() {
+        return output.print(&info);
+    }
+    ProgTable::new_bytecode_info(&info).print();
+    ProgTable::new_bytecode_program_list(&info.programs).print();
+    if !info.maps.is_empty() {
+        ProgTable::new_bytecode_map_list(&info.maps).print();
+    }
+    if !info.globals.is_empty() {
+        ProgTable::new_global_variable_list(&info.globals).print();
+    }
+    Ok(())
+}
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use bpfman::{
+    config::Config, disable_link, enable_link, set_link_priority, set_link_reattach_policy, setup,
+    types::Link,
+};
+use sled::Db;
+
+use crate::{
+    args::{
+        DisableLinkArgs, EnableLinkArgs, LinkSubcommand, SetLinkPriorityArgs,
+        SetLinkReattachPolicyArgs,
+    },
+    output::{LinkOutput, OutputFormat},
+    table::ProgTable,
+};
+
+impl LinkSubcommand {
+    pub(crate) fn execute(&self, output: OutputFormat) -> anyhow::Result<()> {
+        let (config, root_db) = setup()?;
+        let link = match self {
+            LinkSubcommand::Disable(args) => execute_disable(&config, &root_db, args),
+            LinkSubcommand::Enable(args) => execute_enable(&config, &root_db, args),
+            LinkSubcommand::SetPriority(args) => execute_set_priority(&config, &root_db, args),
+            LinkSubcommand::SetReattachPolicy(args) => execute_set_reattach_policy(&root_db, args),
+        }?;
+        print_link(&root_db, &link, output)
+    }
+}
+
+pub(crate) fn execute_disable(
+    config: &Config,
+    root_db: &Db,
+    args: &DisableLinkArgs,
+) -> anyhow::Result<Link> {
+    Ok(disable_link(config, root_db, args.link_id)?)
+}
+
+pub(crate) fn execute_enable(
+    config: &Config,
+    root_db: &Db,
+    args: &EnableLinkArgs,
+) -> anyhow::Result<Link> {
+    Ok(enable_link(config, root_db, args.link_id)?)
+}
+
+pub(crate) fn execute_set_priority(
+    config: &Config,
+    root_db: &Db,
+    args: &SetLinkPriorityArgs,
+) -> anyhow::Result<Link> {
+    Ok(set_link_priority(
+        config,
+        root_db,
+        args.link_id,
+        args.priority,
+    )?)
+}
+
+pub(crate) fn execute_set_reattach_policy(
+    root_db: &Db,
+    args: &SetLinkReattachPolicyArgs,
+) -> anyhow::Result<Link> {
+    Ok(set_link_reattach_policy(
+        root_db,
+        args.link_id,
+        args.policy,
+    )?)
+}
+
+/// Prints a link in the given output format.
+pub(crate) fn print_link(root_db: &Db, link: &Link, output: OutputFormat) -> anyhow::Result<()> {
+    let program = link.get_program(root_db)?;
+
+    if output.is_serialized() {
+        return output.print(&LinkOutput::from_link(root_db, &program, link)?);
+    }
+    if let Ok(p) = ProgTable::new_link(&program, link) {
+        p.print();
+    }
+    Ok(())
+}
@@ -5,48 +5,85 @@ use std::collections::HashMap;
 
 use anyhow::bail;
 use bpfman::{
-    get_link, list_programs, setup,
-    types::{ListFilter, METADATA_APPLICATION_TAG},
+    get_link, list_programs, list_programs_page, setup,
+    types::{ListFilter, METADATA_APPLICATION_TAG, MetadataRequirement},
 };
 use log::warn;
 
 use crate::{
     args::{ListLinkArgs, ListProgramArgs, ListSubcommand},
+    output::{LinkList, LinkOutput, OutputFormat, ProgramList, ProgramOutput},
     table::ProgTable,
 };
 
 impl ListSubcommand {
-    pub(crate) fn execute(&self) -> anyhow::Result<()> {
+    pub(crate) fn execute(&self, output: OutputFormat) -> anyhow::Result<()> {
         match self {
-            ListSubcommand::Programs(args) => execute_program_list(args),
-            ListSubcommand::Program(args) => execute_program_list(args),
-            ListSubcommand::Links(args) => execute_link_list(args),
-            ListSubcommand::Link(args) => execute_link_list(args),
+            ListSubcommand::Programs(args) => execute_program_list(args, output),
+            ListSubcommand::Program(args) => execute_program_list(args, output),
+            ListSubcommand::Links(args) => execute_link_list(args, output),
+            ListSubcommand::Link(args) => execute_link_list(args, output),
         }
     }
 }
 
-pub(crate) fn execute_program_list(args: &ListProgramArgs) -> anyhow::Result<()> {
+pub(crate) fn execute_program_list(
+    args: &ListProgramArgs,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
     let prog_type_filter = args.program_type.map(|p| p as u32);
 
-    let filter = ListFilter::new(
+    let mut requirements = vec![];
+    for selector in &args.selector {
+        requirements.extend(MetadataRequirement::parse_selector(selector)?);
+    }
+    let mut filter = ListFilter::new(
         prog_type_filter,
         parse_metadata(&args.metadata_selector, &args.application),
         !args.all,
-    );
+    )
+    .with_metadata_requirements(requirements)
+    .with_iface(args.iface.clone())
+    .with_no_links(args.no_links)
+    .with_sort_by(args.sort_by)
+    .with_page(args.limit, args.page_token.clone());
+    if let Some(name) = &args.name {
+        filter = filter.with_name_regex(name)?;
+    }
 
-    let mut table = ProgTable::new_program_list();
     let (_, root_db) = setup()?;
-    for r in list_programs(&root_db, filter)? {
+    let page = list_programs_page(&root_db, filter)?;
+    if output != OutputFormat::Table {
+        let programs = page
+            .programs
+            .iter()
+            .map(ProgramOutput::try_from)
+            .collect::<Result<_, _>>()?;
+        return ProgramList {
+            programs,
+            next_page_token: page.next_page_token,
+        }
+        .print(output);
+    }
+
+    let mut table = ProgTable::new_program_list();
+    for r in page.programs {
         if let Err(e) = table.add_program_response(r) {
             bail!(e)
         }
     }
     table.print();
+    print_next_page_token(page.next_page_token.as_deref());
     Ok(())
 }
 
-pub(crate) fn execute_link_list(args: &ListLinkArgs) -> anyhow::Result<()> {
+pub(crate) fn print_next_page_token(token: Option<&str>) {
+    if let Some(token) = token {
+        println!("\nMore programs can be listed with --page-token {token}");
+    }
+}
+
+pub(crate) fn execute_link_list(args: &ListLinkArgs, output: OutputFormat) -> anyhow::Result<()> {
     let prog_type_filter = args.program_type.map(|p| p as u32);
 
     let filter = ListFilter::new(
@@ -56,6 +93,7 @@ pub(crate) fn execute_link_list(args: &ListLinkArgs) -> anyhow::Result<()> {
     );
 
     let mut table = ProgTable::new_link_list();
+    let mut links = vec![];
     let (_, root_db) = setup()?;
     for program in list_programs(&root_db, filter)? {
         let data = program.get_data();
@@ -63,6 +101,9 @@ pub(crate) fn execute_link_list(args: &ListLinkArgs) -> anyhow::Result<()> {
             if !link_ids.is_empty() {
                 for link_id in link_ids {
                     match get_link(&root_db, link_id) {
+                        Ok(link) if output != OutputFormat::Table => {
+                            links.push(LinkOutput::from_link(&root_db, &program, &link)?);
+                        }
                         Ok(link) => {
                             if let Err(e) = table.add_link_response(&program, &link) {
                                 bail!(e)
@@ -76,6 +117,9 @@ pub(crate) fn execute_link_list(args: &ListLinkArgs) -> anyhow::Result<()> {
             }
         }
     }
+    if output != OutputFormat::Table {
+        return LinkList { links }.print(output);
+    }
     table.print();
     Ok(())
 }
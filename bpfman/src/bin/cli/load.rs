@@ -7,30 +7,36 @@ use anyhow::bail;
 use bpfman::{
     add_programs, setup,
     types::{
-        FentryProgram, FexitProgram, KprobeProgram, Link, Location, METADATA_APPLICATION_TAG,
-        Program, ProgramData, TcProgram, TcxProgram, TracepointProgram, UprobeProgram, XdpProgram,
+        FentryProgram, FexitProgram, KprobeProgram, Link, Location, METADATA_ALIAS_TAG,
+        METADATA_APPLICATION_TAG, Program, ProgramData, TcProgram, TcxProgram, TracepointProgram,
+        UprobeProgram, XdpProgram,
     },
+    validate_programs,
 };
 use log::warn;
 
 use crate::{
     args::{GlobalArg, LoadFileArgs, LoadImageArgs, LoadSubcommand},
+    output::{OutputFormat, ProgramList, ProgramOutput},
     table::ProgTable,
 };
 
 impl LoadSubcommand {
-    pub(crate) fn execute(&self) -> anyhow::Result<()> {
+    pub(crate) fn execute(&self, output: OutputFormat) -> anyhow::Result<()> {
         match self {
-            LoadSubcommand::File(l) => execute_load_file(l),
-            LoadSubcommand::Image(l) => execute_load_image(l),
+            LoadSubcommand::File(l) => execute_load_file(l, output),
+            LoadSubcommand::Image(l) => execute_load_image(l, output),
         }
     }
 }
 
-pub(crate) fn execute_load_file(args: &LoadFileArgs) -> anyhow::Result<()> {
+pub(crate) fn execute_load_file(args: &LoadFileArgs, output: OutputFormat) -> anyhow::Result<()> {
     let (config, root_db) = setup()?;
     let bytecode_source = Location::File(args.path.clone());
 
+    if args.alias.is_some() && args.programs.len() > 1 {
+        bail!("--alias can only be used when loading a single program");
+    }
     let mut progs = vec![];
     let prog_list = args.programs.clone();
     for (prog_type, parts) in prog_list {
@@ -40,13 +46,19 @@ pub(crate) fn execute_load_file(args: &LoadFileArgs) -> anyhow::Result<()> {
         if (prog_type == "fentry" || prog_type == "fexit") && parts.len() != 2 {
             bail!("Missing function name for fentry/fexit program");
         }
-        let data = ProgramData::new(
+        let mut data = ProgramData::new(
             bytecode_source.clone(),
             name.clone(),
-            parse_metadata(&args.metadata, &args.application),
+            with_alias(
+                parse_metadata(&args.metadata, &args.application),
+                &args.alias,
+            ),
             parse_global(&args.global),
             args.map_owner_id,
         )?;
+        if let Some(level) = args.verifier_log_level {
+            data.set_verifier_log_level(level)?;
+        }
         // Need to determine the program type here (XDP, TC, etc)
         // This would not be required if we had a generic "load" function
         let prog = match prog_type.as_str() {
@@ -68,27 +80,21 @@ pub(crate) fn execute_load_file(args: &LoadFileArgs) -> anyhow::Result<()> {
         };
         progs.push(prog);
     }
+    if args.dry_run {
+        let programs = validate_programs(&config, &root_db, progs)?;
+        return print_validated_programs(&programs, output);
+    }
     let programs = add_programs(&config, &root_db, progs)?;
 
-    if programs.len() == 1 {
-        let links: Vec<Link> = vec![];
-        ProgTable::new_program(&programs[0], links)?.print();
-        ProgTable::new_kernel_info(&programs[0])?.print();
-    } else {
-        let mut table = ProgTable::new_program_list();
-        for program in programs {
-            if let Err(e) = table.add_program_response(program) {
-                bail!(e)
-            }
-        }
-        table.print();
-    }
-    Ok(())
+    print_loaded_programs(programs, output)
 }
 
-pub(crate) fn execute_load_image(args: &LoadImageArgs) -> anyhow::Result<()> {
+pub(crate) fn execute_load_image(args: &LoadImageArgs, output: OutputFormat) -> anyhow::Result<()> {
     let (config, root_db) = setup()?;
     let bytecode_source = Location::Image((&args.pull_args).try_into()?);
+    if args.alias.is_some() && args.programs.len() > 1 {
+        bail!("--alias can only be used when loading a single program");
+    }
     let mut progs = vec![];
     let prog_list = args.programs.clone();
 
@@ -99,13 +105,19 @@ pub(crate) fn execute_load_image(args: &LoadImageArgs) -> anyhow::Result<()> {
         if (prog_type == "fentry" || prog_type == "fexit") && parts.len() != 2 {
             bail!("Missing function name for fentry/fexit program");
         }
-        let data = ProgramData::new(
+        let mut data = ProgramData::new(
             bytecode_source.clone(),
             name.clone(),
-            parse_metadata(&args.metadata, &args.application),
+            with_alias(
+                parse_metadata(&args.metadata, &args.application),
+                &args.alias,
+            ),
             parse_global(&args.global),
             args.map_owner_id,
         )?;
+        if let Some(level) = args.verifier_log_level {
+            data.set_verifier_log_level(level)?;
+        }
 
         // Need to determine the program type here (XDP, TC, etc)
         // This would not be required if we had a generic "load" function in
@@ -129,8 +141,27 @@ pub(crate) fn execute_load_image(args: &LoadImageArgs) -> anyhow::Result<()> {
         };
         progs.push(prog);
     }
+    if args.dry_run {
+        let programs = validate_programs(&config, &root_db, progs)?;
+        return print_validated_programs(&programs, output);
+    }
     let programs = add_programs(&config, &root_db, progs)?;
 
+    print_loaded_programs(programs, output)
+}
+
+fn print_loaded_programs(programs: Vec<Program>, output: OutputFormat) -> anyhow::Result<()> {
+    if output.is_serialized() {
+        let programs = programs
+            .iter()
+            .map(ProgramOutput::try_from)
+            .collect::<Result<_, _>>()?;
+        return output.print(&ProgramList {
+            programs,
+            next_page_token: None,
+        });
+    }
+
     if programs.len() == 1 {
         let links: Vec<Link> = vec![];
         ProgTable::new_program(&programs[0], links)?.print();
@@ -147,6 +178,31 @@ pub(crate) fn execute_load_image(args: &LoadImageArgs) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn print_validated_programs(programs: &[Program], output: OutputFormat) -> anyhow::Result<()> {
+    if output.is_serialized() {
+        let programs = programs
+            .iter()
+            .map(ProgramOutput::try_from)
+            .collect::<Result<_, _>>()?;
+        return output.print(&ProgramList {
+            programs,
+            next_page_token: None,
+        });
+    }
+
+    let mut table = ProgTable::new_validated_list();
+    for program in programs {
+        let data = program.get_data();
+        table.add_validated_program(
+            data.get_name()?,
+            ProgTable::get_type_str(program),
+            data.get_verifier_log_summary()?,
+        );
+    }
+    table.print();
+    Ok(())
+}
+
 pub(crate) fn parse_metadata(
     metadata: &Option<Vec<(String, String)>>,
     application: &Option<String>,
@@ -176,7 +232,18 @@ pub(crate) fn parse_metadata(
     data
 }
 
-fn parse_global(global: &Option<Vec<GlobalArg>>) -> HashMap<String, Vec<u8>> {
+/// Adds the alias of a program or link to its metadata.
+pub(crate) fn with_alias(
+    mut metadata: HashMap<String, String>,
+    alias: &Option<String>,
+) -> HashMap<String, String> {
+    if let Some(alias) = alias {
+        metadata.insert(METADATA_ALIAS_TAG.to_string(), alias.clone());
+    }
+    metadata
+}
+
+pub(crate) fn parse_global(global: &Option<Vec<GlobalArg>>) -> HashMap<String, Vec<u8>> {
     let mut global_data: HashMap<String, Vec<u8>> = HashMap::new();
 
     if let Some(global) = global {
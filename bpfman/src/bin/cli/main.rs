@@ -3,41 +3,75 @@
 
 use args::Commands;
 use attach::execute_attach;
-use clap::Parser;
+use bundle::{execute_export, execute_import};
+use clap::{CommandFactory, Parser};
+use clap_complete::CompleteEnv;
 use detach::execute_detach;
+use doctor::execute_doctor;
+use inspect::execute_inspect;
 use log::debug;
+use output::OutputFormat;
+use top::execute_top;
+use trace::execute_trace;
 use unload::execute_unload;
+use verify::execute_verify;
 
 mod args;
 mod attach;
+mod bundle;
 mod completions;
 mod detach;
+mod doctor;
 mod get;
 mod image;
+mod inspect;
+mod link;
 mod list;
 mod load;
 mod manpage;
+mod output;
+mod policy;
+mod remote;
 mod table;
+mod top;
+mod trace;
 mod unload;
+mod verify;
 
 fn main() -> anyhow::Result<()> {
+    // Answers the shell when it asks for completions through the scripts of
+    // `bpfman completions --dynamic`, and returns otherwise.
+    CompleteEnv::with_factory(crate::args::Cli::command).complete();
+
     env_logger::try_init()?;
     debug!("Log using env_logger");
 
     let cli = crate::args::Cli::parse();
 
-    cli.command.execute()
+    if cli.remote.remote.is_some() {
+        return cli.command.execute_remote(&cli.remote, cli.output);
+    }
+    cli.command.execute(cli.output)
 }
 
 impl Commands {
-    pub(crate) fn execute(&self) -> Result<(), anyhow::Error> {
+    pub(crate) fn execute(&self, output: OutputFormat) -> Result<(), anyhow::Error> {
         match self {
-            Commands::Load(l) => l.execute(),
+            Commands::Load(l) => l.execute(output),
             Commands::Unload(args) => execute_unload(args),
-            Commands::Attach(args) => execute_attach(args),
+            Commands::Attach(args) => execute_attach(args, output),
             Commands::Detach(args) => execute_detach(args),
-            Commands::List(l) => l.execute(),
-            Commands::Get(g) => g.execute(),
+            Commands::Link(l) => l.execute(output),
+            Commands::List(l) => l.execute(output),
+            Commands::Get(g) => g.execute(output),
+            Commands::Verify(args) => execute_verify(args),
+            Commands::Inspect(args) => execute_inspect(args, output),
+            Commands::Doctor => execute_doctor(output),
+            Commands::Top(args) => execute_top(args),
+            Commands::Trace(args) => execute_trace(args),
+            Commands::Export => execute_export(output),
+            Commands::Import(args) => execute_import(args, output),
+            Commands::Policy(p) => p.execute(),
             Commands::Image(i) => i.execute(),
             Commands::Man(args) => manpage::generate(args),
             Commands::Completions(args) => completions::generate(args),
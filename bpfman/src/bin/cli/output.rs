@@ -0,0 +1,971 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+};
+
+use anyhow::bail;
+use bpfman::{
+    doctor::DoctorReport,
+    errors::BpfmanError,
+    get_kernel_link_info,
+    inspect::BytecodeInfo,
+    types::{
+        BpfProgType, ImagePullPolicy, KernelLinkInfo, Link, LinkGroup, LinkGroupMember, Location,
+        METADATA_APPLICATION_TAG, Program, TcProceedOnEntry, XdpProceedOnEntry,
+    },
+};
+use bpfman_proto::v1::{
+    KernelLinkInfo as RpcKernelLinkInfo, KernelProgramInfo as RpcKernelProgramInfo, LinkGroupInfo,
+    LinkInfo, ProgramInfo as RpcProgramInfo, attach_info::Info,
+    bytecode_location::Location as RpcLocation,
+};
+use clap::ValueEnum;
+use hex::encode_upper;
+use log::warn;
+use serde::Serialize;
+use sled::Db;
+
+use crate::{list::print_next_page_token, table::ProgTable};
+
+/// OutputFormat is how commands that return programs and links print them.
+#[derive(ValueEnum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) enum OutputFormat {
+    /// Tables, meant to be read by people.
+    #[default]
+    Table,
+    /// Tables with additional columns when listing programs and links.
+    Wide,
+    /// JSON, with a schema that is kept stable across releases.
+    Json,
+    /// YAML, with the same schema as JSON.
+    Yaml,
+}
+
+impl OutputFormat {
+    /// Returns true if the output is JSON or YAML rather than a table.
+    pub(crate) fn is_serialized(&self) -> bool {
+        matches!(self, OutputFormat::Json | OutputFormat::Yaml)
+    }
+
+    /// Prints a value as JSON or YAML.
+    pub(crate) fn print<T: Serialize>(&self, value: &T) -> anyhow::Result<()> {
+        print!("{}", self.serialize(value)?);
+        Ok(())
+    }
+
+    /// Returns a value as JSON or YAML, ending with a newline.
+    pub(crate) fn serialize<T: Serialize>(&self, value: &T) -> anyhow::Result<String> {
+        match self {
+            OutputFormat::Json => Ok(format!("{}\n", serde_json::to_string_pretty(value)?)),
+            // Going through a JSON value writes enums as maps, as in JSON,
+            // rather than YAML tags.
+            OutputFormat::Yaml => Ok(serde_yaml::to_string(&serde_json::to_value(value)?)?),
+            OutputFormat::Table | OutputFormat::Wide => bail!("{self:?} output is not serialized"),
+        }
+    }
+}
+
+/// A program, as printed by `list programs`, `get program` and `load`.
+#[derive(Serialize, Debug)]
+pub(crate) struct ProgramOutput {
+    /// The state bpfman keeps for the program, or None if the program wasn't
+    /// loaded by bpfman.
+    pub(crate) bpfman: Option<BpfmanProgramOutput>,
+    /// The kernel's view of the program.
+    pub(crate) kernel: KernelProgramOutput,
+}
+
+#[derive(Serialize, Debug)]
+pub(crate) struct BpfmanProgramOutput {
+    pub(crate) name: String,
+    pub(crate) bytecode: Option<BytecodeOutput>,
+    /// Global variables, as upper case hex strings.
+    pub(crate) global_data: BTreeMap<String, String>,
+    pub(crate) metadata: BTreeMap<String, String>,
+    pub(crate) map_pin_path: Option<String>,
+    pub(crate) map_owner_id: Option<u32>,
+    pub(crate) maps_used_by: Vec<u32>,
+    pub(crate) links: Vec<u32>,
+    /// Summary of the verification of the program when it was loaded.
+    pub(crate) verifier_log_summary: Option<String>,
+}
+
+/// Where the bytecode of a program was loaded from. Like `ProgramInfo`, the
+/// credentials of an image are never included.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum BytecodeOutput {
+    File { path: String },
+    Image { url: String, pull_policy: String },
+}
+
+#[derive(Serialize, Debug)]
+pub(crate) struct KernelProgramOutput {
+    pub(crate) id: u32,
+    pub(crate) name: String,
+    #[serde(rename = "type")]
+    pub(crate) program_type: String,
+    pub(crate) loaded_at: String,
+    pub(crate) tag: String,
+    pub(crate) gpl_compatible: bool,
+    pub(crate) map_ids: Vec<u32>,
+    pub(crate) btf_id: u32,
+    pub(crate) bytes_xlated: u32,
+    pub(crate) jited: bool,
+    pub(crate) bytes_jited: u32,
+    pub(crate) bytes_memlock: u32,
+    pub(crate) verified_insns: u32,
+}
+
+/// The programs printed by `list programs` and `load`.
+#[derive(Serialize, Debug)]
+pub(crate) struct ProgramList {
+    pub(crate) programs: Vec<ProgramOutput>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) next_page_token: Option<String>,
+}
+
+/// A link, as printed by `list links`, `get link`, `attach` and the `link`
+/// commands.
+#[derive(Serialize, Debug)]
+pub(crate) struct LinkOutput {
+    pub(crate) id: u32,
+    pub(crate) program_id: u32,
+    pub(crate) program_name: String,
+    pub(crate) enabled: bool,
+    pub(crate) group: Option<String>,
+    pub(crate) interface_missing: bool,
+    pub(crate) attach: AttachOutput,
+    pub(crate) metadata: BTreeMap<String, String>,
+    /// The kernel's view of the link, or None if it isn't attached.
+    pub(crate) kernel: Option<KernelLinkInfo>,
+}
+
+/// Where a link is attached, tagged with the type of the link.
+#[derive(Serialize, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum AttachOutput {
+    Xdp {
+        iface: String,
+        priority: i32,
+        position: i32,
+        proceed_on: Vec<String>,
+        netns: Option<String>,
+    },
+    Tc {
+        iface: String,
+        direction: String,
+        priority: i32,
+        position: i32,
+        proceed_on: Vec<String>,
+        netns: Option<String>,
+    },
+    Tcx {
+        iface: String,
+        direction: String,
+        priority: i32,
+        position: i32,
+        netns: Option<String>,
+    },
+    Tracepoint {
+        tracepoint: String,
+    },
+    Kprobe {
+        fn_name: String,
+        offset: u64,
+        container_pid: Option<i32>,
+    },
+    Uprobe {
+        target: String,
+        fn_name: Option<String>,
+        offset: u64,
+        pid: Option<i32>,
+        container_pid: Option<i32>,
+    },
+    Fentry {
+        fn_name: Option<String>,
+    },
+    Fexit {
+        fn_name: Option<String>,
+    },
+}
+
+/// The links printed by `list links`.
+#[derive(Serialize, Debug)]
+pub(crate) struct LinkList {
+    pub(crate) links: Vec<LinkOutput>,
+}
+
+/// A link group, as printed by `get link-group` and `attach --group`.
+#[derive(Serialize, Debug)]
+pub(crate) struct LinkGroupOutput {
+    pub(crate) name: String,
+    pub(crate) program_id: u32,
+    pub(crate) selector: String,
+    pub(crate) members: Vec<LinkGroupMember>,
+}
+
+/// The contents of bytecode, as printed by `inspect`.
+#[derive(Serialize, Debug)]
+pub(crate) struct BytecodeInfoOutput {
+    pub(crate) license: String,
+    pub(crate) kernel_version: Option<u32>,
+    pub(crate) programs: Vec<BytecodeProgramOutput>,
+    pub(crate) maps: Vec<BytecodeMapOutput>,
+    pub(crate) globals: Vec<GlobalVariableOutput>,
+    pub(crate) required_features: Vec<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub(crate) struct BytecodeProgramOutput {
+    pub(crate) name: String,
+    pub(crate) section: String,
+    #[serde(rename = "type")]
+    pub(crate) program_type: String,
+    pub(crate) sleepable: bool,
+    pub(crate) attach_target: Option<String>,
+    /// Whether the attach target is in the BTF of the local kernel, or None if
+    /// that couldn't be checked.
+    pub(crate) attach_target_found: Option<bool>,
+}
+
+#[derive(Serialize, Debug)]
+pub(crate) struct BytecodeMapOutput {
+    pub(crate) name: String,
+    #[serde(rename = "type")]
+    pub(crate) map_type: String,
+    pub(crate) key_size: u32,
+    pub(crate) value_size: u32,
+    pub(crate) max_entries: u32,
+}
+
+#[derive(Serialize, Debug)]
+pub(crate) struct GlobalVariableOutput {
+    pub(crate) name: String,
+    pub(crate) section: String,
+    pub(crate) size: u64,
+    #[serde(rename = "type")]
+    pub(crate) type_name: Option<String>,
+}
+
+/// The result of the checks of `doctor`.
+#[derive(Serialize, Debug)]
+pub(crate) struct DoctorReportOutput {
+    /// The worst status of the checks: pass, warn or fail.
+    pub(crate) status: String,
+    pub(crate) checks: Vec<DoctorCheckOutput>,
+}
+
+/// The IDs of the programs and links created by `import`.
+#[derive(Serialize, Debug)]
+pub(crate) struct ImportOutput {
+    pub(crate) programs: Vec<ImportedIdOutput>,
+    pub(crate) links: Vec<ImportedIdOutput>,
+}
+
+#[derive(Serialize, Debug)]
+pub(crate) struct ImportedIdOutput {
+    /// The ID in the state bundle.
+    pub(crate) bundle_id: u32,
+    pub(crate) id: u32,
+}
+
+#[derive(Serialize, Debug)]
+pub(crate) struct DoctorCheckOutput {
+    pub(crate) category: String,
+    pub(crate) name: String,
+    pub(crate) status: String,
+    pub(crate) message: String,
+}
+
+impl TryFrom<&Program> for ProgramOutput {
+    type Error = BpfmanError;
+
+    fn try_from(program: &Program) -> Result<Self, Self::Error> {
+        let data = program.get_data();
+
+        // If the name does not exist, the program wasn't loaded by bpfman.
+        let bpfman = if data.get_name().is_ok() {
+            let bytecode = match data.get_location()? {
+                Location::Image(i) => BytecodeOutput::Image {
+                    url: i.image_url,
+                    pull_policy: i.image_pull_policy.to_string(),
+                },
+                Location::File(path) => BytecodeOutput::File { path },
+            };
+            Some(BpfmanProgramOutput {
+                name: data.get_name()?,
+                bytecode: Some(bytecode),
+                global_data: hex_global_data(data.get_global_data()?),
+                metadata: data.get_metadata()?.into_iter().collect(),
+                map_pin_path: data
+                    .get_map_pin_path()?
+                    .map(|p| p.to_string_lossy().to_string()),
+                map_owner_id: data.get_map_owner_id()?,
+                maps_used_by: data.get_maps_used_by()?,
+                links: data.get_link_ids()?,
+                verifier_log_summary: data.get_verifier_log_summary()?,
+            })
+        } else {
+            None
+        };
+
+        Ok(ProgramOutput {
+            bpfman,
+            kernel: KernelProgramOutput {
+                id: data.get_id()?,
+                name: data.get_kernel_name()?,
+                program_type: program.kind().to_string(),
+                loaded_at: data.get_kernel_loaded_at()?,
+                tag: data.get_kernel_tag()?,
+                gpl_compatible: data.get_kernel_gpl_compatible()?,
+                map_ids: data.get_kernel_map_ids()?,
+                btf_id: data.get_kernel_btf_id()?,
+                bytes_xlated: data.get_kernel_bytes_xlated()?,
+                jited: data.get_kernel_jited()?,
+                bytes_jited: data.get_kernel_bytes_jited()?,
+                bytes_memlock: data.get_kernel_bytes_memlock()?,
+                verified_insns: data.get_kernel_verified_insns()?,
+            },
+        })
+    }
+}
+
+impl ProgramOutput {
+    /// Builds the output of a program returned by a remote bpfman-rpc.
+    pub(crate) fn from_rpc(
+        info: Option<&RpcProgramInfo>,
+        kernel_info: &RpcKernelProgramInfo,
+    ) -> Self {
+        let bpfman = info.map(|info| BpfmanProgramOutput {
+            name: info.name.clone(),
+            bytecode: match info.bytecode.as_ref().and_then(|b| b.location.as_ref()) {
+                Some(RpcLocation::Image(i)) => Some(BytecodeOutput::Image {
+                    url: i.url.clone(),
+                    pull_policy: match ImagePullPolicy::try_from(i.image_pull_policy) {
+                        Ok(pp) => pp.to_string(),
+                        Err(_) => i.image_pull_policy.to_string(),
+                    },
+                }),
+                Some(RpcLocation::File(path)) => Some(BytecodeOutput::File { path: path.clone() }),
+                None => None,
+            },
+            global_data: hex_global_data(info.global_data.clone()),
+            metadata: info.metadata.clone().into_iter().collect(),
+            map_pin_path: Some(info.map_pin_path.clone()).filter(|p| !p.is_empty()),
+            map_owner_id: info.map_owner_id,
+            maps_used_by: info
+                .map_used_by
+                .iter()
+                .filter_map(|id| id.parse().ok())
+                .collect(),
+            links: info.links.clone(),
+            verifier_log_summary: info.verifier_log_summary.clone(),
+        });
+
+        ProgramOutput {
+            bpfman,
+            kernel: KernelProgramOutput {
+                id: kernel_info.id,
+                name: kernel_info.name.clone(),
+                program_type: match BpfProgType::try_from(kernel_info.program_type) {
+                    Ok(t) => t.to_string(),
+                    Err(_) => kernel_info.program_type.to_string(),
+                },
+                loaded_at: kernel_info.loaded_at.clone(),
+                tag: kernel_info.tag.clone(),
+                gpl_compatible: kernel_info.gpl_compatible,
+                map_ids: kernel_info.map_ids.clone(),
+                btf_id: kernel_info.btf_id,
+                bytes_xlated: kernel_info.bytes_xlated,
+                jited: kernel_info.jited,
+                bytes_jited: kernel_info.bytes_jited,
+                bytes_memlock: kernel_info.bytes_memlock,
+                verified_insns: kernel_info.verified_insns,
+            },
+        }
+    }
+
+    /// Returns the application the program belongs to, if any.
+    pub(crate) fn application(&self) -> Option<&str> {
+        self.bpfman
+            .as_ref()
+            .and_then(|b| b.metadata.get(METADATA_APPLICATION_TAG))
+            .map(|a| a.as_str())
+    }
+}
+
+impl ProgramList {
+    /// Prints the programs in a wide table, or as JSON or YAML.
+    pub(crate) fn print(&self, output: OutputFormat) -> anyhow::Result<()> {
+        if output.is_serialized() {
+            return output.print(self);
+        }
+        let mut table = ProgTable::new_program_list_wide();
+        for program in &self.programs {
+            table.add_program_output(program);
+        }
+        table.print();
+        print_next_page_token(self.next_page_token.as_deref());
+        Ok(())
+    }
+}
+
+impl LinkOutput {
+    /// Builds the output of a link of a program loaded on this host.
+    pub(crate) fn from_link(
+        root_db: &Db,
+        program: &Program,
+        link: &Link,
+    ) -> Result<Self, BpfmanError> {
+        let kernel = get_kernel_link_info(root_db, link).unwrap_or_else(|e| {
+            warn!("unable to read the kernel info of link: {e}");
+            None
+        });
+        let netns =
+            |netns: Option<std::path::PathBuf>| netns.map(|p| p.to_string_lossy().to_string());
+        let position = |position: Option<usize>| position.unwrap_or(0) as i32;
+        let attach = match link {
+            Link::Xdp(l) => AttachOutput::Xdp {
+                iface: l.get_iface()?,
+                priority: l.get_priority()?,
+                position: position(l.get_current_position()?),
+                proceed_on: xdp_proceed_on(&l.get_proceed_on()?.as_action_vec()),
+                netns: netns(l.get_netns()?),
+            },
+            Link::Tc(l) => AttachOutput::Tc {
+                iface: l.get_iface()?,
+                direction: l.get_direction()?.to_string(),
+                priority: l.get_priority()?,
+                position: position(l.get_current_position()?),
+                proceed_on: tc_proceed_on(&l.get_proceed_on()?.as_action_vec()),
+                netns: netns(l.get_netns()?),
+            },
+            Link::Tcx(l) => AttachOutput::Tcx {
+                iface: l.get_iface()?,
+                direction: l.get_direction()?.to_string(),
+                priority: l.get_priority()?,
+                position: position(l.get_current_position()?),
+                netns: netns(l.get_netns()?),
+            },
+            Link::Tracepoint(l) => AttachOutput::Tracepoint {
+                tracepoint: l.get_tracepoint()?,
+            },
+            Link::Kprobe(l) => AttachOutput::Kprobe {
+                fn_name: l.get_fn_name()?,
+                offset: l.get_offset()?,
+                container_pid: l.get_container_pid()?,
+            },
+            Link::Uprobe(l) => AttachOutput::Uprobe {
+                target: l.get_target()?,
+                fn_name: l.get_fn_name()?,
+                offset: l.get_offset()?,
+                pid: l.get_pid()?,
+                container_pid: l.get_container_pid()?,
+            },
+            Link::Fentry(_) => AttachOutput::Fentry {
+                fn_name: match program {
+                    Program::Fentry(p) => Some(p.get_fn_name()?),
+                    _ => None,
+                },
+            },
+            Link::Fexit(_) => AttachOutput::Fexit {
+                fn_name: match program {
+                    Program::Fexit(p) => Some(p.get_fn_name()?),
+                    _ => None,
+                },
+            },
+        };
+
+        Ok(LinkOutput {
+            id: link.get_id()?,
+            program_id: link.get_program_id()?,
+            program_name: link.get_program_name()?,
+            enabled: link.get_enabled()?,
+            group: link.get_group()?,
+            interface_missing: link.get_interface_missing()?,
+            attach,
+            metadata: link.get_metadata()?.into_iter().collect(),
+            kernel,
+        })
+    }
+
+    /// Builds the output of a link returned by a remote bpfman-rpc. Returns
+    /// None if the link has no attach info.
+    pub(crate) fn from_rpc(
+        info: &LinkInfo,
+        kernel_info: Option<&RpcKernelLinkInfo>,
+    ) -> Option<Self> {
+        let (attach, metadata) = match info.attach_info.as_ref()?.info.as_ref()? {
+            Info::XdpAttachInfo(i) => (
+                AttachOutput::Xdp {
+                    iface: i.iface.clone(),
+                    priority: i.priority,
+                    position: i.position,
+                    proceed_on: xdp_proceed_on(&i.proceed_on),
+                    netns: i.netns.clone(),
+                },
+                &i.metadata,
+            ),
+            Info::TcAttachInfo(i) => (
+                AttachOutput::Tc {
+                    iface: i.iface.clone(),
+                    direction: i.direction.clone(),
+                    priority: i.priority,
+                    position: i.position,
+                    proceed_on: tc_proceed_on(&i.proceed_on),
+                    netns: i.netns.clone(),
+                },
+                &i.metadata,
+            ),
+            Info::TcxAttachInfo(i) => (
+                AttachOutput::Tcx {
+                    iface: i.iface.clone(),
+                    direction: i.direction.clone(),
+                    priority: i.priority,
+                    position: i.position,
+                    netns: i.netns.clone(),
+                },
+                &i.metadata,
+            ),
+            Info::TracepointAttachInfo(i) => (
+                AttachOutput::Tracepoint {
+                    tracepoint: i.tracepoint.clone(),
+                },
+                &i.metadata,
+            ),
+            Info::KprobeAttachInfo(i) => (
+                AttachOutput::Kprobe {
+                    fn_name: i.fn_name.clone(),
+                    offset: i.offset,
+                    container_pid: i.container_pid,
+                },
+                &i.metadata,
+            ),
+            Info::UprobeAttachInfo(i) => (
+                AttachOutput::Uprobe {
+                    target: i.target.clone(),
+                    fn_name: i.fn_name.clone(),
+                    offset: i.offset,
+                    pid: i.pid,
+                    container_pid: i.container_pid,
+                },
+                &i.metadata,
+            ),
+            Info::FentryAttachInfo(i) => (AttachOutput::Fentry { fn_name: None }, &i.metadata),
+            Info::FexitAttachInfo(i) => (AttachOutput::Fexit { fn_name: None }, &i.metadata),
+        };
+
+        Some(LinkOutput {
+            id: info.link_id,
+            program_id: info.program_id,
+            program_name: info.program_name.clone(),
+            enabled: info.enabled,
+            group: info.group.clone(),
+            interface_missing: info.interface_missing,
+            attach,
+            metadata: metadata.clone().into_iter().collect(),
+            kernel: kernel_info.map(|k| KernelLinkInfo {
+                id: k.id,
+                link_type: k.link_type.clone(),
+                program_id: k.program_id,
+            }),
+        })
+    }
+
+    /// Returns the application the link belongs to, if any.
+    pub(crate) fn application(&self) -> Option<&str> {
+        self.metadata
+            .get(METADATA_APPLICATION_TAG)
+            .map(|a| a.as_str())
+    }
+}
+
+impl AttachOutput {
+    /// Returns the type of the link.
+    pub(crate) fn link_type(&self) -> &'static str {
+        match self {
+            AttachOutput::Xdp { .. } => "xdp",
+            AttachOutput::Tc { .. } => "tc",
+            AttachOutput::Tcx { .. } => "tcx",
+            AttachOutput::Tracepoint { .. } => "tracepoint",
+            AttachOutput::Kprobe { .. } => "kprobe",
+            AttachOutput::Uprobe { .. } => "uprobe",
+            AttachOutput::Fentry { .. } => "fentry",
+            AttachOutput::Fexit { .. } => "fexit",
+        }
+    }
+}
+
+impl std::fmt::Display for AttachOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AttachOutput::Xdp {
+                iface, position, ..
+            } => write!(f, "{iface} pos-{position}"),
+            AttachOutput::Tc {
+                iface,
+                direction,
+                position,
+                ..
+            }
+            | AttachOutput::Tcx {
+                iface,
+                direction,
+                position,
+                ..
+            } => write!(f, "{iface} {direction} pos-{position}"),
+            AttachOutput::Tracepoint { tracepoint } => write!(f, "{tracepoint}"),
+            AttachOutput::Kprobe { fn_name, .. } => write!(f, "{fn_name}"),
+            AttachOutput::Uprobe {
+                target, fn_name, ..
+            } => match fn_name {
+                Some(name) => write!(f, "{target} {name}"),
+                None => write!(f, "{target}"),
+            },
+            AttachOutput::Fentry { fn_name } | AttachOutput::Fexit { fn_name } => {
+                write!(f, "{}", fn_name.as_deref().unwrap_or("unknown"))
+            }
+        }
+    }
+}
+
+impl LinkList {
+    /// Prints the links in a wide table, or as JSON or YAML.
+    pub(crate) fn print(&self, output: OutputFormat) -> anyhow::Result<()> {
+        if output.is_serialized() {
+            return output.print(self);
+        }
+        let mut table = ProgTable::new_link_list_wide();
+        for link in &self.links {
+            table.add_link_output(link);
+        }
+        table.print();
+        Ok(())
+    }
+}
+
+impl TryFrom<&LinkGroup> for LinkGroupOutput {
+    type Error = BpfmanError;
+
+    fn try_from(group: &LinkGroup) -> Result<Self, Self::Error> {
+        Ok(LinkGroupOutput {
+            name: group.get_name()?,
+            program_id: group.get_program_id()?,
+            selector: group.get_selector()?.to_string(),
+            members: group.get_members()?,
+        })
+    }
+}
+
+impl From<&LinkGroupInfo> for LinkGroupOutput {
+    fn from(group: &LinkGroupInfo) -> Self {
+        LinkGroupOutput {
+            name: group.name.clone(),
+            program_id: group.program_id,
+            selector: group.selector.clone(),
+            members: group
+                .members
+                .iter()
+                .map(|m| LinkGroupMember {
+                    iface: m.iface.clone(),
+                    netns: m.netns.as_ref().map(PathBuf::from),
+                    link_id: m.link_id,
+                    error: m.error.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl LinkGroupOutput {
+    /// Prints the link group and its members in a table, or as JSON or YAML.
+    pub(crate) fn print(&self, output: OutputFormat) -> anyhow::Result<()> {
+        if output.is_serialized() {
+            return output.print(self);
+        }
+        ProgTable::new_link_group(self).print();
+        Ok(())
+    }
+}
+
+impl From<&BytecodeInfo> for BytecodeInfoOutput {
+    fn from(info: &BytecodeInfo) -> Self {
+        BytecodeInfoOutput {
+            license: info.license.clone(),
+            kernel_version: info.kernel_version,
+            programs: info
+                .programs
+                .iter()
+                .map(|p| BytecodeProgramOutput {
+                    name: p.name.clone(),
+                    section: p.section.clone(),
+                    program_type: p.program_type.to_string(),
+                    sleepable: p.sleepable,
+                    attach_target: p.attach_target.clone(),
+                    attach_target_found: p.attach_target_found,
+                })
+                .collect(),
+            maps: info
+                .maps
+                .iter()
+                .map(|m| BytecodeMapOutput {
+                    name: m.name.clone(),
+                    map_type: m.map_type.clone(),
+                    key_size: m.key_size,
+                    value_size: m.value_size,
+                    max_entries: m.max_entries,
+                })
+                .collect(),
+            globals: info
+                .globals
+                .iter()
+                .map(|g| GlobalVariableOutput {
+                    name: g.name.clone(),
+                    section: g.section.clone(),
+                    size: g.size,
+                    type_name: g.type_name.clone(),
+                })
+                .collect(),
+            required_features: info.required_features.clone(),
+        }
+    }
+}
+
+impl From<&DoctorReport> for DoctorReportOutput {
+    fn from(report: &DoctorReport) -> Self {
+        DoctorReportOutput {
+            status: report.status().to_string(),
+            checks: report
+                .checks
+                .iter()
+                .map(|c| DoctorCheckOutput {
+                    category: c.category.to_string(),
+                    name: c.name.clone(),
+                    status: c.status.to_string(),
+                    message: c.message.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn hex_global_data(global_data: HashMap<String, Vec<u8>>) -> BTreeMap<String, String> {
+    global_data
+        .into_iter()
+        .map(|(k, v)| (k, encode_upper(v)))
+        .collect()
+}
+
+fn xdp_proceed_on(actions: &[i32]) -> Vec<String> {
+    actions
+        .iter()
+        .map(|a| match XdpProceedOnEntry::try_from(*a) {
+            Ok(entry) => entry.to_string(),
+            Err(_) => a.to_string(),
+        })
+        .collect()
+}
+
+fn tc_proceed_on(actions: &[i32]) -> Vec<String> {
+    actions
+        .iter()
+        .map(|a| match TcProceedOnEntry::try_from(*a) {
+            Ok(entry) => entry.to_string(),
+            Err(_) => a.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use bpfman_proto::v1::{
+        AttachInfo as RpcAttachInfo, BytecodeImage as RpcImage, BytecodeLocation, XdpAttachInfo,
+    };
+    use serde_json::Value;
+
+    use super::*;
+
+    fn rpc_program() -> (RpcProgramInfo, RpcKernelProgramInfo) {
+        let info = RpcProgramInfo {
+            name: "pass".to_string(),
+            bytecode: Some(BytecodeLocation {
+                location: Some(RpcLocation::Image(RpcImage {
+                    url: "quay.io/bpfman-bytecode/xdp_pass:latest".to_string(),
+                    image_pull_policy: 1,
+                    username: Some("user".to_string()),
+                    password: Some("secret".to_string()),
+                })),
+            }),
+            metadata: HashMap::from([("bpfman.io/application".to_string(), "test".to_string())]),
+            global_data: HashMap::from([("GLOBAL_u8".to_string(), vec![0x01])]),
+            map_owner_id: None,
+            map_pin_path: "/run/bpfman/fs/maps/6207".to_string(),
+            map_used_by: vec!["6207".to_string()],
+            links: vec![7, 8],
+            verifier_log_summary: Some("accepted, processed 9 insns".to_string()),
+        };
+        let kernel_info = RpcKernelProgramInfo {
+            id: 6207,
+            name: "pass".to_string(),
+            program_type: 6,
+            loaded_at: "2025-01-23T10:05:02-0500".to_string(),
+            tag: "4b9d1b2c140e87ce".to_string(),
+            gpl_compatible: true,
+            map_ids: vec![2590],
+            btf_id: 3109,
+            bytes_xlated: 96,
+            jited: true,
+            bytes_jited: 67,
+            bytes_memlock: 4096,
+            verified_insns: 9,
+        };
+        (info, kernel_info)
+    }
+
+    #[test]
+    fn test_program_json() {
+        let (info, kernel_info) = rpc_program();
+        let output = ProgramList {
+            programs: vec![ProgramOutput::from_rpc(Some(&info), &kernel_info)],
+            next_page_token: None,
+        };
+        let expected = r#"{
+  "programs": [
+    {
+      "bpfman": {
+        "name": "pass",
+        "bytecode": {
+          "image": {
+            "url": "quay.io/bpfman-bytecode/xdp_pass:latest",
+            "pull_policy": "IfNotPresent"
+          }
+        },
+        "global_data": {
+          "GLOBAL_u8": "01"
+        },
+        "metadata": {
+          "bpfman.io/application": "test"
+        },
+        "map_pin_path": "/run/bpfman/fs/maps/6207",
+        "map_owner_id": null,
+        "maps_used_by": [
+          6207
+        ],
+        "links": [
+          7,
+          8
+        ],
+        "verifier_log_summary": "accepted, processed 9 insns"
+      },
+      "kernel": {
+        "id": 6207,
+        "name": "pass",
+        "type": "xdp",
+        "loaded_at": "2025-01-23T10:05:02-0500",
+        "tag": "4b9d1b2c140e87ce",
+        "gpl_compatible": true,
+        "map_ids": [
+          2590
+        ],
+        "btf_id": 3109,
+        "bytes_xlated": 96,
+        "jited": true,
+        "bytes_jited": 67,
+        "bytes_memlock": 4096,
+        "verified_insns": 9
+      }
+    }
+  ]
+}"#;
+        assert_eq!(serde_json::to_string_pretty(&output).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_unsupported_program_json() {
+        let (_, kernel_info) = rpc_program();
+        let output = ProgramOutput::from_rpc(None, &kernel_info);
+        let value = serde_json::to_value(&output).unwrap();
+        assert_eq!(value["bpfman"], Value::Null);
+        assert_eq!(value["kernel"]["id"], 6207);
+    }
+
+    #[test]
+    fn test_link_json() {
+        let info = LinkInfo {
+            link_id: 7,
+            program_id: 6207,
+            program_name: "pass".to_string(),
+            attach_info: Some(RpcAttachInfo {
+                info: Some(Info::XdpAttachInfo(XdpAttachInfo {
+                    priority: 50,
+                    iface: "eth0".to_string(),
+                    position: 0,
+                    proceed_on: vec![2, 31],
+                    netns: None,
+                    metadata: HashMap::new(),
+                    selector: None,
+                })),
+            }),
+            enabled: true,
+            group: None,
+            interface_missing: false,
+        };
+        let kernel_info = RpcKernelLinkInfo {
+            id: 42,
+            link_type: "xdp".to_string(),
+            program_id: 6210,
+        };
+        let output = LinkOutput::from_rpc(&info, Some(&kernel_info)).unwrap();
+        let expected = r#"{
+  "id": 7,
+  "program_id": 6207,
+  "program_name": "pass",
+  "enabled": true,
+  "group": null,
+  "interface_missing": false,
+  "attach": {
+    "type": "xdp",
+    "iface": "eth0",
+    "priority": 50,
+    "position": 0,
+    "proceed_on": [
+      "pass",
+      "dispatcher_return"
+    ],
+    "netns": null
+  },
+  "metadata": {},
+  "kernel": {
+    "id": 42,
+    "link_type": "xdp",
+    "program_id": 6210
+  }
+}"#;
+        assert_eq!(serde_json::to_string_pretty(&output).unwrap(), expected);
+        assert_eq!(output.attach.to_string(), "eth0 pos-0");
+    }
+
+    #[test]
+    fn test_yaml() {
+        let value = serde_json::json!({
+            "links": [
+                {"id": 7, "attach": {"type": "tc", "proceed_on": ["pipe", "ok"]}},
+                {"id": 8, "metadata": {}},
+            ],
+            "empty": [],
+            "strings": ["plain", "true", "10", "a: b", "", "quay.io/image:latest"],
+            "nested": [[1, 2]],
+            "token": null,
+        });
+        let yaml = OutputFormat::Yaml.serialize(&value).unwrap();
+        assert!(yaml.ends_with('\n'));
+        assert!(yaml.contains("- 'true'\n"));
+        assert!(yaml.contains("- '10'\n"));
+        let parsed: Value = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed, value);
+    }
+}
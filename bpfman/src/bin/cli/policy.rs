@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use anyhow::{Context, bail};
+use bpfman::{config::Config, policy::PolicyManifest, utils::open_config_file};
+
+use crate::{
+    args::{PolicyCheckArgs, PolicySubcommand},
+    table::ProgTable,
+};
+
+impl PolicySubcommand {
+    pub(crate) fn execute(&self) -> anyhow::Result<()> {
+        match self {
+            PolicySubcommand::Check(args) => execute_policy_check(args),
+        }
+    }
+}
+
+pub(crate) fn execute_policy_check(args: &PolicyCheckArgs) -> anyhow::Result<()> {
+    let config = match &args.config {
+        Some(path) => std::fs::read_to_string(path)
+            .with_context(|| format!("unable to read {}", path.display()))?
+            .parse::<Config>()?,
+        None => open_config_file(),
+    };
+    let policy = config.policy().resolve()?;
+
+    let manifest: PolicyManifest = toml::from_str(
+        &std::fs::read_to_string(&args.file)
+            .with_context(|| format!("unable to read {}", args.file.display()))?,
+    )
+    .with_context(|| format!("unable to parse {}", args.file.display()))?;
+
+    let violations = manifest.check(&policy);
+    if violations.is_empty() {
+        println!("No policy violations found\n");
+        return Ok(());
+    }
+
+    let mut table = ProgTable::new_policy_violation_list();
+    for violation in &violations {
+        table.add_policy_violation(violation);
+    }
+    table.print();
+    bail!("{} policy violation(s) found", violations.len())
+}
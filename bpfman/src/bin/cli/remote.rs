@@ -0,0 +1,787 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use std::{fs, path::Path, time::Duration};
+
+use anyhow::{Context, anyhow, bail};
+use bpfman::types::{
+    AttachInfo, BytecodeImage, IdOrAlias, METADATA_ALIAS_TAG, METADATA_APPLICATION_TAG,
+};
+use bpfman_proto::v1::{
+    AttachInfo as RpcAttachInfo, AttachRequest, BpfmanProgramType, BytecodeImage as RpcImage,
+    BytecodeLocation, DetachRequest, DisableLinkRequest, EnableLinkRequest, FentryAttachInfo,
+    FentryLoadInfo, FexitAttachInfo, FexitLoadInfo, GetLinkGroupRequest, GetLinkRequest,
+    GetRequest, KprobeAttachInfo, ListLinksRequest, ListRequest, LoadInfo, LoadRequest,
+    ProgSpecificInfo, PullBytecodeRequest, SetLinkPriorityRequest, SetLinkReattachPolicyRequest,
+    TcAttachInfo, TcxAttachInfo, TracepointAttachInfo, UnloadRequest, UprobeAttachInfo,
+    VerifyRequest, XdpAttachInfo, attach_info::Info, bpfman_client::BpfmanClient,
+    bytecode_location::Location as RpcLocation, prog_specific_info::Info as ProgInfo,
+};
+use tokio::{net::UnixStream, runtime::Runtime, time::timeout};
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint, Identity, Uri};
+use tower::service_fn;
+
+use crate::{
+    args::{
+        AttachArgs, Commands, DetachArgs, GetSubcommand, ImageSubCommand, LinkSubcommand,
+        ListLinkArgs, ListProgramArgs, ListSubcommand, LoadSubcommand, RemoteArgs, UnloadArgs,
+    },
+    completions::IdCandidate,
+    list::print_next_page_token,
+    load::{parse_global, parse_metadata, with_alias},
+    output::{LinkGroupOutput, LinkList, LinkOutput, OutputFormat, ProgramList, ProgramOutput},
+    table::ProgTable,
+    unload::report_selected,
+};
+
+type Client = BpfmanClient<Channel>;
+
+// How long the dynamic completions wait for the remote bpfman-rpc, so that a
+// host that can't be reached doesn't block the shell.
+const COMPLETION_TIMEOUT: Duration = Duration::from_secs(2);
+
+// The default unix socket of bpfman-rpc.
+const RPC_SOCKET: &str = "/run/bpfman-sock/bpfman.sock";
+
+impl Commands {
+    /// Runs the command against the bpfman-rpc of a remote host instead of the
+    /// bpfman library. Commands that don't use bpfman state, such as building
+    /// images, still run locally.
+    pub(crate) fn execute_remote(
+        &self,
+        remote: &RemoteArgs,
+        output: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Commands::Image(i) if !matches!(**i, ImageSubCommand::Pull(_)) => {
+                return self.execute(output);
+            }
+            Commands::Policy(_)
+            | Commands::Inspect(_)
+            | Commands::Doctor
+            | Commands::Man(_)
+            | Commands::Completions(_) => {
+                return self.execute(output);
+            }
+            Commands::Top(_) => bail!("top is not supported with --remote"),
+            Commands::Trace(_) => bail!("trace is not supported with --remote"),
+            Commands::Export | Commands::Import(_) => {
+                bail!("export and import are not supported with --remote")
+            }
+            _ => {}
+        }
+
+        Runtime::new()?.block_on(async {
+            let mut client = connect(remote).await?;
+            match self {
+                Commands::Load(l) => execute_load(&mut client, l, output).await,
+                Commands::Unload(args) => execute_unload(&mut client, args).await,
+                Commands::Attach(args) => execute_attach(&mut client, args, output).await,
+                Commands::Detach(args) => execute_detach(&mut client, args).await,
+                Commands::Link(l) => execute_link(&mut client, l, output).await,
+                Commands::List(ListSubcommand::Programs(args))
+                | Commands::List(ListSubcommand::Program(args)) => {
+                    execute_program_list(&mut client, args, output).await
+                }
+                Commands::List(ListSubcommand::Links(args))
+                | Commands::List(ListSubcommand::Link(args)) => {
+                    execute_link_list(&mut client, args, output).await
+                }
+                Commands::Get(GetSubcommand::Program(args)) => {
+                    print_program(&mut client, &args.program, output).await
+                }
+                Commands::Get(GetSubcommand::Link(args)) => {
+                    print_link(&mut client, &args.link, output).await
+                }
+                Commands::Get(GetSubcommand::LinkGroup(args)) => {
+                    let group = client
+                        .get_link_group(GetLinkGroupRequest {
+                            name: args.name.clone(),
+                        })
+                        .await?
+                        .into_inner()
+                        .group
+                        .ok_or_else(|| anyhow!("link group {} not found", args.name))?;
+                    LinkGroupOutput::from(&group).print(output)
+                }
+                Commands::Verify(args) => {
+                    let drifts = client
+                        .verify(VerifyRequest {
+                            repair: args.repair,
+                        })
+                        .await?
+                        .into_inner()
+                        .drifts;
+                    if drifts.is_empty() {
+                        println!("No drift found\n");
+                        return Ok(());
+                    }
+                    let mut table = ProgTable::new_drift_list();
+                    for drift in &drifts {
+                        table.add_rpc_drift(drift);
+                    }
+                    table.print();
+                    Ok(())
+                }
+                Commands::Image(i) => match &**i {
+                    ImageSubCommand::Pull(args) => {
+                        let image: BytecodeImage = args.try_into()?;
+                        client
+                            .pull_bytecode(PullBytecodeRequest {
+                                image: Some(image_to_rpc(image)),
+                            })
+                            .await?;
+                        Ok(())
+                    }
+                    _ => unreachable!("handled locally"),
+                },
+                Commands::Policy(_)
+                | Commands::Inspect(_)
+                | Commands::Doctor
+                | Commands::Top(_)
+                | Commands::Trace(_)
+                | Commands::Export
+                | Commands::Import(_)
+                | Commands::Man(_)
+                | Commands::Completions(_) => {
+                    unreachable!("handled locally")
+                }
+            }
+        })
+    }
+}
+
+async fn connect(remote: &RemoteArgs) -> anyhow::Result<Client> {
+    let read = |path: &Option<_>| -> anyhow::Result<Vec<u8>> {
+        let path: &Path = path
+            .as_deref()
+            .ok_or_else(|| anyhow!("--tls-cert, --tls-key and --tls-ca are required"))?;
+        fs::read(path).with_context(|| format!("unable to read {}", path.display()))
+    };
+    let url = remote.remote.clone().unwrap_or_default();
+    let tls = ClientTlsConfig::new()
+        .ca_certificate(Certificate::from_pem(read(&remote.tls_ca)?))
+        .identity(Identity::from_pem(
+            read(&remote.tls_cert)?,
+            read(&remote.tls_key)?,
+        ));
+    let channel = Endpoint::from_shared(url.clone())?
+        .tls_config(tls)?
+        .connect()
+        .await
+        .with_context(|| format!("unable to connect to {url}"))?;
+    Ok(BpfmanClient::new(channel))
+}
+
+async fn execute_load(
+    client: &mut Client,
+    load: &LoadSubcommand,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let (bytecode, programs, metadata, global, map_owner_id, verifier_log_level, dry_run) =
+        match load {
+            LoadSubcommand::File(args) => (
+                RpcLocation::File(args.path.clone()),
+                &args.programs,
+                with_alias(
+                    parse_metadata(&args.metadata, &args.application),
+                    &args.alias,
+                ),
+                &args.global,
+                args.map_owner_id,
+                args.verifier_log_level,
+                args.dry_run,
+            ),
+            LoadSubcommand::Image(args) => (
+                RpcLocation::Image(image_to_rpc((&args.pull_args).try_into()?)),
+                &args.programs,
+                with_alias(
+                    parse_metadata(&args.metadata, &args.application),
+                    &args.alias,
+                ),
+                &args.global,
+                args.map_owner_id,
+                args.verifier_log_level,
+                args.dry_run,
+            ),
+        };
+
+    if metadata.contains_key(METADATA_ALIAS_TAG) && programs.len() > 1 {
+        bail!("--alias can only be used when loading a single program");
+    }
+
+    let mut info = vec![];
+    for (prog_type, parts) in programs {
+        let name = parts
+            .first()
+            .ok_or_else(|| anyhow!("Missing program name"))?;
+        if (prog_type == "fentry" || prog_type == "fexit") && parts.len() != 2 {
+            bail!("Missing function name for fentry/fexit program");
+        }
+        let (program_type, prog_info) = match prog_type.as_str() {
+            "xdp" => (BpfmanProgramType::Xdp, None),
+            "tc" => (BpfmanProgramType::Tc, None),
+            "tcx" => (BpfmanProgramType::Tcx, None),
+            "tracepoint" => (BpfmanProgramType::Tracepoint, None),
+            "kprobe" | "kretprobe" => (BpfmanProgramType::Kprobe, None),
+            "uprobe" | "uretprobe" => (BpfmanProgramType::Uprobe, None),
+            "fentry" => (
+                BpfmanProgramType::Fentry,
+                Some(ProgInfo::FentryLoadInfo(FentryLoadInfo {
+                    fn_name: parts[1].clone(),
+                })),
+            ),
+            "fexit" => (
+                BpfmanProgramType::Fexit,
+                Some(ProgInfo::FexitLoadInfo(FexitLoadInfo {
+                    fn_name: parts[1].clone(),
+                })),
+            ),
+            _ => bail!("Unknown program type: {prog_type}"),
+        };
+        info.push(LoadInfo {
+            name: name.clone(),
+            program_type: program_type.into(),
+            info: prog_info.map(|i| ProgSpecificInfo { info: Some(i) }),
+        });
+    }
+
+    let programs = client
+        .load(LoadRequest {
+            bytecode: Some(BytecodeLocation {
+                location: Some(bytecode),
+            }),
+            metadata,
+            global_data: parse_global(global),
+            uuid: None,
+            map_owner_id,
+            info,
+            verifier_log_level,
+            validate_only: dry_run,
+        })
+        .await?
+        .into_inner()
+        .programs;
+
+    if output.is_serialized() {
+        let programs = programs
+            .iter()
+            .filter_map(|p| {
+                p.kernel_info
+                    .as_ref()
+                    .map(|k| ProgramOutput::from_rpc(p.info.as_ref(), k))
+            })
+            .collect();
+        return output.print(&ProgramList {
+            programs,
+            next_page_token: None,
+        });
+    }
+
+    if dry_run {
+        let mut table = ProgTable::new_validated_list();
+        for program in &programs {
+            if let (Some(info), Some(kernel_info)) = (&program.info, &program.kernel_info) {
+                table.add_validated_program(
+                    info.name.clone(),
+                    ProgTable::get_rpc_type_str(kernel_info.program_type),
+                    info.verifier_log_summary.clone(),
+                );
+            }
+        }
+        table.print();
+        return Ok(());
+    }
+
+    if let [program] = programs.as_slice() {
+        if let Some(info) = &program.info {
+            ProgTable::new_rpc_program(info).print();
+        }
+        if let Some(kernel_info) = &program.kernel_info {
+            ProgTable::new_rpc_kernel_info(kernel_info).print();
+        }
+    } else {
+        let mut table = ProgTable::new_program_list();
+        for program in &programs {
+            if let Some(kernel_info) = &program.kernel_info {
+                table.add_rpc_program_response(program.info.as_ref(), kernel_info);
+            }
+        }
+        table.print();
+    }
+    Ok(())
+}
+
+async fn execute_attach(
+    client: &mut Client,
+    args: &AttachArgs,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let group = args.command.get_link_group();
+    if args.dry_run && group.is_some() {
+        bail!("--dry-run is not supported with --group");
+    }
+    let (id, alias) = id_and_alias(&args.program);
+    let program = client
+        .get(GetRequest {
+            id,
+            alias: alias.clone(),
+        })
+        .await?
+        .into_inner();
+    let application = program
+        .info
+        .and_then(|i| i.metadata.get(METADATA_APPLICATION_TAG).cloned());
+    let attach_info = args.command.get_attach_info(&application, &args.alias)?;
+
+    let selector = group.map(|(_, selector)| selector.to_string());
+    let response = client
+        .attach(AttachRequest {
+            id,
+            attach: Some(attach_info_to_rpc(attach_info, selector)),
+            validate_only: args.dry_run,
+            program_alias: alias,
+            group: group.map(|(name, _)| name.to_string()),
+        })
+        .await?
+        .into_inner();
+    if let Some(group) = response.link_group {
+        return LinkGroupOutput::from(&group).print(output);
+    }
+    let link_id = response.link_id;
+    if args.dry_run {
+        println!("Program {} can be attached", args.program);
+        return Ok(());
+    }
+    print_link(client, &IdOrAlias::Id(link_id), output).await
+}
+
+async fn execute_detach(client: &mut Client, args: &DetachArgs) -> anyhow::Result<()> {
+    if let Some(group) = &args.group {
+        client
+            .detach(DetachRequest {
+                link_id: 0,
+                alias: None,
+                selector: None,
+                group: Some(group.clone()),
+            })
+            .await?;
+    } else if let Some(selector) = &args.selector {
+        let results = client
+            .detach(DetachRequest {
+                link_id: 0,
+                alias: None,
+                selector: Some(selector.clone()),
+                group: None,
+            })
+            .await?
+            .into_inner()
+            .results
+            .into_iter()
+            .map(|r| (r.link_id, r.error.map_or(Ok(()), Err)))
+            .collect();
+        report_selected("detach", "Detached", "link", results)?;
+    } else if let Some(link) = &args.link {
+        let (link_id, alias) = id_and_alias(link);
+        client
+            .detach(DetachRequest {
+                link_id,
+                alias,
+                selector: None,
+                group: None,
+            })
+            .await?;
+    }
+    Ok(())
+}
+
+async fn execute_unload(client: &mut Client, args: &UnloadArgs) -> anyhow::Result<()> {
+    if let Some(selector) = &args.selector {
+        let results = client
+            .unload(UnloadRequest {
+                id: 0,
+                alias: None,
+                selector: Some(selector.clone()),
+            })
+            .await?
+            .into_inner()
+            .results
+            .into_iter()
+            .map(|r| (r.id, r.error.map_or(Ok(()), Err)))
+            .collect();
+        report_selected("unload", "Unloaded", "program", results)?;
+    } else if let Some(program) = &args.program {
+        let (id, alias) = id_and_alias(program);
+        client
+            .unload(UnloadRequest {
+                id,
+                alias,
+                selector: None,
+            })
+            .await?;
+    }
+    Ok(())
+}
+
+async fn execute_link(
+    client: &mut Client,
+    link: &LinkSubcommand,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let link_id = match link {
+        LinkSubcommand::Disable(args) => {
+            client
+                .disable_link(DisableLinkRequest {
+                    link_id: args.link_id,
+                })
+                .await?;
+            args.link_id
+        }
+        LinkSubcommand::Enable(args) => {
+            client
+                .enable_link(EnableLinkRequest {
+                    link_id: args.link_id,
+                })
+                .await?;
+            args.link_id
+        }
+        LinkSubcommand::SetPriority(args) => {
+            client
+                .set_link_priority(SetLinkPriorityRequest {
+                    link_id: args.link_id,
+                    priority: args.priority,
+                })
+                .await?;
+            args.link_id
+        }
+        LinkSubcommand::SetReattachPolicy(args) => {
+            client
+                .set_link_reattach_policy(SetLinkReattachPolicyRequest {
+                    link_id: args.link_id,
+                    policy: args.policy.to_string(),
+                })
+                .await?;
+            args.link_id
+        }
+    };
+    print_link(client, &IdOrAlias::Id(link_id), output).await
+}
+
+async fn execute_program_list(
+    client: &mut Client,
+    args: &ListProgramArgs,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let response = client
+        .list(ListRequest {
+            program_type: args.program_type.map(|p| p as u32),
+            bpfman_programs_only: Some(!args.all),
+            match_metadata: parse_metadata(&args.metadata_selector, &args.application),
+            metadata_selector: args.selector.clone(),
+            name_regex: args.name.clone(),
+            iface: args.iface.clone(),
+            no_links: Some(args.no_links),
+            sort_by: args.sort_by as i32,
+            page_size: args.limit.map(|l| l as u32),
+            page_token: args.page_token.clone(),
+        })
+        .await?
+        .into_inner();
+
+    if output != OutputFormat::Table {
+        let programs = response
+            .results
+            .iter()
+            .filter_map(|r| {
+                r.kernel_info
+                    .as_ref()
+                    .map(|k| ProgramOutput::from_rpc(r.info.as_ref(), k))
+            })
+            .collect();
+        return ProgramList {
+            programs,
+            next_page_token: response.next_page_token,
+        }
+        .print(output);
+    }
+
+    let mut table = ProgTable::new_program_list();
+    for r in &response.results {
+        if let Some(kernel_info) = &r.kernel_info {
+            table.add_rpc_program_response(r.info.as_ref(), kernel_info);
+        }
+    }
+    table.print();
+    print_next_page_token(response.next_page_token.as_deref());
+    Ok(())
+}
+
+async fn execute_link_list(
+    client: &mut Client,
+    args: &ListLinkArgs,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let results = client
+        .list(ListRequest {
+            program_type: args.program_type.map(|p| p as u32),
+            bpfman_programs_only: Some(true),
+            match_metadata: parse_metadata(&args.metadata_selector, &args.application),
+            ..Default::default()
+        })
+        .await?
+        .into_inner()
+        .results;
+
+    let mut table = ProgTable::new_link_list();
+    let mut links = vec![];
+    for link_id in results
+        .iter()
+        .filter_map(|r| r.info.as_ref())
+        .flat_map(|i| &i.links)
+    {
+        let link = client
+            .get_link(GetLinkRequest {
+                link_id: *link_id,
+                alias: None,
+            })
+            .await?
+            .into_inner();
+        let Some(info) = &link.info else {
+            continue;
+        };
+        if output == OutputFormat::Table {
+            table.add_rpc_link_response(info);
+        } else if let Some(l) = LinkOutput::from_rpc(info, link.kernel_info.as_ref()) {
+            links.push(l);
+        }
+    }
+    if output != OutputFormat::Table {
+        return LinkList { links }.print(output);
+    }
+    table.print();
+    Ok(())
+}
+
+async fn print_program(
+    client: &mut Client,
+    program_ref: &IdOrAlias,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let (id, alias) = id_and_alias(program_ref);
+    let program = client.get(GetRequest { id, alias }).await?.into_inner();
+    if output.is_serialized() {
+        let kernel_info = program
+            .kernel_info
+            .as_ref()
+            .ok_or_else(|| anyhow!("program {program_ref} has no kernel info"))?;
+        return output.print(&ProgramOutput::from_rpc(program.info.as_ref(), kernel_info));
+    }
+    if let Some(info) = &program.info {
+        ProgTable::new_rpc_program(info).print();
+    }
+    if let Some(kernel_info) = &program.kernel_info {
+        ProgTable::new_rpc_kernel_info(kernel_info).print();
+    }
+    Ok(())
+}
+
+async fn print_link(
+    client: &mut Client,
+    link_ref: &IdOrAlias,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let (link_id, alias) = id_and_alias(link_ref);
+    let link = client
+        .get_link(GetLinkRequest { link_id, alias })
+        .await?
+        .into_inner();
+    if output.is_serialized() {
+        let info = link
+            .info
+            .as_ref()
+            .and_then(|info| LinkOutput::from_rpc(info, link.kernel_info.as_ref()))
+            .ok_or_else(|| anyhow!("link {link_ref} has no attach info"))?;
+        return output.print(&info);
+    }
+    if let Some(info) = &link.info {
+        ProgTable::new_rpc_link(info, link.kernel_info.as_ref()).print();
+    }
+    Ok(())
+}
+
+// Connects to the bpfman-rpc of the local host over its unix socket.
+async fn connect_socket() -> anyhow::Result<Client> {
+    // The URI is required by the endpoint but unused, as the connector always
+    // opens the socket.
+    let channel = Endpoint::from_static("http://[::]:50051")
+        .connect_with_connector(service_fn(|_: Uri| UnixStream::connect(RPC_SOCKET)))
+        .await
+        .with_context(|| format!("unable to connect to {RPC_SOCKET}"))?;
+    Ok(BpfmanClient::new(channel))
+}
+
+// Connects to the remote host, or to the local bpfman-rpc if there is none.
+async fn connect_or_socket(remote: Option<&RemoteArgs>) -> anyhow::Result<Client> {
+    match remote {
+        Some(remote) => connect(remote).await,
+        None => connect_socket().await,
+    }
+}
+
+/// Returns the programs loaded by bpfman on the remote host, or on the local
+/// host through bpfman-rpc if no remote is given, for the dynamic completions.
+pub(crate) fn program_candidates(remote: Option<&RemoteArgs>) -> anyhow::Result<Vec<IdCandidate>> {
+    Runtime::new()?.block_on(timeout(COMPLETION_TIMEOUT, async {
+        let mut client = connect_or_socket(remote).await?;
+        let results = client
+            .list(ListRequest {
+                bpfman_programs_only: Some(true),
+                ..Default::default()
+            })
+            .await?
+            .into_inner()
+            .results;
+        Ok(results
+            .iter()
+            .filter_map(|r| {
+                let info = r.info.as_ref()?;
+                Some(IdCandidate {
+                    id: r.kernel_info.as_ref()?.id,
+                    alias: info.metadata.get(METADATA_ALIAS_TAG).cloned(),
+                    name: info.name.clone(),
+                })
+            })
+            .collect())
+    }))?
+}
+
+/// Returns the links managed by bpfman on the remote host, or on the local
+/// host through bpfman-rpc if no remote is given, for the dynamic completions.
+pub(crate) fn link_candidates(remote: Option<&RemoteArgs>) -> anyhow::Result<Vec<IdCandidate>> {
+    Runtime::new()?.block_on(timeout(COMPLETION_TIMEOUT, async {
+        let mut client = connect_or_socket(remote).await?;
+        let results = client
+            .list_links(ListLinksRequest::default())
+            .await?
+            .into_inner()
+            .results;
+        Ok(results
+            .iter()
+            .filter_map(|r| LinkOutput::from_rpc(r.info.as_ref()?, None))
+            .map(|l| IdCandidate {
+                id: l.id,
+                alias: l.metadata.get(METADATA_ALIAS_TAG).cloned(),
+                name: l.program_name,
+            })
+            .collect())
+    }))?
+}
+
+// Splits a reference to a program or link into the ID and alias fields of a
+// request.
+fn id_and_alias(r: &IdOrAlias) -> (u32, Option<String>) {
+    match r {
+        IdOrAlias::Id(id) => (*id, None),
+        IdOrAlias::Alias(alias) => (0, Some(alias.clone())),
+    }
+}
+
+fn image_to_rpc(image: BytecodeImage) -> RpcImage {
+    RpcImage {
+        url: image.image_url,
+        image_pull_policy: image.image_pull_policy as i32,
+        username: image.username,
+        password: image.password,
+    }
+}
+
+/// Converts the attach info to its RPC form. The interface selector is only
+/// set when attaching a link group.
+fn attach_info_to_rpc(attach_info: AttachInfo, selector: Option<String>) -> RpcAttachInfo {
+    let netns = |n: Option<std::path::PathBuf>| n.map(|p| p.to_string_lossy().to_string());
+    let info = match attach_info {
+        AttachInfo::Xdp {
+            priority,
+            iface,
+            proceed_on,
+            netns: ns,
+            metadata,
+        } => Info::XdpAttachInfo(XdpAttachInfo {
+            priority,
+            iface,
+            position: 0,
+            proceed_on: proceed_on.as_action_vec(),
+            netns: netns(ns),
+            metadata,
+            selector: selector.clone(),
+        }),
+        AttachInfo::Tc {
+            priority,
+            iface,
+            direction,
+            proceed_on,
+            netns: ns,
+            metadata,
+        } => Info::TcAttachInfo(TcAttachInfo {
+            priority,
+            iface,
+            position: 0,
+            direction,
+            proceed_on: proceed_on.as_action_vec(),
+            netns: netns(ns),
+            metadata,
+            selector: selector.clone(),
+        }),
+        AttachInfo::Tcx {
+            priority,
+            iface,
+            direction,
+            netns: ns,
+            metadata,
+        } => Info::TcxAttachInfo(TcxAttachInfo {
+            priority,
+            iface,
+            position: 0,
+            direction,
+            netns: netns(ns),
+            metadata,
+            selector: selector.clone(),
+        }),
+        AttachInfo::Tracepoint {
+            tracepoint,
+            metadata,
+        } => Info::TracepointAttachInfo(TracepointAttachInfo {
+            tracepoint,
+            metadata,
+        }),
+        AttachInfo::Kprobe {
+            fn_name,
+            offset,
+            container_pid,
+            metadata,
+        } => Info::KprobeAttachInfo(KprobeAttachInfo {
+            fn_name,
+            offset,
+            container_pid,
+            metadata,
+        }),
+        AttachInfo::Uprobe {
+            fn_name,
+            offset,
+            target,
+            pid,
+            container_pid,
+            metadata,
+        } => Info::UprobeAttachInfo(UprobeAttachInfo {
+            fn_name,
+            offset,
+            target,
+            pid,
+            container_pid,
+            metadata,
+        }),
+        AttachInfo::Fentry { metadata } => Info::FentryAttachInfo(FentryAttachInfo { metadata }),
+        AttachInfo::Fexit { metadata } => Info::FexitAttachInfo(FexitAttachInfo { metadata }),
+    };
+    RpcAttachInfo { info: Some(info) }
+}
@@ -5,12 +5,26 @@ use std::{collections::HashMap, path::PathBuf};
 
 use bpfman::{
     errors::BpfmanError,
-    types::{ImagePullPolicy, Link, Location, Program, ProgramData},
+    policy::ManifestViolation,
+    types::{
+        BpfProgType, Drift, ImagePullPolicy, Link, Location, METADATA_APPLICATION_TAG, Program,
+        ProgramData,
+    },
+};
+use bpfman_proto::v1::{
+    KernelLinkInfo as RpcKernelLinkInfo, KernelProgramInfo as RpcKernelProgramInfo, LinkInfo,
+    ProgramInfo as RpcProgramInfo, attach_info::Info, bytecode_location::Location as RpcLocation,
+    verify_response::Drift as RpcDrift,
 };
 use comfy_table::{Cell, Color, Table};
 use hex::encode_upper;
 use log::warn;
 
+use crate::output::{
+    BytecodeInfoOutput, BytecodeMapOutput, BytecodeProgramOutput, DoctorCheckOutput,
+    GlobalVariableOutput, ImportOutput, LinkGroupOutput, LinkOutput, ProgramOutput,
+};
+
 pub(crate) struct ProgTable(Table);
 
 const NUM_LIST_LINKS: usize = 3;
@@ -126,6 +140,12 @@ impl ProgTable {
                 self.0.add_row(vec!["Maps Used By:", "None"]);
             }
         };
+
+        Self::add_option_string(
+            self,
+            "Verifier Log:".to_string(),
+            data.get_verifier_log_summary(),
+        );
     }
 
     fn add_program_multiple_links(&mut self, program: &Program, links: Vec<Link>) {
@@ -159,6 +179,10 @@ impl ProgTable {
 
         Self::add_u32(self, "Program ID:".to_string(), link.get_program_id());
         Self::add_u32(self, "Link ID:".to_string(), link.get_id());
+        Self::add_bool(self, "Enabled:".to_string(), link.get_enabled());
+        if let Ok(Some(group)) = link.get_group() {
+            self.0.add_row(vec!["Link Group:".to_string(), group]);
+        }
 
         match link {
             Link::Fentry(fentry_link) => {
@@ -245,6 +269,18 @@ impl ProgTable {
                     tc_link.get_netns(),
                 );
 
+                Self::add_string(
+                    self,
+                    "Reattach Policy:".to_string(),
+                    link.get_reattach_policy().map(|p| p.to_string()),
+                );
+
+                Self::add_bool(
+                    self,
+                    "Interface Missing:".to_string(),
+                    link.get_interface_missing(),
+                );
+
                 Self::add_metadata(self, tc_link.get_metadata());
             }
             Link::Tcx(tcx_link) => {
@@ -274,6 +310,18 @@ impl ProgTable {
                     tcx_link.get_netns(),
                 );
 
+                Self::add_string(
+                    self,
+                    "Reattach Policy:".to_string(),
+                    link.get_reattach_policy().map(|p| p.to_string()),
+                );
+
+                Self::add_bool(
+                    self,
+                    "Interface Missing:".to_string(),
+                    link.get_interface_missing(),
+                );
+
                 Self::add_metadata(self, tcx_link.get_metadata());
             }
             Link::Tracepoint(tracepoint_link) => {
@@ -342,6 +390,18 @@ impl ProgTable {
                     xdp_link.get_netns(),
                 );
 
+                Self::add_string(
+                    self,
+                    "Reattach Policy:".to_string(),
+                    link.get_reattach_policy().map(|p| p.to_string()),
+                );
+
+                Self::add_bool(
+                    self,
+                    "Interface Missing:".to_string(),
+                    link.get_interface_missing(),
+                );
+
                 Self::add_metadata(self, xdp_link.get_metadata());
             }
         }
@@ -364,6 +424,48 @@ impl ProgTable {
         Ok(table)
     }
 
+    pub(crate) fn new_link_group(group: &LinkGroupOutput) -> Self {
+        let mut table = Self::create_bpfman_state_table();
+
+        table
+            .0
+            .add_row(vec!["Link Group:".to_string(), group.name.clone()]);
+        table.0.add_row(vec![
+            "Program ID:".to_string(),
+            group.program_id.to_string(),
+        ]);
+        table
+            .0
+            .add_row(vec!["Selector:".to_string(), group.selector.clone()]);
+        table.0.add_row(vec![
+            "Members:".to_string(),
+            Self::new_link_group_members(group).to_string(),
+        ]);
+
+        table
+    }
+
+    fn new_link_group_members(group: &LinkGroupOutput) -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec!["Interface", "Network Namespace", "Link ID", "Status"]);
+        for member in &group.members {
+            let netns = match &member.netns {
+                Some(netns) => netns.to_string_lossy().to_string(),
+                None => "None".to_string(),
+            };
+            let (link_id, status) = match (member.link_id, &member.error) {
+                (Some(id), _) => (id.to_string(), "attached".to_string()),
+                (None, Some(e)) => ("None".to_string(), format!("failed: {e}")),
+                (None, None) => ("None".to_string(), "failed".to_string()),
+            };
+            table.add_row(vec![member.iface.clone(), netns, link_id, status]);
+        }
+
+        ProgTable(table)
+    }
+
     fn create_kernel_info_table() -> Self {
         let mut table = Table::new();
 
@@ -513,10 +615,12 @@ impl ProgTable {
             "Type",
             "Function Name",
             "Attachment",
+            "Enabled",
         ]);
         ProgTable(table)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn add_link_row_list(
         &mut self,
         prog_id: String,
@@ -525,6 +629,7 @@ impl ProgTable {
         type_: String,
         fn_name: String,
         attachments: String,
+        enabled: String,
     ) {
         self.0.add_row(vec![
             prog_id,
@@ -533,6 +638,7 @@ impl ProgTable {
             type_,
             fn_name,
             attachments,
+            enabled,
         ]);
     }
 
@@ -555,6 +661,10 @@ impl ProgTable {
             Ok(name) => name,
             Err(_) => "None".to_string(),
         };
+        let enabled = match link.get_enabled() {
+            Ok(enabled) => enabled.to_string(),
+            Err(_) => "None".to_string(),
+        };
 
         self.add_link_row_list(
             prog_id,
@@ -563,16 +673,595 @@ impl ProgTable {
             Self::get_type_str(program),
             fn_name,
             Self::get_attach_str(program, link),
+            enabled,
         );
 
         Ok(())
     }
 
+    /// Creates a table of programs with the columns of `new_program_list`,
+    /// every link of each program and more kernel details.
+    pub(crate) fn new_program_list_wide() -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec![
+            "Program ID",
+            "Application",
+            "Type",
+            "Function Name",
+            "Links",
+            "Loaded At",
+            "Tag",
+            "Map IDs",
+        ]);
+        ProgTable(table)
+    }
+
+    /// Adds a row to a table created with `new_program_list_wide`.
+    pub(crate) fn add_program_output(&mut self, program: &ProgramOutput) {
+        let links = program
+            .bpfman
+            .as_ref()
+            .map(|b| {
+                b.links
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default();
+        let map_ids = program
+            .kernel
+            .map_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        self.0.add_row(vec![
+            program.kernel.id.to_string(),
+            program.application().unwrap_or_default().to_string(),
+            program.kernel.program_type.clone(),
+            program.kernel.name.clone(),
+            links,
+            program.kernel.loaded_at.clone(),
+            program.kernel.tag.clone(),
+            map_ids,
+        ]);
+    }
+
+    /// Creates a table of links with the columns of `new_link_list`, the link
+    /// group and the kernel's view of each link.
+    pub(crate) fn new_link_list_wide() -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec![
+            "Program ID",
+            "Link ID",
+            "Application",
+            "Type",
+            "Function Name",
+            "Attachment",
+            "Enabled",
+            "Link Group",
+            "Kernel Link ID",
+            "Kernel Link Type",
+        ]);
+        ProgTable(table)
+    }
+
+    /// Adds a row to a table created with `new_link_list_wide`.
+    pub(crate) fn add_link_output(&mut self, link: &LinkOutput) {
+        let (kernel_id, kernel_type) = match &link.kernel {
+            Some(k) => (k.id.to_string(), k.link_type.clone()),
+            None => ("None".to_string(), "None".to_string()),
+        };
+
+        self.0.add_row(vec![
+            link.program_id.to_string(),
+            link.id.to_string(),
+            link.application().unwrap_or_default().to_string(),
+            link.attach.link_type().to_string(),
+            link.program_name.clone(),
+            link.attach.to_string(),
+            link.enabled.to_string(),
+            link.group.clone().unwrap_or_else(|| "None".to_string()),
+            kernel_id,
+            kernel_type,
+        ]);
+    }
+
+    pub(crate) fn new_drift_list() -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec!["Program ID", "Link ID", "Drift", "Repair"]);
+        ProgTable(table)
+    }
+
+    pub(crate) fn new_policy_violation_list() -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec!["Program", "Rule", "Violation"]);
+        ProgTable(table)
+    }
+
+    pub(crate) fn add_policy_violation(&mut self, violation: &ManifestViolation) {
+        let (rule, reason) = match &violation.error {
+            BpfmanError::PolicyViolation { rule, reason } => (rule.clone(), reason.clone()),
+            e => ("None".to_string(), e.to_string()),
+        };
+        self.0
+            .add_row(vec![violation.program.clone(), rule, reason]);
+    }
+
+    /// Creates a table with the license and required kernel features of
+    /// inspected bytecode.
+    pub(crate) fn new_bytecode_info(info: &BytecodeInfoOutput) -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec![
+            Cell::new("Bytecode")
+                .add_attribute(comfy_table::Attribute::Bold)
+                .add_attribute(comfy_table::Attribute::Underlined)
+                .fg(Color::Green),
+        ]);
+        table.add_row(vec!["License:", &info.license]);
+        if let Some(version) = info.kernel_version {
+            table.add_row(vec!["Kernel Version:", &version.to_string()]);
+        }
+        if info.required_features.is_empty() {
+            table.add_row(vec!["Required Features:", "None"]);
+        }
+        for (i, feature) in info.required_features.iter().enumerate() {
+            let tag = if i == 0 { "Required Features:" } else { "" };
+            table.add_row(vec![tag, feature]);
+        }
+        ProgTable(table)
+    }
+
+    /// Creates a table of the programs of inspected bytecode.
+    pub(crate) fn new_bytecode_program_list(programs: &[BytecodeProgramOutput]) -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec![
+            "Function Name",
+            "Section",
+            "Type",
+            "Sleepable",
+            "Attach Target",
+        ]);
+        for program in programs {
+            let attach_target = match (&program.attach_target, program.attach_target_found) {
+                (Some(target), Some(false)) => format!("{target} (not in kernel BTF)"),
+                (Some(target), _) => target.clone(),
+                (None, _) => "None".to_string(),
+            };
+            table.add_row(vec![
+                program.name.clone(),
+                program.section.clone(),
+                program.program_type.clone(),
+                program.sleepable.to_string(),
+                attach_target,
+            ]);
+        }
+        ProgTable(table)
+    }
+
+    /// Creates a table of the maps of inspected bytecode.
+    pub(crate) fn new_bytecode_map_list(maps: &[BytecodeMapOutput]) -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec![
+            "Map Name",
+            "Type",
+            "Key Size",
+            "Value Size",
+            "Max Entries",
+        ]);
+        for map in maps {
+            table.add_row(vec![
+                map.name.clone(),
+                map.map_type.clone(),
+                map.key_size.to_string(),
+                map.value_size.to_string(),
+                map.max_entries.to_string(),
+            ]);
+        }
+        ProgTable(table)
+    }
+
+    /// Creates a table of the global variables of inspected bytecode.
+    pub(crate) fn new_global_variable_list(globals: &[GlobalVariableOutput]) -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec!["Global Variable", "Section", "Size", "Type"]);
+        for global in globals {
+            table.add_row(vec![
+                global.name.clone(),
+                global.section.clone(),
+                global.size.to_string(),
+                global.type_name.clone().unwrap_or("None".to_string()),
+            ]);
+        }
+        ProgTable(table)
+    }
+
+    /// Creates a table of the checks of `doctor`.
+    pub(crate) fn new_doctor_check_list(checks: &[DoctorCheckOutput]) -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec!["Category", "Check", "Status", "Details"]);
+        for check in checks {
+            table.add_row(vec![
+                check.category.clone(),
+                check.name.clone(),
+                check.status.clone(),
+                check.message.clone(),
+            ]);
+        }
+        ProgTable(table)
+    }
+
+    /// Creates a table of the programs and links created by `import`.
+    pub(crate) fn new_import_list(imported: &ImportOutput) -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec!["Kind", "Bundle ID", "ID"]);
+        for (kind, ids) in [("program", &imported.programs), ("link", &imported.links)] {
+            for i in ids {
+                table.add_row(vec![
+                    kind.to_string(),
+                    i.bundle_id.to_string(),
+                    i.id.to_string(),
+                ]);
+            }
+        }
+        ProgTable(table)
+    }
+
+    /// Creates a table for the programs checked by `load --dry-run`.
+    pub(crate) fn new_validated_list() -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec!["Name", "Type", "Verifier Log"]);
+        ProgTable(table)
+    }
+
+    pub(crate) fn add_validated_program(
+        &mut self,
+        name: String,
+        type_: String,
+        verifier_log: Option<String>,
+    ) {
+        self.0.add_row(vec![
+            name,
+            type_,
+            verifier_log.unwrap_or("None".to_string()),
+        ]);
+    }
+
+    pub(crate) fn add_drift_response(&mut self, drift: &Drift) {
+        let link_id = match drift.link_id {
+            Some(id) => id.to_string(),
+            None => "None".to_string(),
+        };
+
+        self.0.add_row(vec![
+            drift.program_id.to_string(),
+            link_id,
+            drift.kind.to_string(),
+            drift.repair.to_string(),
+        ]);
+    }
+
+    pub(crate) fn add_rpc_drift(&mut self, drift: &RpcDrift) {
+        let link_id = match drift.link_id {
+            Some(id) => id.to_string(),
+            None => "None".to_string(),
+        };
+
+        self.0.add_row(vec![
+            drift.program_id.to_string(),
+            link_id,
+            drift.kind.clone(),
+            drift.repair.clone(),
+        ]);
+    }
+
+    /// Builds the `Bpfman State` table of a program returned by a remote
+    /// bpfman-rpc.
+    pub(crate) fn new_rpc_program(info: &RpcProgramInfo) -> Self {
+        let mut table = Self::create_bpfman_state_table();
+
+        Self::add_string(
+            &mut table,
+            "BPF Function:".to_string(),
+            Ok(info.name.clone()),
+        );
+        match info.bytecode.as_ref().and_then(|b| b.location.as_ref()) {
+            Some(RpcLocation::Image(i)) => {
+                let pull_policy = match TryInto::<ImagePullPolicy>::try_into(i.image_pull_policy) {
+                    Ok(pp) => pp.to_string(),
+                    Err(_) => "None".to_string(),
+                };
+                table.0.add_row(vec!["Image URL:", &i.url]);
+                table.0.add_row(vec!["Pull Policy:", &pull_policy]);
+            }
+            Some(RpcLocation::File(p)) => {
+                table.0.add_row(vec!["Path:", p]);
+            }
+            None => {
+                table.0.add_row(vec!["Path:", "None"]);
+            }
+        };
+        if info.global_data.is_empty() {
+            table.0.add_row(vec!["Global:", "None"]);
+        } else {
+            for (i, (key, value)) in info.global_data.iter().enumerate() {
+                let tag = if i == 0 { "Global:" } else { "" };
+                table
+                    .0
+                    .add_row(vec![tag, &format!("{key}={}", encode_upper(value))]);
+            }
+        }
+        Self::add_metadata(&mut table, Ok(info.metadata.clone()));
+        Self::add_string(
+            &mut table,
+            "Map Pin Path:".to_string(),
+            Ok(info.map_pin_path.clone()),
+        );
+        Self::add_option_string(
+            &mut table,
+            "Map Owner ID:".to_string(),
+            Ok(info.map_owner_id.map(|id| id.to_string())),
+        );
+        Self::add_string(
+            &mut table,
+            "Maps Used By:".to_string(),
+            Ok(info.map_used_by.join(", ")),
+        );
+        Self::add_option_string(
+            &mut table,
+            "Verifier Log:".to_string(),
+            Ok(info.verifier_log_summary.clone()),
+        );
+        let links = info
+            .links
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        Self::add_string(&mut table, "Links:".to_string(), Ok(links));
+
+        table
+    }
+
+    /// Builds the `Kernel State` table of a program returned by a remote
+    /// bpfman-rpc.
+    pub(crate) fn new_rpc_kernel_info(info: &RpcKernelProgramInfo) -> Self {
+        let mut table = Self::create_kernel_info_table();
+
+        table
+            .0
+            .add_row(vec!["Program ID:".to_string(), info.id.to_string()]);
+        Self::add_string(
+            &mut table,
+            "BPF Function:".to_string(),
+            Ok(info.name.clone()),
+        );
+        table.0.add_row(vec![
+            "Kernel Type:".to_string(),
+            Self::get_rpc_type_str(info.program_type),
+        ]);
+        Self::add_string(
+            &mut table,
+            "Loaded At:".to_string(),
+            Ok(info.loaded_at.clone()),
+        );
+        Self::add_string(&mut table, "Tag:".to_string(), Ok(info.tag.clone()));
+        Self::add_bool(
+            &mut table,
+            "GPL Compatible:".to_string(),
+            Ok(info.gpl_compatible),
+        );
+        table
+            .0
+            .add_row(vec!["Map IDs:".to_string(), format!("{:?}", info.map_ids)]);
+        Self::add_u32(&mut table, "BTF ID:".to_string(), Ok(info.btf_id));
+        Self::add_u32(
+            &mut table,
+            "Size Translated (bytes):".to_string(),
+            Ok(info.bytes_xlated),
+        );
+        Self::add_bool(&mut table, "JITted:".to_string(), Ok(info.jited));
+        Self::add_u32(&mut table, "Size JITted:".to_string(), Ok(info.bytes_jited));
+        Self::add_u32(
+            &mut table,
+            "Kernel Allocated Memory (bytes):".to_string(),
+            Ok(info.bytes_memlock),
+        );
+        Self::add_u32(
+            &mut table,
+            "Verified Instruction Count:".to_string(),
+            Ok(info.verified_insns),
+        );
+
+        table
+    }
+
+    /// Builds the `Bpfman State` table of a link returned by a remote
+    /// bpfman-rpc, followed by the kernel's view of the link if it is
+    /// attached.
+    pub(crate) fn new_rpc_link(info: &LinkInfo, kernel_info: Option<&RpcKernelLinkInfo>) -> Self {
+        let mut table = Self::create_bpfman_state_table();
+
+        Self::add_u32(&mut table, "Program ID:".to_string(), Ok(info.program_id));
+        Self::add_u32(&mut table, "Link ID:".to_string(), Ok(info.link_id));
+        Self::add_string(
+            &mut table,
+            "BPF Function:".to_string(),
+            Ok(info.program_name.clone()),
+        );
+        Self::add_bool(&mut table, "Enabled:".to_string(), Ok(info.enabled));
+        Self::add_option_string(
+            &mut table,
+            "Link Group:".to_string(),
+            Ok(info.group.clone()),
+        );
+        Self::add_string(
+            &mut table,
+            "Attachment:".to_string(),
+            Ok(Self::get_rpc_attach_str(info)),
+        );
+        Self::add_bool(
+            &mut table,
+            "Interface Missing:".to_string(),
+            Ok(info.interface_missing),
+        );
+        let metadata = match info.attach_info.as_ref().and_then(|a| a.info.as_ref()) {
+            Some(Info::XdpAttachInfo(i)) => i.metadata.clone(),
+            Some(Info::TcAttachInfo(i)) => i.metadata.clone(),
+            Some(Info::TcxAttachInfo(i)) => i.metadata.clone(),
+            Some(Info::TracepointAttachInfo(i)) => i.metadata.clone(),
+            Some(Info::KprobeAttachInfo(i)) => i.metadata.clone(),
+            Some(Info::UprobeAttachInfo(i)) => i.metadata.clone(),
+            Some(Info::FentryAttachInfo(i)) => i.metadata.clone(),
+            Some(Info::FexitAttachInfo(i)) => i.metadata.clone(),
+            None => HashMap::new(),
+        };
+        Self::add_metadata(&mut table, Ok(metadata));
+        Self::add_option_string(
+            &mut table,
+            "Kernel Link ID:".to_string(),
+            Ok(kernel_info.map(|k| k.id.to_string())),
+        );
+        Self::add_option_string(
+            &mut table,
+            "Kernel Link Type:".to_string(),
+            Ok(kernel_info.map(|k| k.link_type.clone())),
+        );
+
+        table
+    }
+
+    /// Adds a row to a table created with `new_program_list` for a program
+    /// returned by a remote bpfman-rpc.
+    pub(crate) fn add_rpc_program_response(
+        &mut self,
+        info: Option<&RpcProgramInfo>,
+        kernel_info: &RpcKernelProgramInfo,
+    ) {
+        let (application, links) = match info {
+            Some(info) => {
+                let application = info
+                    .metadata
+                    .get(METADATA_APPLICATION_TAG)
+                    .cloned()
+                    .unwrap_or_default();
+                let mut link_ids = info.links.clone();
+                let count = link_ids.len();
+                link_ids.truncate(NUM_LIST_LINKS);
+                let link_list = link_ids
+                    .into_iter()
+                    .map(|m| m.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                let truncate_str = if count > NUM_LIST_LINKS { ", ..." } else { "" };
+                let links = if count == 0 {
+                    "".to_string()
+                } else {
+                    format!("({count}) {link_list}{truncate_str}")
+                };
+                (application, links)
+            }
+            None => (String::new(), String::new()),
+        };
+
+        self.add_program_row_list(
+            kernel_info.id.to_string(),
+            application,
+            Self::get_rpc_type_str(kernel_info.program_type),
+            kernel_info.name.clone(),
+            links,
+        );
+    }
+
+    /// Adds a row to a table created with `new_link_list` for a link returned
+    /// by a remote bpfman-rpc.
+    pub(crate) fn add_rpc_link_response(&mut self, info: &LinkInfo) {
+        let (type_, metadata) = match info.attach_info.as_ref().and_then(|a| a.info.as_ref()) {
+            Some(Info::XdpAttachInfo(i)) => ("xdp", &i.metadata),
+            Some(Info::TcAttachInfo(i)) => ("tc", &i.metadata),
+            Some(Info::TcxAttachInfo(i)) => ("tcx", &i.metadata),
+            Some(Info::TracepointAttachInfo(i)) => ("tracepoint", &i.metadata),
+            Some(Info::KprobeAttachInfo(i)) => ("kprobe", &i.metadata),
+            Some(Info::UprobeAttachInfo(i)) => ("uprobe", &i.metadata),
+            Some(Info::FentryAttachInfo(i)) => ("fentry", &i.metadata),
+            Some(Info::FexitAttachInfo(i)) => ("fexit", &i.metadata),
+            None => {
+                warn!("link {} has no attach info", info.link_id);
+                return;
+            }
+        };
+
+        self.add_link_row_list(
+            info.program_id.to_string(),
+            info.link_id.to_string(),
+            metadata
+                .get(METADATA_APPLICATION_TAG)
+                .cloned()
+                .unwrap_or_default(),
+            type_.to_string(),
+            info.program_name.clone(),
+            Self::get_rpc_attach_str(info),
+            info.enabled.to_string(),
+        );
+    }
+
+    pub(crate) fn get_rpc_type_str(program_type: u32) -> String {
+        match BpfProgType::try_from(program_type) {
+            Ok(t) => t.to_string(),
+            Err(_) => program_type.to_string(),
+        }
+    }
+
+    fn get_rpc_attach_str(info: &LinkInfo) -> String {
+        match info.attach_info.as_ref().and_then(|a| a.info.as_ref()) {
+            Some(Info::XdpAttachInfo(i)) => format!("{} pos-{}", i.iface, i.position),
+            Some(Info::TcAttachInfo(i)) => {
+                format!("{} {} pos-{}", i.iface, i.direction, i.position)
+            }
+            Some(Info::TcxAttachInfo(i)) => {
+                format!("{} {} pos-{}", i.iface, i.direction, i.position)
+            }
+            Some(Info::TracepointAttachInfo(i)) => i.tracepoint.clone(),
+            Some(Info::KprobeAttachInfo(i)) => i.fn_name.clone(),
+            Some(Info::UprobeAttachInfo(i)) => match &i.fn_name {
+                Some(name) => format!("{} {}", i.target, name),
+                None => i.target.clone(),
+            },
+            Some(Info::FentryAttachInfo(_)) | Some(Info::FexitAttachInfo(_)) | None => {
+                "unknown".to_string()
+            }
+        }
+    }
+
     pub(crate) fn print(&self) {
         println!("{self}\n")
     }
 
-    fn get_type_str(program: &Program) -> String {
+    pub(crate) fn get_type_str(program: &Program) -> String {
         match program {
             Program::Fentry(_program) => "fentry".to_string(),
             Program::Fexit(_program) => "fexit".to_string(),
@@ -586,7 +1275,7 @@ impl ProgTable {
         }
     }
 
-    fn get_attach_str(program: &Program, link: &Link) -> String {
+    pub(crate) fn get_attach_str(program: &Program, link: &Link) -> String {
         match link {
             Link::Fentry(_fentry_link) => match program {
                 Program::Fentry(fentry_program) => match fentry_program.get_fn_name() {
@@ -676,7 +1365,7 @@ impl ProgTable {
         }
     }
 
-    fn get_program_application(data: &ProgramData) -> String {
+    pub(crate) fn get_program_application(data: &ProgramData) -> String {
         match data.get_application_from_metadata() {
             Some(application) => application,
             None => "".to_string(),
@@ -0,0 +1,860 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::{self, IsTerminal, Write},
+    os::fd::OwnedFd,
+    time::{Duration, Instant},
+};
+
+use anyhow::bail;
+use aya::{
+    maps::MapInfo,
+    programs::loaded_programs,
+    sys::{Stats, enable_stats},
+};
+use bpfman::{
+    config::Config,
+    detach, detach_batch, get_db_config, init_database, list_dispatchers, list_links,
+    list_programs, remove_program, setup,
+    types::{LinkListFilter, LinkType, ListFilter, MapType, Program},
+};
+use comfy_table::{ContentArrangement, Table};
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::{Attribute, Print, SetAttribute},
+    terminal::{
+        self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode,
+        enable_raw_mode,
+    },
+};
+
+use crate::{args::TopArgs, table::ProgTable};
+
+const BPF_STATS_ENABLED: &str = "/proc/sys/kernel/bpf_stats_enabled";
+// The lines above and below the table: the title, the views, the status line
+// and the key help.
+const HEADER_LINES: u16 = 3;
+const FOOTER_LINES: u16 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum View {
+    Programs,
+    Links,
+    Dispatchers,
+    Maps,
+}
+
+const VIEWS: [View; 4] = [View::Programs, View::Links, View::Dispatchers, View::Maps];
+
+impl View {
+    fn title(&self) -> &'static str {
+        match self {
+            View::Programs => "Programs",
+            View::Links => "Links",
+            View::Dispatchers => "Dispatchers",
+            View::Maps => "Maps",
+        }
+    }
+
+    fn index(&self) -> usize {
+        VIEWS.iter().position(|v| v == self).unwrap_or_default()
+    }
+
+    fn next(&self) -> View {
+        VIEWS[(self.index() + 1) % VIEWS.len()]
+    }
+
+    fn previous(&self) -> View {
+        VIEWS[(self.index() + VIEWS.len() - 1) % VIEWS.len()]
+    }
+}
+
+/// The order of the rows, from the highest value down, except for the id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortBy {
+    /// CPU time used since the previous refresh.
+    Cpu,
+    /// Total run time.
+    RunTime,
+    /// Total run count.
+    RunCount,
+    Id,
+}
+
+impl SortBy {
+    fn name(&self) -> &'static str {
+        match self {
+            SortBy::Cpu => "cpu",
+            SortBy::RunTime => "run time",
+            SortBy::RunCount => "run count",
+            SortBy::Id => "id",
+        }
+    }
+
+    fn next(&self) -> SortBy {
+        match self {
+            SortBy::Cpu => SortBy::RunTime,
+            SortBy::RunTime => SortBy::RunCount,
+            SortBy::RunCount => SortBy::Id,
+            SortBy::Id => SortBy::Cpu,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    Tab,
+    BackTab,
+    Esc,
+    Interrupt,
+    Char(char),
+}
+
+impl Key {
+    /// Returns the key of a terminal event, or None if the event isn't a key
+    /// that top uses.
+    fn from_event(event: &Event) -> Option<Key> {
+        let Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press | KeyEventKind::Repeat,
+            ..
+        }) = event
+        else {
+            return None;
+        };
+        let key = match code {
+            KeyCode::Up => Key::Up,
+            KeyCode::Down => Key::Down,
+            KeyCode::Left => Key::Left,
+            KeyCode::Right => Key::Right,
+            KeyCode::PageUp => Key::PageUp,
+            KeyCode::PageDown => Key::PageDown,
+            KeyCode::Home => Key::Home,
+            KeyCode::End => Key::End,
+            KeyCode::Tab => Key::Tab,
+            KeyCode::BackTab => Key::BackTab,
+            KeyCode::Esc => Key::Esc,
+            KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => Key::Interrupt,
+            KeyCode::Char(c) if modifiers.difference(KeyModifiers::SHIFT).is_empty() => {
+                Key::Char(*c)
+            }
+            _ => return None,
+        };
+        Some(key)
+    }
+}
+
+/// The run statistics of a program, as counted by the kernel while
+/// `kernel.bpf_stats_enabled` is on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct RunStats {
+    run_count: u64,
+    run_time_ns: u64,
+}
+
+impl RunStats {
+    fn add(&mut self, other: RunStats) {
+        self.run_count += other.run_count;
+        self.run_time_ns += other.run_time_ns;
+    }
+
+    fn avg_ns(&self) -> u64 {
+        self.run_time_ns.checked_div(self.run_count).unwrap_or(0)
+    }
+
+    /// Returns the CPU time used since `previous`, as a percentage of
+    /// `elapsed`. It can be above 100 on hosts with several CPUs.
+    fn cpu_percent(&self, previous: Option<RunStats>, elapsed: Duration) -> f64 {
+        let Some(previous) = previous else {
+            return 0.0;
+        };
+        if elapsed.is_zero() {
+            return 0.0;
+        }
+        let used = self.run_time_ns.saturating_sub(previous.run_time_ns);
+        used as f64 * 100.0 / elapsed.as_nanos() as f64
+    }
+}
+
+/// What can be done to the selected row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Action {
+    Unload(u32),
+    Detach(u32),
+    /// Detach all the links of a dispatcher.
+    DetachAll(Vec<u32>),
+}
+
+impl Action {
+    fn prompt(&self) -> String {
+        match self {
+            Action::Unload(id) => format!("Unload program {id}? [y/N]"),
+            Action::Detach(id) => format!("Detach link {id}? [y/N]"),
+            Action::DetachAll(ids) => format!(
+                "Detach links {}? [y/N]",
+                ids.iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Row {
+    cells: Vec<String>,
+    id: u32,
+    stats: RunStats,
+    cpu: f64,
+    action: Option<Action>,
+}
+
+#[derive(Debug, Default)]
+struct Snapshot {
+    programs: Vec<Row>,
+    links: Vec<Row>,
+    dispatchers: Vec<Row>,
+    maps: Vec<Row>,
+}
+
+impl Snapshot {
+    fn rows(&self, view: View) -> &[Row] {
+        match view {
+            View::Programs => &self.programs,
+            View::Links => &self.links,
+            View::Dispatchers => &self.dispatchers,
+            View::Maps => &self.maps,
+        }
+    }
+
+    fn rows_mut(&mut self, view: View) -> &mut Vec<Row> {
+        match view {
+            View::Programs => &mut self.programs,
+            View::Links => &mut self.links,
+            View::Dispatchers => &mut self.dispatchers,
+            View::Maps => &mut self.maps,
+        }
+    }
+}
+
+fn sort_rows(rows: &mut [Row], sort_by: SortBy) {
+    rows.sort_by(|a, b| {
+        let order = match sort_by {
+            SortBy::Cpu => b.cpu.total_cmp(&a.cpu),
+            SortBy::RunTime => b.stats.run_time_ns.cmp(&a.stats.run_time_ns),
+            SortBy::RunCount => b.stats.run_count.cmp(&a.stats.run_count),
+            SortBy::Id => std::cmp::Ordering::Equal,
+        };
+        order.then(a.id.cmp(&b.id))
+    });
+}
+
+fn header(view: View) -> Vec<&'static str> {
+    match view {
+        View::Programs => vec![
+            "Program ID",
+            "Application",
+            "Type",
+            "Function Name",
+            "Links",
+            "Run Count",
+            "Run Time (ns)",
+            "Avg (ns)",
+            "CPU %",
+        ],
+        View::Links => vec![
+            "Link ID",
+            "Program ID",
+            "Type",
+            "Function Name",
+            "Attachment",
+            "Enabled",
+            "Run Count",
+            "Run Time (ns)",
+            "CPU %",
+        ],
+        View::Dispatchers => vec![
+            "Type",
+            "Interface",
+            "Direction",
+            "Mode",
+            "Revision",
+            "Links",
+            "Run Count",
+            "Run Time (ns)",
+            "CPU %",
+        ],
+        View::Maps => vec![
+            "Map ID",
+            "Name",
+            "Type",
+            "Key Size",
+            "Value Size",
+            "Max Entries",
+            "Used By",
+        ],
+    }
+}
+
+struct Top {
+    config: Config,
+    interval: Duration,
+    stats_enabled: bool,
+    view: View,
+    sort_by: SortBy,
+    selected: [usize; VIEWS.len()],
+    snapshot: Snapshot,
+    previous: HashMap<u32, RunStats>,
+    previous_at: Option<Instant>,
+    confirm: Option<Action>,
+    status: String,
+}
+
+impl Top {
+    fn refresh(&mut self) {
+        match self.snapshot() {
+            Ok(snapshot) => self.snapshot = snapshot,
+            Err(e) => self.status = format!("Unable to refresh: {e}"),
+        }
+        for view in VIEWS {
+            let len = self.snapshot.rows(view).len();
+            let selected = &mut self.selected[view.index()];
+            *selected = (*selected).min(len.saturating_sub(1));
+        }
+    }
+
+    fn snapshot(&mut self) -> anyhow::Result<Snapshot> {
+        // The database is only opened while it is read, so that bpfman-rpc
+        // and the other commands aren't locked out of it while top runs.
+        let root_db = init_database(get_db_config())?;
+        let programs = list_programs(&root_db, ListFilter::new(None, HashMap::new(), true))?;
+        let links = list_links(&root_db, LinkListFilter::default())?;
+        let dispatchers = list_dispatchers(&root_db)?;
+        drop(root_db);
+
+        let now = Instant::now();
+        let elapsed = self
+            .previous_at
+            .map(|at| now.duration_since(at))
+            .unwrap_or_default();
+        let stats: HashMap<u32, RunStats> = loaded_programs()
+            .flatten()
+            .map(|p| {
+                (
+                    p.id(),
+                    RunStats {
+                        run_count: p.run_count(),
+                        run_time_ns: p.run_time().as_nanos() as u64,
+                    },
+                )
+            })
+            .collect();
+        let cpu: HashMap<u32, f64> = stats
+            .iter()
+            .map(|(id, s)| (*id, s.cpu_percent(self.previous.get(id).copied(), elapsed)))
+            .collect();
+        let program_stats = |id: u32| {
+            (
+                stats.get(&id).copied().unwrap_or_default(),
+                cpu.get(&id).copied().unwrap_or_default(),
+            )
+        };
+
+        let mut snapshot = Snapshot::default();
+        let mut by_id: HashMap<u32, &Program> = HashMap::new();
+        let mut maps: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+        for program in &programs {
+            let data = program.get_data();
+            let id = data.get_id()?;
+            by_id.insert(id, program);
+            for map_id in data.get_kernel_map_ids().unwrap_or_default() {
+                maps.entry(map_id).or_default().push(id);
+            }
+
+            let (stats, cpu) = program_stats(id);
+            snapshot.programs.push(Row {
+                cells: vec![
+                    id.to_string(),
+                    ProgTable::get_program_application(data),
+                    ProgTable::get_type_str(program),
+                    data.get_kernel_name().unwrap_or_default(),
+                    data.get_link_ids().unwrap_or_default().len().to_string(),
+                    stats.run_count.to_string(),
+                    stats.run_time_ns.to_string(),
+                    stats.avg_ns().to_string(),
+                    format!("{cpu:.2}"),
+                ],
+                id,
+                stats,
+                cpu,
+                action: Some(Action::Unload(id)),
+            });
+        }
+
+        for link in &links {
+            let id = link.get_id()?;
+            let program_id = link.get_program_id()?;
+            let Some(program) = by_id.get(&program_id) else {
+                continue;
+            };
+            let (stats, cpu) = program_stats(program_id);
+            snapshot.links.push(Row {
+                cells: vec![
+                    id.to_string(),
+                    program_id.to_string(),
+                    ProgTable::get_type_str(program),
+                    program.get_data().get_kernel_name().unwrap_or_default(),
+                    ProgTable::get_attach_str(program, link),
+                    link.get_enabled().unwrap_or_default().to_string(),
+                    stats.run_count.to_string(),
+                    stats.run_time_ns.to_string(),
+                    format!("{cpu:.2}"),
+                ],
+                id,
+                stats,
+                cpu,
+                action: Some(Action::Detach(id)),
+            });
+        }
+
+        for (i, dispatcher) in dispatchers.iter().enumerate() {
+            // A dispatcher's run time is the sum of the programs it runs.
+            let mut stats = RunStats::default();
+            let mut cpu = 0.0;
+            for program_id in &dispatcher.program_ids {
+                let (s, c) = program_stats(*program_id);
+                stats.add(s);
+                cpu += c;
+            }
+            let link_type = match dispatcher.link_type {
+                LinkType::Xdp => "xdp",
+                _ => "tc",
+            };
+            snapshot.dispatchers.push(Row {
+                cells: vec![
+                    link_type.to_string(),
+                    dispatcher.if_name.clone(),
+                    dispatcher
+                        .direction
+                        .map(|d| d.to_string())
+                        .unwrap_or_default(),
+                    dispatcher.xdp_mode.clone().unwrap_or_default(),
+                    dispatcher.revision.to_string(),
+                    dispatcher
+                        .link_ids
+                        .iter()
+                        .map(|id| id.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    stats.run_count.to_string(),
+                    stats.run_time_ns.to_string(),
+                    format!("{cpu:.2}"),
+                ],
+                id: i as u32,
+                stats,
+                cpu,
+                action: (!dispatcher.link_ids.is_empty())
+                    .then(|| Action::DetachAll(dispatcher.link_ids.clone())),
+            });
+        }
+
+        for (map_id, program_ids) in maps {
+            let Ok(info) = MapInfo::from_id(map_id) else {
+                continue;
+            };
+            snapshot.maps.push(Row {
+                cells: vec![
+                    map_id.to_string(),
+                    info.name_as_str().unwrap_or_default().to_string(),
+                    info.map_type()
+                        .map(|t| MapType::from(t).to_string())
+                        .unwrap_or_default(),
+                    info.key_size().to_string(),
+                    info.value_size().to_string(),
+                    info.max_entries().to_string(),
+                    program_ids
+                        .iter()
+                        .map(|id| id.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ],
+                id: map_id,
+                ..Default::default()
+            });
+        }
+
+        for view in VIEWS {
+            sort_rows(snapshot.rows_mut(view), self.sort_by);
+        }
+        self.previous = stats;
+        self.previous_at = Some(now);
+        Ok(snapshot)
+    }
+
+    fn run(&mut self, action: Action) {
+        let result = (|| -> anyhow::Result<String> {
+            let root_db = init_database(get_db_config())?;
+            Ok(match &action {
+                Action::Unload(id) => {
+                    remove_program(&self.config, &root_db, *id)?;
+                    format!("Unloaded program {id}")
+                }
+                Action::Detach(id) => {
+                    detach(&self.config, &root_db, *id)?;
+                    format!("Detached link {id}")
+                }
+                Action::DetachAll(ids) => {
+                    for result in detach_batch(&self.config, &root_db, ids) {
+                        result?;
+                    }
+                    format!("Detached {} link(s)", ids.len())
+                }
+            })
+        })();
+        self.status = match result {
+            Ok(status) => status,
+            Err(e) => format!("Error: {e}"),
+        };
+        self.refresh();
+    }
+
+    fn selected_row(&self) -> Option<&Row> {
+        self.snapshot
+            .rows(self.view)
+            .get(self.selected[self.view.index()])
+    }
+
+    // Returns false when top should exit.
+    fn handle_key(&mut self, key: Key, page: usize) -> bool {
+        if let Some(action) = self.confirm.take() {
+            match key {
+                Key::Char('y') | Key::Char('Y') => self.run(action),
+                Key::Interrupt => return false,
+                _ => self.status = "Cancelled".to_string(),
+            }
+            return true;
+        }
+
+        let len = self.snapshot.rows(self.view).len();
+        let selected = &mut self.selected[self.view.index()];
+        let last = len.saturating_sub(1);
+        match key {
+            Key::Char('q') | Key::Esc | Key::Interrupt => return false,
+            Key::Up | Key::Char('k') => *selected = selected.saturating_sub(1),
+            Key::Down | Key::Char('j') => *selected = (*selected + 1).min(last),
+            Key::PageUp => *selected = selected.saturating_sub(page),
+            Key::PageDown => *selected = (*selected + page).min(last),
+            Key::Home | Key::Char('g') => *selected = 0,
+            Key::End | Key::Char('G') => *selected = last,
+            Key::Tab | Key::Right => self.view = self.view.next(),
+            Key::BackTab | Key::Left => self.view = self.view.previous(),
+            Key::Char(c @ '1'..='4') => self.view = VIEWS[c as usize - '1' as usize],
+            Key::Char('s') => {
+                self.sort_by = self.sort_by.next();
+                for view in VIEWS {
+                    sort_rows(self.snapshot.rows_mut(view), self.sort_by);
+                }
+            }
+            Key::Char('r') => self.refresh(),
+            Key::Char('u') | Key::Char('d') => {
+                let action = self.selected_row().and_then(|r| r.action.clone());
+                match (key, action) {
+                    (Key::Char('u'), Some(action @ Action::Unload(_)))
+                    | (Key::Char('d'), Some(action @ (Action::Detach(_) | Action::DetachAll(_)))) =>
+                    {
+                        self.status = action.prompt();
+                        self.confirm = Some(action);
+                    }
+                    _ => self.status = "Nothing to do for the selected row".to_string(),
+                }
+            }
+            _ => {}
+        }
+        true
+    }
+
+    fn draw(&self, out: &mut impl Write) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        let width = width as usize;
+
+        let stats = if self.stats_enabled {
+            "bpf stats enabled".to_string()
+        } else {
+            format!("bpf stats disabled, run times are 0 until {BPF_STATS_ENABLED} is set to 1")
+        };
+        let views = VIEWS
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                if *v == self.view {
+                    format!("[{} {}]", i + 1, v.title())
+                } else {
+                    format!(" {} {} ", i + 1, v.title())
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let lines = [
+            format!(
+                "bpfman top - refresh every {}s - {stats}",
+                self.interval.as_secs()
+            ),
+            format!("{views}   sort: {}", self.sort_by.name()),
+            self.status.clone(),
+        ];
+
+        queue!(out, MoveTo(0, 0))?;
+        for (i, line) in lines.iter().enumerate() {
+            queue!(
+                out,
+                MoveTo(0, i as u16),
+                Print(truncate(line, width)),
+                Clear(ClearType::UntilNewLine)
+            )?;
+        }
+
+        let mut table = Table::new();
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_content_arrangement(ContentArrangement::Disabled);
+        table.set_header(header(self.view));
+        let rows = self.snapshot.rows(self.view);
+        for row in rows {
+            table.add_row(row.cells.clone());
+        }
+        let mut table_lines = table.lines();
+
+        // The table header stays in place while the rows scroll.
+        let visible = height.saturating_sub(HEADER_LINES + FOOTER_LINES + 1) as usize;
+        let selected = self.selected[self.view.index()];
+        let offset = (selected + 1).saturating_sub(visible);
+        let mut y = HEADER_LINES;
+        if let Some(line) = table_lines.next() {
+            queue!(
+                out,
+                MoveTo(0, y),
+                SetAttribute(Attribute::Bold),
+                Print(truncate(&line, width)),
+                SetAttribute(Attribute::Reset),
+                Clear(ClearType::UntilNewLine)
+            )?;
+            y += 1;
+        }
+        for (i, line) in table_lines.enumerate().skip(offset).take(visible) {
+            queue!(out, MoveTo(0, y))?;
+            if i == selected {
+                queue!(out, SetAttribute(Attribute::Reverse))?;
+            }
+            queue!(
+                out,
+                Print(truncate(&line, width)),
+                SetAttribute(Attribute::Reset),
+                Clear(ClearType::UntilNewLine)
+            )?;
+            y += 1;
+        }
+        queue!(out, MoveTo(0, y), Clear(ClearType::FromCursorDown))?;
+
+        let help = "up/down select  tab/1-4 view  s sort  r refresh  u unload  d detach  q quit";
+        queue!(
+            out,
+            MoveTo(0, height.saturating_sub(1)),
+            Print(truncate(help, width))
+        )?;
+        out.flush()
+    }
+}
+
+fn truncate(line: &str, width: usize) -> String {
+    line.chars().take(width).collect()
+}
+
+/// Restores the terminal when top exits, including on errors and panics.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter() -> io::Result<Self> {
+        enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(RawTerminal)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), LeaveAlternateScreen, Show);
+        let _ = disable_raw_mode();
+    }
+}
+
+// Waits up to `timeout` for keys to be pressed.
+fn read_keys(timeout: Duration) -> anyhow::Result<Vec<Key>> {
+    let mut keys = vec![];
+    let mut timeout = timeout;
+    while event::poll(timeout)? {
+        if let Some(key) = Key::from_event(&event::read()?) {
+            keys.push(key);
+        }
+        // Read the rest of the events that are already queued.
+        timeout = Duration::ZERO;
+    }
+    Ok(keys)
+}
+
+pub(crate) fn execute_top(args: &TopArgs) -> anyhow::Result<()> {
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        bail!("top must be run in a terminal");
+    }
+    let (config, root_db) = setup()?;
+    drop(root_db);
+
+    // Keep the run time statistics enabled while top runs. The kernel turns
+    // them off again when the file descriptor is closed.
+    let _stats_fd: Option<OwnedFd> = if args.enable_stats {
+        Some(enable_stats(Stats::RunTime)?)
+    } else {
+        None
+    };
+    let stats_enabled =
+        args.enable_stats || fs::read_to_string(BPF_STATS_ENABLED).is_ok_and(|s| s.trim() != "0");
+
+    let mut top = Top {
+        config,
+        interval: Duration::from_secs(args.interval),
+        stats_enabled,
+        view: View::Programs,
+        sort_by: SortBy::Cpu,
+        selected: [0; VIEWS.len()],
+        snapshot: Snapshot::default(),
+        previous: HashMap::new(),
+        previous_at: None,
+        confirm: None,
+        status: String::new(),
+    };
+
+    let _terminal = RawTerminal::enter()?;
+    let mut stdout = io::stdout();
+    let mut next_refresh = Instant::now();
+    loop {
+        if Instant::now() >= next_refresh {
+            // Keep the previous status while a confirmation is pending.
+            if top.confirm.is_none() {
+                top.status.clear();
+            }
+            top.refresh();
+            next_refresh = Instant::now() + top.interval;
+        }
+        top.draw(&mut stdout)?;
+
+        let page = terminal::size()?
+            .1
+            .saturating_sub(HEADER_LINES + FOOTER_LINES + 1) as usize;
+        for key in read_keys(next_refresh.saturating_duration_since(Instant::now()))? {
+            if !top.handle_key(key, page) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_from_event() {
+        let key = |code, modifiers| Key::from_event(&Event::Key(KeyEvent::new(code, modifiers)));
+        assert_eq!(
+            key(KeyCode::Char('q'), KeyModifiers::NONE),
+            Some(Key::Char('q'))
+        );
+        assert_eq!(
+            key(KeyCode::Char('G'), KeyModifiers::SHIFT),
+            Some(Key::Char('G'))
+        );
+        assert_eq!(
+            key(KeyCode::Char('c'), KeyModifiers::CONTROL),
+            Some(Key::Interrupt)
+        );
+        assert_eq!(key(KeyCode::Char('s'), KeyModifiers::ALT), None);
+        assert_eq!(
+            key(KeyCode::BackTab, KeyModifiers::SHIFT),
+            Some(Key::BackTab)
+        );
+        assert_eq!(
+            key(KeyCode::PageDown, KeyModifiers::NONE),
+            Some(Key::PageDown)
+        );
+        assert_eq!(key(KeyCode::F(1), KeyModifiers::NONE), None);
+        assert_eq!(Key::from_event(&Event::FocusGained), None);
+        assert_eq!(
+            Key::from_event(&Event::Key(KeyEvent::new_with_kind(
+                KeyCode::Char('q'),
+                KeyModifiers::NONE,
+                KeyEventKind::Release,
+            ))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cpu_percent() {
+        let previous = RunStats {
+            run_count: 10,
+            run_time_ns: 1_000_000,
+        };
+        let current = RunStats {
+            run_count: 20,
+            run_time_ns: 21_000_000,
+        };
+        let elapsed = Duration::from_secs(2);
+        assert_eq!(current.cpu_percent(Some(previous), elapsed), 1.0);
+        assert_eq!(current.cpu_percent(None, elapsed), 0.0);
+        assert_eq!(current.avg_ns(), 1_050_000);
+    }
+
+    #[test]
+    fn test_sort_rows() {
+        let row = |id, cpu, run_count| Row {
+            id,
+            cpu,
+            stats: RunStats {
+                run_count,
+                run_time_ns: 0,
+            },
+            ..Default::default()
+        };
+        let mut rows = vec![row(3, 0.5, 1), row(1, 2.0, 1), row(2, 0.5, 9)];
+        sort_rows(&mut rows, SortBy::Cpu);
+        assert_eq!(rows.iter().map(|r| r.id).collect::<Vec<_>>(), vec![1, 2, 3]);
+        sort_rows(&mut rows, SortBy::RunCount);
+        assert_eq!(rows.iter().map(|r| r.id).collect::<Vec<_>>(), vec![2, 1, 3]);
+    }
+
+    #[test]
+    fn test_table_lines() {
+        // draw() relies on the table having a line for the header and one
+        // for each row, without any separator.
+        let mut table = Table::new();
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_content_arrangement(ContentArrangement::Disabled);
+        table.set_header(header(View::Maps));
+        table.add_row(vec!["1", "a"]);
+        table.add_row(vec!["2", "b"]);
+        assert_eq!(table.lines().count(), 3);
+    }
+}
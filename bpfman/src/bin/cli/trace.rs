@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use std::{
+    io::{Write, stdout},
+    thread::sleep,
+    time::Duration,
+};
+
+use bpfman::{resolve_program, setup, trace::trace_program};
+
+use crate::args::TraceArgs;
+
+// How often the trace_pipe and the log map of the program are read.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+pub(crate) fn execute_trace(args: &TraceArgs) -> anyhow::Result<()> {
+    let (_, root_db) = setup()?;
+    let id = resolve_program(&root_db, &args.program)?;
+    let mut trace = trace_program(&root_db, id)?;
+    // The program is only read from the database when the trace is opened, so
+    // the other commands can be used while the trace runs.
+    drop(root_db);
+
+    let mut sources = vec![];
+    if trace.printk_formats() > 0 {
+        sources.push(format!(
+            "trace_pipe lines matching {} bpf_printk formats",
+            trace.printk_formats()
+        ));
+    }
+    if trace.has_log_map() {
+        sources.push("aya-log records".to_string());
+    }
+    eprintln!(
+        "Tracing program {id} from {}, press Ctrl-C to stop",
+        sources.join(" and ")
+    );
+
+    let mut out = stdout().lock();
+    loop {
+        for record in trace.read()? {
+            writeln!(out, "{record}")?;
+        }
+        out.flush()?;
+        sleep(POLL_INTERVAL);
+    }
+}
@@ -1,12 +1,73 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright Authors of bpfman
 
-use bpfman::{remove_program, setup};
+use bpfman::{remove_program, resolve_program, select_programs, setup};
 
 use crate::args::UnloadArgs;
 
 pub(crate) fn execute_unload(args: &UnloadArgs) -> Result<(), anyhow::Error> {
     let (config, root_db) = setup()?;
-    remove_program(&config, &root_db, args.program_id)?;
+    if let Some(selector) = &args.selector {
+        let mut results = vec![];
+        for program in select_programs(&root_db, selector)? {
+            let id = program.get_data().get_id()?;
+            let result = remove_program(&config, &root_db, id).map_err(|e| e.to_string());
+            results.push((id, result));
+        }
+        report_selected("unload", "Unloaded", "program", results)?;
+    } else if let Some(program) = &args.program {
+        remove_program(&config, &root_db, resolve_program(&root_db, program)?)?;
+    }
     Ok(())
 }
+
+/// Prints the outcome for each program or link matched by a `--selector`,
+/// which are all acted on even if some of them fail, and returns an error if
+/// any of them failed. `verb` is the action, such as "unload", and `done` how
+/// it is printed once done, such as "Unloaded".
+pub(crate) fn report_selected(
+    verb: &str,
+    done: &str,
+    kind: &str,
+    results: Vec<(u32, Result<(), String>)>,
+) -> anyhow::Result<()> {
+    let mut failed = 0;
+    for (id, result) in &results {
+        match result {
+            Ok(()) => println!("{done} {kind} {id}"),
+            Err(e) => {
+                eprintln!("Unable to {verb} {kind} {id}: {e}");
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        anyhow::bail!(
+            "unable to {verb} {failed} of the {} {kind}s matched by the selector",
+            results.len()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_selected() {
+        assert!(report_selected("unload", "Unloaded", "program", vec![(1, Ok(()))]).is_ok());
+        // Every result is reported, and a failure in the middle is an error.
+        let err = report_selected(
+            "unload",
+            "Unloaded",
+            "program",
+            vec![(1, Ok(())), (2, Err("busy".to_string())), (3, Ok(()))],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unable to unload 1 of the 3 programs matched by the selector"
+        );
+    }
+}
@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use bpfman::{setup, verify};
+
+use crate::{args::VerifyArgs, table::ProgTable};
+
+pub(crate) fn execute_verify(args: &VerifyArgs) -> Result<(), anyhow::Error> {
+    let (config, root_db) = setup()?;
+    let drifts = verify(&config, &root_db, args.repair)?;
+    if drifts.is_empty() {
+        println!("No drift found\n");
+        return Ok(());
+    }
+
+    let mut table = ProgTable::new_drift_list();
+    for drift in &drifts {
+        table.add_drift_response(drift);
+    }
+    table.print();
+    Ok(())
+}
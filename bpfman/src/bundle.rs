@@ -0,0 +1,809 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+//! Export and import of the programs and links managed by bpfman. A
+//! [`StateBundle`] describes them without the IDs allocated by the kernel, so
+//! that the same state can be recreated on another host, for example when a
+//! node is migrated or rebuilt.
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    path::PathBuf,
+};
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use sled::Db;
+
+use crate::{
+    add_programs, attach_program,
+    config::Config,
+    disable_link,
+    errors::BpfmanError,
+    list_links, list_programs, remove_program, set_link_reattach_policy,
+    types::{
+        AttachInfo, BytecodeImage, FentryProgram, FexitProgram, ImagePullPolicy, KprobeProgram,
+        Link, LinkGroup, LinkGroupMember, LinkListFilter, ListFilter, Location, Program,
+        ProgramData, ReattachPolicy, TcProceedOn, TcProceedOnEntry, TcProgram, TcxProgram,
+        TracepointProgram, UprobeProgram, XdpProceedOn, XdpProceedOnEntry, XdpProgram,
+    },
+};
+
+/// The version of the [`StateBundle`] schema written by [`export_state`].
+pub const STATE_BUNDLE_VERSION: u32 = 1;
+
+/// The programs and links managed by bpfman on a host.
+///
+/// The IDs in a bundle are the IDs on the host it was exported from. They are
+/// only used to refer to programs from links, link groups and other programs
+/// sharing their maps, and are replaced by new IDs when the bundle is imported.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateBundle {
+    pub version: u32,
+    pub programs: Vec<BundleProgram>,
+    pub links: Vec<BundleLink>,
+    #[serde(default)]
+    pub link_groups: Vec<BundleLinkGroup>,
+}
+
+/// The type of a program in a [`StateBundle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BundleProgramType {
+    Xdp,
+    Tc,
+    Tcx,
+    Tracepoint,
+    Kprobe,
+    Uprobe,
+    Fentry,
+    Fexit,
+}
+
+/// Where the bytecode of a program in a [`StateBundle`] comes from.
+///
+/// The credentials of a bytecode image are not exported, so an image that
+/// needs them must be pulled with `bpfman image pull` before it is imported.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BundleLocation {
+    File { path: String },
+    Image { url: String, pull_policy: String },
+}
+
+/// A program in a [`StateBundle`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleProgram {
+    pub id: u32,
+    #[serde(rename = "type")]
+    pub program_type: BundleProgramType,
+    pub name: String,
+    pub location: BundleLocation,
+    /// The kernel function of fentry and fexit programs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fn_name: Option<String>,
+    /// The global data of the program, hex encoded.
+    #[serde(default)]
+    pub global_data: BTreeMap<String, String>,
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+    /// The ID of the program in the bundle whose maps this program shares.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub map_owner_id: Option<u32>,
+}
+
+/// How a link in a [`StateBundle`] attaches its program, with the same
+/// fields as [`AttachInfo`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum BundleAttach {
+    Xdp {
+        iface: String,
+        priority: i32,
+        proceed_on: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        netns: Option<PathBuf>,
+    },
+    Tc {
+        iface: String,
+        priority: i32,
+        direction: String,
+        proceed_on: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        netns: Option<PathBuf>,
+    },
+    Tcx {
+        iface: String,
+        priority: i32,
+        direction: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        netns: Option<PathBuf>,
+    },
+    Tracepoint {
+        tracepoint: String,
+    },
+    Kprobe {
+        fn_name: String,
+        offset: u64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        container_pid: Option<i32>,
+    },
+    Uprobe {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fn_name: Option<String>,
+        offset: u64,
+        target: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pid: Option<i32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        container_pid: Option<i32>,
+    },
+    Fentry,
+    Fexit,
+}
+
+/// A link in a [`StateBundle`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleLink {
+    pub id: u32,
+    pub program_id: u32,
+    pub attach: BundleAttach,
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+    #[serde(default = "enabled_default")]
+    pub enabled: bool,
+    /// The reattach policy of xdp, tc and tcx links, `never` or `by-name`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reattach_policy: Option<String>,
+    /// The name of the link group the link belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+}
+
+fn enabled_default() -> bool {
+    true
+}
+
+/// A link group in a [`StateBundle`]. Its links are the links of the bundle
+/// with the group's name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleLinkGroup {
+    pub name: String,
+    pub program_id: u32,
+    pub selector: String,
+}
+
+/// The IDs given to the programs and links of a [`StateBundle`] by
+/// [`import_state`], as pairs of the ID in the bundle and the new ID.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportedState {
+    pub programs: Vec<(u32, u32)>,
+    pub links: Vec<(u32, u32)>,
+}
+
+impl StateBundle {
+    /// Parses a bundle written by [`export_state`] as JSON and checks its
+    /// version.
+    pub fn from_json(json: &str) -> Result<Self, BpfmanError> {
+        serde_json::from_str::<StateBundle>(json)
+            .map_err(|e| BpfmanError::InvalidBundle(e.to_string()))?
+            .checked()
+    }
+
+    /// Parses a bundle written by [`export_state`] as YAML, with the same
+    /// schema as JSON, and checks its version.
+    pub fn from_yaml(yaml: &str) -> Result<Self, BpfmanError> {
+        // The YAML is read as a JSON value so that enums are maps, as in JSON,
+        // rather than YAML tags.
+        let value: serde_json::Value =
+            serde_yaml::from_str(yaml).map_err(|e| BpfmanError::InvalidBundle(e.to_string()))?;
+        serde_json::from_value::<StateBundle>(value)
+            .map_err(|e| BpfmanError::InvalidBundle(e.to_string()))?
+            .checked()
+    }
+
+    fn checked(self) -> Result<Self, BpfmanError> {
+        if self.version != STATE_BUNDLE_VERSION {
+            return Err(BpfmanError::InvalidBundle(format!(
+                "unsupported version {}, expected {STATE_BUNDLE_VERSION}",
+                self.version
+            )));
+        }
+        Ok(self)
+    }
+
+    /// Returns the programs in the order they must be loaded, with the
+    /// programs that own maps before the programs that share them.
+    fn load_order(&self) -> Result<Vec<&BundleProgram>, BpfmanError> {
+        let mut ids = HashSet::new();
+        for program in &self.programs {
+            if !ids.insert(program.id) {
+                return Err(BpfmanError::InvalidBundle(format!(
+                    "program {} is in the bundle twice",
+                    program.id
+                )));
+            }
+        }
+        let owners = self
+            .programs
+            .iter()
+            .filter(|p| p.map_owner_id.is_none())
+            .map(|p| p.id)
+            .collect::<HashSet<u32>>();
+        for program in &self.programs {
+            if let Some(owner) = program.map_owner_id
+                && !owners.contains(&owner)
+            {
+                return Err(BpfmanError::InvalidBundle(format!(
+                    "map owner {owner} of program {} is not a program of the bundle that owns its maps",
+                    program.id
+                )));
+            }
+        }
+        let mut programs = self.programs.iter().collect::<Vec<_>>();
+        programs.sort_by_key(|p| (p.map_owner_id.is_some(), p.id));
+        Ok(programs)
+    }
+}
+
+impl BundleProgram {
+    fn from_program(program: &Program) -> Result<Option<Self>, BpfmanError> {
+        let (program_type, fn_name) = match program {
+            Program::Xdp(_) => (BundleProgramType::Xdp, None),
+            Program::Tc(_) => (BundleProgramType::Tc, None),
+            Program::Tcx(_) => (BundleProgramType::Tcx, None),
+            Program::Tracepoint(_) => (BundleProgramType::Tracepoint, None),
+            Program::Kprobe(_) => (BundleProgramType::Kprobe, None),
+            Program::Uprobe(_) => (BundleProgramType::Uprobe, None),
+            Program::Fentry(p) => (BundleProgramType::Fentry, Some(p.get_fn_name()?)),
+            Program::Fexit(p) => (BundleProgramType::Fexit, Some(p.get_fn_name()?)),
+            Program::Unsupported(_) => return Ok(None),
+        };
+        let data = program.get_data();
+        let location = match data.get_location()? {
+            Location::File(path) => BundleLocation::File { path },
+            Location::Image(image) => BundleLocation::Image {
+                url: image.image_url,
+                pull_policy: image.image_pull_policy.to_string(),
+            },
+        };
+        Ok(Some(BundleProgram {
+            id: data.get_id()?,
+            program_type,
+            name: data.get_name()?,
+            location,
+            fn_name,
+            global_data: data
+                .get_global_data()?
+                .into_iter()
+                .map(|(k, v)| (k, hex::encode(v)))
+                .collect(),
+            metadata: data.get_metadata()?.into_iter().collect(),
+            map_owner_id: data.get_map_owner_id()?,
+        }))
+    }
+
+    fn to_program(&self, map_owner_id: Option<u32>) -> Result<Program, BpfmanError> {
+        let location = match &self.location {
+            BundleLocation::File { path } => Location::File(path.clone()),
+            BundleLocation::Image { url, pull_policy } => Location::Image(BytecodeImage {
+                image_url: url.clone(),
+                image_pull_policy: ImagePullPolicy::try_from(pull_policy.as_str())?,
+                username: None,
+                password: None,
+            }),
+        };
+        let global_data = self
+            .global_data
+            .iter()
+            .map(|(k, v)| {
+                hex::decode(v).map(|v| (k.clone(), v)).map_err(|e| {
+                    BpfmanError::InvalidBundle(format!(
+                        "global data {k} of program {} is not hex: {e}",
+                        self.id
+                    ))
+                })
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        let data = ProgramData::new(
+            location,
+            self.name.clone(),
+            self.metadata.clone().into_iter().collect(),
+            global_data,
+            map_owner_id,
+        )?;
+        let fn_name = || {
+            self.fn_name.clone().ok_or_else(|| {
+                BpfmanError::InvalidBundle(format!("program {} has no fn_name", self.id))
+            })
+        };
+        Ok(match self.program_type {
+            BundleProgramType::Xdp => Program::Xdp(XdpProgram::new(data)?),
+            BundleProgramType::Tc => Program::Tc(TcProgram::new(data)?),
+            BundleProgramType::Tcx => Program::Tcx(TcxProgram::new(data)?),
+            BundleProgramType::Tracepoint => Program::Tracepoint(TracepointProgram::new(data)?),
+            BundleProgramType::Kprobe => Program::Kprobe(KprobeProgram::new(data)?),
+            BundleProgramType::Uprobe => Program::Uprobe(UprobeProgram::new(data)?),
+            BundleProgramType::Fentry => Program::Fentry(FentryProgram::new(data, fn_name()?)?),
+            BundleProgramType::Fexit => Program::Fexit(FexitProgram::new(data, fn_name()?)?),
+        })
+    }
+}
+
+impl BundleLink {
+    fn from_link(link: &Link) -> Result<Self, BpfmanError> {
+        let attach = match link.get_attach_info()? {
+            AttachInfo::Xdp {
+                priority,
+                iface,
+                proceed_on,
+                netns,
+                ..
+            } => BundleAttach::Xdp {
+                iface,
+                priority,
+                proceed_on: proceed_on
+                    .as_action_vec()
+                    .into_iter()
+                    .map(|a| XdpProceedOnEntry::try_from(a).map(|e| e.to_string()))
+                    .collect::<Result<_, _>>()?,
+                netns,
+            },
+            AttachInfo::Tc {
+                priority,
+                iface,
+                direction,
+                proceed_on,
+                netns,
+                ..
+            } => BundleAttach::Tc {
+                iface,
+                priority,
+                direction,
+                proceed_on: proceed_on
+                    .as_action_vec()
+                    .into_iter()
+                    .map(|a| TcProceedOnEntry::try_from(a).map(|e| e.to_string()))
+                    .collect::<Result<_, _>>()?,
+                netns,
+            },
+            AttachInfo::Tcx {
+                priority,
+                iface,
+                direction,
+                netns,
+                ..
+            } => BundleAttach::Tcx {
+                iface,
+                priority,
+                direction,
+                netns,
+            },
+            AttachInfo::Tracepoint { tracepoint, .. } => BundleAttach::Tracepoint { tracepoint },
+            AttachInfo::Kprobe {
+                fn_name,
+                offset,
+                container_pid,
+                ..
+            } => BundleAttach::Kprobe {
+                fn_name,
+                offset,
+                container_pid,
+            },
+            AttachInfo::Uprobe {
+                fn_name,
+                offset,
+                target,
+                pid,
+                container_pid,
+                ..
+            } => BundleAttach::Uprobe {
+                fn_name,
+                offset,
+                target,
+                pid,
+                container_pid,
+            },
+            AttachInfo::Fentry { .. } => BundleAttach::Fentry,
+            AttachInfo::Fexit { .. } => BundleAttach::Fexit,
+        };
+        let reattach_policy = match link {
+            Link::Xdp(_) | Link::Tc(_) | Link::Tcx(_) => {
+                Some(link.get_reattach_policy()?.to_string())
+            }
+            _ => None,
+        };
+        Ok(BundleLink {
+            id: link.get_id()?,
+            program_id: link.get_program_id()?,
+            attach,
+            metadata: link.get_metadata()?.into_iter().collect(),
+            enabled: link.get_enabled()?,
+            reattach_policy,
+            group: link.get_group()?,
+        })
+    }
+
+    fn to_attach_info(&self) -> Result<AttachInfo, BpfmanError> {
+        let metadata = self.metadata.clone().into_iter().collect();
+        Ok(match self.attach.clone() {
+            BundleAttach::Xdp {
+                iface,
+                priority,
+                proceed_on,
+                netns,
+            } => AttachInfo::Xdp {
+                priority,
+                iface,
+                proceed_on: XdpProceedOn::from_strings(proceed_on)?,
+                netns,
+                metadata,
+            },
+            BundleAttach::Tc {
+                iface,
+                priority,
+                direction,
+                proceed_on,
+                netns,
+            } => AttachInfo::Tc {
+                priority,
+                iface,
+                direction,
+                proceed_on: TcProceedOn::from_strings(proceed_on)?,
+                netns,
+                metadata,
+            },
+            BundleAttach::Tcx {
+                iface,
+                priority,
+                direction,
+                netns,
+            } => AttachInfo::Tcx {
+                priority,
+                iface,
+                direction,
+                netns,
+                metadata,
+            },
+            BundleAttach::Tracepoint { tracepoint } => AttachInfo::Tracepoint {
+                tracepoint,
+                metadata,
+            },
+            BundleAttach::Kprobe {
+                fn_name,
+                offset,
+                container_pid,
+            } => AttachInfo::Kprobe {
+                fn_name,
+                offset,
+                container_pid,
+                metadata,
+            },
+            BundleAttach::Uprobe {
+                fn_name,
+                offset,
+                target,
+                pid,
+                container_pid,
+            } => AttachInfo::Uprobe {
+                fn_name,
+                offset,
+                target,
+                pid,
+                container_pid,
+                metadata,
+            },
+            BundleAttach::Fentry => AttachInfo::Fentry { metadata },
+            BundleAttach::Fexit => AttachInfo::Fexit { metadata },
+        })
+    }
+}
+
+/// Returns the programs loaded by bpfman and their links, sorted by ID.
+/// Programs that bpfman didn't load are left out.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use bpfman::{bundle::export_state, setup};
+///
+/// let (_, root_db) = setup().unwrap();
+/// let bundle = export_state(&root_db).unwrap();
+/// println!("{}", serde_json::to_string_pretty(&bundle).unwrap());
+/// ```
+pub fn export_state(root_db: &Db) -> Result<StateBundle, BpfmanError> {
+    let mut programs = vec![];
+    for program in list_programs(root_db, ListFilter::new(None, HashMap::new(), true))? {
+        if let Some(program) = BundleProgram::from_program(&program)? {
+            programs.push(program);
+        }
+    }
+    programs.sort_by_key(|p| p.id);
+
+    let mut links = vec![];
+    let mut groups = BTreeMap::new();
+    for link in list_links(
+        root_db,
+        LinkListFilter::new(None, None, None, HashMap::new()),
+    )? {
+        let link = BundleLink::from_link(&link)?;
+        if let Some(name) = &link.group
+            && !groups.contains_key(name)
+        {
+            let group = LinkGroup::open(root_db, name)?;
+            groups.insert(
+                name.clone(),
+                BundleLinkGroup {
+                    name: name.clone(),
+                    program_id: group.get_program_id()?,
+                    selector: group.get_selector()?.to_string(),
+                },
+            );
+        }
+        links.push(link);
+    }
+
+    Ok(StateBundle {
+        version: STATE_BUNDLE_VERSION,
+        programs,
+        links,
+        link_groups: groups.into_values().collect(),
+    })
+}
+
+/// Loads the programs of a [`StateBundle`] and attaches their links. The
+/// programs and links get new IDs, and programs that shared the maps of
+/// another program in the bundle share the maps of its new copy.
+///
+/// Links that were disabled are attached and then disabled, and links that
+/// belonged to a link group are added to a new link group with the same name
+/// and selector. The interfaces the selector matches are not attached again.
+///
+/// If a program can't be loaded or a link can't be attached, the programs
+/// that were already imported are unloaded.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use bpfman::{bundle::{StateBundle, import_state}, setup};
+///
+/// let (config, root_db) = setup().unwrap();
+/// let bundle = StateBundle::from_json(&std::fs::read_to_string("state.json").unwrap()).unwrap();
+/// let imported = import_state(&config, &root_db, &bundle).unwrap();
+/// for (old, new) in imported.programs {
+///     println!("program {old} is now {new}");
+/// }
+/// ```
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The bundle refers to programs that are not in it.
+/// * A link group of the bundle already exists.
+/// * A program fails to load or a link fails to attach.
+pub fn import_state(
+    config: &Config,
+    root_db: &Db,
+    bundle: &StateBundle,
+) -> Result<ImportedState, BpfmanError> {
+    info!(
+        "Request to import {} programs and {} links",
+        bundle.programs.len(),
+        bundle.links.len()
+    );
+    let programs = bundle.load_order()?;
+    let ids = programs.iter().map(|p| p.id).collect::<HashSet<u32>>();
+    for link in &bundle.links {
+        if !ids.contains(&link.program_id) {
+            return Err(BpfmanError::InvalidBundle(format!(
+                "program {} of link {} is not in the bundle",
+                link.program_id, link.id
+            )));
+        }
+        if let Some(group) = &link.group
+            && !bundle.link_groups.iter().any(|g| &g.name == group)
+        {
+            return Err(BpfmanError::InvalidBundle(format!(
+                "link group {group} of link {} is not in the bundle",
+                link.id
+            )));
+        }
+    }
+    for group in &bundle.link_groups {
+        if !ids.contains(&group.program_id) {
+            return Err(BpfmanError::InvalidBundle(format!(
+                "program {} of link group {} is not in the bundle",
+                group.program_id, group.name
+            )));
+        }
+        if LinkGroup::open(root_db, &group.name).is_ok() {
+            return Err(BpfmanError::LinkGroupExists(group.name.clone()));
+        }
+    }
+
+    let mut imported = ImportedState::default();
+    let result = import_programs(config, root_db, bundle, &programs, &mut imported);
+    if let Err(e) = result {
+        warn!("Unable to import the bundle, unloading the imported programs: {e}");
+        for (_, id) in imported.programs.iter().rev() {
+            if let Err(e) = remove_program(config, root_db, *id) {
+                warn!("Unable to unload imported program {id}: {e}");
+            }
+        }
+        for group in &bundle.link_groups {
+            if let Ok(group) = LinkGroup::open(root_db, &group.name) {
+                let _ = group.delete(root_db);
+            }
+        }
+        return Err(e);
+    }
+
+    info!(
+        "Success: imported {} programs and {} links",
+        imported.programs.len(),
+        imported.links.len()
+    );
+    Ok(imported)
+}
+
+fn import_programs(
+    config: &Config,
+    root_db: &Db,
+    bundle: &StateBundle,
+    programs: &[&BundleProgram],
+    imported: &mut ImportedState,
+) -> Result<(), BpfmanError> {
+    let mut new_ids = HashMap::new();
+    for program in programs {
+        let map_owner_id = program.map_owner_id.map(|id| new_ids[&id]);
+        let loaded = add_programs(config, root_db, vec![program.to_program(map_owner_id)?])?;
+        let id = loaded[0].get_data().get_id()?;
+        new_ids.insert(program.id, id);
+        imported.programs.push((program.id, id));
+    }
+
+    let mut groups = HashMap::new();
+    for group in &bundle.link_groups {
+        let selector = group.selector.clone().try_into()?;
+        let link_group =
+            LinkGroup::new(root_db, &group.name, new_ids[&group.program_id], &selector)?;
+        groups.insert(group.name.clone(), link_group);
+    }
+
+    let mut links = bundle.links.iter().collect::<Vec<_>>();
+    links.sort_by_key(|l| l.id);
+    for link in links {
+        let info = link.to_attach_info()?;
+        let iface_netns = match &info {
+            AttachInfo::Xdp { iface, netns, .. }
+            | AttachInfo::Tc { iface, netns, .. }
+            | AttachInfo::Tcx { iface, netns, .. } => Some((iface.clone(), netns.clone())),
+            _ => None,
+        };
+        let mut new_link = attach_program(config, root_db, new_ids[&link.program_id], info)?;
+        let id = new_link.get_id()?;
+        imported.links.push((link.id, id));
+
+        if let Some(policy) = &link.reattach_policy {
+            let policy = ReattachPolicy::try_from(policy.clone())?;
+            if policy != ReattachPolicy::Never {
+                set_link_reattach_policy(root_db, id, policy)?;
+            }
+        }
+        if let Some(name) = &link.group {
+            let (iface, netns) = iface_netns.ok_or_else(|| {
+                BpfmanError::InvalidBundle(format!(
+                    "link {} of link group {name} is not attached to an interface",
+                    link.id
+                ))
+            })?;
+            new_link.set_group(name)?;
+            groups[name].add_member(&LinkGroupMember {
+                iface,
+                netns,
+                link_id: Some(id),
+                error: None,
+            })?;
+        }
+        if !link.enabled {
+            disable_link(config, root_db, id)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUNDLE: &str = r#"{
+        "version": 1,
+        "programs": [
+            {
+                "id": 20,
+                "type": "tc",
+                "name": "stats",
+                "location": {"file": {"path": "/run/bpfman/examples/go-tc-counter/bpf_x86_bpfel.o"}},
+                "map_owner_id": 10
+            },
+            {
+                "id": 10,
+                "type": "xdp",
+                "name": "pass",
+                "location": {"image": {"url": "quay.io/bpfman-bytecode/xdp_pass:latest", "pull_policy": "IfNotPresent"}},
+                "global_data": {"GLOBAL_u8": "01"},
+                "metadata": {"owner": "e2e"}
+            }
+        ],
+        "links": [
+            {
+                "id": 30,
+                "program_id": 10,
+                "attach": {"type": "xdp", "iface": "eth0", "priority": 50, "proceed_on": ["pass", "dispatcher_return"]},
+                "group": "edge"
+            },
+            {
+                "id": 31,
+                "program_id": 20,
+                "attach": {"type": "tc", "iface": "eth0", "priority": 100, "direction": "ingress", "proceed_on": []},
+                "enabled": false
+            }
+        ],
+        "link_groups": [{"name": "edge", "program_id": 10, "selector": "eth*"}]
+    }"#;
+
+    #[test]
+    fn test_from_json() {
+        let bundle = StateBundle::from_json(BUNDLE).unwrap();
+        assert_eq!(bundle.programs.len(), 2);
+        assert_eq!(bundle.programs[0].program_type, BundleProgramType::Tc);
+        assert!(bundle.links[0].enabled);
+        assert!(!bundle.links[1].enabled);
+
+        let json = serde_json::to_string(&bundle).unwrap();
+        assert_eq!(StateBundle::from_json(&json).unwrap(), bundle);
+
+        let newer = BUNDLE.replacen("\"version\": 1", "\"version\": 2", 1);
+        assert!(matches!(
+            StateBundle::from_json(&newer),
+            Err(BpfmanError::InvalidBundle(_))
+        ));
+    }
+
+    #[test]
+    fn test_load_order() {
+        let mut bundle = StateBundle::from_json(BUNDLE).unwrap();
+        let order = bundle
+            .load_order()
+            .unwrap()
+            .iter()
+            .map(|p| p.id)
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec![10, 20]);
+
+        bundle.programs[1].map_owner_id = Some(20);
+        assert!(bundle.load_order().is_err());
+
+        bundle.programs[1] = bundle.programs[0].clone();
+        assert!(bundle.load_order().is_err());
+    }
+
+    #[test]
+    fn test_attach_info() {
+        let bundle = StateBundle::from_json(BUNDLE).unwrap();
+        match bundle.links[0].to_attach_info().unwrap() {
+            AttachInfo::Xdp {
+                iface, proceed_on, ..
+            } => {
+                assert_eq!(iface, "eth0");
+                assert_eq!(proceed_on.to_string(), "pass, dispatcher_return");
+            }
+            info => panic!("unexpected attach info {info:?}"),
+        }
+        let program = bundle.programs[1].to_program(None).unwrap();
+        assert_eq!(
+            program.get_data().get_global_data().unwrap()["GLOBAL_u8"],
+            vec![1]
+        );
+    }
+}
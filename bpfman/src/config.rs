@@ -1,12 +1,12 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright Authors of bpfman
 
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, net::SocketAddr, path::PathBuf, str::FromStr};
 
 use aya::programs::XdpFlags;
 use serde::{Deserialize, Serialize};
 
-use crate::errors::ParseError;
+use crate::errors::{BpfmanError, ParseError};
 
 #[derive(Debug, Default, Deserialize, Clone)]
 pub struct Config {
@@ -15,6 +15,10 @@ pub struct Config {
     signing: SigningConfig,
     #[serde(default)]
     database: DatabaseConfig,
+    remote: Option<RemoteConfig>,
+    authorization: Option<AuthorizationConfig>,
+    #[serde(default)]
+    policy: PolicyConfig,
 }
 
 impl Config {
@@ -26,9 +30,21 @@ impl Config {
         &self.signing
     }
 
-    pub(crate) fn database(&self) -> &DatabaseConfig {
+    pub fn database(&self) -> &DatabaseConfig {
         &self.database
     }
+
+    pub fn remote(&self) -> Option<&RemoteConfig> {
+        self.remote.as_ref()
+    }
+
+    pub fn authorization(&self) -> Option<&AuthorizationConfig> {
+        self.authorization.as_ref()
+    }
+
+    pub fn policy(&self) -> &PolicyConfig {
+        &self.policy
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -54,6 +70,7 @@ impl Default for SigningConfig {
 pub struct DatabaseConfig {
     pub max_retries: u32,
     pub millisec_delay: u64,
+    pub millisec_idle: u64,
 }
 
 impl Default for DatabaseConfig {
@@ -63,10 +80,173 @@ impl Default for DatabaseConfig {
             max_retries: 10,
             // Number of milli-seconds to wait between failed database attempts
             millisec_delay: 1000,
+            // Number of milli-seconds bpfman-rpc keeps the database open after the last request
+            millisec_idle: 500,
+        }
+    }
+}
+
+/// RemoteConfig enables a TCP listener in bpfman-rpc that serves the same API
+/// as the unix socket to clients authenticated with mutual TLS.
+#[derive(Debug, Deserialize, Clone)]
+pub struct RemoteConfig {
+    pub address: SocketAddr,  // Address and port to listen on
+    pub server_cert: PathBuf, // PEM encoded server certificate
+    pub server_key: PathBuf,  // PEM encoded server private key
+    pub client_ca: PathBuf,   // PEM encoded CA that client certificates must be signed by
+}
+
+/// PolicyConfig is the admission policy that programs must meet before they
+/// are loaded or attached. Empty lists and unset fields do not restrict
+/// anything.
+#[derive(Debug, Default, Deserialize, Clone)]
+#[serde(default)]
+pub struct PolicyConfig {
+    pub allowed_registries: Vec<String>, // Registries that images may be pulled from
+    pub interfaces: HashMap<String, InterfacePolicy>, // Program types allowed per interface
+    pub denied_kprobe_functions: Vec<String>, // Kernel functions kprobes may not attach to
+    pub max_programs_per_application: Option<usize>, // Per "bpfman_application" metadata
+    pub directory: Option<PathBuf>,      // Directory of additional *.toml policy files
+}
+
+#[derive(Debug, Default, Deserialize, Clone)]
+#[serde(default)]
+pub struct InterfacePolicy {
+    pub program_types: Vec<String>,
+}
+
+/// AuthorizationConfig limits what callers of the bpfman-rpc unix socket may
+/// do, based on the UID and GIDs of the calling process. A caller is allowed
+/// an operation if any rule that applies to it allows the operation.
+#[derive(Debug, Default, Deserialize, Clone)]
+#[serde(default)]
+pub struct AuthorizationConfig {
+    pub rules: Vec<AuthorizationRule>,
+}
+
+/// AuthorizationRule applies to callers with one of its UIDs or GIDs, or to
+/// every caller if it has neither. Empty program types and metadata namespaces
+/// leave the programs the rule covers unrestricted.
+#[derive(Debug, Default, Deserialize, Clone)]
+#[serde(default)]
+pub struct AuthorizationRule {
+    pub uids: Vec<u32>,                   // Callers the rule applies to
+    pub gids: Vec<u32>,                   // Groups the rule applies to
+    pub operations: Vec<Operation>,       // Operations the rule allows
+    pub program_types: Vec<String>,       // Program types the rule covers
+    pub metadata_namespaces: Vec<String>, // Programs need a metadata key in one of these
+    pub owner_only: bool,                 // Programs need "owner" metadata of the caller's UID
+}
+
+/// The operations that an [`AuthorizationRule`] can allow.
+#[derive(Debug, Deserialize, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Operation {
+    Load,
+    Attach,
+    Detach,
+    Unload,
+    List,
+}
+
+impl std::fmt::Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operation::Load => write!(f, "load"),
+            Operation::Attach => write!(f, "attach"),
+            Operation::Detach => write!(f, "detach"),
+            Operation::Unload => write!(f, "unload"),
+            Operation::List => write!(f, "list"),
         }
     }
 }
 
+/// The identity of a process calling bpfman-rpc, as read from its socket.
+#[derive(Debug, Clone)]
+pub struct Caller {
+    pub uid: u32,
+    pub gids: Vec<u32>,
+    pub pid: Option<i32>,
+}
+
+/// The program that an operation acts on.
+#[derive(Debug)]
+pub struct Target<'a> {
+    pub program_type: &'a str,
+    pub metadata: &'a HashMap<String, String>,
+}
+
+/// The metadata key that records the UID of the caller that loaded a program.
+pub const METADATA_OWNER_TAG: &str = "owner";
+
+impl AuthorizationConfig {
+    /// Checks whether `caller` may perform `operation` on `target`. Without a
+    /// target, for example when pulling an image, only the operation is
+    /// checked. Callers running as root are always allowed.
+    pub fn authorize(
+        &self,
+        caller: &Caller,
+        operation: Operation,
+        target: Option<&Target>,
+    ) -> Result<(), BpfmanError> {
+        if caller.uid == 0
+            || self
+                .rules
+                .iter()
+                .any(|rule| rule.allows(caller, operation, target))
+        {
+            return Ok(());
+        }
+
+        Err(BpfmanError::PermissionDenied(match target {
+            Some(target) => format!(
+                "uid {} is not allowed to {operation} this {} program",
+                caller.uid, target.program_type
+            ),
+            None => format!("uid {} is not allowed to {operation}", caller.uid),
+        }))
+    }
+}
+
+impl AuthorizationRule {
+    fn applies_to(&self, caller: &Caller) -> bool {
+        (self.uids.is_empty() && self.gids.is_empty())
+            || self.uids.contains(&caller.uid)
+            || caller.gids.iter().any(|gid| self.gids.contains(gid))
+    }
+
+    fn allows(&self, caller: &Caller, operation: Operation, target: Option<&Target>) -> bool {
+        if !self.applies_to(caller) || !self.operations.contains(&operation) {
+            return false;
+        }
+        let Some(target) = target else {
+            return true;
+        };
+
+        if !self.program_types.is_empty()
+            && !self
+                .program_types
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(target.program_type))
+        {
+            return false;
+        }
+
+        // Metadata keys are namespaced like Kubernetes labels, as in
+        // "example.com/app", and the namespace is everything before the "/".
+        if !self.metadata_namespaces.is_empty()
+            && !target.metadata.keys().any(|key| {
+                key.split_once('/')
+                    .is_some_and(|(ns, _)| self.metadata_namespaces.iter().any(|n| n == ns))
+            })
+        {
+            return false;
+        }
+
+        !self.owner_only || target.metadata.get(METADATA_OWNER_TAG) == Some(&caller.uid.to_string())
+    }
+}
+
 impl FromStr for Config {
     type Err = ParseError;
 
@@ -180,4 +360,103 @@ mod test {
             None => panic!("expected interfaces to be present"),
         }
     }
+
+    #[test]
+    fn test_config_remote() {
+        let input = r#"
+        [remote]
+        address = "0.0.0.0:50051"
+        server_cert = "/etc/bpfman/tls/server.crt"
+        server_key = "/etc/bpfman/tls/server.key"
+        client_ca = "/etc/bpfman/tls/ca.crt"
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let remote = config.remote().expect("expected remote to be present");
+        assert_eq!(remote.address, "0.0.0.0:50051".parse().unwrap());
+        assert_eq!(remote.client_ca, PathBuf::from("/etc/bpfman/tls/ca.crt"));
+        assert!(Config::default().remote().is_none());
+    }
+
+    #[test]
+    fn test_config_authorization() {
+        let input = r#"
+        [[authorization.rules]]
+        operations = ["list"]
+
+        [[authorization.rules]]
+        gids = [1001]
+        operations = ["load", "attach", "detach", "unload"]
+        program_types = ["xdp", "tc"]
+        metadata_namespaces = ["team-a.example.com"]
+        owner_only = true
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let authz = config
+            .authorization()
+            .expect("expected authorization to be present");
+
+        let caller = Caller {
+            uid: 1000,
+            gids: vec![1000, 1001],
+            pid: None,
+        };
+        let other = Caller {
+            uid: 1002,
+            gids: vec![1002],
+            pid: None,
+        };
+        let metadata = HashMap::from([
+            ("team-a.example.com/app".to_string(), "demo".to_string()),
+            (METADATA_OWNER_TAG.to_string(), "1000".to_string()),
+        ]);
+        let xdp = Target {
+            program_type: "xdp",
+            metadata: &metadata,
+        };
+        let kprobe = Target {
+            program_type: "kprobe",
+            metadata: &metadata,
+        };
+        let unowned = HashMap::from([("team-a.example.com/app".to_string(), "demo".to_string())]);
+        let unowned = Target {
+            program_type: "xdp",
+            metadata: &unowned,
+        };
+
+        assert!(authz.authorize(&other, Operation::List, Some(&xdp)).is_ok());
+        assert!(
+            authz
+                .authorize(&caller, Operation::Unload, Some(&xdp))
+                .is_ok()
+        );
+        assert!(authz.authorize(&caller, Operation::Load, None).is_ok());
+        assert!(
+            authz
+                .authorize(&caller, Operation::Unload, Some(&kprobe))
+                .is_err()
+        );
+        assert!(
+            authz
+                .authorize(&caller, Operation::Unload, Some(&unowned))
+                .is_err()
+        );
+        assert!(
+            authz
+                .authorize(&other, Operation::Unload, Some(&xdp))
+                .is_err()
+        );
+        assert!(
+            authz
+                .authorize(
+                    &Caller {
+                        uid: 0,
+                        gids: vec![0],
+                        pid: None
+                    },
+                    Operation::Unload,
+                    Some(&kprobe)
+                )
+                .is_ok()
+        );
+    }
 }
@@ -0,0 +1,496 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+//! Diagnostics of the host that bpfman runs on. The checks probe the kernel
+//! features that each program and link type needs, so that a node that can't
+//! run bpfman is found before the first program is loaded on it.
+
+use std::{fmt, fs, path::Path};
+
+use aya::{features, programs::loaded_programs, util::KernelVersion};
+use caps::{CapSet, Capability};
+
+use crate::{
+    directories::{RTDIR, RTDIR_FS},
+    multiprog::{TcDispatcher, XDP_DISPATCHER_PROGRAM_NAME, XdpDispatcher},
+    netlink::NetlinkManager,
+    utils::{TRACEFS_EVENTS, has_cap, initialize_bpfman, interfaces, is_bpffs_mounted},
+};
+
+const BTF_VMLINUX: &str = "/sys/kernel/btf/vmlinux";
+const LOCKDOWN: &str = "/sys/kernel/security/lockdown";
+const KPROBE_PMU: &str = "/sys/bus/event_source/devices/kprobe";
+const UPROBE_PMU: &str = "/sys/bus/event_source/devices/uprobe";
+
+/// The outcome of a check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CheckStatus {
+    Pass,
+    /// bpfman works, but some programs or features may not.
+    Warn,
+    /// bpfman, or the programs or links of the check, will not work.
+    Fail,
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CheckStatus::Pass => write!(f, "pass"),
+            CheckStatus::Warn => write!(f, "warn"),
+            CheckStatus::Fail => write!(f, "fail"),
+        }
+    }
+}
+
+/// What a check is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckCategory {
+    /// The privileges, file systems and kernel configuration bpfman needs.
+    Environment,
+    /// The kernel support for a program type.
+    Program,
+    /// The kernel support for a link type.
+    Link,
+    /// The programs that are already attached to the network interfaces.
+    Interface,
+}
+
+impl fmt::Display for CheckCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CheckCategory::Environment => write!(f, "environment"),
+            CheckCategory::Program => write!(f, "program"),
+            CheckCategory::Link => write!(f, "link"),
+            CheckCategory::Interface => write!(f, "interface"),
+        }
+    }
+}
+
+/// The result of a single check.
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub category: CheckCategory,
+    pub name: String,
+    pub status: CheckStatus,
+    pub message: String,
+}
+
+impl Check {
+    fn new(category: CheckCategory, name: &str, status: CheckStatus, message: String) -> Self {
+        Check {
+            category,
+            name: name.to_string(),
+            status,
+            message,
+        }
+    }
+}
+
+/// The results of all the checks of [`diagnose`].
+#[derive(Debug, Clone, Default)]
+pub struct DoctorReport {
+    pub checks: Vec<Check>,
+}
+
+impl DoctorReport {
+    /// Returns the worst status of the checks.
+    pub fn status(&self) -> CheckStatus {
+        self.checks
+            .iter()
+            .map(|c| c.status)
+            .max()
+            .unwrap_or(CheckStatus::Pass)
+    }
+
+    /// Returns the number of checks with the given status.
+    pub fn count(&self, status: CheckStatus) -> usize {
+        self.checks.iter().filter(|c| c.status == status).count()
+    }
+
+    fn add(&mut self, category: CheckCategory, name: &str, status: CheckStatus, message: String) {
+        self.checks
+            .push(Check::new(category, name, status, message));
+    }
+}
+
+/// Checks that the host can run bpfman and probes the kernel features that
+/// each program and link type needs.
+///
+/// Like [`crate::setup`], this creates the bpfman runtime directories and
+/// mounts the bpffs if it is not mounted yet. The checks that need these are
+/// reported as failed if that is not possible, for example because the
+/// caller is not privileged.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use bpfman::doctor::{CheckStatus, diagnose};
+///
+/// let report = diagnose();
+/// for check in report.checks.iter().filter(|c| c.status != CheckStatus::Pass) {
+///     println!("{} {}: {}", check.status, check.name, check.message);
+/// }
+/// ```
+pub fn diagnose() -> DoctorReport {
+    let mut report = DoctorReport::default();
+    let kernel = KernelVersion::current().ok();
+
+    check_kernel(&mut report, kernel);
+    check_capabilities(&mut report);
+    let initialized = check_initialization(&mut report);
+    let btf = check_btf(&mut report);
+    check_lockdown(&mut report);
+    check_global_data(&mut report);
+
+    check_dispatchers(&mut report, initialized);
+    check_tcx(&mut report, kernel);
+    check_probes(&mut report);
+    check_tracing(&mut report, kernel, btf);
+    check_links(&mut report, kernel);
+
+    check_interfaces(&mut report);
+
+    report
+}
+
+// Returns whether the running kernel is at least the given version. An
+// unknown kernel version is assumed to be recent enough.
+fn kernel_at_least(kernel: Option<KernelVersion>, major: u8, minor: u8) -> bool {
+    kernel.is_none_or(|k| k >= KernelVersion::new(major, minor, 0))
+}
+
+fn check_kernel(report: &mut DoctorReport, kernel: Option<KernelVersion>) {
+    let (status, message) = match kernel {
+        Some(k) => (CheckStatus::Pass, format!("Linux {k}")),
+        None => (
+            CheckStatus::Warn,
+            "unable to determine the kernel version, the checks that depend on it are skipped"
+                .to_string(),
+        ),
+    };
+    report.add(CheckCategory::Environment, "kernel", status, message);
+}
+
+fn check_capabilities(report: &mut DoctorReport) {
+    let missing = |caps: &[Capability]| -> Vec<String> {
+        caps.iter()
+            .filter(|c| !has_cap(CapSet::Effective, **c))
+            .map(|c| c.to_string())
+            .collect()
+    };
+
+    let required = missing(&[Capability::CAP_BPF, Capability::CAP_SYS_ADMIN]);
+    let optional = missing(&[Capability::CAP_NET_ADMIN, Capability::CAP_PERFMON]);
+    let (status, message) = if !required.is_empty() {
+        (
+            CheckStatus::Fail,
+            format!("missing {}", [required, optional].concat().join(", ")),
+        )
+    } else if !optional.is_empty() {
+        (
+            CheckStatus::Warn,
+            format!(
+                "missing {}, attaching xdp, tc, tcx and probe programs may fail",
+                optional.join(", ")
+            ),
+        )
+    } else {
+        (
+            CheckStatus::Pass,
+            "CAP_BPF, CAP_SYS_ADMIN, CAP_NET_ADMIN and CAP_PERFMON".to_string(),
+        )
+    };
+    report.add(CheckCategory::Environment, "capabilities", status, message);
+}
+
+// Initializes bpfman like `setup` does, which also mounts the bpffs if needed,
+// and returns whether it succeeded.
+fn check_initialization(report: &mut DoctorReport) -> bool {
+    let was_mounted = is_bpffs_mounted().unwrap_or(false);
+    let initialized = initialize_bpfman();
+
+    let (status, message) = match &initialized {
+        Ok(()) => (
+            CheckStatus::Pass,
+            format!("runtime directories created in {RTDIR}"),
+        ),
+        Err(e) => (CheckStatus::Fail, format!("{e:#}")),
+    };
+    report.add(
+        CheckCategory::Environment,
+        "initialization",
+        status,
+        message,
+    );
+
+    let (status, message) = if was_mounted {
+        (CheckStatus::Pass, format!("mounted at {RTDIR_FS}"))
+    } else if is_bpffs_mounted().unwrap_or(false) {
+        (
+            CheckStatus::Warn,
+            format!("was not mounted, bpfman mounted it at {RTDIR_FS}"),
+        )
+    } else {
+        (CheckStatus::Fail, format!("not mounted at {RTDIR_FS}"))
+    };
+    report.add(CheckCategory::Environment, "bpffs", status, message);
+
+    initialized.is_ok()
+}
+
+// Returns whether the kernel BTF is available.
+fn check_btf(report: &mut DoctorReport) -> bool {
+    let (status, message) = if !Path::new(BTF_VMLINUX).exists() {
+        (
+            CheckStatus::Warn,
+            format!("{BTF_VMLINUX} not found, programs that use CO-RE or BTF can't be loaded"),
+        )
+    } else if features().btf().is_none() {
+        (
+            CheckStatus::Warn,
+            "the kernel does not accept BTF with programs".to_string(),
+        )
+    } else {
+        (CheckStatus::Pass, format!("available at {BTF_VMLINUX}"))
+    };
+    report.add(CheckCategory::Environment, "btf", status, message);
+    status == CheckStatus::Pass
+}
+
+// Returns the active lockdown mode, which is the one in brackets, for example
+// "integrity" for "none [integrity] confidentiality".
+fn lockdown_mode(lockdown: &str) -> Option<&str> {
+    lockdown
+        .split_whitespace()
+        .find_map(|m| m.strip_prefix('[')?.strip_suffix(']'))
+}
+
+fn check_lockdown(report: &mut DoctorReport) {
+    let lockdown = fs::read_to_string(LOCKDOWN).ok();
+    let mode = lockdown.as_deref().and_then(lockdown_mode);
+    let (status, message) = match mode {
+        None => (
+            CheckStatus::Pass,
+            "lockdown is not enabled in the kernel".to_string(),
+        ),
+        Some("none") => (CheckStatus::Pass, "none".to_string()),
+        Some("integrity") => (
+            CheckStatus::Warn,
+            "integrity, programs that write to user memory can't be loaded".to_string(),
+        ),
+        Some(m) => (
+            CheckStatus::Fail,
+            format!("{m}, programs that read kernel memory can't be loaded"),
+        ),
+    };
+    report.add(CheckCategory::Environment, "lockdown", status, message);
+}
+
+fn check_global_data(report: &mut DoctorReport) {
+    let (status, message) = if features().bpf_global_data() {
+        (CheckStatus::Pass, "supported".to_string())
+    } else {
+        (
+            CheckStatus::Fail,
+            "not supported, the xdp and tc dispatchers and global variables can't be used"
+                .to_string(),
+        )
+    };
+    report.add(CheckCategory::Environment, "global data", status, message);
+}
+
+fn check_dispatchers(report: &mut DoctorReport, initialized: bool) {
+    if !initialized {
+        for name in ["xdp", "tc"] {
+            report.add(
+                CheckCategory::Program,
+                name,
+                CheckStatus::Fail,
+                "skipped, bpfman could not be initialized".to_string(),
+            );
+        }
+        return;
+    }
+
+    for (name, result) in [
+        ("xdp", XdpDispatcher::get_test().map(|_| ())),
+        ("tc", TcDispatcher::get_test().map(|_| ())),
+    ] {
+        let (status, message) = match result {
+            Ok(()) => (CheckStatus::Pass, "dispatcher loaded".to_string()),
+            Err(e) => (CheckStatus::Fail, format!("unable to load dispatcher: {e}")),
+        };
+        report.add(CheckCategory::Program, name, status, message);
+    }
+}
+
+fn check_tcx(report: &mut DoctorReport, kernel: Option<KernelVersion>) {
+    let (status, message) = if kernel_at_least(kernel, 6, 6) {
+        (CheckStatus::Pass, "supported".to_string())
+    } else {
+        (CheckStatus::Fail, "requires Linux 6.6".to_string())
+    };
+    report.add(CheckCategory::Program, "tcx", status, message);
+}
+
+fn check_probes(report: &mut DoctorReport) {
+    let tracefs = TRACEFS_EVENTS.iter().find(|p| Path::new(p).exists());
+
+    let (status, message) = match tracefs {
+        Some(p) => (CheckStatus::Pass, format!("events found in {p}")),
+        None => (
+            CheckStatus::Fail,
+            "tracefs is not mounted, tracepoints can't be found".to_string(),
+        ),
+    };
+    report.add(CheckCategory::Program, "tracepoint", status, message);
+
+    for (name, pmu) in [("kprobe", KPROBE_PMU), ("uprobe", UPROBE_PMU)] {
+        let (status, message) = if Path::new(pmu).exists() {
+            (CheckStatus::Pass, format!("{name} perf events supported"))
+        } else if tracefs.is_some() {
+            (
+                CheckStatus::Warn,
+                format!("{pmu} not found, {name}s are created with tracefs"),
+            )
+        } else {
+            (
+                CheckStatus::Fail,
+                format!("{pmu} not found and tracefs is not mounted"),
+            )
+        };
+        report.add(CheckCategory::Program, name, status, message);
+    }
+}
+
+fn check_tracing(report: &mut DoctorReport, kernel: Option<KernelVersion>, btf: bool) {
+    let (status, message) = if !kernel_at_least(kernel, 5, 5) {
+        (CheckStatus::Fail, "requires Linux 5.5".to_string())
+    } else if !btf {
+        (CheckStatus::Fail, "requires the kernel BTF".to_string())
+    } else {
+        (CheckStatus::Pass, "supported".to_string())
+    };
+    for name in ["fentry", "fexit"] {
+        report.add(CheckCategory::Program, name, status, message.clone());
+    }
+}
+
+fn check_links(report: &mut DoctorReport, kernel: Option<KernelVersion>) {
+    let (status, message) = if features().bpf_perf_link() {
+        (CheckStatus::Pass, "supported".to_string())
+    } else {
+        (
+            CheckStatus::Warn,
+            "not supported, kprobe, uprobe and tracepoint links can't be pinned".to_string(),
+        )
+    };
+    report.add(CheckCategory::Link, "perf", status, message);
+
+    let (status, message) = if kernel_at_least(kernel, 5, 9) {
+        (CheckStatus::Pass, "supported".to_string())
+    } else {
+        (
+            CheckStatus::Warn,
+            "requires Linux 5.9, xdp programs are attached with netlink instead".to_string(),
+        )
+    };
+    report.add(CheckCategory::Link, "xdp", status, message);
+}
+
+// Reports the XDP programs that are attached to the interfaces of the bpfman
+// network namespace by something other than bpfman, since bpfman can't attach
+// its dispatcher to these interfaces.
+fn check_interfaces(report: &mut DoctorReport) {
+    let interfaces = match interfaces(None) {
+        Ok(i) => i,
+        Err(e) => {
+            report.add(
+                CheckCategory::Interface,
+                "xdp",
+                CheckStatus::Warn,
+                format!("unable to list interfaces: {e}"),
+            );
+            return;
+        }
+    };
+
+    let netlink = NetlinkManager::new();
+    let mut found = false;
+    for (iface, if_index) in interfaces {
+        let (status, message) = match netlink.xdp_prog_id(if_index) {
+            Ok(Some(id)) => {
+                let name = loaded_programs()
+                    .flatten()
+                    .find(|p| p.id() == id)
+                    .and_then(|p| p.name_as_str().map(String::from));
+                if name.as_deref() == Some(XDP_DISPATCHER_PROGRAM_NAME) {
+                    continue;
+                }
+                (
+                    CheckStatus::Warn,
+                    format!(
+                        "xdp program {id} ({}) is not managed by bpfman, \
+                         bpfman can't attach xdp programs to {iface}",
+                        name.as_deref().unwrap_or("unknown")
+                    ),
+                )
+            }
+            Ok(None) => continue,
+            Err(e) => (
+                CheckStatus::Warn,
+                format!("unable to get the xdp program of {iface}: {e}"),
+            ),
+        };
+        found = true;
+        report.add(CheckCategory::Interface, &iface, status, message);
+    }
+
+    if !found {
+        report.add(
+            CheckCategory::Interface,
+            "xdp",
+            CheckStatus::Pass,
+            "no xdp programs that are not managed by bpfman".to_string(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lockdown_mode() {
+        assert_eq!(
+            lockdown_mode("[none] integrity confidentiality\n"),
+            Some("none")
+        );
+        assert_eq!(
+            lockdown_mode("none integrity [confidentiality]\n"),
+            Some("confidentiality")
+        );
+        assert_eq!(lockdown_mode(""), None);
+    }
+
+    #[test]
+    fn test_report_status() {
+        let mut report = DoctorReport::default();
+        assert_eq!(report.status(), CheckStatus::Pass);
+        report.add(
+            CheckCategory::Program,
+            "tcx",
+            CheckStatus::Fail,
+            "requires Linux 6.6".to_string(),
+        );
+        report.add(
+            CheckCategory::Environment,
+            "btf",
+            CheckStatus::Warn,
+            "not found".to_string(),
+        );
+        assert_eq!(report.status(), CheckStatus::Fail);
+        assert_eq!(report.count(CheckStatus::Warn), 1);
+        assert_eq!(report.count(CheckStatus::Pass), 0);
+    }
+}
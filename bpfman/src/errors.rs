@@ -5,7 +5,7 @@ use thiserror::Error;
 use tokio::sync::oneshot;
 use url::ParseError as urlParseError;
 
-use crate::oci_utils::ImageError;
+pub use crate::oci_utils::ImageError;
 
 #[derive(Debug, Error)]
 pub enum BpfmanError {
@@ -85,8 +85,54 @@ pub enum BpfmanError {
     BpfLinkError(#[from] aya::programs::links::LinkError),
     #[error(transparent)]
     BpfParseError(#[from] ParseError),
-    #[error("one or more programs failed to load: {0:?}")]
-    ProgramsLoadFailure(Vec<BpfmanError>),
+    #[error(
+        "one or more programs failed to load: {}",
+        .0.iter().map(|f| f.to_string()).collect::<Vec<_>>().join(", ")
+    )]
+    ProgramsLoadFailure(Vec<ProgramLoadFailure>),
+    #[error("link group {0} not found")]
+    LinkGroupNotFound(String),
+    #[error("link group {0} already exists")]
+    LinkGroupExists(String),
+    #[error(
+        "revision {revision} is no longer in the event log, which starts after revision {compacted}"
+    )]
+    EventsCompacted { revision: u64, compacted: u64 },
+    #[error("permission denied: {0}")]
+    PermissionDenied(String),
+    #[error("policy violation of rule {rule}: {reason}")]
+    PolicyViolation { rule: String, reason: String },
+    #[error(
+        "program {program} was rejected by the verifier: {io_error}. Verifier output:\n{verifier_log}"
+    )]
+    VerifierRejected {
+        program: String,
+        #[source]
+        io_error: std::io::Error,
+        verifier_log: String,
+    },
+    #[error("invalid state bundle: {0}")]
+    InvalidBundle(String),
+    #[error("program {0} does not exist or was not created by bpfman")]
+    ProgramNotFound(u32),
+    #[error("link {0} does not exist")]
+    LinkNotFound(u32),
+    #[error("no {kind} has the alias {alias}")]
+    AliasNotFound { kind: String, alias: String },
+    #[error("the {kind} alias {alias} is already in use")]
+    AliasInUse { kind: String, alias: String },
+}
+
+/// The failure of one of the programs of a request that loads several
+/// programs.
+#[derive(Debug, Error)]
+#[error("{name}: {error}")]
+pub struct ProgramLoadFailure {
+    /// The position of the program in the request.
+    pub index: usize,
+    pub name: String,
+    #[source]
+    pub error: BpfmanError,
 }
 
 #[derive(Error, Debug)]
@@ -115,4 +161,18 @@ pub enum ParseError {
     InvalidLinkAction { action: String },
     #[error("not a valid attach type: {link_type}")]
     InvalidAttachType { link_type: String },
+    #[error("not a valid interface selector: {selector}")]
+    InvalidInterfaceSelector { selector: String },
+    #[error("not a valid reattach policy: {policy}")]
+    InvalidReattachPolicy { policy: String },
+    #[error("not a valid metadata selector: {selector}")]
+    InvalidMetadataSelector { selector: String },
+    #[error("not a valid name regex: {pattern}")]
+    InvalidNameRegex { pattern: String },
+    #[error("not a valid ID or name:<ALIAS>: {value}")]
+    InvalidIdOrAlias { value: String },
+    #[error("not a valid sort key: {sort_by}")]
+    InvalidListSortKey { sort_by: String },
+    #[error("not a valid page token: {token}")]
+    InvalidPageToken { token: String },
 }
@@ -0,0 +1,561 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+//! Inspection of eBPF bytecode before it is loaded. Object files are parsed
+//! offline, without privileges, and bytecode images are pulled with the image
+//! manager first.
+
+use std::collections::{BTreeSet, HashMap};
+
+use aya::Btf;
+use aya_obj::{EbpfSectionKind, ProgramSection, btf::BtfKind};
+use object::{Endianness, Object, ObjectSection, ObjectSymbol, SectionIndex, SymbolKind};
+use sled::Db;
+
+use crate::{
+    errors::BpfmanError,
+    init_image_manager,
+    types::{BpfProgType, BytecodeImage, MapType},
+};
+
+/// The contents of an eBPF object file.
+#[derive(Debug, Clone)]
+pub struct BytecodeInfo {
+    /// The license declared in the `license` section.
+    pub license: String,
+    /// The kernel version declared in the legacy `version` section.
+    pub kernel_version: Option<u32>,
+    pub programs: Vec<BytecodeProgram>,
+    pub maps: Vec<BytecodeMap>,
+    pub globals: Vec<GlobalVariable>,
+    /// The kernel features that the bytecode needs, with the kernel release
+    /// that introduced them.
+    pub required_features: Vec<String>,
+}
+
+/// A program of an eBPF object file.
+#[derive(Debug, Clone)]
+pub struct BytecodeProgram {
+    pub name: String,
+    pub section: String,
+    pub program_type: BpfProgType,
+    pub sleepable: bool,
+    /// The kernel function or tracepoint of fentry, fexit, lsm and tp_btf
+    /// programs, which must be present in the kernel BTF.
+    pub attach_target: Option<String>,
+    /// Whether the attach target is in the BTF of the running kernel. None if
+    /// the program has no BTF-typed attach target or the kernel BTF is not
+    /// available.
+    pub attach_target_found: Option<bool>,
+}
+
+/// A map of an eBPF object file.
+#[derive(Debug, Clone)]
+pub struct BytecodeMap {
+    pub name: String,
+    pub map_type: String,
+    pub key_size: u32,
+    pub value_size: u32,
+    pub max_entries: u32,
+}
+
+/// A global variable of an eBPF object file, which can be set with the global
+/// data of a load request.
+#[derive(Debug, Clone)]
+pub struct GlobalVariable {
+    pub name: String,
+    pub section: String,
+    pub size: u64,
+    /// The C type of the variable, if the object has BTF.
+    pub type_name: Option<String>,
+}
+
+/// Returns the programs, maps, global variables, license and required kernel
+/// features of the eBPF object file in `bytecode`, without loading it.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use bpfman::inspect::inspect_bytecode;
+///
+/// let bytecode = std::fs::read("xdp_pass.bpf.o").unwrap();
+/// let info = inspect_bytecode(&bytecode).unwrap();
+/// for program in info.programs {
+///     println!("{}: {}", program.name, program.program_type);
+/// }
+/// ```
+pub fn inspect_bytecode(bytecode: &[u8]) -> Result<BytecodeInfo, BpfmanError> {
+    let obj = aya_obj::Object::parse(bytecode)
+        .map_err(|e| BpfmanError::Error(format!("unable to parse bytecode: {e}")))?;
+    let elf = object::File::parse(bytecode)
+        .map_err(|e| BpfmanError::Error(format!("unable to parse bytecode: {e}")))?;
+
+    // The kernel BTF is only used to look up attach targets, so bytecode can
+    // still be inspected on hosts without it.
+    let kernel_btf = Btf::from_sys_fs().ok();
+
+    let mut programs: Vec<BytecodeProgram> = obj
+        .programs
+        .iter()
+        .map(|(name, program)| {
+            let section = elf
+                .section_by_index(SectionIndex(program.section_index))
+                .ok()
+                .and_then(|s| s.name().ok())
+                .unwrap_or_default()
+                .to_string();
+            let attach_target = btf_attach_target(&program.section, &section);
+            let attach_target_found = attach_target.as_ref().and_then(|target| {
+                let btf = kernel_btf.as_ref()?;
+                let (name, kind) = match program.section {
+                    ProgramSection::Lsm { .. } => (format!("bpf_lsm_{target}"), BtfKind::Func),
+                    ProgramSection::BtfTracePoint => {
+                        (format!("btf_trace_{target}"), BtfKind::Typedef)
+                    }
+                    _ => (target.clone(), BtfKind::Func),
+                };
+                Some(btf.id_by_type_name_kind(&name, kind).is_ok())
+            });
+            BytecodeProgram {
+                name: name.clone(),
+                section,
+                program_type: program.section.clone().into(),
+                sleepable: is_sleepable(&program.section),
+                attach_target,
+                attach_target_found,
+            }
+        })
+        .collect();
+    programs.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut maps: Vec<BytecodeMap> = obj
+        .maps
+        .iter()
+        .filter(|(_, map)| {
+            !matches!(
+                map.section_kind(),
+                EbpfSectionKind::Data | EbpfSectionKind::Rodata | EbpfSectionKind::Bss
+            )
+        })
+        .map(|(name, map)| BytecodeMap {
+            name: name.clone(),
+            map_type: map_type_name(map.map_type()),
+            key_size: map.key_size(),
+            value_size: map.value_size(),
+            max_entries: map.max_entries(),
+        })
+        .collect();
+    maps.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let globals = global_variables(&elf);
+    let required_features = required_features(&obj, &globals);
+
+    Ok(BytecodeInfo {
+        license: obj.license.to_string_lossy().into_owned(),
+        kernel_version: obj.kernel_version,
+        programs,
+        maps,
+        globals,
+        required_features,
+    })
+}
+
+/// Pulls the bytecode image, unless it is already in the image store and the
+/// pull policy allows it to be reused, and returns the contents of its
+/// bytecode like [`inspect_bytecode`].
+pub fn inspect_image(root_db: &Db, image: &BytecodeImage) -> Result<BytecodeInfo, BpfmanError> {
+    let mut image_manager = init_image_manager()?;
+    let (path, _) = image_manager.get_image(
+        root_db,
+        &image.image_url,
+        image.image_pull_policy.clone(),
+        image.username.clone(),
+        image.password.clone(),
+    )?;
+    let bytecode = image_manager.get_bytecode_from_image_store(root_db, path)?;
+    inspect_bytecode(&bytecode)
+}
+
+// Returns the kernel function or tracepoint that a program is attached to, for
+// program types whose attach target is resolved through the kernel BTF. The
+// target is the part of the section name after the first '/', for example
+// "do_unlinkat" for "fentry/do_unlinkat".
+fn btf_attach_target(program_section: &ProgramSection, section: &str) -> Option<String> {
+    match program_section {
+        ProgramSection::FEntry { .. }
+        | ProgramSection::FExit { .. }
+        | ProgramSection::Lsm { .. }
+        | ProgramSection::BtfTracePoint => section
+            .split_once('/')
+            .map(|(_, target)| target.to_string())
+            .filter(|target| !target.is_empty()),
+        _ => None,
+    }
+}
+
+fn is_sleepable(section: &ProgramSection) -> bool {
+    matches!(
+        section,
+        ProgramSection::UProbe { sleepable: true }
+            | ProgramSection::URetProbe { sleepable: true }
+            | ProgramSection::Lsm { sleepable: true }
+            | ProgramSection::FEntry { sleepable: true }
+            | ProgramSection::FExit { sleepable: true }
+    )
+}
+
+// MapType::from() panics on map types it doesn't know, which a hand crafted
+// object file can contain.
+fn map_type_name(map_type: u32) -> String {
+    if map_type <= MapType::Arena as u32 {
+        MapType::from(map_type).to_string()
+    } else {
+        format!("unknown ({map_type})")
+    }
+}
+
+// Returns the global data symbols of the .data, .rodata and .bss sections, with
+// their types from the BTF of the object if it has one. Static variables, like
+// the format strings of bpf_printk(), are left out.
+fn global_variables(elf: &object::File) -> Vec<GlobalVariable> {
+    let var_types = elf
+        .section_by_name(".BTF")
+        .and_then(|s| s.data().ok())
+        .and_then(|data| BtfTypes::parse(data, elf.endianness()))
+        .map(|btf| btf.var_types())
+        .unwrap_or_default();
+
+    let mut globals: Vec<(u64, GlobalVariable)> = elf
+        .symbols()
+        .filter(|symbol| symbol.kind() == SymbolKind::Data && symbol.is_global())
+        .filter_map(|symbol| {
+            let name = symbol.name().ok().filter(|n| !n.is_empty())?;
+            let section = elf
+                .section_by_index(symbol.section_index()?)
+                .ok()?
+                .name()
+                .ok()?;
+            if ![".data", ".rodata", ".bss"]
+                .iter()
+                .any(|prefix| section.starts_with(prefix))
+            {
+                return None;
+            }
+            Some((
+                symbol.address(),
+                GlobalVariable {
+                    name: name.to_string(),
+                    section: section.to_string(),
+                    size: symbol.size(),
+                    type_name: var_types.get(name).cloned(),
+                },
+            ))
+        })
+        .collect();
+    globals.sort_by(|(a_addr, a), (b_addr, b)| (&a.section, a_addr).cmp(&(&b.section, b_addr)));
+    globals.into_iter().map(|(_, global)| global).collect()
+}
+
+fn required_features(obj: &aya_obj::Object, globals: &[GlobalVariable]) -> Vec<String> {
+    let mut features = BTreeSet::new();
+
+    if !globals.is_empty()
+        || obj.maps.values().any(|map| {
+            matches!(
+                map.section_kind(),
+                EbpfSectionKind::Data | EbpfSectionKind::Rodata | EbpfSectionKind::Bss
+            )
+        })
+    {
+        features.insert("global variables (Linux 5.2)");
+    }
+
+    for program in obj.programs.values() {
+        match program.section {
+            ProgramSection::FEntry { .. } | ProgramSection::FExit { .. } => {
+                features.insert("BPF trampoline (Linux 5.5)");
+                features.insert("kernel BTF (CONFIG_DEBUG_INFO_BTF)");
+            }
+            ProgramSection::BtfTracePoint => {
+                features.insert("BTF-enabled raw tracepoints (Linux 5.5)");
+                features.insert("kernel BTF (CONFIG_DEBUG_INFO_BTF)");
+            }
+            ProgramSection::Lsm { .. } => {
+                features.insert("BPF LSM (Linux 5.7, CONFIG_BPF_LSM)");
+                features.insert("kernel BTF (CONFIG_DEBUG_INFO_BTF)");
+            }
+            ProgramSection::Extension => {
+                features.insert("program extensions (Linux 5.6)");
+            }
+            ProgramSection::Xdp { frags: true, .. } => {
+                features.insert("XDP multi-buffer (Linux 5.18)");
+            }
+            _ => {}
+        }
+        if is_sleepable(&program.section) {
+            features.insert("sleepable programs (Linux 5.10)");
+        }
+    }
+
+    for map in obj.maps.values() {
+        let feature = match map.map_type() {
+            t if t == MapType::Ringbuf as u32 => "BPF ring buffer (Linux 5.8)",
+            t if t == MapType::TaskStorage as u32 => "task local storage (Linux 5.11)",
+            t if t == MapType::BloomFilter as u32 => "bloom filter maps (Linux 5.16)",
+            t if t == MapType::UserRingbuf as u32 => "user ring buffer (Linux 6.1)",
+            t if t == MapType::CgrpStorage as u32 => "cgroup local storage (Linux 6.2)",
+            t if t == MapType::Arena as u32 => "BPF arena (Linux 6.9)",
+            _ => continue,
+        };
+        features.insert(feature);
+    }
+
+    features.into_iter().map(|f| f.to_string()).collect()
+}
+
+const BTF_MAGIC: u16 = 0xeb9f;
+const BTF_KIND_INT: u32 = 1;
+const BTF_KIND_PTR: u32 = 2;
+const BTF_KIND_ARRAY: u32 = 3;
+const BTF_KIND_STRUCT: u32 = 4;
+const BTF_KIND_UNION: u32 = 5;
+const BTF_KIND_ENUM: u32 = 6;
+const BTF_KIND_FWD: u32 = 7;
+const BTF_KIND_TYPEDEF: u32 = 8;
+const BTF_KIND_VOLATILE: u32 = 9;
+const BTF_KIND_CONST: u32 = 10;
+const BTF_KIND_RESTRICT: u32 = 11;
+const BTF_KIND_FUNC: u32 = 12;
+const BTF_KIND_FUNC_PROTO: u32 = 13;
+const BTF_KIND_VAR: u32 = 14;
+const BTF_KIND_DATASEC: u32 = 15;
+const BTF_KIND_FLOAT: u32 = 16;
+const BTF_KIND_DECL_TAG: u32 = 17;
+const BTF_KIND_TYPE_TAG: u32 = 18;
+const BTF_KIND_ENUM64: u32 = 19;
+
+struct BtfType {
+    kind: u32,
+    name: String,
+    // The referenced type, or the element type of an array.
+    type_id: u32,
+    nelems: u32,
+}
+
+// The types of the BTF of an object file. aya-obj parses the BTF but doesn't
+// expose its types, so this only reads what is needed to name the types of
+// global variables.
+struct BtfTypes {
+    // The type with id N is at index N - 1, id 0 is void.
+    types: Vec<BtfType>,
+}
+
+impl BtfTypes {
+    fn parse(data: &[u8], endianness: Endianness) -> Option<BtfTypes> {
+        let u32_at = |offset: usize| -> Option<u32> {
+            let bytes: [u8; 4] = data.get(offset..offset + 4)?.try_into().ok()?;
+            Some(match endianness {
+                Endianness::Little => u32::from_le_bytes(bytes),
+                Endianness::Big => u32::from_be_bytes(bytes),
+            })
+        };
+        let magic = match endianness {
+            Endianness::Little => u16::from_le_bytes(data.get(0..2)?.try_into().ok()?),
+            Endianness::Big => u16::from_be_bytes(data.get(0..2)?.try_into().ok()?),
+        };
+        if magic != BTF_MAGIC {
+            return None;
+        }
+
+        let hdr_len = u32_at(4)? as usize;
+        let type_start = hdr_len + u32_at(8)? as usize;
+        let type_end = type_start + u32_at(12)? as usize;
+        let str_start = hdr_len + u32_at(16)? as usize;
+        let strings = data.get(str_start..str_start + u32_at(20)? as usize)?;
+        let name_at = |offset: u32| -> String {
+            strings
+                .get(offset as usize..)
+                .and_then(|s| s.split(|b| *b == 0).next())
+                .map(|s| String::from_utf8_lossy(s).into_owned())
+                .unwrap_or_default()
+        };
+
+        let mut types = Vec::new();
+        let mut offset = type_start;
+        while offset < type_end {
+            let name_off = u32_at(offset)?;
+            let info = u32_at(offset + 4)?;
+            let size_or_type = u32_at(offset + 8)?;
+            let kind = (info >> 24) & 0x1f;
+            let vlen = (info & 0xffff) as usize;
+            offset += 12;
+
+            let mut btf_type = BtfType {
+                kind,
+                name: name_at(name_off),
+                type_id: size_or_type,
+                nelems: 0,
+            };
+            offset += match kind {
+                BTF_KIND_INT | BTF_KIND_VAR | BTF_KIND_DECL_TAG => 4,
+                BTF_KIND_ARRAY => {
+                    btf_type.type_id = u32_at(offset)?;
+                    btf_type.nelems = u32_at(offset + 8)?;
+                    12
+                }
+                BTF_KIND_STRUCT | BTF_KIND_UNION | BTF_KIND_DATASEC | BTF_KIND_ENUM64 => vlen * 12,
+                BTF_KIND_ENUM | BTF_KIND_FUNC_PROTO => vlen * 8,
+                BTF_KIND_PTR | BTF_KIND_FWD | BTF_KIND_TYPEDEF | BTF_KIND_VOLATILE
+                | BTF_KIND_CONST | BTF_KIND_RESTRICT | BTF_KIND_FUNC | BTF_KIND_FLOAT
+                | BTF_KIND_TYPE_TAG => 0,
+                _ => return None,
+            };
+            types.push(btf_type);
+        }
+        Some(BtfTypes { types })
+    }
+
+    // Returns the type names of the variables, by variable name.
+    fn var_types(&self) -> HashMap<String, String> {
+        self.types
+            .iter()
+            .filter(|t| t.kind == BTF_KIND_VAR)
+            .map(|t| (t.name.clone(), self.type_name(t.type_id)))
+            .collect()
+    }
+
+    fn type_name(&self, type_id: u32) -> String {
+        // Type chains are short, the limit only guards against loops in
+        // malformed BTF.
+        self.type_name_depth(type_id, 32)
+    }
+
+    fn type_name_depth(&self, type_id: u32, depth: u32) -> String {
+        if type_id == 0 {
+            return "void".to_string();
+        }
+        let Some(t) = self.types.get(type_id as usize - 1).filter(|_| depth > 0) else {
+            return "?".to_string();
+        };
+        let named = |prefix: &str| {
+            if t.name.is_empty() {
+                format!("{prefix} (anon)")
+            } else {
+                format!("{prefix} {}", t.name)
+            }
+        };
+        match t.kind {
+            BTF_KIND_INT | BTF_KIND_FLOAT | BTF_KIND_TYPEDEF => t.name.clone(),
+            BTF_KIND_STRUCT | BTF_KIND_FWD => named("struct"),
+            BTF_KIND_UNION => named("union"),
+            BTF_KIND_ENUM | BTF_KIND_ENUM64 => named("enum"),
+            BTF_KIND_PTR => format!("{} *", self.type_name_depth(t.type_id, depth - 1)),
+            BTF_KIND_CONST => format!("const {}", self.type_name_depth(t.type_id, depth - 1)),
+            BTF_KIND_VOLATILE => {
+                format!("volatile {}", self.type_name_depth(t.type_id, depth - 1))
+            }
+            BTF_KIND_RESTRICT => {
+                format!("restrict {}", self.type_name_depth(t.type_id, depth - 1))
+            }
+            BTF_KIND_ARRAY => {
+                // The dimensions of a multi-dimensional array are nested from
+                // the outermost one.
+                let mut dims = format!("[{}]", t.nelems);
+                let mut elem = t.type_id;
+                while let Some(inner) = elem
+                    .checked_sub(1)
+                    .and_then(|i| self.types.get(i as usize))
+                    .filter(|inner| inner.kind == BTF_KIND_ARRAY)
+                    .filter(|_| dims.len() < 64)
+                {
+                    dims.push_str(&format!("[{}]", inner.nelems));
+                    elem = inner.type_id;
+                }
+                format!("{}{dims}", self.type_name_depth(elem, depth - 1))
+            }
+            BTF_KIND_FUNC_PROTO => "func".to_string(),
+            BTF_KIND_VAR | BTF_KIND_TYPE_TAG => self.type_name_depth(t.type_id, depth - 1),
+            _ => "?".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_btf_var_types() {
+        // name_off, info, size_or_type, followed by the kind specific data.
+        let types: Vec<u32> = vec![
+            // [1] int, 4 bytes, signed.
+            1,
+            BTF_KIND_INT << 24,
+            4,
+            (1 << 24) | 32,
+            // [2] typedef __u32 -> [1]
+            5,
+            BTF_KIND_TYPEDEF << 24,
+            1,
+            // [3] const -> [2]
+            0,
+            BTF_KIND_CONST << 24,
+            2,
+            // [4] volatile -> [3]
+            0,
+            BTF_KIND_VOLATILE << 24,
+            3,
+            // [5] int[2][4]
+            0,
+            BTF_KIND_ARRAY << 24,
+            0,
+            6,
+            1,
+            2,
+            // [6] int[4]
+            0,
+            BTF_KIND_ARRAY << 24,
+            0,
+            1,
+            1,
+            4,
+            // [7] volatile const __u32 sampling
+            11,
+            BTF_KIND_VAR << 24,
+            4,
+            1,
+            // [8] int counts[2][4]
+            20,
+            BTF_KIND_VAR << 24,
+            5,
+            1,
+        ];
+        let strings = b"\0int\0__u32\0sampling\0counts\0";
+
+        let type_bytes: Vec<u8> = types.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let mut data = Vec::new();
+        data.extend_from_slice(&BTF_MAGIC.to_le_bytes());
+        data.extend_from_slice(&[1, 0]);
+        for v in [
+            24,
+            0,
+            type_bytes.len() as u32,
+            type_bytes.len() as u32,
+            strings.len() as u32,
+        ] {
+            data.extend_from_slice(&v.to_le_bytes());
+        }
+        data.extend_from_slice(&type_bytes);
+        data.extend_from_slice(strings);
+
+        let btf = BtfTypes::parse(&data, Endianness::Little).expect("error parsing btf");
+        assert_eq!(
+            btf.var_types(),
+            HashMap::from([
+                ("sampling".to_string(), "volatile const __u32".to_string()),
+                ("counts".to_string(), "int[2][4]".to_string()),
+            ])
+        );
+        assert!(BtfTypes::parse(&data, Endianness::Big).is_none());
+    }
+}
@@ -2,7 +2,7 @@
 // Copyright Authors of bpfman
 
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fs::{create_dir_all, remove_dir_all},
     path::{Path, PathBuf},
     thread::sleep,
@@ -11,14 +11,15 @@ use std::{
 
 use anyhow::anyhow;
 use aya::{
-    Btf, Ebpf, EbpfLoader,
+    Btf, Ebpf, EbpfLoader, VerifierLogLevel,
+    maps::MapData,
     programs::{
-        Extension, FEntry, FExit, KProbe, LinkOrder as AyaLinkOrder, ProbeKind, SchedClassifier,
-        TcAttachType, TracePoint, UProbe,
+        Extension, FEntry, FExit, KProbe, LinkOrder as AyaLinkOrder, ProbeKind, ProgramError,
+        SchedClassifier, TcAttachType, TracePoint, UProbe,
         fentry::FEntryLink,
         fexit::FExitLink,
         kprobe::KProbeLink,
-        links::FdLink,
+        links::{FdLink, PinnedLink},
         loaded_programs,
         tc::{SchedClassifierLink, TcAttachOptions},
         trace_point::TracePointLink,
@@ -27,39 +28,56 @@ use aya::{
 };
 use log::{debug, error, info, warn};
 use multiprog::{TcDispatcher, XdpDispatcher};
-use sled::{Config as SledConfig, Db};
-use types::{AttachInfo, AttachOrder, Link, TcxLink};
+use sled::{Config as SledConfig, Db, transaction::ConflictableTransactionError};
+use types::{
+    AttachInfo, AttachOrder, DispatcherSummary, Drift, DriftKind, IdOrAlias, InterfaceSelector,
+    KernelLinkInfo, Link, LinkGroup, LinkGroupMember, LinkListFilter, LinkType, METADATA_ALIAS_TAG,
+    METADATA_APPLICATION_TAG, MetadataRequirement, ReattachPolicy, RepairStatus, TcxLink,
+};
 use utils::{initialize_bpfman, tc_dispatcher_id, xdp_dispatcher_id};
 
 use crate::{
     config::Config,
     directories::*,
-    errors::BpfmanError,
-    multiprog::{Dispatcher, DispatcherId, DispatcherInfo},
+    errors::{BpfmanError, ProgramLoadFailure},
+    multiprog::{
+        Dispatcher, DispatcherId, DispatcherInfo, TC_DISPATCHER_PREFIX, XDP_DISPATCHER_PREFIX,
+    },
     oci_utils::image_manager::ImageManager,
     types::{
-        BpfProgType, BytecodeImage, Direction, LINKS_LINK_PREFIX, ListFilter, PROGRAM_PREFIX,
-        Program, ProgramData,
+        BpfProgType, BytecodeImage, Direction, EVENTS_COMPACTED, EVENTS_REVISION, EVENTS_TREE,
+        Event, EventKind, LINKS_LINK_PREFIX, ListFilter, PREFIX_EVENT, PROGRAM_PREFIX, Program,
+        ProgramData,
     },
     utils::{
-        bytes_to_string, bytes_to_u32, enter_netns, get_error_msg_from_stderr, open_config_file,
-        set_dir_permissions, should_map_be_pinned, sled_insert,
+        bytes_to_string, bytes_to_u32, bytes_to_u64, elf_symbol_exists, enter_netns,
+        get_error_msg_from_stderr, kernel_symbol_exists, open_config_file, program_loaded,
+        select_interfaces, set_dir_permissions, should_map_be_pinned, sled_get_option, sled_insert,
+        tracepoint_exists,
     },
 };
 
+pub mod bundle;
 pub mod config;
 mod dispatcher_config;
+pub mod doctor;
 pub mod errors;
+pub mod inspect;
 mod multiprog;
-mod netlink;
+pub mod netlink;
 mod oci_utils;
+pub mod policy;
 mod static_program;
+pub mod trace;
 pub mod types;
 pub mod utils;
+mod verifier;
 
 const MAPS_MODE: u32 = 0o0660;
 const MAP_PREFIX: &str = "map_";
 const MAPS_USED_BY_PREFIX: &str = "map_used_by_";
+// The number of events kept in the event log.
+const MAX_EVENTS: u64 = 4096;
 
 pub mod directories {
     // Dispatcher bytecode is now embedded at build time for hermetic builds.
@@ -187,17 +205,23 @@ pub fn get_db_config() -> SledConfig {
 /// * The program bytes fail to set.
 /// * Adding the program (multi-attach or single-attach) fails.
 /// * The program is unsupported.
+/// * The programs violate the admission policy in [`Config::policy`].
 ///
 /// In case of failure, any created directories or loaded programs
 /// will be cleaned up to maintain system integrity.
 pub fn add_programs(
-    _config: &Config,
+    config: &Config,
     root_db: &Db,
     programs: Vec<Program>,
 ) -> Result<Vec<Program>, BpfmanError> {
     info!("Request to load {} programs", programs.len());
 
-    let result = add_programs_internal(root_db, programs);
+    let result = config
+        .policy()
+        .resolve()
+        .and_then(|policy| policy.check_load(root_db, &programs))
+        .and_then(|_| check_program_aliases(root_db, &programs))
+        .and_then(|_| add_programs_internal(root_db, programs));
 
     match result {
         Ok(ref p) => {
@@ -209,6 +233,15 @@ pub fn add_programs(
                 let kind = kind.unwrap_or(BpfProgType::Unspec);
                 let name = prog.get_data().get_name().unwrap_or("not set".to_string());
                 info!("Success: loaded {kind} program named \"{name}\"");
+                record_event(
+                    root_db,
+                    new_event(
+                        EventKind::ProgramLoaded,
+                        Some(prog),
+                        None,
+                        format!("loaded {kind} program named \"{name}\""),
+                    ),
+                );
             }
         }
         Err(ref e) => {
@@ -218,6 +251,115 @@ pub fn add_programs(
     result
 }
 
+/// Runs the checks of [`add_programs`] without keeping the programs. The
+/// bytecode is pulled and its signature verified, the programs are loaded into
+/// the kernel to run the verifier, and then they are unloaded again. Nothing
+/// is pinned or recorded in the database, apart from the bytecode images that
+/// are pulled.
+///
+/// The returned programs have the information the kernel reported about them
+/// while they were loaded, including the verifier log summary. Their IDs are
+/// no longer valid once this function returns.
+///
+/// # Errors
+///
+/// This function returns the same errors as [`add_programs`].
+pub fn validate_programs(
+    config: &Config,
+    root_db: &Db,
+    mut programs: Vec<Program>,
+) -> Result<Vec<Program>, BpfmanError> {
+    info!("Request to validate {} programs", programs.len());
+
+    let result = config
+        .policy()
+        .resolve()
+        .and_then(|policy| policy.check_load(root_db, &programs))
+        .and_then(|_| check_program_aliases(root_db, &programs))
+        .and_then(|_| {
+            if let Some(map_owner_id) = programs[0].get_data().get_map_owner_id()? {
+                is_map_owner_id_valid(root_db, map_owner_id)?;
+            }
+
+            let mut image_manager = init_image_manager()?;
+            for program in programs.iter_mut() {
+                program
+                    .get_data_mut()
+                    .set_program_bytes(root_db, &mut image_manager)?;
+            }
+
+            // The programs are unloaded when the loader is dropped.
+            let mut ebpf = load_bytecode(&programs)?;
+            let failures: Vec<ProgramLoadFailure> = programs
+                .iter_mut()
+                .enumerate()
+                .filter_map(|(index, program)| {
+                    load_program_in_kernel(&mut ebpf, program)
+                        .err()
+                        .map(|error| ProgramLoadFailure {
+                            index,
+                            name: program.get_data().get_name().unwrap_or_default(),
+                            error,
+                        })
+                })
+                .collect();
+            if !failures.is_empty() {
+                return Err(BpfmanError::ProgramsLoadFailure(failures));
+            }
+            Ok(())
+        });
+
+    match result {
+        Ok(()) => {
+            info!("Success: validated {} programs", programs.len());
+            Ok(programs)
+        }
+        Err(e) => {
+            error!("Error: failed to validate programs: {e}");
+            Err(e)
+        }
+    }
+}
+
+// Checks that the aliases of programs being loaded are not used by loaded
+// programs, nor by another program of the same request.
+fn check_program_aliases(root_db: &Db, programs: &[Program]) -> Result<(), BpfmanError> {
+    let mut aliases: HashSet<String> = get_programs_iter(root_db)
+        .filter_map(|(_, p)| p.get_data().get_alias())
+        .collect();
+    for program in programs {
+        if let Some(alias) = program.get_data().get_alias()
+            && !aliases.insert(alias.clone())
+        {
+            return Err(BpfmanError::AliasInUse {
+                kind: "program".to_string(),
+                alias,
+            });
+        }
+    }
+    Ok(())
+}
+
+// Checks that the alias of a link being attached is not used by another link.
+fn check_link_alias(root_db: &Db, attach_info: &AttachInfo) -> Result<(), BpfmanError> {
+    let Some(alias) = attach_info.metadata().get(METADATA_ALIAS_TAG) else {
+        return Ok(());
+    };
+    let filter = LinkListFilter::new(
+        None,
+        None,
+        None,
+        HashMap::from([(METADATA_ALIAS_TAG.to_string(), alias.clone())]),
+    );
+    if !list_links(root_db, filter)?.is_empty() {
+        return Err(BpfmanError::AliasInUse {
+            kind: "link".to_string(),
+            alias: alias.clone(),
+        });
+    }
+    Ok(())
+}
+
 fn add_programs_internal(
     root_db: &Db,
     mut programs: Vec<Program>,
@@ -251,34 +393,7 @@ fn add_programs_internal(
             .set_program_bytes(root_db, &mut image_manager)?;
     }
 
-    // Create a single instance of the loader to load all the programs
-    // This ensures that global variables are shared between programs
-    // in the same bytecode image.
-    let mut loader = EbpfLoader::new();
-    loader.allow_unsupported_maps();
-
-    // Global data is the same for all programs
-    let global_data = programs[0].get_data().get_global_data()?;
-    for (key, value) in global_data.iter() {
-        loader.set_global(key, value.as_slice(), true);
-    }
-
-    let extensions: Vec<String> = programs
-        .iter()
-        .filter(|p| {
-            p.kind() == BpfProgType::Xdp
-                || (p.kind() == BpfProgType::Tc && !p.get_data().get_is_tcx())
-        })
-        .map(|p| p.get_data().get_name().unwrap())
-        .collect();
-
-    for extension in extensions.iter() {
-        loader.extension(extension);
-    }
-
-    // Load the bytecode
-    debug!("creating ebpf loader for bytecode");
-    let mut ebpf = loader.load(&programs[0].get_data().get_program_bytes()?)?;
+    let mut ebpf = load_bytecode(&programs)?;
 
     let mut results = vec![];
     for program in programs.iter_mut() {
@@ -294,7 +409,17 @@ fn add_programs_internal(
         .filter_map(|r| r.as_ref().ok())
         .copied()
         .collect::<Vec<u32>>();
-    let err_results: Vec<BpfmanError> = results.into_iter().filter_map(|r| r.err()).collect();
+    let err_results: Vec<ProgramLoadFailure> = results
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, r)| {
+            r.err().map(|error| ProgramLoadFailure {
+                index,
+                name: programs[index].get_data().get_name().unwrap_or_default(),
+                error,
+            })
+        })
+        .collect();
     if !err_results.is_empty() {
         for program in programs.into_iter() {
             if let Ok(Some(pin_path)) = program.get_data().get_map_pin_path() {
@@ -318,6 +443,40 @@ fn add_programs_internal(
     Ok(programs)
 }
 
+// Creates a single instance of the loader to load all the programs of a
+// request. This ensures that global variables are shared between programs
+// in the same bytecode image.
+fn load_bytecode(programs: &[Program]) -> Result<Ebpf, BpfmanError> {
+    let mut loader = EbpfLoader::new();
+    loader.allow_unsupported_maps();
+    if let Some(level) = programs[0].get_data().get_verifier_log_level()? {
+        loader.verifier_log_level(VerifierLogLevel::from_bits_truncate(level));
+    }
+
+    // Global data is the same for all programs
+    let global_data = programs[0].get_data().get_global_data()?;
+    for (key, value) in global_data.iter() {
+        loader.set_global(key, value.as_slice(), true);
+    }
+
+    let extensions: Vec<String> = programs
+        .iter()
+        .filter(|p| {
+            p.kind() == BpfProgType::Xdp
+                || (p.kind() == BpfProgType::Tc && !p.get_data().get_is_tcx())
+        })
+        .map(|p| p.get_data().get_name().unwrap())
+        .collect();
+
+    for extension in extensions.iter() {
+        loader.extension(extension);
+    }
+
+    // Load the bytecode
+    debug!("creating ebpf loader for bytecode");
+    Ok(loader.load(&programs[0].get_data().get_program_bytes()?)?)
+}
+
 /// Removes an eBPF program specified by its ID.
 ///
 /// This function attempts to remove an eBPF program that has been
@@ -370,10 +529,7 @@ pub fn remove_program(config: &Config, root_db: &Db, id: u32) -> Result<(), Bpfm
             error!(
                 "Error: Request to unload program with id {id} but id does not exist or was not created by bpfman"
             );
-            return Err(BpfmanError::Error(format!(
-                "Program {0} does not exist or was not created by bpfman",
-                id,
-            )));
+            return Err(BpfmanError::ProgramNotFound(id));
         }
     };
 
@@ -406,23 +562,186 @@ fn remove_program_internal(
         detach_program_internal(config, root_db, prog.clone(), link)?;
     }
 
+    let event = new_event(
+        EventKind::ProgramUnloaded,
+        Some(&prog),
+        None,
+        format!(
+            "unloaded {} program named \"{}\"",
+            prog.kind(),
+            prog.get_data().get_name().unwrap_or("not set".to_string())
+        ),
+    );
     prog.delete(root_db)
         .map_err(BpfmanError::BpfmanProgramDeleteError)?;
 
     delete_map(root_db, id, map_owner_id)?;
 
+    record_event(root_db, event);
     Ok(())
 }
 
 pub fn detach(config: &Config, root_db: &Db, id: u32) -> Result<(), BpfmanError> {
-    let tree = root_db
-        .open_tree(format!("{LINKS_LINK_PREFIX}{id}"))
-        .expect("Unable to open program database tree");
-    let link = Link::new_from_db(tree)?;
+    let link = get_link(root_db, id)?;
     let program = link.get_program(root_db)?;
     detach_program_internal(config, root_db, program, link)
 }
 
+/// Detaches many links in one call, and returns a result for each link in the
+/// same order as `ids`.
+///
+/// XDP and TC links on the same dispatcher are removed together, so that the
+/// dispatcher is rebuilt once for the whole batch rather than once per link.
+/// All other links are detached one at a time as with [`detach`]. A link that
+/// fails to detach does not stop the others from being detached.
+pub fn detach_batch(config: &Config, root_db: &Db, ids: &[u32]) -> Vec<Result<(), BpfmanError>> {
+    info!("Request to detach a batch of {} links", ids.len());
+    let mut results: Vec<Option<Result<(), BpfmanError>>> = ids.iter().map(|_| None).collect();
+    let mut dispatchers: HashMap<DispatcherId, Vec<PendingLink>> = HashMap::new();
+    let mut seen = HashSet::new();
+
+    for (index, id) in ids.iter().enumerate() {
+        if !seen.insert(id) {
+            results[index] = Some(Err(BpfmanError::Error(format!(
+                "link {id} is repeated in the batch"
+            ))));
+            continue;
+        }
+        match dispatcher_link(root_db, *id) {
+            Ok(Some((did, program, link))) => {
+                dispatchers.entry(did).or_default().push(PendingLink {
+                    index,
+                    program,
+                    link,
+                })
+            }
+            Ok(None) => results[index] = Some(detach(config, root_db, *id)),
+            Err(e) => results[index] = Some(Err(e)),
+        }
+    }
+
+    for (did, pending) in dispatchers {
+        for (index, result) in detach_dispatcher_links(config, root_db, &did, pending) {
+            results[index] = Some(result);
+        }
+    }
+
+    results
+        .into_iter()
+        .map(|r| r.expect("every link has a result"))
+        .collect()
+}
+
+// Returns the dispatcher, program and link of an XDP or TC link that is part
+// of the current revision of its dispatcher.
+fn dispatcher_link(
+    root_db: &Db,
+    id: u32,
+) -> Result<Option<(DispatcherId, Program, Link)>, BpfmanError> {
+    let link = get_link(root_db, id)?;
+    if !matches!(link, Link::Xdp(_) | Link::Tc(_))
+        || !link.get_enabled()?
+        || link.get_interface_missing()?
+    {
+        return Ok(None);
+    }
+    let did = link
+        .dispatcher_id()?
+        .ok_or(BpfmanError::DispatcherNotRequired)?;
+    Ok(Some((did, link.get_program(root_db)?, link)))
+}
+
+// Removes the links of a batch that share a dispatcher, and then replaces the
+// dispatcher with a single new revision containing the remaining links.
+fn detach_dispatcher_links(
+    config: &Config,
+    root_db: &Db,
+    did: &DispatcherId,
+    pending: Vec<PendingLink>,
+) -> Vec<(usize, Result<(), BpfmanError>)> {
+    let mut results = Vec::with_capacity(pending.len());
+    let first = &pending[0];
+    let program_type = first.program.kind();
+    let location = (|| {
+        Ok::<_, BpfmanError>((
+            first.link.ifindex()?,
+            first.link.if_name()?,
+            first.link.direction()?,
+            first.link.nsid()?,
+            first.link.netns()?,
+        ))
+    })();
+    let (if_index, if_name, direction, nsid, netns) = match location {
+        Ok(location) => location,
+        Err(e) => {
+            for p in pending {
+                results.push((p.index, Err(BpfmanError::Error(e.to_string()))));
+            }
+            return results;
+        }
+    };
+
+    let mut removed = vec![];
+    for p in pending {
+        let link_id = p.link.get_id().unwrap_or_default();
+        let event = new_event(
+            EventKind::LinkDetached,
+            Some(&p.program),
+            Some(link_id),
+            format!("detached link {link_id}"),
+        );
+        match p.program.get_data().remove_link(root_db, p.link) {
+            Ok(()) => removed.push((p.index, event)),
+            Err(e) => results.push((p.index, Err(e))),
+        }
+    }
+    if removed.is_empty() {
+        return results;
+    }
+
+    let netns_deleted = netns.is_some_and(|n| !n.exists());
+    let result = get_multi_attach_links(root_db, program_type, if_index, direction, nsid).and_then(
+        |remaining| {
+            let old_dispatcher = get_dispatcher(did, root_db)?;
+            // As when detaching a single link, the dispatcher is deleted rather
+            // than rebuilt when it has no links left or its network namespace
+            // is gone.
+            if remaining.is_empty() || netns_deleted {
+                return match old_dispatcher {
+                    Some(mut old) => old.delete(root_db, true),
+                    None => Ok(()),
+                };
+            }
+            rebuild_dispatcher(
+                root_db,
+                config,
+                program_type,
+                if_index,
+                &if_name,
+                direction,
+                nsid,
+                old_dispatcher,
+            )
+        },
+    );
+
+    match result {
+        Ok(()) => {
+            for (index, event) in removed {
+                record_event(root_db, event);
+                results.push((index, Ok(())));
+            }
+        }
+        Err(e) => {
+            error!("Error: failed to rebuild dispatcher on {if_name}: {e}");
+            for (index, _) in removed {
+                results.push((index, Err(BpfmanError::Error(e.to_string()))));
+            }
+        }
+    }
+    results
+}
+
 /// Attaches an eBPF program, identified by its ID, to a specific
 /// attachment point which is specified by the `AttachInfo` struct.
 pub fn attach_program(
@@ -437,10 +756,7 @@ pub fn attach_program(
             error!(
                 "Error: Request to attach program with id {id} but id does not exist or was not created by bpfman"
             );
-            return Err(BpfmanError::Error(format!(
-                "Program {0} does not exist or was not created by bpfman",
-                id,
-            )));
+            return Err(BpfmanError::ProgramNotFound(id));
         }
     };
 
@@ -453,6 +769,16 @@ pub fn attach_program(
     let name = prog.get_data().get_name().unwrap_or("not set".to_string());
     info!("Request to attach {kind} program named \"{name}\" with id {id}");
 
+    if let Err(e) = config
+        .policy()
+        .resolve()
+        .and_then(|policy| policy.check_attach(&attach_info))
+        .and_then(|_| check_link_alias(root_db, &attach_info))
+    {
+        error!("Error: failed to attach {kind} program named \"{name}\": {e}");
+        return Err(e);
+    }
+
     // Write attach info into the database. Once written to the database,
     // DO NOT EXIT with a failure without calling prog.remove_link().
     let mut link = prog.add_link()?;
@@ -462,10 +788,21 @@ pub fn attach_program(
         Err(e) => Err(e),
     };
     match result {
-        Ok(ref link) => info!(
-            "Success: attached {kind} program named \"{name}\" with program id {id} with link {}",
-            link.get_id().unwrap_or_default(),
-        ),
+        Ok(ref link) => {
+            let link_id = link.get_id().unwrap_or_default();
+            info!(
+                "Success: attached {kind} program named \"{name}\" with program id {id} with link {link_id}"
+            );
+            record_event(
+                root_db,
+                new_event(
+                    EventKind::LinkAttached,
+                    Some(&prog),
+                    Some(link_id),
+                    format!("attached {kind} program named \"{name}\" with link {link_id}"),
+                ),
+            );
+        }
         Err(ref e) => {
             error!("Error: failed to attach {kind} program named \"{name}\": {e}");
             if let Err(e) = prog.remove_link(root_db, link.clone()) {
@@ -478,100 +815,1413 @@ pub fn attach_program(
     result
 }
 
-fn attach_program_internal(
+/// Runs the checks of [`attach_program`] without attaching the program. The
+/// attach info must match the type of the program and be allowed by the
+/// admission policy, and its target must exist: the interface of XDP, TC and
+/// TCX programs, the tracepoint, the kernel function of a kprobe, or the target
+/// and function of a uprobe. An XDP or TC attach also fails if the dispatcher
+/// of the interface is already full.
+///
+/// Kprobe and uprobe targets inside a container, and uprobe targets given as a
+/// library name rather than a path, are only resolved when attaching.
+pub fn validate_attach(
     config: &Config,
     root_db: &Db,
-    program: &Program,
-    mut link: Link,
-) -> Result<Link, BpfmanError> {
-    let program_type = program.kind();
-
-    if let Err(e) = match program {
-        Program::Xdp(_) | Program::Tc(_) => {
-            attach_multi_attach_program(root_db, program_type, &mut link, config)
-        }
-        Program::Tcx(_)
-        | Program::Tracepoint(_)
-        | Program::Kprobe(_)
-        | Program::Uprobe(_)
-        | Program::Fentry(_)
-        | Program::Fexit(_)
-        | Program::Unsupported(_) => attach_single_attach_program(root_db, &mut link),
-    } {
-        link.delete(root_db)?;
-        return Err(e);
+    id: u32,
+    attach_info: AttachInfo,
+) -> Result<(), BpfmanError> {
+    let prog = get(root_db, &id).ok_or(BpfmanError::ProgramNotFound(id))?;
+    info!("Request to validate attach of program {id}");
+
+    config
+        .policy()
+        .resolve()
+        .and_then(|policy| policy.check_attach(&attach_info))?;
+    check_link_alias(root_db, &attach_info)?;
+
+    // The link is never added to the program, so it is dropped along with
+    // its temporary tree.
+    let mut link = Link::new(prog.link_type()?);
+    link.set_program_id(id)?;
+    link.attach(attach_info.clone())?;
+    check_attach_target(&attach_info)?;
+    if let Some(did) = link.dispatcher_id()?
+        && num_attached_programs(&did, root_db)? >= 10
+    {
+        return Err(BpfmanError::TooManyPrograms);
     }
 
-    // write it to the database from the temp tree
-    link.finalize(root_db)?;
-
-    Ok(link)
+    info!("Success: program {id} can be attached");
+    Ok(())
 }
 
-fn detach_program_internal(
-    config: &Config,
-    root_db: &Db,
-    mut program: Program,
-    link: Link,
-) -> Result<(), BpfmanError> {
-    match program {
-        Program::Xdp(_) | Program::Tc(_) => {
-            let did = link
-                .dispatcher_id()?
-                .ok_or(BpfmanError::DispatcherNotRequired)?;
-            let program_type = program.kind();
-
-            let if_index = link.ifindex()?;
-            let if_name = link.if_name().unwrap();
-            let direction = link.direction()?;
-            let nsid = link.nsid()?;
-            let netns = link.netns()?;
-
-            program.get_data().remove_link(root_db, link)?;
-
-            detach_multi_attach_program(
-                root_db,
-                config,
-                did,
-                program_type,
-                if_index,
-                if_name,
-                direction,
-                nsid,
-                netns,
-            )?
+// Checks that the tracepoint, kernel function or uprobe target of an attach
+// exists.
+fn check_attach_target(attach_info: &AttachInfo) -> Result<(), BpfmanError> {
+    match attach_info {
+        AttachInfo::Tracepoint { tracepoint, .. } => {
+            let exists = tracepoint
+                .split_once('/')
+                .is_some_and(|(category, name)| tracepoint_exists(category, name));
+            if !exists {
+                return Err(BpfmanError::InvalidAttach(format!(
+                    "tracepoint {tracepoint}"
+                )));
+            }
         }
-        Program::Tcx(_) => {
-            let if_index = link
-                .ifindex()?
-                .ok_or_else(|| BpfmanError::InvalidInterface)?;
-            let direction = link
-                .direction()?
-                .ok_or_else(|| BpfmanError::InvalidDirection)?;
-            let nsid = link.nsid()?;
-            detach_single_attach_program(root_db, &mut program, link)?;
-            set_tcx_program_positions(root_db, if_index, direction, nsid)?;
+        AttachInfo::Kprobe {
+            fn_name,
+            container_pid: None,
+            ..
+        } if !kernel_symbol_exists(fn_name)? => {
+            return Err(BpfmanError::InvalidAttach(format!(
+                "kernel function {fn_name}"
+            )));
         }
-        Program::Tracepoint(_)
-        | Program::Kprobe(_)
-        | Program::Uprobe(_)
-        | Program::Fentry(_)
-        | Program::Fexit(_)
-        | Program::Unsupported(_) => {
-            detach_single_attach_program(root_db, &mut program, link)?;
+        AttachInfo::Uprobe {
+            fn_name,
+            target,
+            container_pid: None,
+            ..
+        } if target.contains('/') => {
+            let path = Path::new(target);
+            if !path.exists() {
+                return Err(BpfmanError::InvalidAttach(format!(
+                    "uprobe target {target}"
+                )));
+            }
+            if let Some(fn_name) = fn_name
+                && !elf_symbol_exists(path, fn_name)?
+            {
+                return Err(BpfmanError::InvalidAttach(format!(
+                    "function {fn_name} of {target}"
+                )));
+            }
         }
-    };
+        _ => {}
+    }
     Ok(())
 }
 
-/// Lists the currently loaded eBPF programs.
-///
-/// This function fetches the list of all eBPF programs loaded in the
-/// system, combining those managed by `bpfman` with any other loaded
-/// eBPF programs obtained from Aya. It returns a vector of `Program`
-/// objects that match the provided filter.
+/// Attaches many programs in one call, and returns a result for each request
+/// in the same order as `requests`.
 ///
-/// # Arguments
+/// XDP and TC requests for the same interface, direction and network namespace
+/// are grouped, so that their dispatcher is rebuilt once for the whole batch
+/// rather than once per link. All other requests are attached one at a time as
+/// with [`attach_program`].
+///
+/// A request that fails does not stop the others from being attached. If a
+/// dispatcher can't be rebuilt, all the requests grouped on it fail, and the
+/// requests that would take a dispatcher over its maximum number of programs
+/// fail with [`BpfmanError::TooManyPrograms`].
+pub fn attach_program_batch(
+    config: &Config,
+    root_db: &Db,
+    requests: Vec<(u32, AttachInfo)>,
+) -> Vec<Result<Link, BpfmanError>> {
+    info!("Request to attach a batch of {} links", requests.len());
+    let mut results: Vec<Option<Result<Link, BpfmanError>>> =
+        requests.iter().map(|_| None).collect();
+    let mut dispatchers: HashMap<DispatcherId, Vec<PendingLink>> = HashMap::new();
+    // The XDP and TC links of the batch are only attached at the end, so an
+    // alias is also checked against the requests before it in the batch.
+    let mut aliases = HashSet::new();
+
+    for (index, (id, attach_info)) in requests.into_iter().enumerate() {
+        if let Some(alias) = attach_info.metadata().get(METADATA_ALIAS_TAG)
+            && !aliases.insert(alias.clone())
+        {
+            results[index] = Some(Err(BpfmanError::AliasInUse {
+                kind: "link".to_string(),
+                alias: alias.clone(),
+            }));
+            continue;
+        }
+        if !matches!(attach_info, AttachInfo::Xdp { .. } | AttachInfo::Tc { .. }) {
+            results[index] = Some(attach_program(config, root_db, id, attach_info));
+            continue;
+        }
+        match prepare_dispatcher_link(config, root_db, id, attach_info) {
+            Ok((did, program, link)) => dispatchers.entry(did).or_default().push(PendingLink {
+                index,
+                program,
+                link,
+            }),
+            Err(e) => {
+                error!("Error: failed to attach program {id}: {e}");
+                results[index] = Some(Err(e));
+            }
+        }
+    }
+
+    for (did, pending) in dispatchers {
+        for (index, result) in attach_dispatcher_links(config, root_db, &did, pending) {
+            results[index] = Some(result);
+        }
+    }
+
+    results
+        .into_iter()
+        .map(|r| r.expect("every request has a result"))
+        .collect()
+}
+
+// An XDP or TC link of a batch that is waiting for its dispatcher to be
+// rebuilt, along with the index of its request.
+struct PendingLink {
+    index: usize,
+    program: Program,
+    link: Link,
+}
+
+// Creates the link for an XDP or TC request of a batch, without attaching it.
+fn prepare_dispatcher_link(
+    config: &Config,
+    root_db: &Db,
+    id: u32,
+    attach_info: AttachInfo,
+) -> Result<(DispatcherId, Program, Link), BpfmanError> {
+    let mut prog = get(root_db, &id).ok_or(BpfmanError::ProgramNotFound(id))?;
+    config
+        .policy()
+        .resolve()
+        .and_then(|policy| policy.check_attach(&attach_info))?;
+    check_link_alias(root_db, &attach_info)?;
+
+    // Write attach info into the database. Once written to the database,
+    // DO NOT EXIT with a failure without calling abort_link().
+    let mut link = prog.add_link()?;
+    let did = link.attach(attach_info).and_then(|_| {
+        link.dispatcher_id()?
+            .ok_or(BpfmanError::DispatcherNotRequired)
+    });
+    match did {
+        Ok(did) => Ok((did, prog, link)),
+        Err(e) => {
+            abort_link(root_db, &prog, link);
+            Err(e)
+        }
+    }
+}
+
+// Removes a link that failed to attach from the database.
+fn abort_link(root_db: &Db, prog: &Program, link: Link) {
+    if let Err(e) = prog.remove_link(root_db, link.clone()) {
+        warn!("Error: failed to remove link: {e}");
+    }
+    if let Err(e) = link.delete(root_db) {
+        warn!("Error: failed to delete link: {e}");
+    }
+}
+
+// Attaches the links of a batch that share a dispatcher by building a single
+// new revision of the dispatcher.
+fn attach_dispatcher_links(
+    config: &Config,
+    root_db: &Db,
+    did: &DispatcherId,
+    mut pending: Vec<PendingLink>,
+) -> Vec<(usize, Result<Link, BpfmanError>)> {
+    let mut results = Vec::with_capacity(pending.len());
+
+    let result = num_attached_programs(did, root_db).and_then(|attached| {
+        // Links past the maximum fail just as they would one at a time.
+        let available = 10usize.saturating_sub(attached);
+        if pending.len() > available {
+            for p in pending.split_off(available) {
+                abort_link(root_db, &p.program, p.link);
+                results.push((p.index, Err(BpfmanError::TooManyPrograms)));
+            }
+        }
+        if pending.is_empty() {
+            return Ok(());
+        }
+        let links: Vec<Link> = pending.iter().map(|p| p.link.clone()).collect();
+        attach_dispatcher_links_internal(config, root_db, did, pending[0].program.kind(), &links)
+    });
+
+    if let Err(e) = result {
+        error!(
+            "Error: failed to attach {} links to dispatcher: {e}",
+            pending.len()
+        );
+        if let Some(p) = pending.first()
+            && let (Ok(Some(if_index)), Ok(direction), Ok(nsid)) =
+                (p.link.ifindex(), p.link.direction(), p.link.nsid())
+        {
+            let _ = set_program_positions(root_db, p.program.kind(), if_index, direction, nsid);
+        }
+        for p in pending {
+            abort_link(root_db, &p.program, p.link);
+            results.push((p.index, Err(BpfmanError::Error(e.to_string()))));
+        }
+        return results;
+    }
+
+    for PendingLink {
+        index,
+        program,
+        mut link,
+    } in pending
+    {
+        let result = link
+            .set_attached()
+            .and_then(|_| link.finalize(root_db))
+            .map(|_| link.clone());
+        match result {
+            Ok(ref link) => {
+                let link_id = link.get_id().unwrap_or_default();
+                let kind = program.kind();
+                let name = program.get_data().get_name().unwrap_or_default();
+                info!(
+                    "Success: attached {kind} program named \"{name}\" with program id {} with link {link_id}",
+                    link.get_program_id().unwrap_or_default()
+                );
+                record_event(
+                    root_db,
+                    new_event(
+                        EventKind::LinkAttached,
+                        Some(&program),
+                        Some(link_id),
+                        format!("attached {kind} program named \"{name}\" with link {link_id}"),
+                    ),
+                );
+            }
+            Err(ref e) => {
+                error!("Error: failed to attach link: {e}");
+                abort_link(root_db, &program, link);
+            }
+        }
+        results.push((index, result));
+    }
+    results
+}
+
+fn attach_dispatcher_links_internal(
+    config: &Config,
+    root_db: &Db,
+    did: &DispatcherId,
+    program_type: BpfProgType,
+    links: &[Link],
+) -> Result<(), BpfmanError> {
+    let if_index = links[0].ifindex()?;
+    let if_name = links[0].if_name()?;
+    let direction = links[0].direction()?;
+    let nsid = links[0].nsid()?;
+
+    add_and_set_link_positions(root_db, program_type, links)?;
+
+    let mut programs = get_multi_attach_links(root_db, program_type, if_index, direction, nsid)?;
+    programs.extend_from_slice(links);
+
+    let old_dispatcher = get_dispatcher(did, root_db)?;
+    let if_config = config.interfaces().as_ref().and_then(|i| i.get(&if_name));
+    let next_revision = old_dispatcher.as_ref().map_or(1, |old| old.next_revision());
+
+    Dispatcher::new(
+        root_db,
+        if_config,
+        &mut programs,
+        next_revision,
+        old_dispatcher,
+    )?;
+    record_dispatcher_event(
+        root_db,
+        program_type,
+        &if_name,
+        next_revision,
+        programs.len(),
+    );
+    Ok(())
+}
+
+fn attach_program_internal(
+    config: &Config,
+    root_db: &Db,
+    program: &Program,
+    mut link: Link,
+) -> Result<Link, BpfmanError> {
+    let program_type = program.kind();
+
+    if let Err(e) = match program {
+        Program::Xdp(_) | Program::Tc(_) => {
+            attach_multi_attach_program(root_db, program_type, &mut link, config)
+        }
+        Program::Tcx(_)
+        | Program::Tracepoint(_)
+        | Program::Kprobe(_)
+        | Program::Uprobe(_)
+        | Program::Fentry(_)
+        | Program::Fexit(_)
+        | Program::Unsupported(_) => attach_single_attach_program(root_db, &mut link),
+    } {
+        link.delete(root_db)?;
+        return Err(e);
+    }
+
+    // write it to the database from the temp tree
+    link.finalize(root_db)?;
+
+    Ok(link)
+}
+
+fn detach_program_internal(
+    config: &Config,
+    root_db: &Db,
+    mut program: Program,
+    link: Link,
+) -> Result<(), BpfmanError> {
+    let link_id = link.get_id()?;
+    let event = new_event(
+        EventKind::LinkDetached,
+        Some(&program),
+        Some(link_id),
+        format!("detached link {link_id}"),
+    );
+    match program {
+        Program::Xdp(_) | Program::Tc(_) => {
+            // A disabled link, or one whose interface was removed, is not part
+            // of the current dispatcher revision, so there is nothing to
+            // rebuild.
+            if !link.get_enabled()? || link.get_interface_missing()? {
+                program.get_data().remove_link(root_db, link)?;
+                record_event(root_db, event);
+                return Ok(());
+            }
+
+            let did = link
+                .dispatcher_id()?
+                .ok_or(BpfmanError::DispatcherNotRequired)?;
+            let program_type = program.kind();
+
+            let if_index = link.ifindex()?;
+            let if_name = link.if_name().unwrap();
+            let direction = link.direction()?;
+            let nsid = link.nsid()?;
+            let netns = link.netns()?;
+
+            program.get_data().remove_link(root_db, link)?;
+
+            detach_multi_attach_program(
+                root_db,
+                config,
+                did,
+                program_type,
+                if_index,
+                if_name,
+                direction,
+                nsid,
+                netns,
+            )?
+        }
+        Program::Tcx(_) => {
+            let if_index = link
+                .ifindex()?
+                .ok_or_else(|| BpfmanError::InvalidInterface)?;
+            let direction = link
+                .direction()?
+                .ok_or_else(|| BpfmanError::InvalidDirection)?;
+            let nsid = link.nsid()?;
+            detach_single_attach_program(root_db, &mut program, link)?;
+            set_tcx_program_positions(root_db, if_index, direction, nsid)?;
+        }
+        Program::Tracepoint(_)
+        | Program::Kprobe(_)
+        | Program::Uprobe(_)
+        | Program::Fentry(_)
+        | Program::Fexit(_)
+        | Program::Unsupported(_) => {
+            detach_single_attach_program(root_db, &mut program, link)?;
+        }
+    };
+    record_event(root_db, event);
+    Ok(())
+}
+
+/// Disables an attached link without removing it from bpfman. The link keeps
+/// its ID, priority and metadata so that it can later be restored with
+/// [`enable_link`].
+///
+/// XDP and TC links are removed from the dispatcher by building a new
+/// dispatcher revision without them, so the remaining programs on the
+/// interface keep running in order. For all other link types the kernel link
+/// is detached by removing its pin, while the attach information is retained
+/// in the database and used to attach the link again when it is enabled.
+///
+/// Disabling a link that is already disabled is not an error.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The link does not exist.
+/// * The dispatcher could not be rebuilt or the link pin could not be removed.
+///   In this case the link is left enabled.
+pub fn disable_link(config: &Config, root_db: &Db, id: u32) -> Result<Link, BpfmanError> {
+    let mut link = get_link(root_db, id)?;
+    info!("Request to disable link {id}");
+
+    if !link.get_enabled()? {
+        info!("Link {id} is already disabled");
+        return Ok(link);
+    }
+
+    // The link is not attached while its interface is missing, so only the
+    // flag needs to change.
+    if link.get_interface_missing()? {
+        link.set_enabled(false)?;
+        info!("Success: disabled link {id}");
+        return Ok(link);
+    }
+
+    let program = link.get_program(root_db)?;
+    let result = disable_link_internal(config, root_db, &program, &mut link);
+
+    match result {
+        Ok(_) => info!("Success: disabled link {id}"),
+        Err(ref e) => error!("Error: failed to disable link {id}: {e}"),
+    };
+    result.map(|_| link)
+}
+
+fn disable_link_internal(
+    config: &Config,
+    root_db: &Db,
+    program: &Program,
+    link: &mut Link,
+) -> Result<(), BpfmanError> {
+    link.set_enabled(false)?;
+
+    let result = match program {
+        Program::Xdp(_) | Program::Tc(_) => {
+            let did = link
+                .dispatcher_id()?
+                .ok_or(BpfmanError::DispatcherNotRequired)?;
+            link.set_detached()?;
+            detach_multi_attach_program(
+                root_db,
+                config,
+                did,
+                program.kind(),
+                link.ifindex()?,
+                link.if_name()?,
+                link.direction()?,
+                link.nsid()?,
+                link.netns()?,
+            )
+        }
+        Program::Tcx(_) => link.unpin().and_then(|_| {
+            set_tcx_program_positions(
+                root_db,
+                link.ifindex()?.ok_or(BpfmanError::InvalidInterface)?,
+                link.direction()?.ok_or(BpfmanError::InvalidDirection)?,
+                link.nsid()?,
+            )
+        }),
+        _ => link.unpin(),
+    };
+
+    if result.is_err() {
+        link.set_enabled(true)?;
+        if let Program::Xdp(_) | Program::Tc(_) = program {
+            link.set_attached()?;
+            set_program_positions(
+                root_db,
+                program.kind(),
+                link.ifindex()?.ok_or(BpfmanError::InvalidInterface)?,
+                link.direction()?,
+                link.nsid()?,
+            )?;
+        }
+    }
+    result
+}
+
+/// Enables a link that was previously disabled with [`disable_link`]. XDP and
+/// TC links are placed back into the dispatcher according to their priority,
+/// and all other link types are attached to the kernel again using the attach
+/// information stored for the link. The link ID is preserved.
+///
+/// Enabling a link that is already enabled is not an error.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The link does not exist.
+/// * The program could not be attached. In this case the link is left
+///   disabled.
+pub fn enable_link(config: &Config, root_db: &Db, id: u32) -> Result<Link, BpfmanError> {
+    let mut link = get_link(root_db, id)?;
+    info!("Request to enable link {id}");
+
+    if link.get_enabled()? {
+        info!("Link {id} is already enabled");
+        return Ok(link);
+    }
+
+    // The link is attached when its interface reappears.
+    if link.get_interface_missing()? {
+        link.set_enabled(true)?;
+        info!("Success: enabled link {id}");
+        return Ok(link);
+    }
+
+    let program = link.get_program(root_db)?;
+    let program_type = program.kind();
+
+    // The link is only marked as enabled once it has been attached, so the
+    // position calculations below treat it as a new link.
+    let result = match program {
+        Program::Xdp(_) | Program::Tc(_) => {
+            attach_multi_attach_program(root_db, program_type, &mut link, config).inspect_err(
+                |_| {
+                    if let Ok(Some(if_index)) = link.ifindex() {
+                        let _ = set_program_positions(
+                            root_db,
+                            program_type,
+                            if_index,
+                            link.direction().unwrap_or(None),
+                            link.nsid().unwrap_or_default(),
+                        );
+                    }
+                },
+            )
+        }
+        Program::Tcx(_) => attach_single_attach_program(root_db, &mut link).inspect_err(|_| {
+            if let (Ok(Some(if_index)), Ok(Some(direction))) = (link.ifindex(), link.direction()) {
+                let _ = set_tcx_program_positions(
+                    root_db,
+                    if_index,
+                    direction,
+                    link.nsid().unwrap_or_default(),
+                );
+            }
+        }),
+        _ => attach_single_attach_program(root_db, &mut link),
+    };
+
+    match result {
+        Ok(_) => {
+            link.set_enabled(true)?;
+            info!("Success: enabled link {id}");
+        }
+        Err(ref e) => error!("Error: failed to enable link {id}: {e}"),
+    };
+    result.map(|_| link)
+}
+
+/// Changes the priority of an attached XDP, TC or TCX link without detaching
+/// it. The link ID and metadata are preserved and the positions of all links
+/// on the interface are recomputed.
+///
+/// XDP and TC links are reordered by building a new dispatcher revision which
+/// replaces the existing one, so none of the programs on the interface stop
+/// running. TCX links are detached and attached again next to the link that
+/// now precedes or follows them. If the link is disabled only the stored
+/// priority is updated, and the link is placed accordingly when it is enabled.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The link does not exist or is not an XDP, TC or TCX link.
+/// * The priority is not between 1 and 1000.
+/// * The dispatcher could not be rebuilt or the TCX link could not be attached
+///   again. In this case the previous priority is restored.
+pub fn set_link_priority(
+    config: &Config,
+    root_db: &Db,
+    id: u32,
+    priority: i32,
+) -> Result<Link, BpfmanError> {
+    let mut link = get_link(root_db, id)?;
+    info!("Request to set priority of link {id} to {priority}");
+
+    let result = set_link_priority_internal(config, root_db, &mut link, priority);
+
+    match result {
+        Ok(_) => info!("Success: set priority of link {id} to {priority}"),
+        Err(ref e) => error!("Error: failed to set priority of link {id}: {e}"),
+    };
+    result.map(|_| link)
+}
+
+fn set_link_priority_internal(
+    config: &Config,
+    root_db: &Db,
+    link: &mut Link,
+    priority: i32,
+) -> Result<(), BpfmanError> {
+    if !(1..=1000).contains(&priority) {
+        return Err(BpfmanError::InvalidPriority(priority));
+    }
+
+    let old_priority = match link {
+        Link::Xdp(_) | Link::Tc(_) | Link::Tcx(_) => link.priority()?,
+        _ => {
+            return Err(BpfmanError::InvalidAttach(
+                "priority can only be set on xdp, tc and tcx links".to_string(),
+            ));
+        }
+    };
+    if old_priority == priority {
+        return Ok(());
+    }
+
+    link.set_priority(priority)?;
+    if !link.get_enabled()? || link.get_interface_missing()? {
+        return Ok(());
+    }
+
+    let result = reorder_link(config, root_db, link);
+    if result.is_err() {
+        link.set_priority(old_priority)?;
+        if let Err(e) = reorder_link(config, root_db, link) {
+            warn!(
+                "Unable to restore link {} to priority {old_priority}: {e}",
+                link.get_id()?
+            );
+            // The tcx link is no longer attached, so record it as disabled
+            // so that it can be attached again with `enable_link`.
+            if let Link::Tcx(_) = link {
+                link.set_enabled(false)?;
+            }
+        }
+    }
+    result
+}
+
+// Moves an attached link to the position given by its current priority.
+fn reorder_link(config: &Config, root_db: &Db, link: &mut Link) -> Result<(), BpfmanError> {
+    match link {
+        Link::Xdp(_) | Link::Tc(_) => {
+            let did = link
+                .dispatcher_id()?
+                .ok_or(BpfmanError::DispatcherNotRequired)?;
+            let program_type = link.get_program(root_db)?.kind();
+            let old_dispatcher = get_dispatcher(&did, root_db)?;
+            rebuild_dispatcher(
+                root_db,
+                config,
+                program_type,
+                link.ifindex()?,
+                &link.if_name()?,
+                link.direction()?,
+                link.nsid()?,
+                old_dispatcher,
+            )
+        }
+        Link::Tcx(_) => {
+            // The kernel does not allow a tcx link to be moved, so the link is
+            // replaced. While that happens it is treated as disabled so that
+            // it is not used as its own anchor.
+            link.unpin()?;
+            link.set_enabled(false)?;
+            let result = attach_single_attach_program(root_db, link);
+            link.set_enabled(true)?;
+            if result.is_err() {
+                set_tcx_program_positions(
+                    root_db,
+                    link.ifindex()?.ok_or(BpfmanError::InvalidInterface)?,
+                    link.direction()?.ok_or(BpfmanError::InvalidDirection)?,
+                    link.nsid()?,
+                )?;
+            }
+            result
+        }
+        _ => Err(BpfmanError::InvalidAttach(
+            "priority can only be set on xdp, tc and tcx links".to_string(),
+        )),
+    }
+}
+
+/// Attaches an XDP, TC or TCX program to every interface matched by an
+/// [`InterfaceSelector`], creating a named link group.
+///
+/// The `attach_info` is used as a template for each member of the group, with
+/// its interface replaced by each of the selected interfaces. For the `Glob`,
+/// `Regex` and `Physical` selectors the interfaces are selected from the
+/// network namespace in `attach_info`, while the `Netns` selector provides its
+/// own namespaces.
+///
+/// A failure to attach to one interface does not stop the others from being
+/// attached; the result for each interface is recorded as a
+/// [`LinkGroupMember`]. Each link in the group is a regular link which can be
+/// managed on its own, and the group is removed once none of its links are
+/// left.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * A link group with the same name already exists.
+/// * The selector does not match any interfaces.
+/// * The program could not be attached to any of the selected interfaces.
+pub fn attach_link_group(
+    config: &Config,
+    root_db: &Db,
+    id: u32,
+    name: &str,
+    selector: InterfaceSelector,
+    attach_info: AttachInfo,
+) -> Result<LinkGroup, BpfmanError> {
+    info!("Request to attach program {id} to link group {name} with selector {selector}");
+
+    // Every link of the group gets the metadata of the attach info.
+    if attach_info.metadata().contains_key(METADATA_ALIAS_TAG) {
+        return Err(BpfmanError::InvalidAttach(
+            "an alias can't be given to the links of a link group".to_string(),
+        ));
+    }
+
+    let interfaces = select_interfaces(&selector, attach_info.netns())?;
+    if interfaces.is_empty() {
+        return Err(BpfmanError::InvalidAttach(format!(
+            "no interfaces match selector {selector}"
+        )));
+    }
+
+    let group = LinkGroup::new(root_db, name, id, &selector)?;
+    let mut first_error = None;
+    let mut attached = 0;
+    for (iface, netns) in interfaces {
+        let result = attach_info
+            .with_interface(iface.clone(), netns.clone())
+            .and_then(|info| attach_program(config, root_db, id, info))
+            .and_then(|mut link| {
+                link.set_group(name)?;
+                link.get_id()
+            });
+        let member = match result {
+            Ok(link_id) => {
+                attached += 1;
+                LinkGroupMember {
+                    iface,
+                    netns,
+                    link_id: Some(link_id),
+                    error: None,
+                }
+            }
+            Err(e) => {
+                warn!("Unable to attach link group {name} to {iface}: {e}");
+                let error = e.to_string();
+                first_error.get_or_insert(e);
+                LinkGroupMember {
+                    iface,
+                    netns,
+                    link_id: None,
+                    error: Some(error),
+                }
+            }
+        };
+        group.add_member(&member)?;
+    }
+
+    if attached == 0 {
+        group.delete(root_db)?;
+        let e = first_error.expect("at least one interface failed to attach");
+        error!("Error: failed to attach link group {name}: {e}");
+        return Err(e);
+    }
+
+    info!("Success: attached link group {name} with {attached} links");
+    Ok(group)
+}
+
+/// Detaches all the links in a link group and removes the group.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The link group does not exist.
+/// * One of the links could not be detached. The links that were detached
+///   before the failure are removed from the group.
+pub fn detach_link_group(config: &Config, root_db: &Db, name: &str) -> Result<(), BpfmanError> {
+    info!("Request to detach link group {name}");
+    let group = LinkGroup::open(root_db, name)?;
+
+    for member in group.get_members()? {
+        if let Some(link_id) = member.link_id {
+            detach(config, root_db, link_id).inspect_err(|e| {
+                error!("Error: failed to detach link {link_id} of link group {name}: {e}")
+            })?;
+        }
+    }
+    group.delete(root_db)?;
+
+    info!("Success: detached link group {name}");
+    Ok(())
+}
+
+/// Retrieves a link group by name.
+///
+/// # Errors
+///
+/// This function will return an error if the link group does not exist.
+pub fn get_link_group(root_db: &Db, name: &str) -> Result<LinkGroup, BpfmanError> {
+    LinkGroup::open(root_db, name)
+}
+
+/// Sets the [`ReattachPolicy`] of an XDP, TC or TCX link, which decides
+/// whether the link is attached again when its interface is removed and an
+/// interface with the same name is created. The policy is applied by
+/// [`reconcile_interfaces`] the next time the interfaces change.
+///
+/// # Errors
+///
+/// This function will return an error if the link does not exist or is not an
+/// XDP, TC or TCX link.
+pub fn set_link_reattach_policy(
+    root_db: &Db,
+    id: u32,
+    policy: ReattachPolicy,
+) -> Result<Link, BpfmanError> {
+    let mut link = get_link(root_db, id)?;
+    info!("Request to set reattach policy of link {id} to {policy}");
+    link.set_reattach_policy(policy)?;
+    info!("Success: set reattach policy of link {id} to {policy}");
+    Ok(link)
+}
+
+/// Returns the network namespaces that contain the interfaces of XDP, TC and
+/// TCX links. `None` is the bpfman network namespace, which is always
+/// included. Namespaces that no longer exist are left out.
+///
+/// # Errors
+///
+/// This function will return an error if the links could not be read from the
+/// database.
+pub fn get_link_namespaces(root_db: &Db) -> Result<Vec<Option<PathBuf>>, BpfmanError> {
+    let mut namespaces = vec![None];
+    for link in get_interface_links(root_db)? {
+        let netns = link.netns()?;
+        if netns.as_ref().is_some_and(|n| n.exists()) && !namespaces.contains(&netns) {
+            namespaces.push(netns);
+        }
+    }
+    Ok(namespaces)
+}
+
+/// Updates the XDP, TC and TCX links in a network namespace to match the
+/// interfaces that currently exist there, where `None` is the bpfman network
+/// namespace. This is called by bpfman-rpc whenever it sees an interface
+/// being added or removed, and is safe to call at any time.
+///
+/// A link whose interface no longer exists is marked with
+/// [`Link::get_interface_missing`] and left in the database. For XDP and TC
+/// the dispatcher of the removed interface is deleted, and for TCX the link
+/// pin is removed.
+///
+/// A link that is marked as missing and has a [`ReattachPolicy::ByName`]
+/// policy is attached again once an interface with the same name exists. It
+/// keeps its ID, priority and metadata. If the link could not be attached it
+/// stays marked as missing. Disabled links only have their interface index
+/// updated and are attached when they are enabled.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// * The network namespace does not exist or its interfaces could not be
+///   listed.
+/// * The links could not be read from or written to the database.
+pub fn reconcile_interfaces(
+    config: &Config,
+    root_db: &Db,
+    netns: Option<PathBuf>,
+) -> Result<(), BpfmanError> {
+    let nsid = utils::nsid(netns.clone())?;
+    let interfaces: HashMap<String, u32> = utils::interfaces(netns)?.into_iter().collect();
+    let mut links = get_interface_links(root_db)?
+        .into_iter()
+        .filter(|l| l.nsid().is_ok_and(|n| n == nsid))
+        .collect::<Vec<_>>();
+
+    // Detach the links of interfaces that were removed.
+    let mut removed_dispatchers = HashSet::new();
+    for link in links.iter_mut() {
+        if link.get_interface_missing()? {
+            continue;
+        }
+        let if_index = link.ifindex()?.ok_or(BpfmanError::InvalidInterface)?;
+        if interfaces.values().any(|i| *i == if_index) {
+            continue;
+        }
+        info!(
+            "Interface {} of link {} was removed",
+            link.if_name()?,
+            link.get_id()?
+        );
+        match link {
+            Link::Xdp(_) | Link::Tc(_) => {
+                if let Some(did) = link.dispatcher_id()? {
+                    removed_dispatchers.insert(did);
+                }
+                link.set_detached()?;
+            }
+            _ => {
+                if let Err(e) = link.unpin() {
+                    warn!("Unable to unpin link {}: {e}", link.get_id()?);
+                }
+            }
+        }
+        link.set_interface_missing(true)?;
+        record_event(
+            root_db,
+            new_event(
+                EventKind::LinkDetached,
+                link.get_program(root_db).ok().as_ref(),
+                Some(link.get_id()?),
+                format!("interface {} was removed", link.if_name()?),
+            ),
+        );
+    }
+    for did in removed_dispatchers {
+        if let Some(mut dispatcher) = get_dispatcher(&did, root_db)?
+            && let Err(e) = dispatcher.delete(root_db, true)
+        {
+            warn!("Unable to delete dispatcher of removed interface: {e}");
+        }
+    }
+
+    // Attach the links of interfaces that were recreated.
+    let mut dispatchers: HashMap<DispatcherId, Vec<Link>> = HashMap::new();
+    let mut tcx_links = Vec::new();
+    for mut link in links {
+        if !link.get_interface_missing()? || link.get_reattach_policy()? != ReattachPolicy::ByName {
+            continue;
+        }
+        let Some(if_index) = interfaces.get(&link.if_name()?) else {
+            continue;
+        };
+        info!(
+            "Interface {} of link {} was recreated",
+            link.if_name()?,
+            link.get_id()?
+        );
+        link.set_ifindex(*if_index)?;
+        if !link.get_enabled()? {
+            link.set_interface_missing(false)?;
+            continue;
+        }
+        match link {
+            Link::Xdp(_) | Link::Tc(_) => {
+                link.set_interface_missing(false)?;
+                let did = link
+                    .dispatcher_id()?
+                    .ok_or(BpfmanError::DispatcherNotRequired)?;
+                dispatchers.entry(did).or_default().push(link);
+            }
+            _ => tcx_links.push(link),
+        }
+    }
+
+    for (did, mut links) in dispatchers {
+        let link = &links[0];
+        let program_type = match link {
+            Link::Xdp(_) => BpfProgType::Xdp,
+            _ => BpfProgType::Tc,
+        };
+        let if_index = link.ifindex()?;
+        let if_name = link.if_name()?;
+        let direction = link.direction()?;
+        let old_dispatcher = get_dispatcher(&did, root_db)?;
+        if let Err(e) = rebuild_dispatcher(
+            root_db,
+            config,
+            program_type,
+            if_index,
+            &if_name,
+            direction,
+            nsid,
+            old_dispatcher,
+        ) {
+            warn!("Unable to reattach links to interface {if_name}: {e}");
+            for link in links.iter_mut() {
+                link.set_interface_missing(true)?;
+            }
+            set_program_positions(
+                root_db,
+                program_type,
+                if_index.ok_or(BpfmanError::InvalidInterface)?,
+                direction,
+                nsid,
+            )?;
+        } else {
+            for link in links.iter() {
+                record_reattach_event(root_db, link)?;
+            }
+        }
+    }
+
+    // Tcx links are attached one at a time in priority order, so that each
+    // one can be anchored to the links already attached. Until a link is
+    // attached it stays marked as missing so it is not used as an anchor.
+    tcx_links.sort_by_key(|l| l.priority().unwrap_or_default());
+    let mut tcx_interfaces = HashSet::new();
+    for mut link in tcx_links {
+        let if_index = link.ifindex()?.ok_or(BpfmanError::InvalidInterface)?;
+        let direction = link.direction()?.ok_or(BpfmanError::InvalidDirection)?;
+        tcx_interfaces.insert((if_index, direction));
+        match attach_single_attach_program(root_db, &mut link) {
+            Ok(_) => {
+                link.set_interface_missing(false)?;
+                record_reattach_event(root_db, &link)?;
+            }
+            Err(e) => warn!(
+                "Unable to reattach link {} to interface {}: {e}",
+                link.get_id()?,
+                link.if_name()?
+            ),
+        }
+    }
+    for (if_index, direction) in tcx_interfaces {
+        set_tcx_program_positions(root_db, if_index, direction, nsid)?;
+    }
+
+    Ok(())
+}
+
+// Records that a link was attached to a recreated interface.
+fn record_reattach_event(root_db: &Db, link: &Link) -> Result<(), BpfmanError> {
+    record_event(
+        root_db,
+        new_event(
+            EventKind::LinkAttached,
+            link.get_program(root_db).ok().as_ref(),
+            Some(link.get_id()?),
+            format!("interface {} was recreated", link.if_name()?),
+        ),
+    );
+    Ok(())
+}
+
+/// Checks that the programs and links in the bpfman database still match the
+/// state of the kernel, and returns a [`Drift`] for each difference found.
+///
+/// For every program bpfman checks that it is still loaded and pinned, and
+/// that the maps it owns are still pinned. For every enabled link it checks
+/// that the interface of XDP, TC and TCX links still exists, that the
+/// dispatcher of XDP and TC links is still attached to the interface, and that
+/// the pin of all other links still refers to a live link.
+///
+/// If `repair` is true bpfman also tries to repair each drift:
+/// * A program that is no longer loaded is removed along with its links.
+/// * A link whose pin no longer refers to a live link is attached again.
+/// * A dispatcher that is no longer attached is rebuilt and attached again.
+/// * A link whose interface no longer exists is marked as detached, as
+///   described in [`reconcile_interfaces`].
+///
+/// Missing program and map pins cannot be repaired without loading the
+/// program again.
+///
+/// # Errors
+///
+/// This function will return an error if the programs and links could not be
+/// read from the database. Repairs that fail are reported in the returned
+/// [`Drift`]s instead.
+pub fn verify(config: &Config, root_db: &Db, repair: bool) -> Result<Vec<Drift>, BpfmanError> {
+    verify_with(config, root_db, repair, |_| true)
+}
+
+/// Checks the bpfman state like [`verify`], but only repairs a drift if
+/// `may_repair` accepts every program whose links the repair changes. Other
+/// drifts are reported with [`RepairStatus::NotPermitted`].
+///
+/// Removing a program or attaching one of its links again only changes that
+/// program, but rebuilding a dispatcher changes every program attached to it
+/// and reconciling the interfaces of a network namespace changes every program
+/// attached to an interface in it.
+///
+/// # Errors
+///
+/// This function will return an error if the programs and links could not be
+/// read from the database.
+pub fn verify_with(
+    config: &Config,
+    root_db: &Db,
+    repair: bool,
+    may_repair: impl Fn(&Program) -> bool,
+) -> Result<Vec<Drift>, BpfmanError> {
+    info!("Request to verify bpfman state");
+
+    let loaded: HashSet<u32> = loaded_programs().flatten().map(|p| p.id()).collect();
+    let mut interfaces: HashMap<Option<PathBuf>, HashSet<u32>> = HashMap::new();
+    let mut dispatchers: HashMap<DispatcherId, bool> = HashMap::new();
+    let mut found = Vec::new();
+
+    for (program_id, program) in get_programs_iter(root_db).collect::<Vec<_>>() {
+        if !loaded.contains(&program_id) {
+            found.push((
+                program_id,
+                None,
+                DriftKind::ProgramNotLoaded,
+                Repair::RemoveProgram(program),
+            ));
+            continue;
+        }
+
+        let path = PathBuf::from(format!("{RTDIR_FS}/prog_{program_id}"));
+        if !path.exists() {
+            found.push((
+                program_id,
+                None,
+                DriftKind::ProgramPinMissing { path },
+                Repair::NotRepairable,
+            ));
+        }
+
+        if program.get_data().get_map_owner_id()?.is_none()
+            && let Some(map_pin_path) = program.get_data().get_map_pin_path()?
+        {
+            for path in missing_map_pins(&map_pin_path) {
+                found.push((
+                    program_id,
+                    None,
+                    DriftKind::MapPinMissing { path },
+                    Repair::NotRepairable,
+                ));
+            }
+        }
+
+        for link in program.get_data().get_links(root_db)? {
+            if !link.get_enabled()? || link.get_interface_missing()? {
+                continue;
+            }
+            let link_id = link.get_id()?;
+
+            if let Link::Xdp(_) | Link::Tc(_) | Link::Tcx(_) = link {
+                let netns = link.netns()?;
+                // Links in a deleted network namespace are cleaned up when
+                // they are detached.
+                if netns.as_ref().is_some_and(|n| !n.exists()) {
+                    continue;
+                }
+                if !interfaces.contains_key(&netns) {
+                    let indexes = utils::interfaces(netns.clone())?
+                        .into_iter()
+                        .map(|(_, i)| i)
+                        .collect();
+                    interfaces.insert(netns.clone(), indexes);
+                }
+                if link
+                    .ifindex()?
+                    .is_none_or(|i| !interfaces[&netns].contains(&i))
+                {
+                    found.push((
+                        program_id,
+                        Some(link_id),
+                        DriftKind::InterfaceMissing {
+                            iface: link.if_name()?,
+                        },
+                        Repair::Reconcile(netns),
+                    ));
+                    continue;
+                }
+            }
+
+            match link {
+                Link::Xdp(_) | Link::Tc(_) => {
+                    let did = link
+                        .dispatcher_id()?
+                        .ok_or(BpfmanError::DispatcherNotRequired)?;
+                    if !dispatchers.contains_key(&did) {
+                        let attached = match get_dispatcher(&did, root_db)? {
+                            Some(d) => d.is_attached(link.netns()?).unwrap_or_else(|e| {
+                                warn!("Unable to check dispatcher of link {link_id}: {e}");
+                                true
+                            }),
+                            None => false,
+                        };
+                        dispatchers.insert(did.clone(), attached);
+                    }
+                    if !dispatchers[&did] {
+                        found.push((
+                            program_id,
+                            Some(link_id),
+                            DriftKind::DispatcherDetached {
+                                iface: link.if_name()?,
+                            },
+                            Repair::RebuildDispatcher(did, link),
+                        ));
+                    }
+                }
+                _ => {
+                    let path = PathBuf::from(format!("{RTDIR_FS_LINKS}/{link_id}"));
+                    if PinnedLink::from_pin(&path).is_err() {
+                        found.push((
+                            program_id,
+                            Some(link_id),
+                            DriftKind::LinkPinMissing { path },
+                            Repair::ReattachLink(link),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    let mut rebuilt = HashMap::new();
+    let mut reconciled = HashMap::new();
+    let mut drifts = Vec::new();
+    for (program_id, link_id, kind, action) in found {
+        warn!(
+            "Drift in program {program_id}{}: {kind}",
+            link_id.map(|id| format!(" link {id}")).unwrap_or_default()
+        );
+        let mut event = new_event(
+            EventKind::DriftDetected,
+            get(root_db, &program_id).as_ref(),
+            link_id,
+            kind.to_string(),
+        );
+        // Only repair drifts in programs the caller may change.
+        let permitted = if repair {
+            repaired_programs(root_db, &action).map(|programs| programs.iter().all(&may_repair))
+        } else {
+            Ok(true)
+        };
+        let repair = match (action, permitted) {
+            _ if !repair => RepairStatus::NotAttempted,
+            (_, Err(e)) => RepairStatus::Failed(e.to_string()),
+            (_, Ok(false)) => RepairStatus::NotPermitted,
+            (Repair::NotRepairable, _) => RepairStatus::NotRepairable,
+            (Repair::RemoveProgram(program), _) => repair_status(remove_program_internal(
+                program_id, config, root_db, program,
+            )),
+            (Repair::ReattachLink(mut link), _) => repair_status(match link {
+                Link::Tcx(_) => reorder_link(config, root_db, &mut link),
+                _ => link
+                    .unpin()
+                    .and_then(|_| attach_single_attach_program(root_db, &mut link)),
+            }),
+            (Repair::RebuildDispatcher(did, link), _) => rebuilt
+                .entry(did.clone())
+                .or_insert_with(|| repair_status(repair_dispatcher(config, root_db, &did, &link)))
+                .clone(),
+            (Repair::Reconcile(netns), _) => reconciled
+                .entry(netns.clone())
+                .or_insert_with(|| repair_status(reconcile_interfaces(config, root_db, netns)))
+                .clone(),
+        };
+        event.detail = format!("{}, repair {repair}", event.detail);
+        record_event(root_db, event);
+        drifts.push(Drift {
+            program_id,
+            link_id,
+            kind,
+            repair,
+        });
+    }
+
+    info!(
+        "Success: verified bpfman state, found {} drifts",
+        drifts.len()
+    );
+    Ok(drifts)
+}
+
+// How a drift found by `verify` is repaired.
+enum Repair {
+    NotRepairable,
+    RemoveProgram(Program),
+    ReattachLink(Link),
+    RebuildDispatcher(DispatcherId, Link),
+    Reconcile(Option<PathBuf>),
+}
+
+// Returns the programs whose links a repair changes.
+fn repaired_programs(root_db: &Db, action: &Repair) -> Result<Vec<Program>, BpfmanError> {
+    let links = match action {
+        Repair::NotRepairable => return Ok(vec![]),
+        Repair::RemoveProgram(program) => return Ok(vec![program.clone()]),
+        Repair::ReattachLink(link) => vec![link.clone()],
+        Repair::RebuildDispatcher(_, link) => get_multi_attach_links(
+            root_db,
+            link.get_program(root_db)?.kind(),
+            link.ifindex()?,
+            link.direction()?,
+            link.nsid()?,
+        )?,
+        Repair::Reconcile(netns) => {
+            let nsid = utils::nsid(netns.clone())?;
+            get_interface_links(root_db)?
+                .into_iter()
+                .filter(|l| l.nsid().is_ok_and(|n| n == nsid))
+                .collect()
+        }
+    };
+    links.iter().map(|l| l.get_program(root_db)).collect()
+}
+
+fn repair_status(result: Result<(), BpfmanError>) -> RepairStatus {
+    match result {
+        Ok(()) => RepairStatus::Repaired,
+        Err(e) => RepairStatus::Failed(e.to_string()),
+    }
+}
+
+// Returns the pins in a map pin directory that no longer refer to a map, or the
+// directory itself if it has been removed.
+fn missing_map_pins(map_pin_path: &Path) -> Vec<PathBuf> {
+    match std::fs::read_dir(map_pin_path) {
+        Ok(entries) => entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| MapData::from_pin(p).is_err())
+            .collect(),
+        Err(_) => vec![map_pin_path.to_path_buf()],
+    }
+}
+
+// Replaces a dispatcher that is no longer attached to its interface with a new
+// one containing the same links.
+fn repair_dispatcher(
+    config: &Config,
+    root_db: &Db,
+    did: &DispatcherId,
+    link: &Link,
+) -> Result<(), BpfmanError> {
+    if let Some(mut old) = get_dispatcher(did, root_db)?
+        && let Err(e) = old.delete(root_db, true)
+    {
+        debug!("Unable to fully delete detached dispatcher: {e}");
+    }
+    rebuild_dispatcher(
+        root_db,
+        config,
+        link.get_program(root_db)?.kind(),
+        link.ifindex()?,
+        &link.if_name()?,
+        link.direction()?,
+        link.nsid()?,
+        None,
+    )
+}
+
+// Returns all the XDP, TC and TCX links in the database.
+fn get_interface_links(root_db: &Db) -> Result<Vec<Link>, BpfmanError> {
+    let mut links = Vec::new();
+    for p in root_db.tree_names() {
+        if !bytes_to_string(&p).starts_with(LINKS_LINK_PREFIX) {
+            continue;
+        }
+        let tree = root_db.open_tree(p).map_err(|e| {
+            BpfmanError::DatabaseError("Unable to open database tree".to_string(), e.to_string())
+        })?;
+        if let Ok(link @ (Link::Xdp(_) | Link::Tc(_) | Link::Tcx(_))) = Link::new_from_db(tree) {
+            links.push(link);
+        }
+    }
+    Ok(links)
+}
+
+/// Lists the currently loaded eBPF programs.
+///
+/// This function fetches the list of all eBPF programs loaded in the
+/// system, combining those managed by `bpfman` with any other loaded
+/// eBPF programs obtained from Aya. It returns a vector of `Program`
+/// objects that match the provided filter. Use [`list_programs_page`] to get
+/// the programs one page at a time.
+///
+/// # Arguments
 ///
 /// * `filter` - A `ListFilter` used to filter the programs based on the caller's criteria.
 ///
@@ -631,22 +2281,50 @@ fn detach_program_internal(
 /// }
 /// ```
 pub fn list_programs(root_db: &Db, filter: ListFilter) -> Result<Vec<Program>, BpfmanError> {
+    list_programs_page(root_db, filter).map(|page| page.programs)
+}
+
+/// A page of the programs listed by [`list_programs_page`].
+#[derive(Debug)]
+pub struct ProgramPage {
+    /// The programs on the page, in the order requested by the filter.
+    pub programs: Vec<Program>,
+    /// The token to pass to [`ListFilter::with_page`] to get the next page,
+    /// if there are more programs.
+    pub next_page_token: Option<String>,
+}
+
+/// Lists the currently loaded eBPF programs that match the filter, sorted
+/// and limited to the page selected by [`ListFilter::with_sort_by`] and
+/// [`ListFilter::with_page`].
+///
+/// Every loaded program is only read from the kernel when the filter can
+/// match programs that weren't loaded by bpfman. Otherwise only the programs in
+/// the database are looked up by ID, so listing bpfman programs stays fast on
+/// hosts with many other eBPF programs.
+///
+/// # Errors
+///
+/// Returns `BpfmanError::BpfParseError` if the page token is invalid or was
+/// created with a different sort order.
+pub fn list_programs_page(root_db: &Db, filter: ListFilter) -> Result<ProgramPage, BpfmanError> {
     debug!("BpfManager::list_programs()");
 
     // Get an iterator for the bpfman load programs, a hash map indexed by program id.
     let mut bpfman_progs: HashMap<u32, Program> = get_programs_iter(root_db).collect();
+    let start_id = filter.page_start_id();
+
+    let programs = if filter.includes_unsupported() {
+        // Call Aya to get ALL the loaded eBPF programs, and loop through each one.
+        loaded_programs()
+            .filter_map(|p| p.ok())
+            .filter(|prog| start_id.is_none_or(|id| prog.id() > id))
+            .map(|prog| {
+                let prog_id: u32 = prog.id();
 
-    // Call Aya to get ALL the loaded eBPF programs, and loop through each one.
-    Ok(loaded_programs()
-        .filter_map(|p| p.ok())
-        .map(|prog| {
-            let prog_id: u32 = prog.id();
-
-            // If the program was loaded by bpfman (check the hash map), then use it.
-            // Otherwise, convert the data returned from Aya into an Unsupported Program Object.
-            match bpfman_progs.remove(&prog_id) {
-                Some(p) => p.to_owned(),
-                None => {
+                // If the program was loaded by bpfman (check the hash map), then use it.
+                // Otherwise, convert the data returned from Aya into an Unsupported Program Object.
+                bpfman_progs.remove(&prog_id).unwrap_or_else(|| {
                     let db_tree = root_db
                         .open_tree(prog_id.to_string())
                         .expect("Unable to open program database tree for listing programs");
@@ -657,11 +2335,26 @@ pub fn list_programs(root_db: &Db, filter: ListFilter) -> Result<Vec<Program>, B
                     };
 
                     Program::Unsupported(data)
-                }
-            }
-        })
-        .filter(|p| filter.matches(p))
-        .collect())
+                })
+            })
+            .filter(|p| filter.matches(root_db, p))
+            .collect()
+    } else {
+        // Only the programs loaded by bpfman can match, so look up each of them
+        // by ID rather than walking every program loaded in the kernel.
+        bpfman_progs
+            .into_iter()
+            .filter(|(id, _)| start_id.is_none_or(|start| *id > start) && program_loaded(*id))
+            .map(|(_, p)| p)
+            .filter(|p| filter.matches(root_db, p))
+            .collect()
+    };
+
+    let (programs, next_page_token) = filter.paginate(programs)?;
+    Ok(ProgramPage {
+        programs,
+        next_page_token,
+    })
 }
 
 /// Retrieves information about a currently loaded eBPF program.
@@ -698,8 +2391,8 @@ pub fn list_programs(root_db: &Db, filter: ListFilter) -> Result<Vec<Program>, B
 ///   database tree for the program.
 /// * `BpfmanError::KernelInfoError` - If there is an issue setting
 ///   the kernel information for the program data.
-/// * `BpfmanError::Error` - If the program with the specified `id`
-///   does not exist.
+/// * `BpfmanError::ProgramNotFound` - If the program with the specified
+///   `id` does not exist.
 ///
 /// # Examples
 ///
@@ -738,10 +2431,7 @@ pub fn get_program(root_db: &Db, id: u32) -> Result<Program, BpfmanError> {
                     None
                 }
             })
-            .ok_or(BpfmanError::Error(format!(
-                "Program {0} does not exist",
-                id
-            ))),
+            .ok_or(BpfmanError::ProgramNotFound(id)),
     }
 }
 
@@ -779,7 +2469,7 @@ pub fn get_program(root_db: &Db, id: u32) -> Result<Program, BpfmanError> {
 ///   database tree for the program.
 /// * `BpfmanError::KernelInfoError` - If there is an issue setting
 ///   the kernel information for the program data.
-/// * `BpfmanError::Error` - If the program with the specified `id`
+/// * `BpfmanError::LinkNotFound` - If the link with the specified `id`
 ///   does not exist.
 ///
 /// # Examples
@@ -795,13 +2485,266 @@ pub fn get_program(root_db: &Db, id: u32) -> Result<Program, BpfmanError> {
 ///
 /// ```
 pub fn get_link(root_db: &Db, id: u32) -> Result<Link, BpfmanError> {
+    // Opening a tree creates it, so check that the link exists first.
+    let link_tree: sled::IVec = format!("{LINKS_LINK_PREFIX}{id}").as_bytes().into();
+    if !root_db.tree_names().contains(&link_tree) {
+        return Err(BpfmanError::LinkNotFound(id));
+    }
     let tree = root_db
-        .open_tree(format!("{LINKS_LINK_PREFIX}{id}"))
+        .open_tree(link_tree)
         .expect("Unable to open link database tree");
     let link = Link::new_from_db(tree)?;
     Ok(link)
 }
 
+/// Returns the ID of the program loaded by bpfman that `program` refers to,
+/// either directly or by its alias.
+///
+/// # Errors
+///
+/// Returns `BpfmanError::AliasNotFound` if no program has the alias.
+pub fn resolve_program(root_db: &Db, program: &IdOrAlias) -> Result<u32, BpfmanError> {
+    match program {
+        IdOrAlias::Id(id) => Ok(*id),
+        IdOrAlias::Alias(alias) => get_programs_iter(root_db)
+            .find(|(_, p)| p.get_data().get_alias().as_ref() == Some(alias))
+            .map(|(id, _)| id)
+            .ok_or_else(|| BpfmanError::AliasNotFound {
+                kind: "program".to_string(),
+                alias: alias.clone(),
+            }),
+    }
+}
+
+/// Returns the ID of the link that `link` refers to, either directly or by
+/// its alias.
+///
+/// # Errors
+///
+/// Returns `BpfmanError::AliasNotFound` if no link has the alias.
+pub fn resolve_link(root_db: &Db, link: &IdOrAlias) -> Result<u32, BpfmanError> {
+    match link {
+        IdOrAlias::Id(id) => Ok(*id),
+        IdOrAlias::Alias(alias) => {
+            let filter = LinkListFilter::new(
+                None,
+                None,
+                None,
+                HashMap::from([(METADATA_ALIAS_TAG.to_string(), alias.clone())]),
+            );
+            list_links(root_db, filter)?
+                .first()
+                .map(|l| l.get_id())
+                .transpose()?
+                .ok_or_else(|| BpfmanError::AliasNotFound {
+                    kind: "link".to_string(),
+                    alias: alias.clone(),
+                })
+        }
+    }
+}
+
+/// Returns the programs loaded by bpfman whose metadata matches a set-based
+/// selector, such as `application=TestEbpfApp` or `env in (prod,staging)`.
+/// The `application` key matches the application the programs were loaded
+/// with.
+pub fn select_programs(root_db: &Db, selector: &str) -> Result<Vec<Program>, BpfmanError> {
+    let filter = ListFilter::new(None, HashMap::new(), true)
+        .with_metadata_requirements(parse_metadata_selector(selector)?);
+    list_programs(root_db, filter)
+}
+
+/// Returns the links whose metadata matches a set-based selector, as with
+/// [`select_programs`].
+pub fn select_links(root_db: &Db, selector: &str) -> Result<Vec<Link>, BpfmanError> {
+    let filter =
+        LinkListFilter::default().with_metadata_requirements(parse_metadata_selector(selector)?);
+    list_links(root_db, filter)
+}
+
+/// Lists the names of the network interfaces on the host, or in the network
+/// namespace at `netns` if one is given.
+pub fn list_interfaces(netns: Option<PathBuf>) -> Result<Vec<String>, BpfmanError> {
+    utils::interface_names(netns)
+}
+
+/// Lists the tracepoints of the kernel that programs can be attached to, as
+/// `<CATEGORY>/<NAME>`.
+pub fn list_tracepoints() -> Result<Vec<String>, BpfmanError> {
+    utils::tracepoints()
+}
+
+/// Lists the functions of the kernel and of its loaded modules that start
+/// with `prefix`, which kprobes, fentry and fexit programs can be attached to.
+pub fn list_kernel_functions(prefix: &str) -> Result<Vec<String>, BpfmanError> {
+    utils::kernel_functions(prefix)
+}
+
+// Parses a selector of select_programs or select_links, where the key
+// `application` stands for the metadata tag of the application.
+fn parse_metadata_selector(selector: &str) -> Result<Vec<MetadataRequirement>, BpfmanError> {
+    Ok(MetadataRequirement::parse_selector(selector)?
+        .into_iter()
+        .map(|requirement| match requirement {
+            MetadataRequirement::In(key, values) if key == "application" => {
+                MetadataRequirement::In(METADATA_APPLICATION_TAG.to_string(), values)
+            }
+            MetadataRequirement::NotIn(key, values) if key == "application" => {
+                MetadataRequirement::NotIn(METADATA_APPLICATION_TAG.to_string(), values)
+            }
+            MetadataRequirement::Exists(key) if key == "application" => {
+                MetadataRequirement::Exists(METADATA_APPLICATION_TAG.to_string())
+            }
+            MetadataRequirement::DoesNotExist(key) if key == "application" => {
+                MetadataRequirement::DoesNotExist(METADATA_APPLICATION_TAG.to_string())
+            }
+            requirement => requirement,
+        })
+        .collect())
+}
+
+/// Lists the links managed by bpfman that match the provided filter.
+///
+/// # Arguments
+///
+/// * `root_db` - The root database.
+/// * `filter` - A `LinkListFilter` selecting links by program id, link type,
+///   interface and metadata.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::collections::HashMap;
+/// use bpfman::{list_links, setup, types::LinkListFilter};
+///
+/// let (_, root_db) = setup().unwrap();
+/// let filter = LinkListFilter::new(None, None, Some("eth0".to_string()), HashMap::new());
+/// for link in list_links(&root_db, filter).unwrap() {
+///     println!("Link id: {:?}", link.get_id());
+/// }
+/// ```
+pub fn list_links(root_db: &Db, filter: LinkListFilter) -> Result<Vec<Link>, BpfmanError> {
+    let mut links = Vec::new();
+    for p in root_db.tree_names() {
+        if !bytes_to_string(&p).starts_with(LINKS_LINK_PREFIX) {
+            continue;
+        }
+        let tree = root_db.open_tree(p).map_err(|e| {
+            BpfmanError::DatabaseError("Unable to open database tree".to_string(), e.to_string())
+        })?;
+        let link = match Link::new_from_db(tree) {
+            Ok(link) => link,
+            Err(e) => {
+                warn!("Skipping unreadable link: {e}");
+                continue;
+            }
+        };
+        if filter.matches(&link)? {
+            links.push(link);
+        }
+    }
+    links.sort_by_key(|l| l.get_id().unwrap_or_default());
+    Ok(links)
+}
+
+/// Returns the xdp and tc dispatchers that bpfman has attached to network
+/// interfaces, with the links that are attached to each of them.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use bpfman::{list_dispatchers, setup};
+///
+/// let (_, root_db) = setup().unwrap();
+/// for d in list_dispatchers(&root_db).unwrap() {
+///     println!("{:?} dispatcher on {}: links {:?}", d.link_type, d.if_name, d.link_ids);
+/// }
+/// ```
+pub fn list_dispatchers(root_db: &Db) -> Result<Vec<DispatcherSummary>, BpfmanError> {
+    let mut links = vec![];
+    for link in list_links(root_db, LinkListFilter::default())? {
+        // Links without an ifindex are not attached to any dispatcher.
+        if matches!(link.kind(), LinkType::Xdp | LinkType::Tc)
+            && link.get_attached()?
+            && link.ifindex()?.is_some()
+            && let Some(id) = link.dispatcher_id()?
+        {
+            links.push((id, link));
+        }
+    }
+    links.sort_by_key(|(_, l)| l.get_current_position().ok().flatten());
+
+    let mut dispatchers = vec![];
+    for name in root_db.tree_names() {
+        let name_str = bytes_to_string(&name);
+        if !name_str.starts_with(XDP_DISPATCHER_PREFIX)
+            && !name_str.starts_with(TC_DISPATCHER_PREFIX)
+        {
+            continue;
+        }
+        let tree = root_db.open_tree(name).map_err(|e| {
+            BpfmanError::DatabaseError("Unable to open database tree".to_string(), e.to_string())
+        })?;
+        let dispatcher = Dispatcher::new_from_db(tree);
+        let id = dispatcher.id()?;
+        let mut summary = dispatcher.summary()?;
+        for (_, link) in links.iter().filter(|(link_id, _)| *link_id == id) {
+            summary.link_ids.push(link.get_id()?);
+            summary.program_ids.push(link.get_program_id()?);
+        }
+        dispatchers.push(summary);
+    }
+    dispatchers.sort_by_key(|d| (d.nsid, d.if_index, d.direction.map(|d| d as u32)));
+    Ok(dispatchers)
+}
+
+/// Returns the kernel's view of a link, read from the link's pin.
+///
+/// Returns `Ok(None)` if the link is not currently attached, either because
+/// it is disabled, its interface is missing, or its pin no longer exists.
+/// XDP and TC links are reported through the dispatcher extension link, so
+/// the program id in the result is the extension program.
+pub fn get_kernel_link_info(
+    root_db: &Db,
+    link: &Link,
+) -> Result<Option<KernelLinkInfo>, BpfmanError> {
+    if !link.get_enabled()? || link.get_interface_missing()? {
+        return Ok(None);
+    }
+    let path = match link {
+        Link::Xdp(_) | Link::Tc(_) => {
+            let did = link
+                .dispatcher_id()?
+                .ok_or(BpfmanError::DispatcherNotRequired)?;
+            let (Some(dispatcher), Some(position)) =
+                (get_dispatcher(&did, root_db)?, link.get_current_position()?)
+            else {
+                return Ok(None);
+            };
+            let revision = dispatcher.revision()?;
+            match did {
+                DispatcherId::Xdp(DispatcherInfo(nsid, if_index, _)) => {
+                    utils::xdp_dispatcher_link_id_path(nsid, if_index, revision, position as u32)?
+                }
+                DispatcherId::Tc(DispatcherInfo(nsid, if_index, direction)) => {
+                    utils::tc_dispatcher_link_id_path(
+                        direction.ok_or(BpfmanError::InvalidDirection)?,
+                        nsid,
+                        if_index,
+                        revision,
+                        position as u32,
+                    )?
+                }
+            }
+        }
+        _ => format!("{RTDIR_FS_LINKS}/{}", link.get_id()?),
+    };
+    if !Path::new(&path).exists() {
+        return Ok(None);
+    }
+    utils::kernel_link_info(&path).map(Some)
+}
+
 /// Pulls an OCI-compliant image containing eBPF bytecode from a
 /// remote container registry.
 ///
@@ -847,25 +2790,219 @@ pub fn get_link(root_db: &Db, id: u32) -> Result<Link, BpfmanError> {
 ///     password: Some("password".to_string()),
 /// };
 ///
-/// match pull_bytecode(&root_db, image) {
-///     Ok(_) => println!("Image pulled successfully."),
-///     Err(e) => eprintln!("Failed to pull image: {}", e),
-/// }
+/// match pull_bytecode(&root_db, image) {
+///     Ok(_) => println!("Image pulled successfully."),
+///     Err(e) => eprintln!("Failed to pull image: {}", e),
+/// }
+///
+/// ```
+pub fn pull_bytecode(root_db: &Db, image: BytecodeImage) -> anyhow::Result<()> {
+    let image_manager = &mut init_image_manager().map_err(|e| anyhow!(format!("{e}")))?;
+
+    image_manager.get_image(
+        root_db,
+        &image.image_url,
+        image.image_pull_policy.clone(),
+        image.username.clone(),
+        image.password.clone(),
+    )?;
+    record_event(
+        root_db,
+        new_event(
+            EventKind::ImagePulled,
+            None,
+            None,
+            format!("pulled image {}", image.image_url),
+        ),
+    );
+    Ok(())
+}
+
+/// Returns the events recorded after `revision`, oldest first. Pass 0 to
+/// get every event that is still in the event log, or the revision of the
+/// last event seen to resume from it.
+///
+/// Every change to the programs and links managed by bpfman is recorded as
+/// an [`Event`], no matter whether it was made by the CLI, bpfman-rpc or
+/// [`reconcile_interfaces`]. Only the newest events are kept.
+///
+/// # Errors
+///
+/// This function will return [`BpfmanError::EventsCompacted`] if events
+/// after `revision` were already dropped from the event log, in which case
+/// the current state has to be listed again. It will also return an error if
+/// the event log could not be read.
+pub fn get_events(root_db: &Db, revision: u64) -> Result<Vec<Event>, BpfmanError> {
+    let tree = open_event_log(root_db)?;
+    let compacted = sled_get_option(&tree, EVENTS_COMPACTED)?
+        .map(bytes_to_u64)
+        .unwrap_or_default();
+    if revision < compacted {
+        return Err(BpfmanError::EventsCompacted {
+            revision,
+            compacted,
+        });
+    }
+
+    tree.range(event_key(revision.saturating_add(1))..=event_key(u64::MAX))
+        .values()
+        .map(|v| {
+            let v = v.map_err(|e| {
+                BpfmanError::DatabaseError("Failed to read event".to_string(), e.to_string())
+            })?;
+            serde_json::from_slice(&v).map_err(|e| {
+                BpfmanError::DatabaseError("Failed to deserialize event".to_string(), e.to_string())
+            })
+        })
+        .collect()
+}
+
+/// Returns the revision of the newest event in the event log, or 0 if no
+/// event was recorded yet. Pass it to [`get_events`] to only get the events
+/// recorded from now on.
+///
+/// # Errors
 ///
-/// ```
-pub fn pull_bytecode(root_db: &Db, image: BytecodeImage) -> anyhow::Result<()> {
-    let image_manager = &mut init_image_manager().map_err(|e| anyhow!(format!("{e}")))?;
+/// This function will return an error if the event log could not be read.
+pub fn get_event_revision(root_db: &Db) -> Result<u64, BpfmanError> {
+    let tree = open_event_log(root_db)?;
+    match tree.scan_prefix(PREFIX_EVENT).keys().next_back() {
+        Some(key) => {
+            let key = key.map_err(|e| {
+                BpfmanError::DatabaseError("Failed to read event".to_string(), e.to_string())
+            })?;
+            Ok(revision_from_key(&key))
+        }
+        None => Ok(sled_get_option(&tree, EVENTS_COMPACTED)?
+            .map(bytes_to_u64)
+            .unwrap_or_default()),
+    }
+}
 
-    image_manager.get_image(
-        root_db,
-        &image.image_url,
-        image.image_pull_policy.clone(),
-        image.username.clone(),
-        image.password.clone(),
-    )?;
+// Creates an event about a program or one of its links. The event is created
+// before it is recorded so that it can describe a program that is about to be
+// deleted.
+fn new_event(
+    kind: EventKind,
+    program: Option<&Program>,
+    link_id: Option<u32>,
+    detail: String,
+) -> Event {
+    Event {
+        revision: 0,
+        kind,
+        program_id: program.and_then(|p| p.get_data().get_id().ok()),
+        link_id,
+        program_type: program.map(|p| p.kind().into()),
+        program_type_name: program.map(|p| p.type_name()),
+        metadata: program
+            .and_then(|p| p.get_data().get_metadata().ok())
+            .unwrap_or_default(),
+        detail,
+    }
+}
+
+// Records an event in the event log. A failure to record the event is only
+// logged, since the change it describes has already been made.
+fn record_event(root_db: &Db, event: Event) {
+    let kind = event.kind;
+    if let Err(e) = record_event_internal(root_db, event) {
+        warn!("Unable to record {kind} event: {e}");
+    }
+}
+
+fn record_event_internal(root_db: &Db, event: Event) -> Result<(), BpfmanError> {
+    let tree = open_event_log(root_db)?;
+    // Event logs written before the newest revision was stored continue from
+    // their newest event.
+    let last = get_event_revision(root_db)?;
+    // The revision is allocated and the event inserted in one transaction, so
+    // that an event is never visible before the events of earlier revisions,
+    // even when mutations run concurrently.
+    let revision = tree
+        .transaction(|tx| {
+            // Revisions start at 1 so that 0 can be used to read the whole log.
+            let revision = tx
+                .get(EVENTS_REVISION)?
+                .map(|v| bytes_to_u64(v.to_vec()))
+                .unwrap_or(last)
+                + 1;
+            let event = Event {
+                revision,
+                ..event.clone()
+            };
+            let value = serde_json::to_vec(&event)
+                .map_err(|e| ConflictableTransactionError::Abort(e.to_string()))?;
+            tx.insert(EVENTS_REVISION, &revision.to_ne_bytes())?;
+            tx.insert(event_key(revision), value)?;
+            Ok(revision)
+        })
+        .map_err(|e| {
+            BpfmanError::DatabaseError("Failed to insert event".to_string(), e.to_string())
+        })?;
+
+    // Only the events of the newest MAX_EVENTS revisions are kept. Remember
+    // the newest event dropped so that readers resuming from before it know
+    // they missed events.
+    let Some(oldest) = revision.checked_sub(MAX_EVENTS) else {
+        return Ok(());
+    };
+    for key in tree.range(event_key(0)..=event_key(oldest)).keys() {
+        let key = key.map_err(|e| {
+            BpfmanError::DatabaseError("Failed to read event".to_string(), e.to_string())
+        })?;
+        tree.remove(&key).map_err(|e| {
+            BpfmanError::DatabaseError("Failed to remove event".to_string(), e.to_string())
+        })?;
+        sled_insert(
+            &tree,
+            EVENTS_COMPACTED,
+            &revision_from_key(&key).to_ne_bytes(),
+        )?;
+    }
     Ok(())
 }
 
+// Records that a new revision of a dispatcher was attached to an interface.
+fn record_dispatcher_event(
+    root_db: &Db,
+    program_type: BpfProgType,
+    if_name: &str,
+    revision: u32,
+    programs: usize,
+) {
+    let mut event = new_event(
+        EventKind::DispatcherRebuilt,
+        None,
+        None,
+        format!(
+            "{program_type} dispatcher on {if_name} rebuilt at revision {revision} with {programs} programs"
+        ),
+    );
+    event.program_type = Some(program_type.into());
+    record_event(root_db, event);
+}
+
+fn open_event_log(root_db: &Db) -> Result<sled::Tree, BpfmanError> {
+    root_db.open_tree(EVENTS_TREE).map_err(|e| {
+        BpfmanError::DatabaseError("Unable to open event log tree".to_string(), e.to_string())
+    })
+}
+
+// Event keys hold the revision in big endian so that they sort in revision
+// order.
+fn event_key(revision: u64) -> Vec<u8> {
+    [PREFIX_EVENT.as_bytes(), &revision.to_be_bytes()].concat()
+}
+
+fn revision_from_key(key: &[u8]) -> u64 {
+    u64::from_be_bytes(
+        key[PREFIX_EVENT.len()..]
+            .try_into()
+            .expect("event key has the wrong length"),
+    )
+}
+
 pub fn init_database(sled_config: SledConfig) -> Result<Db, BpfmanError> {
     let database_config = open_config_file().database().to_owned();
     for _ in 0..=database_config.max_retries {
@@ -997,6 +3134,12 @@ fn get_multi_attach_links(
             continue;
         }
 
+        // Disabled links, and links whose interface was removed, keep their
+        // database entry but are left out of the dispatcher.
+        if !link.get_enabled()? || link.get_interface_missing()? {
+            continue;
+        }
+
         debug!(
             "link {}: program_id: {} ifindex {} direction {:?} nsid {}",
             link.get_id()?,
@@ -1039,6 +3182,8 @@ fn get_tcx_links(
                             if tcx_p_if_index == if_index
                                 && tcx_p_direction == direction
                                 && tcx_p_nsid == nsid
+                                && tcx_p.0.get_enabled().unwrap_or(true)
+                                && !tcx_p.0.get_interface_missing().unwrap_or(false)
                             {
                                 tcx_links.push(tcx_p);
                             }
@@ -1119,21 +3264,22 @@ fn set_tcx_program_positions(
     Ok(())
 }
 
-// Adds a new link and sets the positions of links that are to be attached via a dispatcher.
+// Adds new links and sets the positions of links that are to be attached via a dispatcher.
+// All the new links must be on the same dispatcher.
 // Positions are set based on order of priority. Ties are broken based on:
 // - Already attached programs are preferred
 // - Program name. Lowest lexical order wins.
 fn add_and_set_link_positions(
     root_db: &Db,
     program_type: BpfProgType,
-    link: Link,
+    links: &[Link],
 ) -> Result<(), BpfmanError> {
     debug!("BpfManager::add_and_set_link_positions()");
-    let if_index = link.ifindex().unwrap();
-    let direction = link.direction().unwrap();
-    let nsid = link.nsid().unwrap();
+    let if_index = links[0].ifindex().unwrap();
+    let direction = links[0].direction().unwrap();
+    let nsid = links[0].nsid().unwrap();
     let mut extensions = get_multi_attach_links(root_db, program_type, if_index, direction, nsid)?;
-    extensions.push(link);
+    extensions.extend_from_slice(links);
     debug!("Found {} extensions", extensions.len());
     extensions.sort_by_key(|b| {
         (
@@ -1229,6 +3375,28 @@ pub fn setup() -> Result<(Config, Db), BpfmanError> {
     Ok((open_config_file(), init_database(get_db_config())?))
 }
 
+/// Opens the bpfman database at the default location for callers that only
+/// read the bpfman state, such as shell completions. Unlike [`setup`], bpfman
+/// is not initialized, the database is not created if bpfman has never run,
+/// and the database is opened once instead of waiting for another process to
+/// release its lock.
+///
+/// # Errors
+///
+/// * `BpfmanError::DatabaseError` - If the database does not exist.
+/// * `BpfmanError::DatabaseLockError` - If another process holds the database.
+pub fn open_database() -> Result<Db, BpfmanError> {
+    if !Path::new(RTDIR_DB).exists() {
+        return Err(BpfmanError::DatabaseError(
+            "bpfman database not found".to_string(),
+            RTDIR_DB.to_string(),
+        ));
+    }
+    get_db_config()
+        .open()
+        .map_err(|_| BpfmanError::DatabaseLockError)
+}
+
 pub(crate) fn load_program(
     root_db: &Db,
     loader: &mut Ebpf,
@@ -1237,178 +3405,159 @@ pub(crate) fn load_program(
     debug!("BpfManager::load_program()");
     let name = &p.get_data().get_name()?;
 
+    let res = load_program_in_kernel(loader, &mut p).and_then(|_| {
+        let id = p.get_data().get_id()?;
+        loader
+            .program_mut(name)
+            .ok_or(BpfmanError::BpfFunctionNameNotValid(name.to_owned()))?
+            .pin(format!("{RTDIR_FS}/prog_{id}"))
+            .map_err(BpfmanError::UnableToPinProgram)?;
+        Ok(id)
+    });
+
+    match res {
+        Ok(id) => {
+            // If this program is the map(s) owner pin all maps (except for .rodata and .bss) by name.
+            if p.get_data().get_map_pin_path()?.is_none() {
+                let map_pin_path = calc_map_pin_path(id);
+                p.get_data_mut().set_map_pin_path(&map_pin_path)?;
+                create_map_pin_path(&map_pin_path)?;
+
+                for (name, map) in loader.maps_mut() {
+                    if !should_map_be_pinned(name) {
+                        continue;
+                    }
+                    debug!(
+                        "Pinning map: {name} to path: {}",
+                        map_pin_path.join(name).display()
+                    );
+                    map.pin(map_pin_path.join(name))
+                        .map_err(BpfmanError::UnableToPinMap)?;
+                }
+            }
+        }
+        Err(_) => {
+            // If kernel ID was never set there's no pins to cleanup here so just continue
+            if p.get_data().get_id().is_ok() {
+                p.delete(root_db)
+                    .map_err(BpfmanError::BpfmanProgramDeleteError)?;
+            };
+        }
+    };
+
+    res
+}
+
+// Loads a program of the bytecode into the kernel, which runs the verifier,
+// and records what the kernel reports about it. The program is not pinned, so
+// it is unloaded again when the loader is dropped unless the caller pins it.
+fn load_program_in_kernel(loader: &mut Ebpf, p: &mut Program) -> Result<(), BpfmanError> {
+    let name = &p.get_data().get_name()?;
+    load_program_type(loader, p, name).map_err(|e| match e {
+        BpfmanError::BpfProgramError(ProgramError::LoadError {
+            io_error,
+            verifier_log,
+        }) => BpfmanError::VerifierRejected {
+            program: name.to_owned(),
+            io_error,
+            verifier_log: verifier_log.to_string(),
+        },
+        e => e,
+    })?;
+
+    // The loader only returns the verifier log when a program is rejected,
+    // so the program is verified again to read the log if one was asked for.
+    // Otherwise the statistics the kernel reports are kept instead.
+    let log = match p.get_data().get_verifier_log_level()? {
+        Some(level) if level > 0 => match verifier::verifier_log(loader, p, level) {
+            Ok(log) if !log.trim().is_empty() => Some(verifier::truncate_log(&log)),
+            Ok(_) => None,
+            Err(e) => {
+                warn!("Unable to read the verifier log of {name}: {e}");
+                None
+            }
+        },
+        _ => None,
+    };
+    let summary = match (log, p.get_data().get_kernel_verified_insns()?) {
+        (Some(log), _) => log,
+        (None, 0) => "accepted".to_string(),
+        (None, insns) => format!("accepted, processed {insns} insns"),
+    };
+    p.get_data_mut().set_verifier_log_summary(&summary)
+}
+
+fn load_program_type(loader: &mut Ebpf, p: &mut Program, name: &str) -> Result<(), BpfmanError> {
     let raw_program = loader
         .program_mut(name)
         .ok_or(BpfmanError::BpfFunctionNameNotValid(name.to_owned()))?;
 
-    let res = match p {
-        Program::Tc(ref mut program) => {
+    match p {
+        Program::Tc(program) => {
             let ext: &mut Extension = raw_program.try_into()?;
             let dispatcher = TcDispatcher::get_test()?;
             let fd = dispatcher.fd()?.try_clone()?;
             ext.load(fd, "compat_test")?;
-            program.get_data_mut().set_kernel_info(&ext.info()?)?;
-
-            let id = program.data.get_id()?;
-
-            ext.pin(format!("{RTDIR_FS}/prog_{id}"))
-                .map_err(BpfmanError::UnableToPinProgram)?;
-
-            Ok(id)
+            program.get_data_mut().set_kernel_info(&ext.info()?)
         }
-        Program::Xdp(ref mut program) => {
+        Program::Xdp(program) => {
             let ext: &mut Extension = raw_program.try_into()?;
             let dispatcher = XdpDispatcher::get_test()?;
             let fd = dispatcher.fd()?.try_clone()?;
             ext.load(fd, "compat_test")?;
-            program.get_data_mut().set_kernel_info(&ext.info()?)?;
-
-            let id = program.data.get_id()?;
-
-            ext.pin(format!("{RTDIR_FS}/prog_{id}"))
-                .map_err(BpfmanError::UnableToPinProgram)?;
-
-            Ok(id)
+            program.get_data_mut().set_kernel_info(&ext.info()?)
         }
-        Program::Tracepoint(ref mut program) => {
+        Program::Tracepoint(program) => {
             let tracepoint: &mut TracePoint = raw_program.try_into()?;
             tracepoint.load()?;
-
-            program
-                .get_data_mut()
-                .set_kernel_info(&tracepoint.info()?)?;
-
-            let id = program.data.get_id()?;
-
-            tracepoint
-                .pin(format!("{RTDIR_FS}/prog_{id}"))
-                .map_err(BpfmanError::UnableToPinProgram)?;
-
-            Ok(id)
+            program.get_data_mut().set_kernel_info(&tracepoint.info()?)
         }
-        Program::Kprobe(ref mut program) => {
+        Program::Kprobe(program) => {
             let kprobe: &mut KProbe = raw_program.try_into()?;
             kprobe.load()?;
             match kprobe.kind() {
                 ProbeKind::KRetProbe => program.set_retprobe(true),
                 _ => Ok(()),
             }?;
-
-            program.get_data_mut().set_kernel_info(&kprobe.info()?)?;
-
-            let id = program.data.get_id()?;
-
-            kprobe
-                .pin(format!("{RTDIR_FS}/prog_{id}"))
-                .map_err(BpfmanError::UnableToPinProgram)?;
-
-            Ok(id)
+            program.get_data_mut().set_kernel_info(&kprobe.info()?)
         }
-        Program::Uprobe(ref mut program) => {
+        Program::Uprobe(program) => {
             let uprobe: &mut UProbe = raw_program.try_into()?;
             uprobe.load()?;
-
             match uprobe.kind() {
                 ProbeKind::URetProbe => program.set_retprobe(true),
                 _ => Ok(()),
             }?;
-
-            program.get_data_mut().set_kernel_info(&uprobe.info()?)?;
-
-            let id = program.data.get_id()?;
-
-            let program_pin_path = format!("{RTDIR_FS}/prog_{id}");
-
-            uprobe
-                .pin(program_pin_path.clone())
-                .map_err(BpfmanError::UnableToPinProgram)?;
-
-            Ok(id)
+            program.get_data_mut().set_kernel_info(&uprobe.info()?)
         }
-        Program::Fentry(ref mut program) => {
+        Program::Fentry(program) => {
             let fn_name = program.get_fn_name()?;
             let btf = Btf::from_sys_fs()?;
             let fentry: &mut FEntry = raw_program.try_into()?;
             fentry
                 .load(&fn_name, &btf)
                 .map_err(BpfmanError::BpfProgramError)?;
-            program.get_data_mut().set_kernel_info(&fentry.info()?)?;
-
-            let id = program.data.get_id()?;
-
-            fentry
-                .pin(format!("{RTDIR_FS}/prog_{id}"))
-                .map_err(BpfmanError::UnableToPinProgram)?;
-
-            Ok(id)
+            program.get_data_mut().set_kernel_info(&fentry.info()?)
         }
-        Program::Fexit(ref mut program) => {
+        Program::Fexit(program) => {
             let fn_name = program.get_fn_name()?;
             let btf = Btf::from_sys_fs()?;
             let fexit: &mut FExit = raw_program.try_into()?;
             fexit
                 .load(&fn_name, &btf)
                 .map_err(BpfmanError::BpfProgramError)?;
-            program.get_data_mut().set_kernel_info(&fexit.info()?)?;
-
-            let id = program.data.get_id()?;
-
-            fexit
-                .pin(format!("{RTDIR_FS}/prog_{id}"))
-                .map_err(BpfmanError::UnableToPinProgram)?;
-
-            Ok(id)
+            program.get_data_mut().set_kernel_info(&fexit.info()?)
         }
-        Program::Tcx(ref mut program) => {
+        Program::Tcx(program) => {
             debug!("Loading TCX program");
             let tcx: &mut SchedClassifier = raw_program.try_into()?;
 
             debug!("Calling load on TCX program");
             tcx.load()?;
-            program.get_data_mut().set_kernel_info(&tcx.info()?)?;
-
-            let id = program.data.get_id()?;
-
-            debug!("Pinning TCX program");
-            tcx.pin(format!("{RTDIR_FS}/prog_{id}"))
-                .map_err(BpfmanError::UnableToPinProgram)?;
-
-            Ok(id)
+            program.get_data_mut().set_kernel_info(&tcx.info()?)
         }
         _ => panic!("not a supported single attach program"),
-    };
-
-    match res {
-        Ok(id) => {
-            // If this program is the map(s) owner pin all maps (except for .rodata and .bss) by name.
-            if p.get_data().get_map_pin_path()?.is_none() {
-                let map_pin_path = calc_map_pin_path(id);
-                p.get_data_mut().set_map_pin_path(&map_pin_path)?;
-                create_map_pin_path(&map_pin_path)?;
-
-                for (name, map) in loader.maps_mut() {
-                    if !should_map_be_pinned(name) {
-                        continue;
-                    }
-                    debug!(
-                        "Pinning map: {name} to path: {}",
-                        map_pin_path.join(name).display()
-                    );
-                    map.pin(map_pin_path.join(name))
-                        .map_err(BpfmanError::UnableToPinMap)?;
-                }
-            }
-        }
-        Err(_) => {
-            // If kernel ID was never set there's no pins to cleanup here so just continue
-            if p.get_data().get_id().is_ok() {
-                p.delete(root_db)
-                    .map_err(BpfmanError::BpfmanProgramDeleteError)?;
-            };
-        }
-    };
-
-    res
+    }
 }
 
 pub(crate) fn attach_single_attach_program(root_db: &Db, l: &mut Link) -> Result<(), BpfmanError> {
@@ -1736,7 +3885,7 @@ pub(crate) fn attach_multi_attach_program(
     let direction = l.direction()?;
     let nsid = l.nsid()?;
 
-    add_and_set_link_positions(root_db, program_type, l.clone())?;
+    add_and_set_link_positions(root_db, program_type, std::slice::from_ref(l))?;
 
     let mut programs = get_multi_attach_links(root_db, program_type, if_index, direction, nsid)?;
     programs.push(l.clone());
@@ -1762,6 +3911,13 @@ pub(crate) fn attach_multi_attach_program(
         next_revision,
         old_dispatcher,
     )?;
+    record_dispatcher_event(
+        root_db,
+        program_type,
+        &if_name,
+        next_revision,
+        programs.len(),
+    );
     l.set_attached()?;
     Ok(())
 }
@@ -1805,13 +3961,38 @@ fn detach_multi_attach_program(
         return Ok(());
     }
 
+    rebuild_dispatcher(
+        root_db,
+        config,
+        program_type,
+        if_index,
+        &if_name,
+        direction,
+        nsid,
+        old_dispatcher,
+    )
+}
+
+// Recomputes the positions of the links on a dispatcher and replaces the
+// dispatcher with a new revision containing those links.
+#[allow(clippy::too_many_arguments)]
+fn rebuild_dispatcher(
+    root_db: &Db,
+    config: &Config,
+    program_type: BpfProgType,
+    if_index: Option<u32>,
+    if_name: &str,
+    direction: Option<Direction>,
+    nsid: u64,
+    old_dispatcher: Option<Dispatcher>,
+) -> Result<(), BpfmanError> {
     set_program_positions(root_db, program_type, if_index.unwrap(), direction, nsid)?;
 
     // Intentionally don't add filter program here
     let mut programs = get_multi_attach_links(root_db, program_type, if_index, direction, nsid)?;
 
     let if_config = if let Some(i) = config.interfaces() {
-        i.get(&if_name)
+        i.get(if_name)
     } else {
         None
     };
@@ -1829,6 +4010,13 @@ fn detach_multi_attach_program(
         next_revision,
         old_dispatcher,
     )?;
+    record_dispatcher_event(
+        root_db,
+        program_type,
+        if_name,
+        next_revision,
+        programs.len(),
+    );
 
     Ok(())
 }
@@ -2047,6 +4235,10 @@ fn get_map(id: u32, root_db: &Db) -> Option<sled::Tree> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        config::{Caller, METADATA_OWNER_TAG, Operation, Target},
+        types::{ListSortKey, MetadataRequirement, XdpProceedOn, XdpProgram},
+    };
 
     #[test]
     fn test_assert_rtdir_db() {
@@ -2054,4 +4246,296 @@ mod tests {
         // See https://github.com/bpfman/bpfman/issues/1563
         assert!(RTDIR_DB.starts_with("/run/"));
     }
+
+    fn record_test_event(root_db: &Db, detail: &str) {
+        record_event(
+            root_db,
+            new_event(EventKind::ImagePulled, None, None, detail.to_string()),
+        );
+    }
+
+    #[test]
+    fn test_get_events() {
+        let root_db = get_db_config().open().unwrap();
+        assert_eq!(get_event_revision(&root_db).unwrap(), 0);
+        assert!(get_events(&root_db, 0).unwrap().is_empty());
+
+        for detail in ["first", "second", "third"] {
+            record_test_event(&root_db, detail);
+        }
+        let events = get_events(&root_db, 0).unwrap();
+        let details: Vec<_> = events.iter().map(|e| e.detail.as_str()).collect();
+        assert_eq!(details, vec!["first", "second", "third"]);
+        assert!(events.windows(2).all(|w| w[0].revision < w[1].revision));
+        assert_eq!(get_event_revision(&root_db).unwrap(), events[2].revision);
+
+        let events = get_events(&root_db, events[0].revision).unwrap();
+        let details: Vec<_> = events.iter().map(|e| e.detail.as_str()).collect();
+        assert_eq!(details, vec!["second", "third"]);
+    }
+
+    #[test]
+    fn test_get_events_concurrent() {
+        let root_db = get_db_config().open().unwrap();
+        let writers: Vec<_> = (0..4)
+            .map(|_| {
+                let root_db = root_db.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..50 {
+                        record_test_event(&root_db, "next");
+                    }
+                })
+            })
+            .collect();
+
+        // A reader resuming from the last revision it saw must get every
+        // event, so events must become visible in revision order.
+        let mut revision = 0;
+        while revision < 200 {
+            for event in get_events(&root_db, revision).unwrap() {
+                assert_eq!(event.revision, revision + 1);
+                revision = event.revision;
+            }
+        }
+        for writer in writers {
+            writer.join().unwrap();
+        }
+        assert_eq!(get_event_revision(&root_db).unwrap(), 200);
+    }
+
+    #[test]
+    fn test_get_events_compacted() {
+        let root_db = get_db_config().open().unwrap();
+        record_test_event(&root_db, "first");
+        let first = get_event_revision(&root_db).unwrap();
+        for _ in 0..MAX_EVENTS {
+            record_test_event(&root_db, "next");
+        }
+
+        assert!(matches!(
+            get_events(&root_db, 0),
+            Err(BpfmanError::EventsCompacted { compacted, .. }) if compacted >= first
+        ));
+        let revision = get_event_revision(&root_db).unwrap();
+        assert!(get_events(&root_db, revision).unwrap().is_empty());
+        assert!(!get_events(&root_db, revision - 2).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_metadata_requirements() {
+        let requirements = MetadataRequirement::parse_selector(
+            "owner=acme,env in (prod, staging),tier notin (db),debug,!deprecated",
+        )
+        .unwrap();
+        assert_eq!(
+            requirements,
+            vec![
+                MetadataRequirement::In("owner".to_string(), vec!["acme".to_string()]),
+                MetadataRequirement::In(
+                    "env".to_string(),
+                    vec!["prod".to_string(), "staging".to_string()]
+                ),
+                MetadataRequirement::NotIn("tier".to_string(), vec!["db".to_string()]),
+                MetadataRequirement::Exists("debug".to_string()),
+                MetadataRequirement::DoesNotExist("deprecated".to_string()),
+            ]
+        );
+
+        let metadata = HashMap::from([
+            ("owner".to_string(), "acme".to_string()),
+            ("env".to_string(), "staging".to_string()),
+            ("debug".to_string(), "true".to_string()),
+        ]);
+        assert!(requirements.iter().all(|r| r.matches(&metadata)));
+        let metadata = HashMap::from([
+            ("owner".to_string(), "acme".to_string()),
+            ("env".to_string(), "dev".to_string()),
+            ("debug".to_string(), "true".to_string()),
+        ]);
+        assert!(!requirements.iter().all(|r| r.matches(&metadata)));
+
+        for selector in ["", "env in prod", "env in ()", "env within (prod)", "a b=c"] {
+            assert!(
+                MetadataRequirement::parse_selector(selector).is_err(),
+                "{selector} should not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_link_not_found() {
+        let root_db = get_db_config().open().unwrap();
+        let trees = root_db.tree_names().len();
+        assert!(matches!(
+            get_link(&root_db, 42),
+            Err(BpfmanError::LinkNotFound(42))
+        ));
+        // Looking up a missing link doesn't leave an empty tree behind.
+        assert_eq!(root_db.tree_names().len(), trees);
+    }
+
+    #[test]
+    fn test_attach_batch_alias_in_use() {
+        let root_db = get_db_config().open().unwrap();
+        let xdp = |iface: &str| AttachInfo::Xdp {
+            priority: 50,
+            iface: iface.to_string(),
+            proceed_on: XdpProceedOn::default(),
+            netns: None,
+            metadata: HashMap::from([(METADATA_ALIAS_TAG.to_string(), "edge".to_string())]),
+        };
+        let results = attach_program_batch(
+            &Config::default(),
+            &root_db,
+            vec![(42, xdp("eth0")), (43, xdp("eth1"))],
+        );
+        assert!(matches!(results[0], Err(BpfmanError::ProgramNotFound(42))));
+        // The alias is in use by the first request even though its link
+        // isn't recorded yet.
+        assert!(matches!(
+            &results[1],
+            Err(BpfmanError::AliasInUse { alias, .. }) if alias == "edge"
+        ));
+    }
+
+    #[test]
+    fn test_id_or_alias() {
+        assert_eq!(
+            IdOrAlias::try_from("63178".to_string()).unwrap(),
+            IdOrAlias::Id(63178)
+        );
+        let alias = IdOrAlias::try_from("name:edge-pass".to_string()).unwrap();
+        assert_eq!(alias, IdOrAlias::Alias("edge-pass".to_string()));
+        assert_eq!(alias.to_string(), "name:edge-pass");
+        for value in ["", "name:", "edge-pass", "-1"] {
+            assert!(
+                IdOrAlias::try_from(value.to_string()).is_err(),
+                "{value} should not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_metadata_selector() {
+        assert_eq!(
+            parse_metadata_selector("application=TestEbpfApp,!application,owner").unwrap(),
+            vec![
+                MetadataRequirement::In(
+                    METADATA_APPLICATION_TAG.to_string(),
+                    vec!["TestEbpfApp".to_string()]
+                ),
+                MetadataRequirement::DoesNotExist(METADATA_APPLICATION_TAG.to_string()),
+                MetadataRequirement::Exists("owner".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_repair_permission() {
+        let config: Config = r#"
+        [[authorization.rules]]
+        operations = ["list", "unload", "attach"]
+        owner_only = true
+        "#
+        .parse()
+        .unwrap();
+        let authorization = config.authorization().unwrap();
+        let caller = Caller {
+            uid: 1000,
+            gids: vec![1000],
+            pid: None,
+        };
+        let root_db = get_db_config().open().unwrap();
+        // Neither program is loaded in the kernel, so repairing would remove
+        // both of them.
+        for (id, owner) in [(4_000_000_001, "1000"), (4_000_000_002, "1001")] {
+            let mut data =
+                ProgramData::new_empty(root_db.open_tree(format!("{PROGRAM_PREFIX}{id}")).unwrap());
+            data.set_id(id).unwrap();
+            data.set_metadata(HashMap::from([(
+                METADATA_OWNER_TAG.to_string(),
+                owner.to_string(),
+            )]))
+            .unwrap();
+            XdpProgram::new(data).unwrap();
+        }
+
+        let drifts = verify_with(&config, &root_db, true, |program| {
+            let metadata = program.get_data().get_metadata().unwrap();
+            [Operation::Unload, Operation::Attach].iter().all(|op| {
+                authorization
+                    .authorize(
+                        &caller,
+                        *op,
+                        Some(&Target {
+                            program_type: "xdp",
+                            metadata: &metadata,
+                        }),
+                    )
+                    .is_ok()
+            })
+        })
+        .unwrap();
+        let repair = |id| {
+            drifts
+                .iter()
+                .find(|d| d.program_id == id)
+                .map(|d| d.repair.clone())
+                .unwrap()
+        };
+        assert_ne!(repair(4_000_000_001), RepairStatus::NotPermitted);
+        assert_eq!(repair(4_000_000_002), RepairStatus::NotPermitted);
+        assert!(get(&root_db, &4_000_000_002).is_some());
+    }
+
+    #[test]
+    fn test_list_filter_pages() {
+        let root_db = get_db_config().open().unwrap();
+        let programs: Vec<Program> = [(3, "c"), (1, "b"), (4, "a"), (2, "d")]
+            .into_iter()
+            .map(|(id, name)| {
+                let mut data = ProgramData::new_empty(root_db.open_tree(id.to_string()).unwrap());
+                data.set_id(id).unwrap();
+                data.set_kernel_name(name).unwrap();
+                Program::Unsupported(data)
+            })
+            .collect();
+        let ids = |programs: &[Program]| -> Vec<u32> {
+            programs
+                .iter()
+                .map(|p| p.get_data().get_id().unwrap())
+                .collect()
+        };
+
+        let filter = ListFilter::default()
+            .with_sort_by(ListSortKey::Name)
+            .with_page(Some(3), None);
+        let (page, token) = filter.paginate(programs.clone()).unwrap();
+        assert_eq!(ids(&page), vec![4, 1, 3]);
+        let filter = filter.with_page(Some(3), token);
+        let (page, token) = filter.paginate(programs.clone()).unwrap();
+        assert_eq!(ids(&page), vec![2]);
+        assert!(token.is_none());
+
+        // A token can't be used with a different sort order.
+        let (_, token) = ListFilter::default()
+            .with_page(Some(1), None)
+            .paginate(programs.clone())
+            .unwrap();
+        assert!(
+            ListFilter::default()
+                .with_sort_by(ListSortKey::Name)
+                .with_page(Some(1), token)
+                .paginate(programs.clone())
+                .is_err()
+        );
+
+        let filter = ListFilter::default().with_name_regex("^[ab]$").unwrap();
+        let matched: Vec<Program> = programs
+            .into_iter()
+            .filter(|p| filter.matches(&root_db, p))
+            .collect();
+        let (page, _) = filter.paginate(matched).unwrap();
+        assert_eq!(ids(&page), vec![1, 4]);
+    }
 }
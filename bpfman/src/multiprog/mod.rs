@@ -4,15 +4,18 @@
 mod tc;
 mod xdp;
 
+use std::path::PathBuf;
+
 use log::debug;
 use sled::Db;
 pub use tc::TcDispatcher;
+pub(crate) use xdp::XDP_DISPATCHER_PROGRAM_NAME;
 pub use xdp::XdpDispatcher;
 
 use crate::{
     config::{InterfaceConfig, XdpMode},
     errors::BpfmanError,
-    types::{Direction, Link},
+    types::{Direction, DispatcherSummary, Link, LinkType},
     utils::bytes_to_string,
 };
 
@@ -110,6 +113,64 @@ impl Dispatcher {
         }
     }
 
+    /// Returns true if the dispatcher is still attached to its interface.
+    /// `netns` is the network namespace of the interface.
+    pub(crate) fn is_attached(&self, netns: Option<PathBuf>) -> Result<bool, BpfmanError> {
+        match self {
+            Dispatcher::Xdp(d) => d.is_attached(netns),
+            Dispatcher::Tc(d) => d.is_attached(),
+        }
+    }
+
+    /// Returns the id that the links attached to this dispatcher refer to.
+    pub(crate) fn id(&self) -> Result<DispatcherId, BpfmanError> {
+        Ok(match self {
+            Dispatcher::Xdp(d) => {
+                DispatcherId::Xdp(DispatcherInfo(d.get_nsid()?, d.get_ifindex()?, None))
+            }
+            Dispatcher::Tc(d) => DispatcherId::Tc(DispatcherInfo(
+                d.get_nsid()?,
+                d.get_ifindex()?,
+                Some(d.get_direction()?),
+            )),
+        })
+    }
+
+    /// Returns a summary of the dispatcher, without its links.
+    pub(crate) fn summary(&self) -> Result<DispatcherSummary, BpfmanError> {
+        Ok(match self {
+            Dispatcher::Xdp(d) => DispatcherSummary {
+                link_type: LinkType::Xdp,
+                if_name: d.get_ifname()?,
+                if_index: d.get_ifindex()?,
+                nsid: d.get_nsid()?,
+                direction: None,
+                xdp_mode: Some(d.get_mode()?.to_string()),
+                revision: d.get_revision()?,
+                link_ids: vec![],
+                program_ids: vec![],
+            },
+            Dispatcher::Tc(d) => DispatcherSummary {
+                link_type: LinkType::Tc,
+                if_name: d.get_ifname()?,
+                if_index: d.get_ifindex()?,
+                nsid: d.get_nsid()?,
+                direction: Some(d.get_direction()?),
+                xdp_mode: None,
+                revision: d.get_revision()?,
+                link_ids: vec![],
+                program_ids: vec![],
+            },
+        })
+    }
+
+    pub(crate) fn revision(&self) -> Result<u32, BpfmanError> {
+        match self {
+            Dispatcher::Xdp(d) => d.get_revision(),
+            Dispatcher::Tc(d) => d.get_revision(),
+        }
+    }
+
     pub(crate) fn next_revision(&self) -> u32 {
         let current = match self {
             Dispatcher::Xdp(d) => d
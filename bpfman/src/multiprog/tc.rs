@@ -17,6 +17,7 @@ use aya::{
     },
 };
 use log::debug;
+use netlink_packet_route::tc::TcHandle;
 use sled::Db;
 
 use crate::{
@@ -419,6 +420,27 @@ impl TcDispatcher {
         }
     }
 
+    /// Returns true if the filter of this dispatcher is still attached to its
+    /// interface.
+    pub(crate) fn is_attached(&self) -> Result<bool, BpfmanError> {
+        let Some(handle) = self.get_handle()? else {
+            return Ok(false);
+        };
+        let if_index = self.get_ifindex()?;
+        let parent_minor = match self.get_direction()? {
+            Direction::Ingress => TcHandle::MIN_INGRESS,
+            Direction::Egress => TcHandle::MIN_EGRESS,
+        };
+        let _netns_guard = self.get_netns()?.map(enter_netns).transpose()?;
+        NetlinkManager::new()
+            .has_tc_filter(if_index, parent_minor, handle, self.get_priority()?)
+            .map_err(|e| {
+                BpfmanError::Error(format!(
+                    "unable to get tc filters of if_index {if_index}: {e}"
+                ))
+            })
+    }
+
     pub(crate) fn set_revision(&mut self, revision: u32) -> Result<(), BpfmanError> {
         sled_insert(&self.db_tree, REVISION, &revision.to_ne_bytes())
     }
@@ -11,6 +11,7 @@ use aya::{
     programs::{
         Extension, Xdp,
         links::{FdLink, PinnedLink},
+        loaded_programs,
     },
 };
 use aya_obj::programs::XdpAttachType;
@@ -23,6 +24,7 @@ use crate::{
     dispatcher_config::XdpDispatcherConfig,
     errors::BpfmanError,
     multiprog::Dispatcher,
+    netlink::NetlinkManager,
     types::{Link, XdpLink},
     utils::{
         bytes_to_string, bytes_to_u32, bytes_to_u64, bytes_to_usize, enter_netns, nsid, sled_get,
@@ -32,7 +34,7 @@ use crate::{
 };
 
 pub(crate) const DEFAULT_PRIORITY: u32 = 50;
-const XDP_DISPATCHER_PROGRAM_NAME: &str = "xdp_dispatcher";
+pub(crate) const XDP_DISPATCHER_PROGRAM_NAME: &str = "xdp_dispatcher";
 
 static XDP_DISPATCHER_BYTES: &[u8] =
     include_bytes_aligned!(concat!(env!("OUT_DIR"), "/xdp_dispatcher_v2.bpf.o"));
@@ -328,6 +330,25 @@ impl XdpDispatcher {
         Ok(())
     }
 
+    /// Returns true if this dispatcher is still the XDP program attached to its
+    /// interface. `netns` is the network namespace of the interface.
+    pub(crate) fn is_attached(&self, netns: Option<PathBuf>) -> Result<bool, BpfmanError> {
+        let if_index = self.get_ifindex()?;
+        let prog_id = {
+            let _netns_guard = netns.map(enter_netns).transpose()?;
+            NetlinkManager::new().xdp_prog_id(if_index).map_err(|e| {
+                BpfmanError::Error(format!(
+                    "unable to get xdp program of if_index {if_index}: {e}"
+                ))
+            })?
+        };
+        Ok(prog_id.is_some_and(|id| {
+            loaded_programs()
+                .flatten()
+                .any(|p| p.id() == id && p.name_as_str() == Some(XDP_DISPATCHER_PROGRAM_NAME))
+        }))
+    }
+
     pub(crate) fn set_revision(&mut self, revision: u32) -> Result<(), BpfmanError> {
         sled_insert(&self.db_tree, REVISION, &revision.to_ne_bytes())
     }
@@ -4,20 +4,29 @@
 use std::{
     cell::RefCell,
     fs::File,
+    io::ErrorKind,
     os::fd::{FromRawFd, OwnedFd, RawFd},
+    path::Path,
+    time::Duration,
 };
 
 use anyhow::{anyhow, bail};
 use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST, NetlinkMessage, NetlinkPayload};
 use netlink_packet_route::{
     RouteNetlinkMessage,
-    tc::{TcAttribute, TcMessage},
+    link::{LinkAttribute, LinkMessage, LinkXdp},
+    tc::{TcAttribute, TcHandle, TcMessage},
 };
 use netlink_sys::{Socket, SocketAddr, constants::NETLINK_ROUTE};
 use nix::{
     fcntl::{self, OFlag},
+    libc::{ENOBUFS, RTMGRP_LINK},
     sched::{CloneFlags, setns},
-    sys::stat::Mode,
+    sys::{
+        socket::{setsockopt, sockopt},
+        stat::Mode,
+        time::TimeVal,
+    },
 };
 
 pub struct NetlinkManager {
@@ -38,6 +47,158 @@ impl NetlinkManager {
         })
     }
 
+    /// Creates a socket subscribed to the link events of the network namespace
+    /// at `ns`, or of the current network namespace if none is given. Waiting
+    /// for an event on the socket gives up after `timeout`.
+    ///
+    /// The socket holds a reference to the network namespace, which keeps the
+    /// namespace alive until the socket is dropped.
+    pub fn new_link_monitor(ns: Option<&Path>, timeout: Duration) -> Result<Self, anyhow::Error> {
+        let sock = init_monitor_sock(ns)?;
+        setsockopt(
+            &sock,
+            sockopt::ReceiveTimeout,
+            &TimeVal::new(timeout.as_secs() as _, timeout.subsec_micros() as _),
+        )?;
+        Ok(NetlinkManager {
+            sock: RefCell::new(sock),
+        })
+    }
+
+    /// Waits until an interface is added, removed or changed in the network
+    /// namespace of a socket created with [`NetlinkManager::new_link_monitor`].
+    /// Returns `true` if that happened, or `false` if the timeout of the socket
+    /// expired first. If the kernel dropped events because they were not read
+    /// quickly enough `true` is returned, since interfaces may have changed.
+    pub fn wait_for_link_change(&self) -> Result<bool, anyhow::Error> {
+        let socket = self.sock.borrow_mut();
+        let mut receive_buffer = vec![0; 65536];
+        loop {
+            let n = match socket.recv(&mut &mut receive_buffer[..], 0) {
+                Ok(n) => n,
+                Err(e) if e.raw_os_error() == Some(ENOBUFS) => return Ok(true),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return Ok(false),
+                Err(e) => bail!(e),
+            };
+            let mut offset = 0;
+            while offset < n {
+                let bytes = &receive_buffer[offset..n];
+                let rx_packet: NetlinkMessage<RouteNetlinkMessage> =
+                    NetlinkMessage::deserialize(bytes).map_err(|e| anyhow!(e))?;
+                if let NetlinkPayload::InnerMessage(
+                    RouteNetlinkMessage::NewLink(_) | RouteNetlinkMessage::DelLink(_),
+                ) = rx_packet.payload
+                {
+                    return Ok(true);
+                }
+                if rx_packet.header.length == 0 {
+                    break;
+                }
+                offset += rx_packet.header.length as usize;
+            }
+        }
+    }
+
+    /// Returns the ID of the XDP program attached to the interface, if any.
+    pub(crate) fn xdp_prog_id(&self, if_index: u32) -> Result<Option<u32>, anyhow::Error> {
+        let mut link_message = LinkMessage::default();
+        link_message.header.index = if_index;
+
+        for message in self.request(RouteNetlinkMessage::GetLink(link_message), false)? {
+            if let RouteNetlinkMessage::NewLink(link_message) = message {
+                for attribute in link_message.attributes {
+                    if let LinkAttribute::Xdp(xdp) = attribute
+                        && let Some(id) = xdp.iter().find_map(|x| match x {
+                            LinkXdp::ProgId(id) if *id != 0 => Some(*id),
+                            _ => None,
+                        })
+                    {
+                        return Ok(Some(id));
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns true if a filter with the given handle and priority is attached
+    /// to the clsact qdisc of the interface. `parent_minor` selects the ingress
+    /// or egress hook.
+    pub(crate) fn has_tc_filter(
+        &self,
+        if_index: u32,
+        parent_minor: u16,
+        handle: u32,
+        priority: u16,
+    ) -> Result<bool, anyhow::Error> {
+        let mut tc_message = TcMessage::default();
+        tc_message.header.index = if_index as i32;
+        tc_message.header.parent = TcHandle {
+            major: TcHandle::CLSACT.major,
+            minor: parent_minor,
+        };
+
+        Ok(self
+            .request(RouteNetlinkMessage::GetTrafficFilter(tc_message), true)?
+            .into_iter()
+            .any(|message| {
+                matches!(message, RouteNetlinkMessage::NewTrafficFilter(filter)
+                    if u32::from(filter.header.handle) == handle
+                        && (filter.header.info >> 16) as u16 == priority)
+            }))
+    }
+
+    // Sends a request and returns the messages sent in reply. For a dump
+    // request all the messages up to the end of the dump are returned,
+    // otherwise only the first one.
+    fn request(
+        &self,
+        message: RouteNetlinkMessage,
+        dump: bool,
+    ) -> Result<Vec<RouteNetlinkMessage>, anyhow::Error> {
+        let mut req = NetlinkMessage::from(message);
+        req.header.flags = if dump {
+            NLM_F_REQUEST | NLM_F_DUMP
+        } else {
+            NLM_F_REQUEST
+        };
+
+        req.finalize();
+        let mut buf = vec![0; req.header.length as usize];
+        req.serialize(&mut buf);
+
+        let socket = self.sock.borrow_mut();
+        socket.send(&buf, 0)?;
+
+        let mut receive_buffer = vec![0; 65536];
+        let mut messages = Vec::new();
+        loop {
+            let n = socket.recv(&mut &mut receive_buffer[..], 0)?;
+            let mut offset = 0;
+            while offset < n {
+                let rx_packet: NetlinkMessage<RouteNetlinkMessage> =
+                    NetlinkMessage::deserialize(&receive_buffer[offset..n])
+                        .map_err(|e| anyhow!(e))?;
+                let length = rx_packet.header.length as usize;
+                match rx_packet.payload {
+                    NetlinkPayload::Done(_) => return Ok(messages),
+                    NetlinkPayload::Error(e) => bail!(e),
+                    NetlinkPayload::InnerMessage(message) => {
+                        messages.push(message);
+                        if !dump {
+                            return Ok(messages);
+                        }
+                    }
+                    _ => {}
+                }
+                if length == 0 {
+                    break;
+                }
+                offset += length;
+            }
+        }
+    }
+
     pub(crate) fn has_qdisc(
         &self,
         qdisc_name: String,
@@ -103,6 +264,21 @@ fn init_namespace_sock(namespace: &str) -> Result<Socket, anyhow::Error> {
     Ok(socket)
 }
 
+fn init_monitor_sock(namespace: Option<&Path>) -> Result<Socket, anyhow::Error> {
+    let current_netns = current_netns()?;
+    if let Some(namespace) = namespace {
+        change_netns_fd(namespace)?;
+    }
+    let socket = Socket::new(NETLINK_ROUTE).and_then(|mut socket| {
+        socket.bind(&SocketAddr::new(0, RTMGRP_LINK as u32))?;
+        Ok(socket)
+    });
+    if namespace.is_some() {
+        change_netns_id(current_netns)?;
+    }
+    Ok(socket?)
+}
+
 pub fn current_netns() -> Result<OwnedFd, anyhow::Error> {
     // FD is opened with CLOEXEC so it will be closed once we exit
     // We need to keep this alive so we can get back home
@@ -117,7 +293,7 @@ pub fn current_netns() -> Result<OwnedFd, anyhow::Error> {
     Ok(fd)
 }
 
-fn change_netns_fd(path: &str) -> Result<(), anyhow::Error> {
+fn change_netns_fd<P: AsRef<Path>>(path: P) -> Result<(), anyhow::Error> {
     let f = File::open(path)?;
     setns(f, CloneFlags::CLONE_NEWNET).map_err(|e| anyhow!(e))
 }
@@ -0,0 +1,373 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+//! Admission policy that is evaluated before programs are loaded and before
+//! they are attached. The policy is configured in the `[policy]` section of
+//! `bpfman.toml`, and optionally in a directory of policy files.
+
+use std::{collections::HashMap, fs, path::Path};
+
+use oci_client::Reference;
+use serde::Deserialize;
+use sled::Db;
+
+use crate::{
+    config::PolicyConfig,
+    errors::BpfmanError,
+    get_programs_iter,
+    types::{AttachInfo, Location, METADATA_APPLICATION_TAG, Program},
+};
+
+impl PolicyConfig {
+    /// Returns the policy with the policy files in [`PolicyConfig::directory`]
+    /// merged in. Lists of allowed and denied values are combined, and the
+    /// smallest maximum program count wins.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or one of its policy files cannot be
+    /// read or parsed, so that a broken policy is never silently ignored.
+    pub fn resolve(&self) -> Result<PolicyConfig, BpfmanError> {
+        let mut policy = self.clone();
+        let Some(directory) = &self.directory else {
+            return Ok(policy);
+        };
+
+        let mut paths = fs::read_dir(directory)
+            .map_err(|e| {
+                BpfmanError::Error(format!(
+                    "unable to read policy directory {}: {e}",
+                    directory.display()
+                ))
+            })?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        for path in paths {
+            policy.merge(PolicyConfig::from_file(&path)?);
+        }
+        Ok(policy)
+    }
+
+    fn from_file(path: &Path) -> Result<PolicyConfig, BpfmanError> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            BpfmanError::Error(format!(
+                "unable to read policy file {}: {e}",
+                path.display()
+            ))
+        })?;
+        toml::from_str(&content).map_err(|e| {
+            BpfmanError::Error(format!(
+                "unable to parse policy file {}: {e}",
+                path.display()
+            ))
+        })
+    }
+
+    fn merge(&mut self, other: PolicyConfig) {
+        self.allowed_registries.extend(other.allowed_registries);
+        self.denied_kprobe_functions
+            .extend(other.denied_kprobe_functions);
+        for (iface, p) in other.interfaces {
+            self.interfaces
+                .entry(iface)
+                .or_default()
+                .program_types
+                .extend(p.program_types);
+        }
+        self.max_programs_per_application = match (
+            self.max_programs_per_application,
+            other.max_programs_per_application,
+        ) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+    }
+
+    /// Checks that `image_url` is in one of the allowed registries. An entry
+    /// matches the registry and repository of the image, so
+    /// `quay.io/bpfman-bytecode` allows `quay.io/bpfman-bytecode/xdp_pass:latest`.
+    pub(crate) fn check_image(&self, image_url: &str) -> Result<(), BpfmanError> {
+        if self.allowed_registries.is_empty() {
+            return Ok(());
+        }
+        let image: Reference = image_url.parse().map_err(|e| {
+            violation(
+                "allowed_registries",
+                format!("unable to parse image {image_url}: {e}"),
+            )
+        })?;
+        let name = format!("{}/{}", image.registry(), image.repository());
+        let allowed = self.allowed_registries.iter().any(|registry| {
+            let registry = registry.trim_end_matches('/');
+            name == registry || name.starts_with(&format!("{registry}/"))
+        });
+        if allowed {
+            Ok(())
+        } else {
+            Err(violation(
+                "allowed_registries",
+                format!("image {image_url} is not from an allowed registry"),
+            ))
+        }
+    }
+
+    /// Checks that programs of `program_type` may be attached to `iface`.
+    pub(crate) fn check_interface(
+        &self,
+        iface: &str,
+        program_type: &str,
+    ) -> Result<(), BpfmanError> {
+        match self.interfaces.get(iface) {
+            Some(p) if !p.program_types.iter().any(|t| t == program_type) => Err(violation(
+                &format!("interfaces.{iface}.program_types"),
+                format!("{program_type} programs may not be attached to {iface}"),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that a kprobe may be attached to `fn_name`. Entries ending with
+    /// `*` deny every function that starts with the rest of the entry.
+    pub(crate) fn check_kprobe(&self, fn_name: &str) -> Result<(), BpfmanError> {
+        let denied =
+            self.denied_kprobe_functions
+                .iter()
+                .find(|pattern| match pattern.strip_suffix('*') {
+                    Some(prefix) => fn_name.starts_with(prefix),
+                    None => fn_name == pattern.as_str(),
+                });
+        match denied {
+            Some(pattern) => Err(violation(
+                &format!("denied_kprobe_functions: {pattern}"),
+                format!("kprobes may not be attached to {fn_name}"),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks that an application would not have more than the maximum number
+    /// of programs.
+    pub(crate) fn check_application_count(
+        &self,
+        application: &str,
+        count: usize,
+    ) -> Result<(), BpfmanError> {
+        match self.max_programs_per_application {
+            Some(max) if count > max => Err(violation(
+                "max_programs_per_application",
+                format!(
+                    "application {application} would have {count} programs, more than the maximum of {max}"
+                ),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks an attach request, for the program types allowed on interfaces
+    /// and the kernel functions that kprobes may not be attached to.
+    pub(crate) fn check_attach(&self, attach_info: &AttachInfo) -> Result<(), BpfmanError> {
+        match attach_info {
+            AttachInfo::Xdp { iface, .. } => self.check_interface(iface, "xdp"),
+            AttachInfo::Tc { iface, .. } => self.check_interface(iface, "tc"),
+            AttachInfo::Tcx { iface, .. } => self.check_interface(iface, "tcx"),
+            AttachInfo::Kprobe { fn_name, .. } => self.check_kprobe(fn_name),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks a load request, for the registries that images may come from and
+    /// the number of programs of each application, including the programs that
+    /// are already loaded.
+    pub(crate) fn check_load(&self, root_db: &Db, programs: &[Program]) -> Result<(), BpfmanError> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for program in programs {
+            if let Location::Image(image) = program.get_data().get_location()? {
+                self.check_image(image.get_url())?;
+            }
+            if let Some(app) = program
+                .get_data()
+                .get_metadata()?
+                .get(METADATA_APPLICATION_TAG)
+            {
+                *counts.entry(app.clone()).or_default() += 1;
+            }
+        }
+
+        if counts.is_empty() || self.max_programs_per_application.is_none() {
+            return Ok(());
+        }
+        for (_, program) in get_programs_iter(root_db) {
+            if let Ok(metadata) = program.get_data().get_metadata()
+                && let Some(app) = metadata.get(METADATA_APPLICATION_TAG)
+                && let Some(count) = counts.get_mut(app)
+            {
+                *count += 1;
+            }
+        }
+        for (app, count) in counts {
+            self.check_application_count(&app, count)?;
+        }
+        Ok(())
+    }
+}
+
+fn violation(rule: &str, reason: String) -> BpfmanError {
+    BpfmanError::PolicyViolation {
+        rule: rule.to_string(),
+        reason,
+    }
+}
+
+/// A manifest of programs and their attachments, checked against the policy
+/// without loading anything by `bpfman policy check`.
+#[derive(Debug, Default, Deserialize, Clone)]
+#[serde(default)]
+pub struct PolicyManifest {
+    pub programs: Vec<ManifestProgram>,
+}
+
+#[derive(Debug, Default, Deserialize, Clone)]
+#[serde(default)]
+pub struct ManifestProgram {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub program_type: String,
+    pub image: Option<String>,
+    pub path: Option<String>,
+    pub metadata: HashMap<String, String>,
+    pub attach: Vec<ManifestAttach>,
+}
+
+#[derive(Debug, Default, Deserialize, Clone)]
+#[serde(default)]
+pub struct ManifestAttach {
+    pub iface: Option<String>,
+    pub fn_name: Option<String>,
+}
+
+/// A policy violation found in a manifest.
+#[derive(Debug)]
+pub struct ManifestViolation {
+    pub program: String,
+    pub error: BpfmanError,
+}
+
+impl PolicyManifest {
+    /// Checks every program and attachment in the manifest against `policy`
+    /// and returns all the violations. Programs that are already loaded are
+    /// not counted towards the maximum number of programs per application.
+    pub fn check(&self, policy: &PolicyConfig) -> Vec<ManifestViolation> {
+        let mut violations = vec![];
+        let mut push = |program: &ManifestProgram, result: Result<(), BpfmanError>| {
+            if let Err(error) = result {
+                violations.push(ManifestViolation {
+                    program: program.name.clone(),
+                    error,
+                });
+            }
+        };
+
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for program in &self.programs {
+            if let Some(image) = &program.image {
+                push(program, policy.check_image(image));
+            }
+            for attach in &program.attach {
+                if let Some(iface) = &attach.iface {
+                    push(
+                        program,
+                        policy.check_interface(iface, &program.program_type),
+                    );
+                }
+                if program.program_type == "kprobe"
+                    && let Some(fn_name) = &attach.fn_name
+                {
+                    push(program, policy.check_kprobe(fn_name));
+                }
+            }
+            if let Some(app) = program.metadata.get(METADATA_APPLICATION_TAG) {
+                let count = counts.entry(app).or_default();
+                *count += 1;
+                // Only report the program that goes over the maximum.
+                if policy.max_programs_per_application == Some(*count - 1) {
+                    push(program, policy.check_application_count(app, *count));
+                }
+            }
+        }
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_manifest() {
+        let policy: PolicyConfig = toml::from_str(
+            r#"
+            allowed_registries = ["quay.io/bpfman-bytecode"]
+            denied_kprobe_functions = ["commit_creds", "security_*"]
+            max_programs_per_application = 1
+
+            [interfaces.eth0]
+            program_types = ["xdp"]
+            "#,
+        )
+        .expect("error parsing policy");
+        let manifest: PolicyManifest = toml::from_str(
+            r#"
+            [[programs]]
+            name = "pass"
+            type = "xdp"
+            image = "quay.io/bpfman-bytecode/xdp_pass:latest"
+            metadata = { bpfman_application = "demo" }
+            attach = [{ iface = "eth0" }]
+
+            [[programs]]
+            name = "stats"
+            type = "tc"
+            image = "docker.io/example/tc_stats:latest"
+            metadata = { bpfman_application = "demo" }
+            attach = [{ iface = "eth0" }, { iface = "eth1" }]
+
+            [[programs]]
+            name = "probe"
+            type = "kprobe"
+            path = "/tmp/kprobe.o"
+            attach = [{ fn_name = "security_file_open" }, { fn_name = "do_unlinkat" }]
+            "#,
+        )
+        .expect("error parsing manifest");
+
+        let violations: Vec<(String, String)> = manifest
+            .check(&policy)
+            .into_iter()
+            .map(|v| match v.error {
+                BpfmanError::PolicyViolation { rule, .. } => (v.program, rule),
+                e => panic!("unexpected error {e}"),
+            })
+            .collect();
+        assert_eq!(
+            violations,
+            vec![
+                ("stats".to_string(), "allowed_registries".to_string()),
+                (
+                    "stats".to_string(),
+                    "interfaces.eth0.program_types".to_string()
+                ),
+                (
+                    "stats".to_string(),
+                    "max_programs_per_application".to_string()
+                ),
+                (
+                    "probe".to_string(),
+                    "denied_kprobe_functions: security_*".to_string()
+                ),
+            ]
+        );
+    }
+}
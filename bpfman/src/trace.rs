@@ -0,0 +1,820 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+//! Streaming of the debug output of a loaded eBPF program. Programs print
+//! with `bpf_printk` to the trace_pipe of the kernel, which is shared by all
+//! the programs on the host, or log with aya-log to a perf event array or ring
+//! buffer map that bpfman pins under the map pin path of the program.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    fs::{File, OpenOptions},
+    io::{ErrorKind, Read},
+    net::{Ipv4Addr, Ipv6Addr},
+    os::unix::fs::OpenOptionsExt,
+    path::Path,
+};
+
+use aya::{
+    maps::{Map, MapData, MapType, PerfEventArray, RingBuf, perf::PerfEventArrayBuffer},
+    util::online_cpus,
+};
+use bytes::BytesMut;
+use nix::libc::O_NONBLOCK;
+use object::{Object, ObjectSection, ObjectSymbol, RelocationTarget, SectionKind, SymbolIndex};
+use regex::Regex;
+use sled::Db;
+
+use crate::{errors::BpfmanError, get_program, utils::TRACEFS_EVENTS};
+
+/// The name of the map that aya-log sends the records of a program to.
+pub const AYA_LOG_MAP: &str = "AYA_LOGS";
+
+// The line of the trace_pipe that precedes the output of bpf_printk.
+const PRINTK_EVENT: &str = ": bpf_trace_printk: ";
+
+// The number of records read from a perf buffer at once.
+const PERF_READ_BUFFERS: usize = 16;
+
+/// The level of a record logged with aya-log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LogLevel::Error => write!(f, "ERROR"),
+            LogLevel::Warn => write!(f, "WARN"),
+            LogLevel::Info => write!(f, "INFO"),
+            LogLevel::Debug => write!(f, "DEBUG"),
+            LogLevel::Trace => write!(f, "TRACE"),
+        }
+    }
+}
+
+/// A line of debug output of a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceRecord {
+    /// A line of the trace_pipe printed with `bpf_printk`.
+    Printk(String),
+    /// A record logged with aya-log.
+    Log {
+        level: LogLevel,
+        target: String,
+        message: String,
+    },
+    /// The number of aya-log records dropped by the kernel because they were
+    /// not read fast enough.
+    Lost(usize),
+}
+
+impl fmt::Display for TraceRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TraceRecord::Printk(line) => write!(f, "{line}"),
+            TraceRecord::Log {
+                level,
+                target,
+                message,
+            } => write!(f, "[{level} {target}] {message}"),
+            TraceRecord::Lost(count) => write!(f, "lost {count} log records"),
+        }
+    }
+}
+
+enum LogReader {
+    Perf(Vec<PerfEventArrayBuffer<MapData>>),
+    RingBuf(RingBuf<MapData>),
+}
+
+/// The debug output of a loaded program, returned by [`trace_program`].
+pub struct ProgramTrace {
+    formats: Vec<Regex>,
+    trace_pipe: Option<File>,
+    partial_line: String,
+    log: Option<LogReader>,
+}
+
+impl ProgramTrace {
+    /// Returns the number of `bpf_printk` formats of the program that the
+    /// lines of the trace_pipe are matched against.
+    pub fn printk_formats(&self) -> usize {
+        self.formats.len()
+    }
+
+    /// Returns whether the records of the aya-log map of the program are read.
+    pub fn has_log_map(&self) -> bool {
+        self.log.is_some()
+    }
+
+    /// Returns the output of the program written since the last call, without
+    /// waiting for more.
+    ///
+    /// Lines of the trace_pipe are consumed by the first reader, so they are
+    /// not seen by other readers of the trace_pipe, including other
+    /// `bpfman trace` commands.
+    pub fn read(&mut self) -> Result<Vec<TraceRecord>, BpfmanError> {
+        let mut records = self.read_trace_pipe()?;
+        match &mut self.log {
+            Some(LogReader::Perf(buffers)) => {
+                let mut out_bufs = vec![BytesMut::with_capacity(1024); PERF_READ_BUFFERS];
+                for buffer in buffers.iter_mut() {
+                    while buffer.readable() {
+                        let events = buffer.read_events(&mut out_bufs).map_err(|e| {
+                            BpfmanError::Error(format!("unable to read {AYA_LOG_MAP}: {e}"))
+                        })?;
+                        for buf in &out_bufs[..events.read] {
+                            records.push(decode(buf)?);
+                        }
+                        if events.lost > 0 {
+                            records.push(TraceRecord::Lost(events.lost));
+                        }
+                    }
+                }
+            }
+            Some(LogReader::RingBuf(ring)) => {
+                while let Some(item) = ring.next() {
+                    records.push(decode(&item)?);
+                }
+            }
+            None => {}
+        }
+        Ok(records)
+    }
+
+    fn read_trace_pipe(&mut self) -> Result<Vec<TraceRecord>, BpfmanError> {
+        let Some(trace_pipe) = &mut self.trace_pipe else {
+            return Ok(vec![]);
+        };
+        let mut buf = [0u8; 65536];
+        loop {
+            match trace_pipe.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => self
+                    .partial_line
+                    .push_str(&String::from_utf8_lossy(&buf[..n])),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    return Err(BpfmanError::Error(format!(
+                        "unable to read the trace_pipe: {e}"
+                    )));
+                }
+            }
+        }
+        // The last line is kept until the rest of it is read.
+        let Some((lines, partial_line)) = self.partial_line.rsplit_once('\n') else {
+            return Ok(vec![]);
+        };
+        let records = lines
+            .lines()
+            .filter(|line| printk_matches(&self.formats, line))
+            .map(|line| TraceRecord::Printk(line.to_string()))
+            .collect();
+        self.partial_line = partial_line.to_string();
+        Ok(records)
+    }
+}
+
+/// Opens the debug output of the program with the kernel ID `id`, which must
+/// have been loaded by bpfman.
+///
+/// Lines of the trace_pipe are matched against the `bpf_printk` formats of the
+/// bytecode of the program. The kernel doesn't record which program printed a
+/// line, so lines of other programs with the same format are also returned.
+/// Records logged with aya-log are read from the map of the program, and are
+/// only returned for that program. A perf event array map sends its records
+/// to the last process that opened it, so only one trace of the program gets
+/// them at a time.
+///
+/// # Errors
+///
+/// This function returns an error if the program was not loaded by bpfman,
+/// or if it neither calls `bpf_printk` nor has an aya-log map.
+pub fn trace_program(root_db: &Db, id: u32) -> Result<ProgramTrace, BpfmanError> {
+    let program = get_program(root_db, id)?;
+    let data = program.get_data();
+
+    let formats = printk_formats(&data.get_program_bytes()?)?;
+    let trace_pipe = if formats.is_empty() {
+        None
+    } else {
+        Some(open_trace_pipe()?)
+    };
+
+    let log = match data.get_map_pin_path()? {
+        Some(path) if path.join(AYA_LOG_MAP).exists() => {
+            Some(open_log_map(&path.join(AYA_LOG_MAP))?)
+        }
+        _ => None,
+    };
+
+    if trace_pipe.is_none() && log.is_none() {
+        return Err(BpfmanError::Error(format!(
+            "program {id} neither calls bpf_printk nor has an {AYA_LOG_MAP} map"
+        )));
+    }
+
+    Ok(ProgramTrace {
+        formats,
+        trace_pipe,
+        partial_line: String::new(),
+        log,
+    })
+}
+
+fn open_trace_pipe() -> Result<File, BpfmanError> {
+    let path = TRACEFS_EVENTS
+        .iter()
+        .filter_map(|events| Path::new(events).parent())
+        .map(|tracefs| tracefs.join("trace_pipe"))
+        .find(|path| path.exists())
+        .ok_or_else(|| BpfmanError::Error("tracefs is not mounted".to_string()))?;
+    OpenOptions::new()
+        .read(true)
+        .custom_flags(O_NONBLOCK)
+        .open(&path)
+        .map_err(|e| BpfmanError::Error(format!("unable to open {}: {e}", path.display())))
+}
+
+fn open_log_map(path: &Path) -> Result<LogReader, BpfmanError> {
+    let map_error = |e: aya::maps::MapError| {
+        BpfmanError::Error(format!("unable to open {}: {e}", path.display()))
+    };
+    let map_data = MapData::from_pin(path).map_err(map_error)?;
+    match map_data
+        .info()
+        .and_then(|i| i.map_type())
+        .map_err(map_error)?
+    {
+        MapType::PerfEventArray => {
+            let mut array =
+                PerfEventArray::try_from(Map::PerfEventArray(map_data)).map_err(map_error)?;
+            let cpus = online_cpus()
+                .map_err(|(_, e)| BpfmanError::Error(format!("unable to list the CPUs: {e}")))?;
+            let buffers = cpus
+                .into_iter()
+                .map(|cpu| array.open(cpu, None))
+                .collect::<Result<_, _>>()
+                .map_err(|e| {
+                    BpfmanError::Error(format!("unable to open {}: {e}", path.display()))
+                })?;
+            Ok(LogReader::Perf(buffers))
+        }
+        MapType::RingBuf => Ok(LogReader::RingBuf(
+            RingBuf::try_from(Map::RingBuf(map_data)).map_err(map_error)?,
+        )),
+        map_type => Err(BpfmanError::Error(format!(
+            "{} is a {map_type:?} map, not a perf event array or ring buffer",
+            path.display()
+        ))),
+    }
+}
+
+// The bpf_trace_printk and bpf_trace_vprintk helpers that bpf_printk calls.
+const PRINTK_HELPERS: [i32; 2] = [6, 177];
+
+// Returns the bpf_printk formats of the bytecode, which are the strings of
+// its read only data sections that are passed to bpf_trace_printk, as
+// regular expressions matching the lines they print.
+fn printk_formats(bytecode: &[u8]) -> Result<Vec<Regex>, BpfmanError> {
+    let elf = object::File::parse(bytecode)
+        .map_err(|e| BpfmanError::Error(format!("unable to parse bytecode: {e}")))?;
+    let mut formats = vec![];
+    for section in elf.sections() {
+        if section.kind() != SectionKind::Text {
+            continue;
+        }
+        let Ok(code) = section.data() else {
+            continue;
+        };
+        let targets: HashMap<u64, SymbolIndex> = section
+            .relocations()
+            .filter_map(|(offset, relocation)| match relocation.target() {
+                RelocationTarget::Symbol(symbol) => Some((offset, symbol)),
+                _ => None,
+            })
+            .collect();
+        for (offset, imm) in printk_calls(code, elf.is_little_endian(), &targets) {
+            let Some(string) = rodata_string(&elf, targets[&offset], imm) else {
+                continue;
+            };
+            let string = string.trim_end_matches('\n');
+            if string
+                .chars()
+                .any(|c| c.is_control() && c != '\t' && c != '\n')
+            {
+                continue;
+            }
+            if let Some(format) = format_regex(string)
+                && !formats
+                    .iter()
+                    .any(|f: &Regex| f.as_str() == format.as_str())
+            {
+                formats.push(format);
+            }
+        }
+    }
+    Ok(formats)
+}
+
+// Returns the offset and immediate of the instructions that load the format
+// of each call to bpf_trace_printk in the code of a program, which are the
+// 64-bit loads of a relocated address into r1 that the calls follow.
+fn printk_calls(
+    code: &[u8],
+    little_endian: bool,
+    targets: &HashMap<u64, SymbolIndex>,
+) -> Vec<(u64, i32)> {
+    let mut calls = vec![];
+    let mut r1 = None;
+    let mut insns = code.chunks_exact(8).enumerate();
+    while let Some((index, insn)) = insns.next() {
+        let offset = index as u64 * 8;
+        let opcode = insn[0];
+        let (dst, src) = if little_endian {
+            (insn[1] & 0xf, insn[1] >> 4)
+        } else {
+            (insn[1] >> 4, insn[1] & 0xf)
+        };
+        let imm = [insn[4], insn[5], insn[6], insn[7]];
+        let imm = if little_endian {
+            i32::from_le_bytes(imm)
+        } else {
+            i32::from_be_bytes(imm)
+        };
+        match opcode {
+            // ld_imm64, which takes two instructions.
+            0x18 => {
+                insns.next();
+                if dst == 1 {
+                    r1 = targets.contains_key(&offset).then_some((offset, imm));
+                }
+            }
+            // call, after which r1 to r5 are clobbered.
+            0x85 => {
+                if src == 0
+                    && PRINTK_HELPERS.contains(&imm)
+                    && let Some(call) = r1
+                {
+                    calls.push(call);
+                }
+                r1 = None;
+            }
+            // The classes of instructions that write to their destination
+            // register: ld, ldx, alu and alu64.
+            _ if dst == 1 && matches!(opcode & 0x7, 0x0 | 0x1 | 0x4 | 0x7) => r1 = None,
+            _ => {}
+        }
+    }
+    calls
+}
+
+// Returns the string at an offset from a symbol of a read only data section.
+fn rodata_string<'a>(elf: &object::File<'a>, symbol: SymbolIndex, offset: i32) -> Option<&'a str> {
+    let symbol = elf.symbol_by_index(symbol).ok()?;
+    let section = elf.section_by_index(symbol.section_index()?).ok()?;
+    if !section.name().is_ok_and(|n| n.starts_with(".rodata")) {
+        return None;
+    }
+    let data = section.data().ok()?;
+    let start = usize::try_from(symbol.address().checked_add_signed(offset.into())?).ok()?;
+    let string = data.get(start..)?.split(|b| *b == 0).next()?;
+    std::str::from_utf8(string).ok()
+}
+
+// Converts a bpf_printk format to a regular expression matching its output,
+// where each conversion matches any text. Formats without any text besides
+// conversions are ignored, as they would match the output of every program.
+fn format_regex(format: &str) -> Option<Regex> {
+    let conversion =
+        Regex::new(r"%(%|[-+ #0]*\d*(\.\d+)?(hh|h|ll|l|z)?(p[a-zA-Z0-9]*|[diouxXcs]))").ok()?;
+    if conversion
+        .replace_all(format, "")
+        .chars()
+        .all(char::is_whitespace)
+    {
+        return None;
+    }
+    let mut pattern = String::from("^");
+    let mut last = 0;
+    for m in conversion.find_iter(format) {
+        pattern.push_str(&regex::escape(&format[last..m.start()]));
+        if m.as_str() == "%%" {
+            pattern.push('%');
+        } else {
+            pattern.push_str(".*?");
+        }
+        last = m.end();
+    }
+    pattern.push_str(&regex::escape(&format[last..]));
+    pattern.push('$');
+    Regex::new(&pattern).ok()
+}
+
+// Returns whether a line of the trace_pipe was printed with bpf_printk with
+// one of the formats.
+fn printk_matches(formats: &[Regex], line: &str) -> bool {
+    let Some((_, message)) = line.split_once(PRINTK_EVENT) else {
+        return false;
+    };
+    formats.iter().any(|f| f.is_match(message))
+}
+
+// The version of aya-log-common whose record layout is decoded. The layout
+// isn't versioned, so records of other versions are detected by their fields
+// not matching it.
+const AYA_LOG_LAYOUT: &str = "aya-log-common 0.1.15";
+
+// The tags of the fields of an aya-log record, which are written in this
+// order, and of its arguments.
+const FIELD_TARGET: u8 = 1;
+const FIELD_LEVEL: u8 = 2;
+const FIELD_MODULE: u8 = 3;
+const FIELD_FILE: u8 = 4;
+const FIELD_LINE: u8 = 5;
+const FIELD_NUM_ARGS: u8 = 6;
+
+const ARG_DISPLAY_HINT: u8 = 0;
+const ARG_I8: u8 = 1;
+const ARG_I16: u8 = 2;
+const ARG_I32: u8 = 3;
+const ARG_I64: u8 = 4;
+const ARG_ISIZE: u8 = 5;
+const ARG_U8: u8 = 6;
+const ARG_U16: u8 = 7;
+const ARG_U32: u8 = 8;
+const ARG_U64: u8 = 9;
+const ARG_USIZE: u8 = 10;
+const ARG_F32: u8 = 11;
+const ARG_F64: u8 = 12;
+const ARG_IPV4_ADDR: u8 = 13;
+const ARG_IPV6_ADDR: u8 = 14;
+const ARG_ARR_U8_LEN4: u8 = 15;
+const ARG_ARR_U8_LEN6: u8 = 16;
+const ARG_ARR_U8_LEN16: u8 = 17;
+const ARG_ARR_U16_LEN8: u8 = 18;
+const ARG_BYTES: u8 = 19;
+const ARG_STR: u8 = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DisplayHint {
+    Default,
+    LowerHex,
+    UpperHex,
+    Ip,
+    LowerMac,
+    UpperMac,
+}
+
+impl DisplayHint {
+    fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            1 => Some(DisplayHint::Default),
+            2 => Some(DisplayHint::LowerHex),
+            3 => Some(DisplayHint::UpperHex),
+            4 => Some(DisplayHint::Ip),
+            5 => Some(DisplayHint::LowerMac),
+            6 => Some(DisplayHint::UpperMac),
+            _ => None,
+        }
+    }
+}
+
+// Returns the tag and the value of the next field of an aya-log record, which
+// are written as a u8 tag, a u16 length and the value.
+fn next_field<'a>(buf: &mut &'a [u8]) -> Option<(u8, &'a [u8])> {
+    let (&tag, rest) = buf.split_first()?;
+    let len = u16::from_ne_bytes(rest.get(..2)?.try_into().ok()?) as usize;
+    let value = rest.get(2..2 + len)?;
+    *buf = &rest[2 + len..];
+    Some((tag, value))
+}
+
+// Decodes a record logged with aya-log, or returns an error if the record
+// doesn't have the layout of the supported version of aya-log.
+fn decode(record: &[u8]) -> Result<TraceRecord, BpfmanError> {
+    decode_record(record).ok_or_else(|| {
+        BpfmanError::Error(format!(
+            "unable to decode an {AYA_LOG_MAP} record of {} bytes, only records of \
+             programs built with {AYA_LOG_LAYOUT} are supported",
+            record.len()
+        ))
+    })
+}
+
+fn decode_record(mut buf: &[u8]) -> Option<TraceRecord> {
+    let mut target = String::new();
+    let mut level = None;
+    let mut num_args = 0;
+    for field in [
+        FIELD_TARGET,
+        FIELD_LEVEL,
+        FIELD_MODULE,
+        FIELD_FILE,
+        FIELD_LINE,
+        FIELD_NUM_ARGS,
+    ] {
+        let (tag, value) = next_field(&mut buf)?;
+        if tag != field {
+            return None;
+        }
+        match tag {
+            FIELD_TARGET => target = String::from_utf8_lossy(value).into_owned(),
+            FIELD_LEVEL => {
+                level = match value.first()? {
+                    1 => Some(LogLevel::Error),
+                    2 => Some(LogLevel::Warn),
+                    3 => Some(LogLevel::Info),
+                    4 => Some(LogLevel::Debug),
+                    5 => Some(LogLevel::Trace),
+                    _ => return None,
+                }
+            }
+            FIELD_NUM_ARGS => {
+                num_args = match value.len() {
+                    4 => u32::from_ne_bytes(value.try_into().ok()?) as usize,
+                    8 => u64::from_ne_bytes(value.try_into().ok()?) as usize,
+                    _ => return None,
+                }
+            }
+            FIELD_LINE if value.len() != 4 => return None,
+            // The module, file and line of the record are not shown.
+            _ => {}
+        }
+    }
+
+    let mut message = String::new();
+    let mut hint = DisplayHint::Default;
+    for _ in 0..num_args {
+        let (tag, value) = next_field(&mut buf)?;
+        if tag == ARG_DISPLAY_HINT {
+            hint = DisplayHint::from_tag(*value.first()?)?;
+            continue;
+        }
+        message.push_str(&format_argument(tag, value, hint)?);
+        hint = DisplayHint::Default;
+    }
+
+    Some(TraceRecord::Log {
+        level: level?,
+        target,
+        message,
+    })
+}
+
+fn format_argument(tag: u8, value: &[u8], hint: DisplayHint) -> Option<String> {
+    let signed = |v: i64| match hint {
+        DisplayHint::LowerHex => format!("{v:x}"),
+        DisplayHint::UpperHex => format!("{v:X}"),
+        _ => v.to_string(),
+    };
+    let unsigned = |v: u64| match hint {
+        DisplayHint::LowerHex => format!("{v:x}"),
+        DisplayHint::UpperHex => format!("{v:X}"),
+        _ => v.to_string(),
+    };
+    let formatted = match tag {
+        ARG_I8 => signed(i8::from_ne_bytes(value.try_into().ok()?) as i64),
+        ARG_I16 => signed(i16::from_ne_bytes(value.try_into().ok()?) as i64),
+        ARG_I32 => signed(i32::from_ne_bytes(value.try_into().ok()?) as i64),
+        ARG_I64 | ARG_ISIZE => signed(i64::from_ne_bytes(value.try_into().ok()?)),
+        ARG_U8 => unsigned(u8::from_ne_bytes(value.try_into().ok()?) as u64),
+        ARG_U16 => unsigned(u16::from_ne_bytes(value.try_into().ok()?) as u64),
+        ARG_U32 => {
+            let v = u32::from_ne_bytes(value.try_into().ok()?);
+            if hint == DisplayHint::Ip {
+                Ipv4Addr::from(v).to_string()
+            } else {
+                unsigned(v as u64)
+            }
+        }
+        ARG_U64 | ARG_USIZE => unsigned(u64::from_ne_bytes(value.try_into().ok()?)),
+        ARG_F32 => f32::from_ne_bytes(value.try_into().ok()?).to_string(),
+        ARG_F64 => f64::from_ne_bytes(value.try_into().ok()?).to_string(),
+        ARG_IPV4_ADDR => Ipv4Addr::from(<[u8; 4]>::try_from(value).ok()?).to_string(),
+        ARG_IPV6_ADDR => Ipv6Addr::from(<[u8; 16]>::try_from(value).ok()?).to_string(),
+        ARG_ARR_U8_LEN4 if hint == DisplayHint::Ip => {
+            Ipv4Addr::from(<[u8; 4]>::try_from(value).ok()?).to_string()
+        }
+        ARG_ARR_U8_LEN16 if hint == DisplayHint::Ip => {
+            Ipv6Addr::from(<[u8; 16]>::try_from(value).ok()?).to_string()
+        }
+        ARG_ARR_U16_LEN8 if hint == DisplayHint::Ip => {
+            let mut segments = [0u16; 8];
+            for (segment, bytes) in segments.iter_mut().zip(value.chunks_exact(2)) {
+                *segment = u16::from_ne_bytes(bytes.try_into().ok()?);
+            }
+            Ipv6Addr::from(segments).to_string()
+        }
+        ARG_ARR_U8_LEN6 if hint == DisplayHint::LowerMac => hex_bytes(value, ":", false),
+        ARG_ARR_U8_LEN6 if hint == DisplayHint::UpperMac => hex_bytes(value, ":", true),
+        ARG_ARR_U8_LEN4 | ARG_ARR_U8_LEN6 | ARG_ARR_U8_LEN16 | ARG_BYTES => match hint {
+            DisplayHint::LowerHex => hex_bytes(value, "", false),
+            DisplayHint::UpperHex => hex_bytes(value, "", true),
+            _ => format!("{value:?}"),
+        },
+        ARG_ARR_U16_LEN8 => {
+            let values: Vec<u16> = value
+                .chunks_exact(2)
+                .map(|b| u16::from_ne_bytes([b[0], b[1]]))
+                .collect();
+            format!("{values:?}")
+        }
+        ARG_STR => String::from_utf8_lossy(value).into_owned(),
+        _ => return None,
+    };
+    Some(formatted)
+}
+
+fn hex_bytes(bytes: &[u8], separator: &str, upper: bool) -> String {
+    bytes
+        .iter()
+        .map(|b| {
+            if upper {
+                format!("{b:02X}")
+            } else {
+                format!("{b:02x}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(tag: u8, value: &[u8]) -> Vec<u8> {
+        let mut field = vec![tag];
+        field.extend_from_slice(&(value.len() as u16).to_ne_bytes());
+        field.extend_from_slice(value);
+        field
+    }
+
+    #[test]
+    fn test_printk_matches() {
+        let formats: Vec<Regex> = ["xdp: received packet on %d, len %llu", "100%% done", "%d"]
+            .iter()
+            .filter_map(|f| format_regex(f))
+            .collect();
+        assert!(printk_matches(
+            &formats,
+            "          <idle>-0       [003] ..s21  7435.123: bpf_trace_printk: xdp: received packet on 2, len 98"
+        ));
+        assert!(printk_matches(
+            &formats,
+            " ping-12 [001] d.s11 7.5: bpf_trace_printk: 100% done"
+        ));
+        assert!(!printk_matches(
+            &formats,
+            " ping-12 [001] d.s11 7.5: bpf_trace_printk: tc: received packet"
+        ));
+        assert!(!printk_matches(
+            &formats,
+            " ping-12 [001] d.s11 7.5: bpf_trace_printk: 42"
+        ));
+        // Other events of the trace_pipe are never matched.
+        assert!(!printk_matches(
+            &formats,
+            " ping-12 [001] d.s11 7.5: sched_switch: 100% done"
+        ));
+    }
+
+    // Returns a BPF object with the strings in .rodata and a program calling
+    // bpf_trace_printk with those of the printk offsets as format.
+    fn printk_object(strings: &[&str], printk: &[usize]) -> Vec<u8> {
+        use object::{
+            Architecture, BinaryFormat, Endianness, SymbolFlags,
+            write::{Relocation, StandardSection},
+        };
+        let mut obj =
+            object::write::Object::new(BinaryFormat::Elf, Architecture::Bpf, Endianness::Little);
+        let mut rodata = vec![];
+        let mut offsets = vec![];
+        for string in strings {
+            offsets.push(rodata.len() as i32);
+            rodata.extend_from_slice(string.as_bytes());
+            rodata.push(0);
+        }
+        let rodata_id = obj.section_id(StandardSection::ReadOnlyData);
+        obj.append_section_data(rodata_id, &rodata, 1);
+        let rodata_symbol = obj.section_symbol(rodata_id);
+        let text_id = obj.add_section(vec![], b"xdp".to_vec(), object::SectionKind::Text);
+        let mut code = vec![];
+        for index in printk {
+            // r1 = .rodata + offset ll; r2 = 8; call 6
+            let offset = code.len() as u64;
+            code.extend([0x18, 0x01, 0, 0]);
+            code.extend(offsets[*index].to_le_bytes());
+            code.extend([0; 8]);
+            code.extend([0xb7, 0x02, 0, 0, 8, 0, 0, 0]);
+            code.extend([0x85, 0, 0, 0, 6, 0, 0, 0]);
+            obj.add_relocation(
+                text_id,
+                Relocation {
+                    offset,
+                    symbol: rodata_symbol,
+                    addend: 0,
+                    flags: object::RelocationFlags::Elf {
+                        r_type: object::elf::R_BPF_64_64,
+                    },
+                },
+            )
+            .unwrap();
+        }
+        // r0 = 2; exit
+        code.extend([0xb7, 0, 0, 0, 2, 0, 0, 0]);
+        code.extend([0x95, 0, 0, 0, 0, 0, 0, 0]);
+        obj.append_section_data(text_id, &code, 8);
+        obj.add_symbol(object::write::Symbol {
+            name: b"xdp_prog".to_vec(),
+            value: 0,
+            size: code.len() as u64,
+            kind: object::SymbolKind::Text,
+            scope: object::SymbolScope::Dynamic,
+            weak: false,
+            section: object::write::SymbolSection::Section(text_id),
+            flags: SymbolFlags::None,
+        });
+        obj.write().unwrap()
+    }
+
+    #[test]
+    fn test_printk_formats() {
+        let bytecode = printk_object(
+            &[
+                "%d",
+                "xdp: received packet on %d\n",
+                "unrelated constant %s",
+                "%s %d",
+            ],
+            &[0, 1, 3],
+        );
+        let formats = printk_formats(&bytecode).unwrap();
+        // Strings not passed to bpf_trace_printk and formats made only of
+        // conversions are not used.
+        assert_eq!(
+            formats.iter().map(|f| f.as_str()).collect::<Vec<_>>(),
+            [r"^xdp: received packet on .*?$"]
+        );
+        assert!(printk_matches(
+            &formats,
+            " ping-12 [001] d.s11 7.5: bpf_trace_printk: xdp: received packet on 2"
+        ));
+        assert!(!printk_matches(
+            &formats,
+            " ping-12 [001] d.s11 7.5: bpf_trace_printk: 42"
+        ));
+        assert!(!printk_matches(
+            &formats,
+            " ping-12 [001] d.s11 7.5: bpf_trace_printk: unrelated constant 42"
+        ));
+    }
+
+    #[test]
+    fn test_decode_record() {
+        let mut record = vec![];
+        record.extend(field(FIELD_TARGET, b"xdp_log"));
+        record.extend(field(FIELD_LEVEL, &[3]));
+        record.extend(field(FIELD_MODULE, b"xdp_log"));
+        record.extend(field(FIELD_FILE, b"src/main.rs"));
+        record.extend(field(FIELD_LINE, &12u32.to_ne_bytes()));
+        record.extend(field(FIELD_NUM_ARGS, &4usize.to_ne_bytes()));
+        record.extend(field(ARG_STR, b"received a packet from "));
+        record.extend(field(ARG_DISPLAY_HINT, &[4]));
+        record.extend(field(ARG_U32, &0x0a000001u32.to_ne_bytes()));
+        record.extend(field(ARG_STR, b"!"));
+        assert_eq!(
+            decode_record(&record),
+            Some(TraceRecord::Log {
+                level: LogLevel::Info,
+                target: "xdp_log".to_string(),
+                message: "received a packet from 10.0.0.1!".to_string(),
+            })
+        );
+        // A record cut short is not decoded.
+        assert_eq!(decode_record(&record[..record.len() - 2]), None);
+
+        // Records of another layout, here with the level before the target,
+        // are an error rather than decoded with the wrong fields.
+        let mut other = vec![];
+        other.extend(field(FIELD_LEVEL, &[3]));
+        other.extend(field(FIELD_TARGET, b"xdp_log"));
+        other.extend_from_slice(&record[14..]);
+        assert_eq!(decode_record(&other), None);
+        assert!(
+            decode(&other)
+                .unwrap_err()
+                .to_string()
+                .contains(AYA_LOG_LAYOUT)
+        );
+    }
+}
@@ -18,6 +18,7 @@ use aya::{
         ProgramType as AyaProgramType,
     },
 };
+use base64::Engine as _;
 use chrono::{Local, prelude::DateTime};
 use clap::ValueEnum;
 use log::{debug, info, warn};
@@ -38,6 +39,9 @@ use crate::{
 
 // Special metadata tag used to group programs loaded under the same load request
 pub const METADATA_APPLICATION_TAG: &str = "bpfman_application";
+// Special metadata tag holding the unique alias a program or link can be
+// addressed by instead of its kernel ID
+pub const METADATA_ALIAS_TAG: &str = "bpfman_alias";
 
 // These constants define the key of SLED DB
 // Program database layout
@@ -85,6 +89,8 @@ const LOCATION_USERNAME: &str = "location_username";
 const LOCATION_PASSWORD: &str = "location_password";
 const MAP_OWNER_ID: &str = "map_owner_id";
 const MAP_PIN_PATH: &str = "map_pin_path";
+const VERIFIER_LOG_LEVEL: &str = "verifier_log_level";
+const VERIFIER_LOG_SUMMARY: &str = "verifier_log_summary";
 const PREFIX_GLOBAL_DATA: &str = "global_data_";
 const PREFIX_METADATA: &str = "metadata_";
 const PROGRAM_LINKS: &str = "links_";
@@ -110,12 +116,46 @@ const PREFIX_KERNEL_MAP_IDS: &str = "kernel_map_ids_";
 // Root
 // |- link_{id} || pre_attach_link_{id} # tree
 //    |- program_id # fkey
+//    |- enabled
+//    |- group # fkey to group_{name}
+//    |- interface_missing
+//    |- reattach_policy
 //    |- {metadata_key} # metadata
 pub(crate) const LINKS_LINK_PREFIX: &str = "link_";
 const LINKS_PRE_ATTACH_LINK_PREFIX: &str = "pre_attach_link_";
 const LINKS_LINK_PROGRAM_ID: &str = "program_id";
 const LINKS_LINK_TYPE: &str = "type";
 const LINKS_PROG_NAME: &str = "prog_name";
+const LINKS_LINK_ENABLED: &str = "enabled";
+const LINKS_LINK_GROUP: &str = "group";
+const LINKS_LINK_INTERFACE_MISSING: &str = "interface_missing";
+const LINKS_LINK_REATTACH_POLICY: &str = "reattach_policy";
+
+// Link group database layout
+//
+// Root
+// |- group_{name} # tree
+//    |- name
+//    |- program_id # fkey
+//    |- selector
+//    |- member_{index} # serialized LinkGroupMember
+pub(crate) const LINK_GROUP_PREFIX: &str = "group_";
+const LINK_GROUP_NAME: &str = "name";
+const LINK_GROUP_PROGRAM_ID: &str = "program_id";
+const LINK_GROUP_SELECTOR: &str = "selector";
+const PREFIX_LINK_GROUP_MEMBER: &str = "member_";
+
+// Event log database layout
+//
+// Root
+// |- events # tree
+//    |- compacted # revision of the newest event dropped from the log
+//    |- revision # revision of the newest event recorded
+//    |- event_{revision} # serialized Event, revision in big endian
+pub(crate) const EVENTS_TREE: &str = "events";
+pub(crate) const EVENTS_COMPACTED: &str = "compacted";
+pub(crate) const EVENTS_REVISION: &str = "revision";
+pub(crate) const PREFIX_EVENT: &str = "event_";
 
 // Link metadata keys
 const XDP_PRIORITY: &str = "xdp_priority";
@@ -214,6 +254,101 @@ impl LinkData {
         sled_insert(&self.0, LINKS_LINK_TYPE, &(link_type as u32).to_ne_bytes())
     }
 
+    pub(crate) fn set_enabled(&mut self, enabled: bool) -> Result<(), BpfmanError> {
+        sled_insert(&self.0, LINKS_LINK_ENABLED, &bool_to_bytes(enabled))
+    }
+
+    /// Retrieves whether the link is enabled. Links created before the flag
+    /// existed have no entry and are treated as enabled.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<bool, BpfmanError>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - There is an issue fetching the flag from the database.
+    pub fn get_enabled(&self) -> Result<bool, BpfmanError> {
+        Ok(sled_get_option(&self.0, LINKS_LINK_ENABLED)?
+            .map(bytes_to_bool)
+            .unwrap_or(true))
+    }
+
+    pub(crate) fn set_group(&mut self, name: &str) -> Result<(), BpfmanError> {
+        sled_insert(&self.0, LINKS_LINK_GROUP, name.as_bytes())
+    }
+
+    /// Retrieves the name of the link group the link was created by, if any.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Option<String>, BpfmanError>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - There is an issue fetching the group from the database.
+    pub fn get_group(&self) -> Result<Option<String>, BpfmanError> {
+        sled_get_option(&self.0, LINKS_LINK_GROUP).map(|v| v.map(|v| bytes_to_string(&v)))
+    }
+
+    pub(crate) fn set_interface_missing(&mut self, missing: bool) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.0,
+            LINKS_LINK_INTERFACE_MISSING,
+            &bool_to_bytes(missing),
+        )
+    }
+
+    /// Retrieves whether the interface the link was attached to has been
+    /// removed. Such links stay in the database but are not attached to
+    /// anything.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<bool, BpfmanError>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - There is an issue fetching the flag from the database.
+    pub fn get_interface_missing(&self) -> Result<bool, BpfmanError> {
+        Ok(sled_get_option(&self.0, LINKS_LINK_INTERFACE_MISSING)?
+            .map(bytes_to_bool)
+            .unwrap_or(false))
+    }
+
+    pub(crate) fn set_reattach_policy(
+        &mut self,
+        policy: ReattachPolicy,
+    ) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.0,
+            LINKS_LINK_REATTACH_POLICY,
+            &(policy as u32).to_ne_bytes(),
+        )
+    }
+
+    /// Retrieves what happens to the link when its interface is removed and
+    /// later recreated. Links without an entry use [`ReattachPolicy::Never`].
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<ReattachPolicy, BpfmanError>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - There is an issue fetching the policy from the database.
+    /// - The stored policy is not valid.
+    pub fn get_reattach_policy(&self) -> Result<ReattachPolicy, BpfmanError> {
+        match sled_get_option(&self.0, LINKS_LINK_REATTACH_POLICY)? {
+            Some(v) => bytes_to_u32(v).try_into(),
+            None => Ok(ReattachPolicy::Never),
+        }
+    }
+
     pub(crate) fn finalize(&mut self, root_db: &Db) -> Result<(), BpfmanError> {
         let new_tree = root_db
             .open_tree(LINKS_LINK_PREFIX.to_string() + &self.get_id()?.to_string())
@@ -911,6 +1046,11 @@ impl Link {
 
     pub(crate) fn delete(self, root_db: &Db) -> Result<(), anyhow::Error> {
         let id = self.get_id()?;
+        if let Some(name) = self.get_group()?
+            && let Ok(group) = LinkGroup::open(root_db, &name)
+        {
+            group.remove_link(root_db, id)?;
+        }
         root_db.drop_tree(format!("{LINKS_LINK_PREFIX}{id}"))?;
         let path = format!("{RTDIR_FS_LINKS}/{id}");
         if PathBuf::from(&path).exists() {
@@ -919,6 +1059,19 @@ impl Link {
         Ok(())
     }
 
+    /// Removes the pinned kernel link while keeping the link in the database.
+    /// Once the pin is gone the kernel releases the link, detaching the program
+    /// from its hook point.
+    pub(crate) fn unpin(&self) -> Result<(), BpfmanError> {
+        let path = format!("{RTDIR_FS_LINKS}/{}", self.get_id()?);
+        if PathBuf::from(&path).exists() {
+            fs::remove_file(&path).map_err(|e| {
+                BpfmanError::Error(format!("unable to remove link pin {path}: {e}"))
+            })?;
+        }
+        Ok(())
+    }
+
     pub fn get_id(&self) -> Result<u32, BpfmanError> {
         match self {
             Link::Xdp(p) => p.0.get_id(),
@@ -932,6 +1085,19 @@ impl Link {
         }
     }
 
+    pub fn kind(&self) -> LinkType {
+        match self {
+            Link::Xdp(_) => LinkType::Xdp,
+            Link::Tc(_) => LinkType::Tc,
+            Link::Tcx(_) => LinkType::Tcx,
+            Link::Tracepoint(_) => LinkType::Tracepoint,
+            Link::Kprobe(_) => LinkType::Kprobe,
+            Link::Uprobe(_) => LinkType::Uprobe,
+            Link::Fentry(_) => LinkType::Fentry,
+            Link::Fexit(_) => LinkType::Fexit,
+        }
+    }
+
     pub fn get_program_id(&self) -> Result<u32, BpfmanError> {
         match self {
             Link::Xdp(p) => p.0.get_program_id(),
@@ -1007,6 +1173,158 @@ impl Link {
         application
     }
 
+    /// Returns the alias the user assigned, if any.
+    pub fn get_alias(&self) -> Option<String> {
+        Self::get_metadata(self)
+            .ok()
+            .and_then(|mut metadata| metadata.remove(METADATA_ALIAS_TAG))
+    }
+
+    pub fn get_enabled(&self) -> Result<bool, BpfmanError> {
+        match self {
+            Link::Xdp(p) => p.0.get_enabled(),
+            Link::Tc(p) => p.0.get_enabled(),
+            Link::Tcx(p) => p.0.get_enabled(),
+            Link::Tracepoint(p) => p.0.get_enabled(),
+            Link::Kprobe(p) => p.0.get_enabled(),
+            Link::Uprobe(p) => p.0.get_enabled(),
+            Link::Fentry(p) => p.0.get_enabled(),
+            Link::Fexit(p) => p.0.get_enabled(),
+        }
+    }
+
+    pub fn get_group(&self) -> Result<Option<String>, BpfmanError> {
+        match self {
+            Link::Xdp(p) => p.0.get_group(),
+            Link::Tc(p) => p.0.get_group(),
+            Link::Tcx(p) => p.0.get_group(),
+            Link::Tracepoint(p) => p.0.get_group(),
+            Link::Kprobe(p) => p.0.get_group(),
+            Link::Uprobe(p) => p.0.get_group(),
+            Link::Fentry(p) => p.0.get_group(),
+            Link::Fexit(p) => p.0.get_group(),
+        }
+    }
+
+    pub(crate) fn set_group(&mut self, name: &str) -> Result<(), BpfmanError> {
+        match self {
+            Link::Xdp(p) => p.0.set_group(name),
+            Link::Tc(p) => p.0.set_group(name),
+            Link::Tcx(p) => p.0.set_group(name),
+            Link::Tracepoint(p) => p.0.set_group(name),
+            Link::Kprobe(p) => p.0.set_group(name),
+            Link::Uprobe(p) => p.0.set_group(name),
+            Link::Fentry(p) => p.0.set_group(name),
+            Link::Fexit(p) => p.0.set_group(name),
+        }
+    }
+
+    pub(crate) fn set_enabled(&mut self, enabled: bool) -> Result<(), BpfmanError> {
+        match self {
+            Link::Xdp(p) => p.0.set_enabled(enabled),
+            Link::Tc(p) => p.0.set_enabled(enabled),
+            Link::Tcx(p) => p.0.set_enabled(enabled),
+            Link::Tracepoint(p) => p.0.set_enabled(enabled),
+            Link::Kprobe(p) => p.0.set_enabled(enabled),
+            Link::Uprobe(p) => p.0.set_enabled(enabled),
+            Link::Fentry(p) => p.0.set_enabled(enabled),
+            Link::Fexit(p) => p.0.set_enabled(enabled),
+        }
+    }
+
+    pub fn get_interface_missing(&self) -> Result<bool, BpfmanError> {
+        match self {
+            Link::Xdp(p) => p.0.get_interface_missing(),
+            Link::Tc(p) => p.0.get_interface_missing(),
+            Link::Tcx(p) => p.0.get_interface_missing(),
+            _ => Ok(false),
+        }
+    }
+
+    pub(crate) fn set_interface_missing(&mut self, missing: bool) -> Result<(), BpfmanError> {
+        match self {
+            Link::Xdp(p) => p.0.set_interface_missing(missing),
+            Link::Tc(p) => p.0.set_interface_missing(missing),
+            Link::Tcx(p) => p.0.set_interface_missing(missing),
+            _ => Err(BpfmanError::Error(
+                "cannot set interface status on programs other than TC, TCX and XDP".to_string(),
+            )),
+        }
+    }
+
+    pub fn get_reattach_policy(&self) -> Result<ReattachPolicy, BpfmanError> {
+        match self {
+            Link::Xdp(p) => p.0.get_reattach_policy(),
+            Link::Tc(p) => p.0.get_reattach_policy(),
+            Link::Tcx(p) => p.0.get_reattach_policy(),
+            _ => Ok(ReattachPolicy::Never),
+        }
+    }
+
+    /// Returns the [`AttachInfo`] that attaches the link's program again in
+    /// the same way, with the link's metadata.
+    pub fn get_attach_info(&self) -> Result<AttachInfo, BpfmanError> {
+        let metadata = self.get_metadata()?;
+        Ok(match self {
+            Link::Xdp(l) => AttachInfo::Xdp {
+                priority: l.get_priority()?,
+                iface: l.get_iface()?,
+                proceed_on: l.get_proceed_on()?,
+                netns: l.get_netns()?,
+                metadata,
+            },
+            Link::Tc(l) => AttachInfo::Tc {
+                priority: l.get_priority()?,
+                iface: l.get_iface()?,
+                direction: l.get_direction()?.to_string(),
+                proceed_on: l.get_proceed_on()?,
+                netns: l.get_netns()?,
+                metadata,
+            },
+            Link::Tcx(l) => AttachInfo::Tcx {
+                priority: l.get_priority()?,
+                iface: l.get_iface()?,
+                direction: l.get_direction()?.to_string(),
+                netns: l.get_netns()?,
+                metadata,
+            },
+            Link::Tracepoint(l) => AttachInfo::Tracepoint {
+                tracepoint: l.get_tracepoint()?,
+                metadata,
+            },
+            Link::Kprobe(l) => AttachInfo::Kprobe {
+                fn_name: l.get_fn_name()?,
+                offset: l.get_offset()?,
+                container_pid: l.get_container_pid()?,
+                metadata,
+            },
+            Link::Uprobe(l) => AttachInfo::Uprobe {
+                fn_name: l.get_fn_name()?,
+                offset: l.get_offset()?,
+                target: l.get_target()?,
+                pid: l.get_pid()?,
+                container_pid: l.get_container_pid()?,
+                metadata,
+            },
+            Link::Fentry(_) => AttachInfo::Fentry { metadata },
+            Link::Fexit(_) => AttachInfo::Fexit { metadata },
+        })
+    }
+
+    pub(crate) fn set_reattach_policy(
+        &mut self,
+        policy: ReattachPolicy,
+    ) -> Result<(), BpfmanError> {
+        match self {
+            Link::Xdp(p) => p.0.set_reattach_policy(policy),
+            Link::Tc(p) => p.0.set_reattach_policy(policy),
+            Link::Tcx(p) => p.0.set_reattach_policy(policy),
+            _ => Err(BpfmanError::InvalidAttach(
+                "a reattach policy can only be set on xdp, tc and tcx links".to_string(),
+            )),
+        }
+    }
+
     pub fn get_current_position(&self) -> Result<Option<usize>, BpfmanError> {
         match self {
             Link::Xdp(p) => p.get_current_position(),
@@ -1028,6 +1346,22 @@ impl Link {
     }
 
     pub(crate) fn attach(&mut self, info: AttachInfo) -> Result<(), BpfmanError> {
+        let matches = matches!(
+            (&*self, &info),
+            (Link::Xdp(_), AttachInfo::Xdp { .. })
+                | (Link::Tc(_), AttachInfo::Tc { .. })
+                | (Link::Tcx(_), AttachInfo::Tcx { .. })
+                | (Link::Tracepoint(_), AttachInfo::Tracepoint { .. })
+                | (Link::Kprobe(_), AttachInfo::Kprobe { .. })
+                | (Link::Uprobe(_), AttachInfo::Uprobe { .. })
+                | (Link::Fentry(_), AttachInfo::Fentry { .. })
+                | (Link::Fexit(_), AttachInfo::Fexit { .. })
+        );
+        if !matches {
+            return Err(BpfmanError::InvalidAttach(
+                "attach info does not match the program type".to_string(),
+            ));
+        }
         match self {
             Link::Xdp(p) => p.attach(info)?,
             Link::Tc(p) => p.attach(info)?,
@@ -1061,6 +1395,16 @@ impl Link {
         }
     }
 
+    pub(crate) fn set_detached(&mut self) -> Result<(), BpfmanError> {
+        match self {
+            Link::Xdp(p) => p.set_attached(false),
+            Link::Tc(p) => p.set_attached(false),
+            _ => Err(BpfmanError::Error(
+                "cannot set attached status on programs other than TC and XDP".to_string(),
+            )),
+        }
+    }
+
     pub(crate) fn dispatcher_id(&self) -> Result<Option<DispatcherId>, BpfmanError> {
         Ok(match self {
             Link::Xdp(p) => Some(DispatcherId::Xdp(DispatcherInfo(
@@ -1090,6 +1434,17 @@ impl Link {
         }
     }
 
+    pub(crate) fn set_ifindex(&mut self, ifindex: u32) -> Result<(), BpfmanError> {
+        match self {
+            Link::Xdp(p) => p.set_ifindex(ifindex),
+            Link::Tc(p) => p.set_ifindex(ifindex),
+            Link::Tcx(p) => p.set_ifindex(ifindex),
+            _ => Err(BpfmanError::Error(
+                "cannot set ifindex on programs other than TC, TCX and XDP".to_string(),
+            )),
+        }
+    }
+
     pub(crate) fn if_name(&self) -> Result<String, BpfmanError> {
         match self {
             Link::Xdp(p) => p.get_iface(),
@@ -1112,6 +1467,17 @@ impl Link {
         }
     }
 
+    pub(crate) fn set_priority(&mut self, priority: i32) -> Result<(), BpfmanError> {
+        match self {
+            Link::Xdp(p) => p.set_priority(priority),
+            Link::Tc(p) => p.set_priority(priority),
+            Link::Tcx(p) => p.set_priority(priority),
+            _ => Err(BpfmanError::Error(
+                "cannot set priority on programs other than TC, TCX and XDP".to_string(),
+            )),
+        }
+    }
+
     pub(crate) fn direction(&self) -> Result<Option<Direction>, BpfmanError> {
         match self {
             Link::Tc(p) => Ok(Some(p.get_direction()?)),
@@ -1152,6 +1518,151 @@ impl Link {
     }
 }
 
+/// LinkGroupMember records the result of attaching a link group to a single
+/// interface selected by the group's [`InterfaceSelector`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct LinkGroupMember {
+    /// Name of the interface.
+    pub iface: String,
+    /// Network namespace the interface is in, if not the bpfman namespace.
+    pub netns: Option<PathBuf>,
+    /// ID of the link attached to the interface, if the attach succeeded.
+    pub link_id: Option<u32>,
+    /// Reason the attach failed, if it did not succeed.
+    pub error: Option<String>,
+}
+
+/// LinkGroup is a named set of links created by attaching a program to every
+/// interface matched by an [`InterfaceSelector`]. The links in a group can be
+/// detached as a unit.
+#[derive(Debug, Clone)]
+pub struct LinkGroup(pub(crate) sled::Tree);
+
+impl LinkGroup {
+    pub(crate) fn new(
+        root_db: &Db,
+        name: &str,
+        program_id: u32,
+        selector: &InterfaceSelector,
+    ) -> Result<Self, BpfmanError> {
+        let tree_name = format!("{LINK_GROUP_PREFIX}{name}");
+        if root_db.tree_names().contains(&tree_name.as_bytes().into()) {
+            return Err(BpfmanError::LinkGroupExists(name.to_string()));
+        }
+        let tree = root_db.open_tree(tree_name).map_err(|e| {
+            BpfmanError::DatabaseError("Unable to open link group tree".to_string(), e.to_string())
+        })?;
+        sled_insert(&tree, LINK_GROUP_NAME, name.as_bytes())?;
+        sled_insert(&tree, LINK_GROUP_PROGRAM_ID, &program_id.to_ne_bytes())?;
+        sled_insert(&tree, LINK_GROUP_SELECTOR, selector.to_string().as_bytes())?;
+        Ok(LinkGroup(tree))
+    }
+
+    pub(crate) fn open(root_db: &Db, name: &str) -> Result<Self, BpfmanError> {
+        let tree_name = format!("{LINK_GROUP_PREFIX}{name}");
+        if !root_db.tree_names().contains(&tree_name.as_bytes().into()) {
+            return Err(BpfmanError::LinkGroupNotFound(name.to_string()));
+        }
+        let tree = root_db.open_tree(tree_name).map_err(|e| {
+            BpfmanError::DatabaseError("Unable to open link group tree".to_string(), e.to_string())
+        })?;
+        Ok(LinkGroup(tree))
+    }
+
+    pub(crate) fn delete(self, root_db: &Db) -> Result<(), BpfmanError> {
+        root_db.drop_tree(self.0.name()).map_err(|e| {
+            BpfmanError::DatabaseError(
+                "Unable to delete link group tree".to_string(),
+                e.to_string(),
+            )
+        })?;
+        Ok(())
+    }
+
+    pub fn get_name(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.0, LINK_GROUP_NAME).map(|v| bytes_to_string(&v))
+    }
+
+    pub fn get_program_id(&self) -> Result<u32, BpfmanError> {
+        sled_get(&self.0, LINK_GROUP_PROGRAM_ID).map(bytes_to_u32)
+    }
+
+    pub fn get_selector(&self) -> Result<InterfaceSelector, BpfmanError> {
+        let selector = sled_get(&self.0, LINK_GROUP_SELECTOR).map(|v| bytes_to_string(&v))?;
+        Ok(InterfaceSelector::try_from(selector)?)
+    }
+
+    pub(crate) fn add_member(&self, member: &LinkGroupMember) -> Result<(), BpfmanError> {
+        let index = self.0.scan_prefix(PREFIX_LINK_GROUP_MEMBER).count();
+        let value = serde_json::to_vec(member).map_err(|e| {
+            BpfmanError::DatabaseError("Failed to serialize member".to_string(), e.to_string())
+        })?;
+        sled_insert(
+            &self.0,
+            format!("{PREFIX_LINK_GROUP_MEMBER}{index}").as_str(),
+            &value,
+        )
+    }
+
+    /// Retrieves the members of the link group, sorted by network namespace
+    /// and interface name.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<LinkGroupMember>, BpfmanError>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - There is an issue fetching the members from the database.
+    pub fn get_members(&self) -> Result<Vec<LinkGroupMember>, BpfmanError> {
+        let mut members = self
+            .0
+            .scan_prefix(PREFIX_LINK_GROUP_MEMBER)
+            .map(|n| {
+                let (_, v) = n.map_err(|e| {
+                    BpfmanError::DatabaseError("Failed to get members".to_string(), e.to_string())
+                })?;
+                serde_json::from_slice::<LinkGroupMember>(&v).map_err(|e| {
+                    BpfmanError::DatabaseError(
+                        "Failed to deserialize member".to_string(),
+                        e.to_string(),
+                    )
+                })
+            })
+            .collect::<Result<Vec<LinkGroupMember>, BpfmanError>>()?;
+        members.sort_by(|a, b| (&a.netns, &a.iface).cmp(&(&b.netns, &b.iface)));
+        Ok(members)
+    }
+
+    /// Removes the member that owns the given link. The group is deleted once
+    /// none of its members has a link left.
+    pub(crate) fn remove_link(self, root_db: &Db, link_id: u32) -> Result<(), BpfmanError> {
+        let mut remaining = 0;
+        for entry in self.0.scan_prefix(PREFIX_LINK_GROUP_MEMBER) {
+            let (k, v) = entry.map_err(|e| {
+                BpfmanError::DatabaseError("Failed to get members".to_string(), e.to_string())
+            })?;
+            match serde_json::from_slice::<LinkGroupMember>(&v) {
+                Ok(member) if member.link_id == Some(link_id) => {
+                    self.0.remove(k).map_err(|e| {
+                        BpfmanError::DatabaseError(
+                            "Failed to remove member".to_string(),
+                            e.to_string(),
+                        )
+                    })?;
+                }
+                Ok(member) if member.link_id.is_some() => remaining += 1,
+                _ => (),
+            }
+        }
+        if remaining == 0 {
+            self.delete(root_db)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BytecodeImage {
     pub image_url: String,
@@ -1177,91 +1688,592 @@ impl BytecodeImage {
         }
     }
 
-    pub fn get_url(&self) -> &str {
-        &self.image_url
+    pub fn get_url(&self) -> &str {
+        &self.image_url
+    }
+
+    pub fn get_pull_policy(&self) -> &ImagePullPolicy {
+        &self.image_pull_policy
+    }
+}
+#[derive(Debug, Clone, Default)]
+pub struct ListFilter {
+    pub(crate) program_type: Option<u32>,
+    pub(crate) metadata_selector: HashMap<String, String>,
+    pub(crate) bpfman_programs_only: bool,
+    pub(crate) metadata_requirements: Vec<MetadataRequirement>,
+    pub(crate) name_regex: Option<regex::Regex>,
+    pub(crate) iface: Option<String>,
+    pub(crate) no_links: bool,
+    pub(crate) sort_by: ListSortKey,
+    pub(crate) page_size: Option<usize>,
+    pub(crate) page_token: Option<String>,
+}
+
+impl ListFilter {
+    pub fn new(
+        program_type: Option<u32>,
+        metadata_selector: HashMap<String, String>,
+        bpfman_programs_only: bool,
+    ) -> Self {
+        Self {
+            program_type,
+            metadata_selector,
+            bpfman_programs_only,
+            ..Default::default()
+        }
+    }
+
+    /// Only matches programs whose metadata meets all the requirements, in
+    /// addition to the exact matches of the metadata selector.
+    pub fn with_metadata_requirements(mut self, requirements: Vec<MetadataRequirement>) -> Self {
+        self.metadata_requirements = requirements;
+        self
+    }
+
+    /// Only matches programs whose name matches the regular expression. The
+    /// name of a program that wasn't loaded by bpfman is its kernel name.
+    pub fn with_name_regex(mut self, pattern: &str) -> Result<Self, ParseError> {
+        let name_regex = regex::Regex::new(pattern).map_err(|_| ParseError::InvalidNameRegex {
+            pattern: pattern.to_string(),
+        })?;
+        self.name_regex = Some(name_regex);
+        Ok(self)
+    }
+
+    /// Only matches programs with a link attached to the interface `iface`.
+    pub fn with_iface(mut self, iface: Option<String>) -> Self {
+        self.iface = iface;
+        self
+    }
+
+    /// Only matches programs without any links when `no_links` is true.
+    pub fn with_no_links(mut self, no_links: bool) -> Self {
+        self.no_links = no_links;
+        self
+    }
+
+    /// Sets the order in which [`crate::list_programs_page`] returns programs.
+    pub fn with_sort_by(mut self, sort_by: ListSortKey) -> Self {
+        self.sort_by = sort_by;
+        self
+    }
+
+    /// Limits [`crate::list_programs_page`] to `page_size` programs, starting
+    /// after the position encoded in `page_token`, which is the
+    /// `next_page_token` of the previous page.
+    pub fn with_page(mut self, page_size: Option<usize>, page_token: Option<String>) -> Self {
+        self.page_size = page_size;
+        self.page_token = page_token;
+        self
+    }
+
+    /// Returns true if the event concerns a program of the filter's program
+    /// type whose metadata contains every key and value of the metadata
+    /// selector.
+    pub fn matches_event(&self, event: &Event) -> bool {
+        if let Some(prog_type) = self.program_type
+            && event.program_type != Some(prog_type)
+        {
+            return false;
+        }
+        self.metadata_selector
+            .iter()
+            .all(|(key, value)| event.metadata.get(key) == Some(value))
+            && self
+                .metadata_requirements
+                .iter()
+                .all(|r| r.matches(&event.metadata))
+    }
+
+    /// Returns true if programs that weren't loaded by bpfman can match. They
+    /// have no metadata and bpfman doesn't know their links, so any metadata
+    /// or link filter only matches programs loaded by bpfman.
+    pub(crate) fn includes_unsupported(&self) -> bool {
+        !self.bpfman_programs_only
+            && self.metadata_selector.is_empty()
+            && self.metadata_requirements.is_empty()
+            && self.iface.is_none()
+            && !self.no_links
+    }
+
+    pub(crate) fn matches(&self, root_db: &Db, program: &Program) -> bool {
+        if let Program::Unsupported(_) = program {
+            if !self.includes_unsupported() {
+                return false;
+            }
+
+            if let Some(prog_type) = self.program_type {
+                match program.get_data().get_kernel_program_type() {
+                    Ok(kernel_prog_type) => {
+                        if kernel_prog_type != prog_type {
+                            return false;
+                        }
+                    }
+                    Err(e) => {
+                        warn!("Failed to get kernel program type during list match: {}", e);
+                        return false;
+                    }
+                }
+            }
+        } else {
+            // Program type filtering has to be done differently for bpfman owned
+            // programs since XDP and TC programs have a type EXT when loaded by
+            // bpfman.
+            let prog_type_internal: u32 = program.kind().into();
+            if let Some(prog_type) = self.program_type {
+                if prog_type_internal != prog_type {
+                    return false;
+                }
+            }
+            // Filter on the input metadata field if provided
+            if !self.metadata_selector.is_empty() || !self.metadata_requirements.is_empty() {
+                let metadata = match program.get_data().get_metadata() {
+                    Ok(metadata) => metadata,
+                    Err(e) => {
+                        warn!("Failed to get metadata during list match: {}", e);
+                        return false;
+                    }
+                };
+                if !self
+                    .metadata_selector
+                    .iter()
+                    .all(|(key, value)| metadata.get(key) == Some(value))
+                    || !self
+                        .metadata_requirements
+                        .iter()
+                        .all(|r| r.matches(&metadata))
+                {
+                    return false;
+                }
+            }
+            if !self.matches_links(root_db, program) {
+                return false;
+            }
+        }
+
+        if let Some(name_regex) = &self.name_regex
+            && !name_regex.is_match(&program_name(program))
+        {
+            return false;
+        }
+        true
+    }
+
+    fn matches_links(&self, root_db: &Db, program: &Program) -> bool {
+        if self.iface.is_none() && !self.no_links {
+            return true;
+        }
+        let link_ids = match program.get_data().get_link_ids() {
+            Ok(link_ids) => link_ids,
+            Err(e) => {
+                warn!("Failed to get links during list match: {}", e);
+                return false;
+            }
+        };
+        if self.no_links && !link_ids.is_empty() {
+            return false;
+        }
+        if let Some(iface) = &self.iface {
+            return link_ids.into_iter().any(|id| {
+                crate::get_link(root_db, id).is_ok_and(|link| {
+                    matches!(link, Link::Xdp(_) | Link::Tc(_) | Link::Tcx(_))
+                        && link.if_name().is_ok_and(|name| name == *iface)
+                })
+            });
+        }
+        true
+    }
+
+    /// Sorts the matching programs and returns the page selected by the
+    /// filter, along with the token of the next page if there is one.
+    pub(crate) fn paginate(
+        &self,
+        programs: Vec<Program>,
+    ) -> Result<(Vec<Program>, Option<String>), BpfmanError> {
+        let mut programs: Vec<(SortPosition, Program)> = programs
+            .into_iter()
+            .map(|p| (self.sort_by.position(&p), p))
+            .collect();
+        programs.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        if let Some(token) = &self.page_token {
+            let start = SortPosition::decode(token, self.sort_by)?;
+            programs.retain(|(position, _)| *position > start);
+        }
+        let next_page_token = match self.page_size {
+            Some(page_size) if page_size > 0 && programs.len() > page_size => {
+                programs.truncate(page_size);
+                programs.last().map(|(position, _)| position.encode())
+            }
+            _ => None,
+        };
+        Ok((
+            programs.into_iter().map(|(_, p)| p).collect(),
+            next_page_token,
+        ))
+    }
+
+    /// Returns the ID that every program on the requested page is greater
+    /// than, when sorting by ID. This lets programs on earlier pages be
+    /// skipped before their kernel info is read.
+    pub(crate) fn page_start_id(&self) -> Option<u32> {
+        if self.sort_by != ListSortKey::Id {
+            return None;
+        }
+        self.page_token
+            .as_ref()
+            .and_then(|token| SortPosition::decode(token, ListSortKey::Id).ok())
+            .map(|position| position.id)
+    }
+}
+
+// Returns the name of a program, which is its kernel name if it wasn't
+// loaded by bpfman.
+fn program_name(program: &Program) -> String {
+    let data = program.get_data();
+    match program {
+        Program::Unsupported(_) => data.get_kernel_name(),
+        _ => data.get_name(),
+    }
+    .unwrap_or_default()
+}
+
+/// The order in which programs are listed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ListSortKey {
+    /// By program ID.
+    #[default]
+    Id = 0,
+    /// By the time the program was loaded into the kernel, oldest first.
+    LoadTime = 1,
+    /// By program name.
+    Name = 2,
+}
+
+impl ListSortKey {
+    fn position(&self, program: &Program) -> SortPosition {
+        let data = program.get_data();
+        let value = match self {
+            ListSortKey::Id => SortValue::Id,
+            ListSortKey::LoadTime => SortValue::LoadTime(
+                data.get_kernel_loaded_at()
+                    .ok()
+                    .and_then(|t| DateTime::parse_from_str(&t, "%Y-%m-%dT%H:%M:%S%z").ok())
+                    .map(|t| t.timestamp())
+                    .unwrap_or_default(),
+            ),
+            ListSortKey::Name => SortValue::Name(program_name(program)),
+        };
+        SortPosition {
+            value,
+            id: data.get_id().unwrap_or_default(),
+        }
+    }
+}
+
+impl TryFrom<i32> for ListSortKey {
+    type Error = BpfmanError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ListSortKey::Id),
+            1 => Ok(ListSortKey::LoadTime),
+            2 => Ok(ListSortKey::Name),
+            _ => Err(BpfmanError::Error("Invalid list sort key".to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for ListSortKey {
+    type Error = ParseError;
+
+    fn try_from(v: String) -> Result<Self, Self::Error> {
+        Ok(match v.as_str() {
+            "id" => ListSortKey::Id,
+            "load-time" => ListSortKey::LoadTime,
+            "name" => ListSortKey::Name,
+            _ => return Err(ParseError::InvalidListSortKey { sort_by: v }),
+        })
+    }
+}
+
+impl std::fmt::Display for ListSortKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ListSortKey::Id => write!(f, "id"),
+            ListSortKey::LoadTime => write!(f, "load-time"),
+            ListSortKey::Name => write!(f, "name"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+enum SortValue {
+    Id,
+    LoadTime(i64),
+    Name(String),
+}
+
+// The position of a program in a sorted list. Page tokens encode the position
+// of the last program on a page, so that the next page starts after it even if
+// programs were loaded or unloaded in between.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+struct SortPosition {
+    value: SortValue,
+    id: u32,
+}
+
+impl SortPosition {
+    fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("unable to serialize page token");
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(json)
+    }
+
+    fn decode(token: &str, sort_by: ListSortKey) -> Result<Self, ParseError> {
+        let invalid = || ParseError::InvalidPageToken {
+            token: token.to_string(),
+        };
+        let json = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(token)
+            .map_err(|_| invalid())?;
+        let position: SortPosition = serde_json::from_slice(&json).map_err(|_| invalid())?;
+        // A token can only be used with the sort order it was created with.
+        let sorted_by = match position.value {
+            SortValue::Id => ListSortKey::Id,
+            SortValue::LoadTime(_) => ListSortKey::LoadTime,
+            SortValue::Name(_) => ListSortKey::Name,
+        };
+        if sorted_by != sort_by {
+            return Err(invalid());
+        }
+        Ok(position)
+    }
+}
+
+/// A requirement on the metadata of a program, in the set-based syntax of
+/// Kubernetes label selectors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetadataRequirement {
+    /// `key in (v1,v2)`, or `key=v1`: the key has one of the values.
+    In(String, Vec<String>),
+    /// `key notin (v1,v2)`, or `key!=v1`: the key is missing or has none of
+    /// the values.
+    NotIn(String, Vec<String>),
+    /// `key`: the key is present.
+    Exists(String),
+    /// `!key`: the key is missing.
+    DoesNotExist(String),
+}
+
+impl MetadataRequirement {
+    pub fn matches(&self, metadata: &HashMap<String, String>) -> bool {
+        match self {
+            MetadataRequirement::In(key, values) => {
+                metadata.get(key).is_some_and(|v| values.contains(v))
+            }
+            MetadataRequirement::NotIn(key, values) => {
+                metadata.get(key).is_none_or(|v| !values.contains(v))
+            }
+            MetadataRequirement::Exists(key) => metadata.contains_key(key),
+            MetadataRequirement::DoesNotExist(key) => !metadata.contains_key(key),
+        }
     }
 
-    pub fn get_pull_policy(&self) -> &ImagePullPolicy {
-        &self.image_pull_policy
+    /// Parses a selector made of requirements separated by commas, such as
+    /// `owner=acme,env in (prod,staging),!deprecated`.
+    pub fn parse_selector(selector: &str) -> Result<Vec<Self>, ParseError> {
+        let mut requirements = vec![];
+        let mut depth = 0;
+        let mut start = 0;
+        for (i, c) in selector.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                ',' if depth == 0 => {
+                    requirements.push(selector[start..i].to_string().try_into()?);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        requirements.push(selector[start..].to_string().try_into()?);
+        Ok(requirements)
+    }
+}
+
+impl TryFrom<String> for MetadataRequirement {
+    type Error = ParseError;
+
+    fn try_from(v: String) -> Result<Self, Self::Error> {
+        let invalid = || ParseError::InvalidMetadataSelector {
+            selector: v.clone(),
+        };
+        let valid_key = |key: &str| {
+            !key.is_empty()
+                && key
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || "-_./".contains(c))
+        };
+        let s = v.trim();
+
+        let requirement = if let Some(key) = s.strip_prefix('!') {
+            MetadataRequirement::DoesNotExist(key.trim().to_string())
+        } else if let Some((key, value)) = s.split_once("!=") {
+            MetadataRequirement::NotIn(key.trim().to_string(), vec![value.trim().to_string()])
+        } else if let Some((key, value)) = s.split_once('=') {
+            let value = value.strip_prefix('=').unwrap_or(value);
+            MetadataRequirement::In(key.trim().to_string(), vec![value.trim().to_string()])
+        } else if let Some((key, rest)) = s.split_once(char::is_whitespace) {
+            let rest = rest.trim_start();
+            let (op, values) = rest
+                .split_once('(')
+                .map(|(op, values)| (op.trim(), values))
+                .ok_or_else(invalid)?;
+            let values: Vec<String> = values
+                .strip_suffix(')')
+                .ok_or_else(invalid)?
+                .split(',')
+                .map(|value| value.trim().to_string())
+                .collect();
+            if values.iter().any(|value| value.is_empty()) {
+                return Err(invalid());
+            }
+            match op {
+                "in" => MetadataRequirement::In(key.to_string(), values),
+                "notin" => MetadataRequirement::NotIn(key.to_string(), values),
+                _ => return Err(invalid()),
+            }
+        } else {
+            MetadataRequirement::Exists(s.to_string())
+        };
+
+        let key = match &requirement {
+            MetadataRequirement::In(key, _)
+            | MetadataRequirement::NotIn(key, _)
+            | MetadataRequirement::Exists(key)
+            | MetadataRequirement::DoesNotExist(key) => key,
+        };
+        if !valid_key(key) {
+            return Err(invalid());
+        }
+        Ok(requirement)
     }
 }
+
+/// LinkListFilter selects the links returned by [`crate::list_links`].
 #[derive(Debug, Clone, Default)]
-pub struct ListFilter {
-    pub(crate) program_type: Option<u32>,
+pub struct LinkListFilter {
+    pub(crate) program_id: Option<u32>,
+    pub(crate) link_type: Option<u32>,
+    pub(crate) iface: Option<String>,
     pub(crate) metadata_selector: HashMap<String, String>,
-    pub(crate) bpfman_programs_only: bool,
+    pub(crate) metadata_requirements: Vec<MetadataRequirement>,
 }
 
-impl ListFilter {
+impl LinkListFilter {
     pub fn new(
-        program_type: Option<u32>,
+        program_id: Option<u32>,
+        link_type: Option<u32>,
+        iface: Option<String>,
         metadata_selector: HashMap<String, String>,
-        bpfman_programs_only: bool,
     ) -> Self {
         Self {
-            program_type,
+            program_id,
+            link_type,
+            iface,
             metadata_selector,
-            bpfman_programs_only,
+            ..Default::default()
         }
     }
 
-    pub(crate) fn matches(&self, program: &Program) -> bool {
-        if let Program::Unsupported(_) = program {
-            if self.bpfman_programs_only {
-                return false;
-            }
+    /// Only matches links whose metadata meets all the requirements, in
+    /// addition to the exact matches of the metadata selector.
+    pub fn with_metadata_requirements(mut self, requirements: Vec<MetadataRequirement>) -> Self {
+        self.metadata_requirements = requirements;
+        self
+    }
 
-            if let Some(prog_type) = self.program_type {
-                match program.get_data().get_kernel_program_type() {
-                    Ok(kernel_prog_type) => {
-                        if kernel_prog_type != prog_type {
-                            return false;
-                        }
-                    }
-                    Err(e) => {
-                        warn!("Failed to get kernel program type during list match: {}", e);
-                        return false;
+    pub(crate) fn matches(&self, link: &Link) -> Result<bool, BpfmanError> {
+        if let Some(program_id) = self.program_id
+            && link.get_program_id()? != program_id
+        {
+            return Ok(false);
+        }
+        if let Some(link_type) = self.link_type
+            && link.kind() as u32 != link_type
+        {
+            return Ok(false);
+        }
+        if let Some(iface) = &self.iface {
+            match link {
+                Link::Xdp(_) | Link::Tc(_) | Link::Tcx(_) => {
+                    if link.if_name()? != *iface {
+                        return Ok(false);
                     }
                 }
+                _ => return Ok(false),
             }
+        }
+        let metadata = link.get_metadata()?;
+        Ok(self
+            .metadata_selector
+            .iter()
+            .all(|(key, value)| metadata.get(key) == Some(value))
+            && self
+                .metadata_requirements
+                .iter()
+                .all(|requirement| requirement.matches(&metadata)))
+    }
+}
 
-            // If a selector was provided, skip over non-bpfman loaded programs.
-            if !self.metadata_selector.is_empty() {
-                return false;
-            }
-        } else {
-            // Program type filtering has to be done differently for bpfman owned
-            // programs since XDP and TC programs have a type EXT when loaded by
-            // bpfman.
-            let prog_type_internal: u32 = program.kind().into();
-            if let Some(prog_type) = self.program_type {
-                if prog_type_internal != prog_type {
-                    return false;
-                }
-            }
-            // Filter on the input metadata field if provided
-            for (key, value) in &self.metadata_selector {
-                match program.get_data().get_metadata() {
-                    Ok(metadata) => {
-                        if let Some(v) = metadata.get(key) {
-                            if *value != *v {
-                                return false;
-                            }
-                        } else {
-                            return false;
-                        }
-                    }
-                    Err(e) => {
-                        warn!("Failed to get metadata during list match: {}", e);
-                        return false;
-                    }
-                }
+/// Identifies a program or link either by its kernel ID, or by the alias it
+/// was given when it was loaded or attached, written as `name:<ALIAS>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdOrAlias {
+    Id(u32),
+    Alias(String),
+}
+
+impl TryFrom<String> for IdOrAlias {
+    type Error = ParseError;
+
+    fn try_from(v: String) -> Result<Self, Self::Error> {
+        if let Some(alias) = v.strip_prefix("name:") {
+            if alias.is_empty() {
+                return Err(ParseError::InvalidIdOrAlias { value: v });
             }
+            return Ok(IdOrAlias::Alias(alias.to_string()));
+        }
+        v.parse()
+            .map(IdOrAlias::Id)
+            .map_err(|_| ParseError::InvalidIdOrAlias { value: v })
+    }
+}
+
+impl std::fmt::Display for IdOrAlias {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            IdOrAlias::Id(id) => write!(f, "{id}"),
+            IdOrAlias::Alias(alias) => write!(f, "name:{alias}"),
         }
-        true
     }
 }
 
+/// KernelLinkInfo is the kernel's view of an attached link, as opposed to the
+/// attach information bpfman keeps in its database.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KernelLinkInfo {
+    /// ID the kernel assigned to the link.
+    pub id: u32,
+    /// Kernel link type, such as "xdp", "tracing" or "perf_event".
+    pub link_type: String,
+    /// ID of the program the kernel reports as attached through the link.
+    /// For XDP and TC links this is the extension program attached to the
+    /// dispatcher.
+    pub program_id: u32,
+}
+
 /// `Program` represents various types of eBPF programs that are
 /// supported by bpfman.
 #[derive(Debug, Clone)]
@@ -1380,6 +2392,356 @@ pub enum AttachInfo {
     },
 }
 
+impl AttachInfo {
+    /// Returns the metadata of the link to be created.
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        match self {
+            AttachInfo::Xdp { metadata, .. }
+            | AttachInfo::Tc { metadata, .. }
+            | AttachInfo::Tcx { metadata, .. }
+            | AttachInfo::Tracepoint { metadata, .. }
+            | AttachInfo::Kprobe { metadata, .. }
+            | AttachInfo::Uprobe { metadata, .. }
+            | AttachInfo::Fentry { metadata, .. }
+            | AttachInfo::Fexit { metadata, .. } => metadata,
+        }
+    }
+
+    pub(crate) fn netns(&self) -> Option<PathBuf> {
+        match self {
+            AttachInfo::Xdp { netns, .. }
+            | AttachInfo::Tc { netns, .. }
+            | AttachInfo::Tcx { netns, .. } => netns.clone(),
+            _ => None,
+        }
+    }
+
+    /// Returns a copy of the attach info for an XDP, TC or TCX program with the
+    /// interface and network namespace replaced.
+    pub(crate) fn with_interface(
+        &self,
+        iface: String,
+        netns: Option<PathBuf>,
+    ) -> Result<AttachInfo, BpfmanError> {
+        let mut info = self.clone();
+        match &mut info {
+            AttachInfo::Xdp {
+                iface: i, netns: n, ..
+            }
+            | AttachInfo::Tc {
+                iface: i, netns: n, ..
+            }
+            | AttachInfo::Tcx {
+                iface: i, netns: n, ..
+            } => {
+                *i = iface;
+                *n = netns;
+            }
+            _ => {
+                return Err(BpfmanError::InvalidAttach(
+                    "an interface selector can only be used with xdp, tc and tcx programs"
+                        .to_string(),
+                ));
+            }
+        }
+        Ok(info)
+    }
+}
+
+/// ReattachPolicy controls what bpfman does with a TC, TCX or XDP link when
+/// the interface it is attached to is removed and an interface with the same
+/// name later appears in the same network namespace.
+///
+/// The string forms accepted by `TryFrom<String>` are `never` and `by-name`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(u32)]
+pub enum ReattachPolicy {
+    /// The link stays detached until it is removed.
+    #[default]
+    Never = 0,
+    /// The link is attached to the new interface with the same name.
+    ByName = 1,
+}
+
+impl TryFrom<u32> for ReattachPolicy {
+    type Error = BpfmanError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ReattachPolicy::Never),
+            1 => Ok(ReattachPolicy::ByName),
+            _ => Err(BpfmanError::Error("Invalid reattach policy".to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for ReattachPolicy {
+    type Error = ParseError;
+
+    fn try_from(v: String) -> Result<Self, Self::Error> {
+        Ok(match v.as_str() {
+            "never" => ReattachPolicy::Never,
+            "by-name" => ReattachPolicy::ByName,
+            _ => return Err(ParseError::InvalidReattachPolicy { policy: v }),
+        })
+    }
+}
+
+impl std::fmt::Display for ReattachPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReattachPolicy::Never => f.write_str("never"),
+            ReattachPolicy::ByName => f.write_str("by-name"),
+        }
+    }
+}
+
+/// DriftKind describes how the kernel state of a program or link managed by
+/// bpfman differs from the state recorded in the bpfman database.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DriftKind {
+    /// The program is no longer loaded in the kernel.
+    ProgramNotLoaded,
+    /// The program is loaded but its pin has been removed.
+    ProgramPinMissing { path: PathBuf },
+    /// A map pin of the program has been removed or no longer refers to a map.
+    MapPinMissing { path: PathBuf },
+    /// The link pin has been removed or no longer refers to a link.
+    LinkPinMissing { path: PathBuf },
+    /// The dispatcher running the link is no longer attached to its interface.
+    DispatcherDetached { iface: String },
+    /// The interface of the link no longer exists.
+    InterfaceMissing { iface: String },
+}
+
+impl std::fmt::Display for DriftKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DriftKind::ProgramNotLoaded => f.write_str("program is not loaded in the kernel"),
+            DriftKind::ProgramPinMissing { path } => {
+                write!(f, "program pin {} is missing", path.display())
+            }
+            DriftKind::MapPinMissing { path } => {
+                write!(f, "map pin {} is missing", path.display())
+            }
+            DriftKind::LinkPinMissing { path } => {
+                write!(f, "link pin {} is not a live link", path.display())
+            }
+            DriftKind::DispatcherDetached { iface } => {
+                write!(f, "dispatcher is not attached to interface {iface}")
+            }
+            DriftKind::InterfaceMissing { iface } => write!(f, "interface {iface} does not exist"),
+        }
+    }
+}
+
+/// RepairStatus records what was done about a [`Drift`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RepairStatus {
+    /// No repair was requested.
+    NotAttempted,
+    /// The drift was repaired.
+    Repaired,
+    /// bpfman cannot repair the drift without the program being loaded again.
+    NotRepairable,
+    /// The caller is not allowed to change the programs the repair changes.
+    NotPermitted,
+    /// The repair failed with the given error.
+    Failed(String),
+}
+
+impl std::fmt::Display for RepairStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepairStatus::NotAttempted => f.write_str("not attempted"),
+            RepairStatus::Repaired => f.write_str("repaired"),
+            RepairStatus::NotRepairable => f.write_str("not repairable"),
+            RepairStatus::NotPermitted => f.write_str("not permitted"),
+            RepairStatus::Failed(e) => write!(f, "failed: {e}"),
+        }
+    }
+}
+
+/// DispatcherSummary describes an xdp or tc dispatcher returned by
+/// [`crate::list_dispatchers`], with the links attached to it.
+#[derive(Debug, Clone)]
+pub struct DispatcherSummary {
+    /// Either `LinkType::Xdp` or `LinkType::Tc`.
+    pub link_type: LinkType,
+    pub if_name: String,
+    pub if_index: u32,
+    /// ID of the network namespace of the interface.
+    pub nsid: u64,
+    /// Direction of a tc dispatcher.
+    pub direction: Option<Direction>,
+    /// XDP mode of an xdp dispatcher.
+    pub xdp_mode: Option<String>,
+    pub revision: u32,
+    /// IDs of the links attached to the dispatcher, in the order they run.
+    pub link_ids: Vec<u32>,
+    /// IDs of the programs of `link_ids`.
+    pub program_ids: Vec<u32>,
+}
+
+/// Drift is a difference between the bpfman database and the kernel found by
+/// [`crate::verify`], for a program or one of its links.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Drift {
+    /// ID of the program.
+    pub program_id: u32,
+    /// ID of the link, if the drift concerns a link.
+    pub link_id: Option<u32>,
+    /// What differs from the database.
+    pub kind: DriftKind,
+    /// What was done about it.
+    pub repair: RepairStatus,
+}
+
+/// EventKind is the type of change recorded in an [`Event`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventKind {
+    /// A program was loaded.
+    ProgramLoaded,
+    /// A program was unloaded.
+    ProgramUnloaded,
+    /// A link was attached, or attached again to a recreated interface.
+    LinkAttached,
+    /// A link was detached, or its interface was removed.
+    LinkDetached,
+    /// A new revision of an XDP or TC dispatcher was attached.
+    DispatcherRebuilt,
+    /// A bytecode image was pulled.
+    ImagePulled,
+    /// [`crate::verify`] found a drift from the kernel state.
+    DriftDetected,
+}
+
+impl std::fmt::Display for EventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventKind::ProgramLoaded => f.write_str("program loaded"),
+            EventKind::ProgramUnloaded => f.write_str("program unloaded"),
+            EventKind::LinkAttached => f.write_str("link attached"),
+            EventKind::LinkDetached => f.write_str("link detached"),
+            EventKind::DispatcherRebuilt => f.write_str("dispatcher rebuilt"),
+            EventKind::ImagePulled => f.write_str("image pulled"),
+            EventKind::DriftDetected => f.write_str("drift detected"),
+        }
+    }
+}
+
+/// Event is a change to the programs and links managed by bpfman, as
+/// returned by [`crate::get_events`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Event {
+    /// Position of the event in the event log. Each event has a higher
+    /// revision than the events recorded before it.
+    pub revision: u64,
+    /// What changed.
+    pub kind: EventKind,
+    /// ID of the program, if the event concerns a program.
+    pub program_id: Option<u32>,
+    /// ID of the link, if the event concerns a link.
+    pub link_id: Option<u32>,
+    /// Program type as used by [`ListFilter`], if the event concerns a
+    /// program or dispatcher.
+    pub program_type: Option<u32>,
+    /// Name of the program type as returned by [`Program::type_name`], if the
+    /// event concerns a program.
+    #[serde(default)]
+    pub program_type_name: Option<String>,
+    /// Metadata of the program when the event was recorded.
+    pub metadata: HashMap<String, String>,
+    /// Human readable description of the change.
+    pub detail: String,
+}
+
+/// InterfaceSelector picks the set of interfaces a link group is attached to.
+///
+/// The string form of each selector, as accepted by `TryFrom<String>`, is:
+/// * `glob:<pattern>` - Interfaces whose name matches a glob, where `*`
+///   matches any number of characters and `?` matches a single character.
+/// * `regex:<pattern>` - Interfaces whose name matches a regular expression.
+/// * `physical` - All interfaces backed by a physical device.
+/// * `netns:<path>[,<path>...]` - All interfaces, except loopback, in each of
+///   the given network namespaces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InterfaceSelector {
+    Glob(String),
+    Regex(String),
+    Physical,
+    Netns(Vec<PathBuf>),
+}
+
+impl InterfaceSelector {
+    /// Returns the regular expression used to match interface names for the
+    /// `Glob` and `Regex` selectors.
+    pub(crate) fn name_regex(&self) -> Result<Option<regex::Regex>, ParseError> {
+        let pattern = match self {
+            InterfaceSelector::Glob(glob) => {
+                let mut pattern = String::from("^");
+                for c in glob.chars() {
+                    match c {
+                        '*' => pattern.push_str(".*"),
+                        '?' => pattern.push('.'),
+                        c => pattern.push_str(&regex::escape(&c.to_string())),
+                    }
+                }
+                pattern.push('$');
+                pattern
+            }
+            InterfaceSelector::Regex(pattern) => pattern.clone(),
+            _ => return Ok(None),
+        };
+        regex::Regex::new(&pattern)
+            .map(Some)
+            .map_err(|_| ParseError::InvalidInterfaceSelector {
+                selector: self.to_string(),
+            })
+    }
+}
+
+impl TryFrom<String> for InterfaceSelector {
+    type Error = ParseError;
+
+    fn try_from(v: String) -> Result<Self, Self::Error> {
+        let selector = match v.split_once(':') {
+            Some(("glob", pattern)) if !pattern.is_empty() => {
+                InterfaceSelector::Glob(pattern.to_string())
+            }
+            Some(("regex", pattern)) if !pattern.is_empty() => {
+                InterfaceSelector::Regex(pattern.to_string())
+            }
+            Some(("netns", paths)) if !paths.is_empty() => {
+                InterfaceSelector::Netns(paths.split(',').map(PathBuf::from).collect())
+            }
+            None if v == "physical" => InterfaceSelector::Physical,
+            _ => return Err(ParseError::InvalidInterfaceSelector { selector: v }),
+        };
+        selector.name_regex()?;
+        Ok(selector)
+    }
+}
+
+impl std::fmt::Display for InterfaceSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterfaceSelector::Glob(pattern) => write!(f, "glob:{pattern}"),
+            InterfaceSelector::Regex(pattern) => write!(f, "regex:{pattern}"),
+            InterfaceSelector::Physical => f.write_str("physical"),
+            InterfaceSelector::Netns(paths) => write!(
+                f,
+                "netns:{}",
+                paths
+                    .iter()
+                    .map(|p| p.to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum Location {
     Image(BytecodeImage),
@@ -1940,6 +3302,13 @@ impl ProgramData {
         application
     }
 
+    /// Returns the alias the user assigned, if any.
+    pub fn get_alias(&self) -> Option<String> {
+        Self::get_metadata(self)
+            .ok()
+            .and_then(|mut metadata| metadata.remove(METADATA_ALIAS_TAG))
+    }
+
     pub(crate) fn set_map_owner_id(&mut self, id: u32) -> Result<(), BpfmanError> {
         sled_insert(&self.0, MAP_OWNER_ID, &id.to_ne_bytes())
     }
@@ -1958,6 +3327,59 @@ impl ProgramData {
         sled_get_option(&self.0, MAP_OWNER_ID).map(|v| v.map(bytes_to_u32))
     }
 
+    /// Sets the log level of the kernel verifier when the program is loaded,
+    /// as a bitmask of 1 (log the path that fails verification), 2 (log every
+    /// instruction) and 4 (log statistics). The log is returned in
+    /// [`BpfmanError::VerifierRejected`] if the verifier rejects the program,
+    /// and the end of the log is kept as the verifier log summary if the
+    /// program is accepted.
+    ///
+    /// Programs that are loaded from the same bytecode in one request share a
+    /// loader, so the level of the first program is used for all of them.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - There is an issue storing the log level in the database.
+    pub fn set_verifier_log_level(&mut self, level: u32) -> Result<(), BpfmanError> {
+        sled_insert(&self.0, VERIFIER_LOG_LEVEL, &level.to_ne_bytes())
+    }
+
+    /// Retrieves the log level of the kernel verifier, if one was set.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Option<u32>, BpfmanError>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - There is an issue fetching the log level from the database.
+    pub fn get_verifier_log_level(&self) -> Result<Option<u32>, BpfmanError> {
+        sled_get_option(&self.0, VERIFIER_LOG_LEVEL).map(|v| v.map(bytes_to_u32))
+    }
+
+    pub(crate) fn set_verifier_log_summary(&mut self, summary: &str) -> Result<(), BpfmanError> {
+        sled_insert(&self.0, VERIFIER_LOG_SUMMARY, summary.as_bytes())
+    }
+
+    /// Retrieves the summary of the verification of the program by the kernel
+    /// verifier when it was loaded. If a verifier log level was set, this is
+    /// the end of the verifier log, at most 4 KiB of it. Otherwise it is built
+    /// from the statistics the kernel reports for the program.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Option<String>, BpfmanError>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - There is an issue fetching the summary from the database.
+    pub fn get_verifier_log_summary(&self) -> Result<Option<String>, BpfmanError> {
+        sled_get_option(&self.0, VERIFIER_LOG_SUMMARY).map(|v| v.map(|s| bytes_to_string(&s)))
+    }
+
     pub(crate) fn set_map_pin_path(&mut self, path: &Path) -> Result<(), BpfmanError> {
         sled_insert(&self.0, MAP_PIN_PATH, path.to_str().unwrap().as_bytes())
     }
@@ -2612,8 +4034,33 @@ impl Program {
         }
     }
 
+    /// Returns the name of the program type, such as "tcx", as used in
+    /// authorization rules.
+    pub fn type_name(&self) -> String {
+        match self {
+            Program::Xdp(_) => "xdp".to_string(),
+            Program::Tc(_) => "tc".to_string(),
+            Program::Tcx(_) => "tcx".to_string(),
+            Program::Tracepoint(_) => "tracepoint".to_string(),
+            Program::Kprobe(_) => "kprobe".to_string(),
+            Program::Uprobe(_) => "uprobe".to_string(),
+            Program::Fentry(_) => "fentry".to_string(),
+            Program::Fexit(_) => "fexit".to_string(),
+            Program::Unsupported(_) => self.kind().to_string(),
+        }
+    }
+
     pub fn add_link(&mut self) -> Result<Link, BpfmanError> {
-        let link_type = match self {
+        let mut link = Link::new(self.link_type()?);
+        link.set_program_id(self.get_data().get_id()?)?;
+        link.set_program_name(self.get_data().get_name()?)?;
+        self.get_data_mut().add_link(link.clone())?;
+        Ok(link)
+    }
+
+    /// Returns the type of the links of the program.
+    pub(crate) fn link_type(&self) -> Result<LinkType, BpfmanError> {
+        Ok(match self {
             Program::Xdp(_) => LinkType::Xdp,
             Program::Tc(_) => LinkType::Tc,
             Program::Tcx(_) => LinkType::Tcx,
@@ -2625,13 +4072,7 @@ impl Program {
             Program::Unsupported(_) => {
                 return Err(BpfmanError::Error("Unsupported program type".to_string()));
             }
-        };
-
-        let mut link = Link::new(link_type);
-        link.set_program_id(self.get_data().get_id()?)?;
-        link.set_program_name(self.get_data().get_name()?)?;
-        self.get_data_mut().add_link(link.clone())?;
-        Ok(link)
+        })
     }
 
     pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
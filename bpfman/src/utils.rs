@@ -2,11 +2,18 @@
 // Copyright Authors of bpfman
 
 use std::{
+    ffi::CString,
     fs,
     fs::{File, OpenOptions, create_dir_all, set_permissions},
     io::{BufRead, BufReader, Read},
     option::Option,
-    os::unix::fs::{MetadataExt, OpenOptionsExt, PermissionsExt},
+    os::{
+        fd::{AsRawFd, FromRawFd, OwnedFd, RawFd},
+        unix::{
+            ffi::OsStrExt,
+            fs::{MetadataExt, OpenOptionsExt, PermissionsExt},
+        },
+    },
     path::{Path, PathBuf},
     process,
 };
@@ -16,10 +23,11 @@ use log::{debug, info, warn};
 use nix::{
     libc::RLIM_INFINITY,
     mount::{MsFlags, mount},
-    net::if_::if_nametoindex,
+    net::if_::{if_nameindex, if_nametoindex},
     sched::{CloneFlags, setns},
     sys::resource::{Resource, setrlimit},
 };
+use object::{Object, ObjectSymbol};
 use sled::Tree;
 
 use crate::{
@@ -27,13 +35,19 @@ use crate::{
     directories::*,
     errors::BpfmanError,
     multiprog::{TC_DISPATCHER_PREFIX, XDP_DISPATCHER_PREFIX},
-    types::Direction,
+    types::{Direction, InterfaceSelector, KernelLinkInfo},
 };
 
 // The bpfman socket should always allow the same users and members of the same group
 // to Read/Write to it.
 pub const SOCK_MODE: u32 = 0o0660;
 
+// The directories where tracefs may list the tracepoints of the kernel.
+pub(crate) const TRACEFS_EVENTS: [&str; 2] = [
+    "/sys/kernel/tracing/events",
+    "/sys/kernel/debug/tracing/events",
+];
+
 // Like tokio::fs::read, but with O_NOCTTY set
 pub(crate) fn read<P: AsRef<Path>>(path: P) -> Result<Vec<u8>, BpfmanError> {
     let mut data = vec![];
@@ -69,6 +83,160 @@ pub(crate) fn get_ifindex(iface: &str, netns: Option<PathBuf>) -> Result<u32, Bp
     }
 }
 
+// Returns the names and indexes of the interfaces in the given network
+// namespace, or in the bpfman network namespace if none is given.
+pub(crate) fn interfaces(netns: Option<PathBuf>) -> Result<Vec<(String, u32)>, BpfmanError> {
+    let _netns_guard = netns.map(enter_netns).transpose()?;
+    let interfaces = if_nameindex()
+        .map_err(|e| BpfmanError::Error(format!("unable to list interfaces: {e}")))?;
+    Ok(interfaces
+        .iter()
+        .map(|i| (i.name().to_string_lossy().to_string(), i.index()))
+        .collect())
+}
+
+// Returns the names of the interfaces in the given network namespace, or in the
+// bpfman network namespace if none is given.
+pub(crate) fn interface_names(netns: Option<PathBuf>) -> Result<Vec<String>, BpfmanError> {
+    Ok(interfaces(netns)?
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect())
+}
+
+/// Returns the interfaces matched by an interface selector as pairs of
+/// interface name and network namespace. The `netns` is the network namespace
+/// the `Glob`, `Regex` and `Physical` selectors are applied in.
+pub(crate) fn select_interfaces(
+    selector: &InterfaceSelector,
+    netns: Option<PathBuf>,
+) -> Result<Vec<(String, Option<PathBuf>)>, BpfmanError> {
+    let selected = match selector {
+        InterfaceSelector::Glob(_) | InterfaceSelector::Regex(_) => {
+            let re = selector
+                .name_regex()?
+                .expect("glob and regex selectors have a name regex");
+            interface_names(netns.clone())?
+                .into_iter()
+                .filter(|iface| re.is_match(iface))
+                .map(|iface| (iface, netns.clone()))
+                .collect()
+        }
+        InterfaceSelector::Physical => {
+            // Only the bpfman network namespace has its devices in sysfs.
+            if netns.is_some() {
+                return Err(BpfmanError::InvalidAttach(
+                    "the physical interface selector cannot be used with a network namespace"
+                        .to_string(),
+                ));
+            }
+            interface_names(None)?
+                .into_iter()
+                .filter(|iface| {
+                    Path::new("/sys/class/net")
+                        .join(iface)
+                        .join("device")
+                        .exists()
+                })
+                .map(|iface| (iface, None))
+                .collect()
+        }
+        InterfaceSelector::Netns(paths) => {
+            if netns.is_some() {
+                return Err(BpfmanError::InvalidAttach(
+                    "the netns interface selector cannot be used with a network namespace"
+                        .to_string(),
+                ));
+            }
+            let mut selected = vec![];
+            for path in paths {
+                for iface in interface_names(Some(path.clone()))? {
+                    if iface != "lo" {
+                        selected.push((iface, Some(path.clone())));
+                    }
+                }
+            }
+            selected
+        }
+    };
+    Ok(selected)
+}
+
+/// Returns whether the kernel has a tracepoint named `category/name`.
+pub(crate) fn tracepoint_exists(category: &str, name: &str) -> bool {
+    TRACEFS_EVENTS
+        .iter()
+        .any(|events| Path::new(events).join(category).join(name).exists())
+}
+
+/// Returns whether `fn_name` is a symbol of the kernel or of one of its
+/// loaded modules.
+pub(crate) fn kernel_symbol_exists(fn_name: &str) -> Result<bool, BpfmanError> {
+    let kallsyms = fs::read_to_string("/proc/kallsyms")
+        .map_err(|e| BpfmanError::Error(format!("can't read kernel symbols: {e}")))?;
+    Ok(kallsyms
+        .lines()
+        .any(|line| line.split_whitespace().nth(2) == Some(fn_name)))
+}
+
+/// Returns the tracepoints of the kernel as `category/name`, sorted.
+pub(crate) fn tracepoints() -> Result<Vec<String>, BpfmanError> {
+    let events = TRACEFS_EVENTS
+        .iter()
+        .find(|events| Path::new(events).is_dir())
+        .ok_or_else(|| BpfmanError::Error("tracefs is not mounted".to_string()))?;
+    let mut tracepoints = vec![];
+    let categories = fs::read_dir(events)
+        .map_err(|e| BpfmanError::Error(format!("can't read {events}: {e}")))?;
+    for category in categories.filter_map(|c| c.ok()) {
+        let Ok(names) = fs::read_dir(category.path()) else {
+            continue;
+        };
+        for name in names.filter_map(|n| n.ok()) {
+            if name.path().is_dir() {
+                tracepoints.push(format!(
+                    "{}/{}",
+                    category.file_name().to_string_lossy(),
+                    name.file_name().to_string_lossy()
+                ));
+            }
+        }
+    }
+    tracepoints.sort();
+    Ok(tracepoints)
+}
+
+/// Returns the functions of the kernel and of its loaded modules whose names
+/// start with `prefix`, sorted and without duplicates.
+pub(crate) fn kernel_functions(prefix: &str) -> Result<Vec<String>, BpfmanError> {
+    let kallsyms = fs::read_to_string("/proc/kallsyms")
+        .map_err(|e| BpfmanError::Error(format!("can't read kernel symbols: {e}")))?;
+    let mut functions: Vec<String> = kallsyms
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace().skip(1);
+            match (fields.next(), fields.next()) {
+                (Some("t" | "T"), Some(name)) if name.starts_with(prefix) => Some(name.to_string()),
+                _ => None,
+            }
+        })
+        .collect();
+    functions.sort();
+    functions.dedup();
+    Ok(functions)
+}
+
+/// Returns whether the ELF file at `path` has a symbol named `fn_name`.
+pub(crate) fn elf_symbol_exists(path: &Path, fn_name: &str) -> Result<bool, BpfmanError> {
+    let data = read(path)?;
+    let elf = object::File::parse(data.as_slice())
+        .map_err(|e| BpfmanError::Error(format!("can't parse {}: {e}", path.display())))?;
+    Ok(elf
+        .symbols()
+        .chain(elf.dynamic_symbols())
+        .any(|sym| sym.name() == Ok(fn_name)))
+}
+
 pub fn set_file_permissions(path: &Path, mode: u32) {
     // Set the permissions on the file based on input
     if (set_permissions(path, std::fs::Permissions::from_mode(mode))).is_err() {
@@ -242,7 +410,7 @@ pub(crate) fn get_error_msg_from_stderr(stderr: &[u8]) -> String {
         .to_string()
 }
 
-pub(crate) fn open_config_file() -> Config {
+pub fn open_config_file() -> Config {
     if let Ok(c) = std::fs::read_to_string(CFGPATH_BPFMAN_CONFIG) {
         if let Ok(config) = c.parse::<Config>() {
             config
@@ -256,11 +424,14 @@ pub(crate) fn open_config_file() -> Config {
     }
 }
 
-fn has_cap(cset: caps::CapSet, cap: caps::Capability) {
-    debug!("Has {}: {}", cap, caps::has_cap(None, cset, cap).unwrap());
+pub(crate) fn has_cap(cset: caps::CapSet, cap: caps::Capability) -> bool {
+    let has = caps::has_cap(None, cset, cap).unwrap_or(false);
+    debug!("Has {}: {}", cap, has);
+    has
 }
 
-fn is_bpffs_mounted() -> Result<bool, anyhow::Error> {
+/// Returns whether the bpffs used by bpfman is mounted.
+pub fn is_bpffs_mounted() -> Result<bool, anyhow::Error> {
     let file = File::open("/proc/mounts").context("Failed to open /proc/mounts")?;
     let reader = BufReader::new(file);
     for l in reader.lines() {
@@ -458,3 +629,107 @@ pub(crate) fn tc_dispatcher_link_id_path(
         id
     ))
 }
+
+/// Reads the kernel's view of the link pinned at `path`.
+///
+/// aya has no public API for querying a pinned link, so the link is opened
+/// with BPF_OBJ_GET and its details are read from the file descriptor's
+/// fdinfo.
+pub(crate) fn kernel_link_info<P: AsRef<Path>>(path: P) -> Result<KernelLinkInfo, BpfmanError> {
+    // Matches the BPF_OBJ_* member of `union bpf_attr`.
+    #[repr(C)]
+    #[derive(Default)]
+    struct BpfObjAttr {
+        pathname: u64,
+        bpf_fd: u32,
+        file_flags: u32,
+        path_fd: i32,
+    }
+    const BPF_OBJ_GET: nix::libc::c_long = 7;
+
+    let path = path.as_ref();
+    let pathname = CString::new(path.as_os_str().as_bytes()).map_err(|e| {
+        BpfmanError::Error(format!("invalid link pin path {}: {e}", path.display()))
+    })?;
+    let attr = BpfObjAttr {
+        pathname: pathname.as_ptr() as u64,
+        ..Default::default()
+    };
+    // SAFETY: `attr` is a valid BPF_OBJ_GET attribute whose pathname points to
+    // a NUL terminated string that outlives the call.
+    let fd = unsafe {
+        nix::libc::syscall(
+            nix::libc::SYS_bpf,
+            BPF_OBJ_GET,
+            &attr as *const BpfObjAttr,
+            std::mem::size_of::<BpfObjAttr>(),
+        )
+    };
+    if fd < 0 {
+        return Err(BpfmanError::Error(format!(
+            "unable to open pinned link {}: {}",
+            path.display(),
+            std::io::Error::last_os_error()
+        )));
+    }
+    // SAFETY: the kernel returned a new file descriptor that nothing else owns.
+    let fd = unsafe { OwnedFd::from_raw_fd(fd as RawFd) };
+
+    let fdinfo = fs::read_to_string(format!("/proc/self/fdinfo/{}", fd.as_raw_fd()))
+        .map_err(|e| BpfmanError::Error(format!("unable to read link fdinfo: {e}")))?;
+    let field = |name: &str| {
+        fdinfo
+            .lines()
+            .find_map(|l| l.strip_prefix(name)?.strip_prefix(':'))
+            .map(|v| v.trim().to_string())
+            .ok_or_else(|| BpfmanError::Error(format!("link fdinfo has no {name} field")))
+    };
+    let parse = |name: &str| {
+        field(name)?
+            .parse::<u32>()
+            .map_err(|e| BpfmanError::Error(format!("invalid link fdinfo {name}: {e}")))
+    };
+
+    Ok(KernelLinkInfo {
+        id: parse("link_id")?,
+        link_type: field("link_type")?,
+        program_id: parse("prog_id")?,
+    })
+}
+
+/// Returns whether an eBPF program with the ID is loaded in the kernel.
+///
+/// aya can only look up a program by walking every loaded program, so the
+/// program is opened by ID with BPF_PROG_GET_FD_BY_ID instead.
+pub(crate) fn program_loaded(id: u32) -> bool {
+    // Matches the BPF_*_GET_*_ID member of `union bpf_attr`.
+    #[repr(C)]
+    #[derive(Default)]
+    struct BpfGetIdAttr {
+        id: u32,
+        next_id: u32,
+        open_flags: u32,
+    }
+    const BPF_PROG_GET_FD_BY_ID: nix::libc::c_long = 13;
+
+    let attr = BpfGetIdAttr {
+        id,
+        ..Default::default()
+    };
+    // SAFETY: `attr` is a valid BPF_PROG_GET_FD_BY_ID attribute.
+    let fd = unsafe {
+        nix::libc::syscall(
+            nix::libc::SYS_bpf,
+            BPF_PROG_GET_FD_BY_ID,
+            &attr as *const BpfGetIdAttr,
+            std::mem::size_of::<BpfGetIdAttr>(),
+        )
+    };
+    if fd < 0 {
+        return false;
+    }
+    // SAFETY: the kernel returned a new file descriptor that nothing else owns,
+    // which is closed when dropped.
+    drop(unsafe { OwnedFd::from_raw_fd(fd as RawFd) });
+    true
+}
@@ -0,0 +1,296 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+//! Reads the verifier log of programs that the kernel accepted.
+//!
+//! aya only asks the kernel for a verifier log when a load fails, and drops
+//! the log of a load that succeeds. To get the log of an accepted program,
+//! the program is loaded a second time from the same bytecode with a log
+//! buffer, using the maps that aya created for the first load, and the second
+//! copy is closed again right away.
+
+use std::{
+    collections::HashSet,
+    io, mem,
+    os::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd},
+};
+
+use aya::{Btf, Ebpf, features, maps::Map, programs::ProgramFd, util::KernelVersion};
+use aya_obj::{
+    Object, ProgramSection,
+    generated::{BPF_F_SLEEPABLE, bpf_attach_type, bpf_attr, bpf_cmd, bpf_prog_info},
+};
+
+use crate::{
+    errors::BpfmanError,
+    multiprog::{TcDispatcher, XdpDispatcher},
+    types::Program,
+};
+
+// The size of the buffer the verifier writes its log to. Logs that don't fit
+// are cut by the kernel, which is fine as only the end of the log is kept.
+const VERIFIER_LOG_SIZE: usize = 1024 * 1024;
+
+/// The most of the verifier log, in bytes, that is kept for a program.
+pub(crate) const VERIFIER_LOG_SUMMARY_SIZE: usize = 4096;
+
+/// Returns the verifier log of program `p`, which `loader` loaded, at log
+/// level `level`.
+pub(crate) fn verifier_log(loader: &Ebpf, p: &Program, level: u32) -> Result<String, BpfmanError> {
+    let data = p.get_data();
+    let name = data.get_name()?;
+    let info = prog_info(
+        loader
+            .program(&name)
+            .ok_or(BpfmanError::BpfFunctionNameNotValid(name.clone()))?
+            .fd()?
+            .as_fd(),
+    )?;
+
+    let mut obj = Object::parse(&data.get_program_bytes()?)
+        .map_err(|e| BpfmanError::Error(format!("unable to parse bytecode: {e}")))?;
+    let btf_fd = match features().btf() {
+        Some(btf_features) => match obj
+            .fixup_and_sanitize_btf(btf_features)
+            .map_err(|e| BpfmanError::Error(format!("unable to sanitize BTF: {e}")))?
+        {
+            Some(btf) => Some(load_btf(&btf.to_bytes())?),
+            None => None,
+        },
+        None => None,
+    };
+    if let Ok(btf) = Btf::from_sys_fs() {
+        obj.relocate_btf(&btf)
+            .map_err(|e| BpfmanError::Error(format!("unable to relocate BTF: {e}")))?;
+    }
+
+    // The instructions refer to the maps that were created for the first load.
+    let obj_maps = obj.maps.clone();
+    let text_sections: HashSet<usize> = obj
+        .functions
+        .keys()
+        .map(|(section_index, _)| *section_index)
+        .collect();
+    obj.relocate_maps(
+        loader
+            .maps()
+            .filter_map(|(name, map)| Some((name, map_fd(map), obj_maps.get(name)?))),
+        &text_sections,
+    )
+    .map_err(|e| BpfmanError::Error(format!("unable to relocate maps: {e}")))?;
+    obj.relocate_calls(&text_sections)
+        .map_err(|e| BpfmanError::Error(format!("unable to relocate calls: {e}")))?;
+    obj.sanitize_functions(features());
+
+    let program = obj
+        .programs
+        .get(&name)
+        .ok_or(BpfmanError::BpfFunctionNameNotValid(name.clone()))?;
+    let function = obj
+        .functions
+        .get(&program.function_key())
+        .ok_or_else(|| BpfmanError::Error(format!("unable to find the instructions of {name}")))?;
+
+    // Extensions are verified against the function they replace, which is
+    // the test dispatcher's for programs loaded by bpfman.
+    let attach_prog: Option<ProgramFd> = match p {
+        Program::Tc(_) => Some(TcDispatcher::get_test()?.fd()?.try_clone()?),
+        Program::Xdp(_) => Some(XdpDispatcher::get_test()?.fd()?.try_clone()?),
+        _ => None,
+    };
+    let expected_attach_type = match p {
+        Program::Fentry(_) => Some(bpf_attach_type::BPF_TRACE_FENTRY),
+        Program::Fexit(_) => Some(bpf_attach_type::BPF_TRACE_FEXIT),
+        _ => None,
+    };
+    let sleepable = matches!(
+        program.section,
+        ProgramSection::FEntry { sleepable: true }
+            | ProgramSection::FExit { sleepable: true }
+            | ProgramSection::UProbe { sleepable: true }
+            | ProgramSection::URetProbe { sleepable: true }
+    );
+    let kernel_version = match program.kernel_version {
+        Some(version) => version,
+        None => KernelVersion::current().map(|v| v.code()).unwrap_or(0),
+    };
+
+    // These must outlive the load, as the attributes point into them.
+    let func_info = function.func_info.func_info_bytes();
+    let line_info = function.line_info.line_info_bytes();
+    let mut log_buf = vec![0u8; VERIFIER_LOG_SIZE];
+
+    // SAFETY: `bpf_attr` is plain data, for which all zeroes is valid.
+    let mut attr = unsafe { mem::zeroed::<bpf_attr>() };
+    // SAFETY: BPF_PROG_LOAD takes this member of the union.
+    let u = unsafe { &mut attr.__bindgen_anon_3 };
+    u.prog_type = info.type_;
+    if let Some(attach_type) = expected_attach_type {
+        u.expected_attach_type = attach_type as u32;
+    }
+    if sleepable {
+        u.prog_flags = BPF_F_SLEEPABLE;
+    }
+    u.insns = function.instructions.as_ptr() as u64;
+    u.insn_cnt = function.instructions.len() as u32;
+    u.license = program.license.as_ptr() as u64;
+    u.kern_version = kernel_version;
+    if let Some(btf_fd) = &btf_fd {
+        u.prog_btf_fd = btf_fd.as_raw_fd() as u32;
+        if function.line_info_rec_size > 0 {
+            u.line_info = line_info.as_ptr() as u64;
+            u.line_info_cnt = function.line_info.len() as u32;
+            u.line_info_rec_size = function.line_info_rec_size as u32;
+        }
+        if function.func_info_rec_size > 0 {
+            u.func_info = func_info.as_ptr() as u64;
+            u.func_info_cnt = function.func_info.len() as u32;
+            u.func_info_rec_size = function.func_info_rec_size as u32;
+        }
+    }
+    u.log_level = level;
+    u.log_buf = log_buf.as_mut_ptr() as u64;
+    u.log_size = log_buf.len() as u32;
+    if let Some(fd) = &attach_prog {
+        u.__bindgen_anon_1.attach_prog_fd = fd.as_fd().as_raw_fd() as u32;
+    }
+    u.attach_btf_id = info.attach_btf_id;
+
+    match sys_bpf(bpf_cmd::BPF_PROG_LOAD, &mut attr) {
+        // SAFETY: the kernel returned a new file descriptor that nothing else
+        // owns, which is closed when dropped.
+        Ok(fd) => drop(unsafe { OwnedFd::from_raw_fd(fd as RawFd) }),
+        // The log didn't fit in the buffer. The kernel fails the load, but
+        // still writes as much of the log as fits.
+        Err(e) if e.raw_os_error() == Some(nix::libc::ENOSPC) => {}
+        Err(e) => {
+            return Err(BpfmanError::Error(format!(
+                "unable to load {name} again to read the verifier log: {e}"
+            )));
+        }
+    }
+
+    let len = log_buf
+        .iter()
+        .position(|b| *b == 0)
+        .unwrap_or(log_buf.len());
+    Ok(String::from_utf8_lossy(&log_buf[..len]).into_owned())
+}
+
+/// Returns the end of the verifier log `log`, starting at a line, that fits
+/// in [`VERIFIER_LOG_SUMMARY_SIZE`]. The end of the log has the statistics of
+/// the verification.
+pub(crate) fn truncate_log(log: &str) -> String {
+    let log = log.trim_end();
+    if log.len() <= VERIFIER_LOG_SUMMARY_SIZE {
+        return log.to_string();
+    }
+    let mut start = log.len() - VERIFIER_LOG_SUMMARY_SIZE;
+    match log.as_bytes()[start..].iter().position(|b| *b == b'\n') {
+        Some(newline) => start += newline + 1,
+        None => {
+            while !log.is_char_boundary(start) {
+                start += 1;
+            }
+        }
+    }
+    format!("...\n{}", &log[start..])
+}
+
+fn map_fd(map: &Map) -> RawFd {
+    let data = match map {
+        Map::Array(data)
+        | Map::BloomFilter(data)
+        | Map::CpuMap(data)
+        | Map::DevMap(data)
+        | Map::DevMapHash(data)
+        | Map::HashMap(data)
+        | Map::LpmTrie(data)
+        | Map::LruHashMap(data)
+        | Map::PerCpuArray(data)
+        | Map::PerCpuHashMap(data)
+        | Map::PerCpuLruHashMap(data)
+        | Map::PerfEventArray(data)
+        | Map::ProgramArray(data)
+        | Map::Queue(data)
+        | Map::RingBuf(data)
+        | Map::SockHash(data)
+        | Map::SockMap(data)
+        | Map::Stack(data)
+        | Map::StackTraceMap(data)
+        | Map::Unsupported(data)
+        | Map::XskMap(data) => data,
+    };
+    data.fd().as_fd().as_raw_fd()
+}
+
+fn prog_info(fd: BorrowedFd<'_>) -> Result<bpf_prog_info, BpfmanError> {
+    // SAFETY: `bpf_prog_info` and `bpf_attr` are plain data, for which all
+    // zeroes is valid.
+    let mut info = unsafe { mem::zeroed::<bpf_prog_info>() };
+    let mut attr = unsafe { mem::zeroed::<bpf_attr>() };
+    // SAFETY: BPF_OBJ_GET_INFO_BY_FD takes this member of the union.
+    let u = unsafe { &mut attr.info };
+    u.bpf_fd = fd.as_raw_fd() as u32;
+    u.info = &mut info as *mut bpf_prog_info as u64;
+    u.info_len = mem::size_of::<bpf_prog_info>() as u32;
+    sys_bpf(bpf_cmd::BPF_OBJ_GET_INFO_BY_FD, &mut attr)?;
+    Ok(info)
+}
+
+fn load_btf(btf: &[u8]) -> Result<OwnedFd, BpfmanError> {
+    // SAFETY: `bpf_attr` is plain data, for which all zeroes is valid.
+    let mut attr = unsafe { mem::zeroed::<bpf_attr>() };
+    // SAFETY: BPF_BTF_LOAD takes this member of the union.
+    let u = unsafe { &mut attr.__bindgen_anon_7 };
+    u.btf = btf.as_ptr() as u64;
+    u.btf_size = btf.len() as u32;
+    let fd = sys_bpf(bpf_cmd::BPF_BTF_LOAD, &mut attr)?;
+    // SAFETY: the kernel returned a new file descriptor that nothing else owns,
+    // which is closed when dropped.
+    Ok(unsafe { OwnedFd::from_raw_fd(fd as RawFd) })
+}
+
+fn sys_bpf(cmd: bpf_cmd, attr: &mut bpf_attr) -> io::Result<i64> {
+    // SAFETY: `attr` is a valid attribute of `cmd`, whose pointers outlive
+    // the call.
+    let ret = unsafe {
+        nix::libc::syscall(
+            nix::libc::SYS_bpf,
+            cmd as nix::libc::c_long,
+            attr as *mut bpf_attr,
+            mem::size_of::<bpf_attr>(),
+        )
+    };
+    if ret < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_log() {
+        let log = "func#0 @0\n0: R1=ctx() R10=fp0\n0: (b7) r0 = 0\n1: (95) exit\n";
+        assert_eq!(truncate_log(log), log.trim_end());
+
+        let lines: Vec<String> = (0..1000).map(|i| format!("{i}: (b7) r0 = 0")).collect();
+        let log = format!(
+            "{}\nprocessed 1001 insns (limit 1000000) max_states_per_insn 0\n",
+            lines.join("\n")
+        );
+        let truncated = truncate_log(&log);
+        assert!(truncated.len() <= VERIFIER_LOG_SUMMARY_SIZE + "...\n".len());
+        assert!(truncated.starts_with("...\n"));
+        // The log is cut at the start of a line and keeps the statistics.
+        assert!(
+            truncated["...\n".len()..]
+                .split('\n')
+                .all(|line| { line.starts_with("processed") || line.contains(": (b7) r0 = 0") })
+        );
+        assert!(truncated.ends_with("max_states_per_insn 0"));
+    }
+}
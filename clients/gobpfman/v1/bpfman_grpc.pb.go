@@ -1,7 +1,7 @@
 // Code generated by protoc-gen-go-grpc. DO NOT EDIT.
 // versions:
 // - protoc-gen-go-grpc v1.3.0
-// - protoc             v3.19.6
+// - protoc             v7.36.2
 // source: bpfman.proto
 
 package v1
@@ -19,13 +19,24 @@ import (
 const _ = grpc.SupportPackageIsVersion8
 
 const (
-	Bpfman_Load_FullMethodName         = "/bpfman.v1.Bpfman/Load"
-	Bpfman_Unload_FullMethodName       = "/bpfman.v1.Bpfman/Unload"
-	Bpfman_Attach_FullMethodName       = "/bpfman.v1.Bpfman/Attach"
-	Bpfman_Detach_FullMethodName       = "/bpfman.v1.Bpfman/Detach"
-	Bpfman_List_FullMethodName         = "/bpfman.v1.Bpfman/List"
-	Bpfman_PullBytecode_FullMethodName = "/bpfman.v1.Bpfman/PullBytecode"
-	Bpfman_Get_FullMethodName          = "/bpfman.v1.Bpfman/Get"
+	Bpfman_Load_FullMethodName                  = "/bpfman.v1.Bpfman/Load"
+	Bpfman_Unload_FullMethodName                = "/bpfman.v1.Bpfman/Unload"
+	Bpfman_Attach_FullMethodName                = "/bpfman.v1.Bpfman/Attach"
+	Bpfman_Detach_FullMethodName                = "/bpfman.v1.Bpfman/Detach"
+	Bpfman_AttachBatch_FullMethodName           = "/bpfman.v1.Bpfman/AttachBatch"
+	Bpfman_DetachBatch_FullMethodName           = "/bpfman.v1.Bpfman/DetachBatch"
+	Bpfman_DisableLink_FullMethodName           = "/bpfman.v1.Bpfman/DisableLink"
+	Bpfman_EnableLink_FullMethodName            = "/bpfman.v1.Bpfman/EnableLink"
+	Bpfman_SetLinkPriority_FullMethodName       = "/bpfman.v1.Bpfman/SetLinkPriority"
+	Bpfman_SetLinkReattachPolicy_FullMethodName = "/bpfman.v1.Bpfman/SetLinkReattachPolicy"
+	Bpfman_List_FullMethodName                  = "/bpfman.v1.Bpfman/List"
+	Bpfman_PullBytecode_FullMethodName          = "/bpfman.v1.Bpfman/PullBytecode"
+	Bpfman_Get_FullMethodName                   = "/bpfman.v1.Bpfman/Get"
+	Bpfman_ListLinks_FullMethodName             = "/bpfman.v1.Bpfman/ListLinks"
+	Bpfman_GetLink_FullMethodName               = "/bpfman.v1.Bpfman/GetLink"
+	Bpfman_GetLinkGroup_FullMethodName          = "/bpfman.v1.Bpfman/GetLinkGroup"
+	Bpfman_Verify_FullMethodName                = "/bpfman.v1.Bpfman/Verify"
+	Bpfman_Watch_FullMethodName                 = "/bpfman.v1.Bpfman/Watch"
 )
 
 // BpfmanClient is the client API for Bpfman service.
@@ -36,9 +47,20 @@ type BpfmanClient interface {
 	Unload(ctx context.Context, in *UnloadRequest, opts ...grpc.CallOption) (*UnloadResponse, error)
 	Attach(ctx context.Context, in *AttachRequest, opts ...grpc.CallOption) (*AttachResponse, error)
 	Detach(ctx context.Context, in *DetachRequest, opts ...grpc.CallOption) (*DetachResponse, error)
+	AttachBatch(ctx context.Context, in *AttachBatchRequest, opts ...grpc.CallOption) (*AttachBatchResponse, error)
+	DetachBatch(ctx context.Context, in *DetachBatchRequest, opts ...grpc.CallOption) (*DetachBatchResponse, error)
+	DisableLink(ctx context.Context, in *DisableLinkRequest, opts ...grpc.CallOption) (*DisableLinkResponse, error)
+	EnableLink(ctx context.Context, in *EnableLinkRequest, opts ...grpc.CallOption) (*EnableLinkResponse, error)
+	SetLinkPriority(ctx context.Context, in *SetLinkPriorityRequest, opts ...grpc.CallOption) (*SetLinkPriorityResponse, error)
+	SetLinkReattachPolicy(ctx context.Context, in *SetLinkReattachPolicyRequest, opts ...grpc.CallOption) (*SetLinkReattachPolicyResponse, error)
 	List(ctx context.Context, in *ListRequest, opts ...grpc.CallOption) (*ListResponse, error)
 	PullBytecode(ctx context.Context, in *PullBytecodeRequest, opts ...grpc.CallOption) (*PullBytecodeResponse, error)
 	Get(ctx context.Context, in *GetRequest, opts ...grpc.CallOption) (*GetResponse, error)
+	ListLinks(ctx context.Context, in *ListLinksRequest, opts ...grpc.CallOption) (*ListLinksResponse, error)
+	GetLink(ctx context.Context, in *GetLinkRequest, opts ...grpc.CallOption) (*GetLinkResponse, error)
+	GetLinkGroup(ctx context.Context, in *GetLinkGroupRequest, opts ...grpc.CallOption) (*GetLinkGroupResponse, error)
+	Verify(ctx context.Context, in *VerifyRequest, opts ...grpc.CallOption) (*VerifyResponse, error)
+	Watch(ctx context.Context, in *WatchRequest, opts ...grpc.CallOption) (Bpfman_WatchClient, error)
 }
 
 type bpfmanClient struct {
@@ -89,6 +111,66 @@ func (c *bpfmanClient) Detach(ctx context.Context, in *DetachRequest, opts ...gr
 	return out, nil
 }
 
+func (c *bpfmanClient) AttachBatch(ctx context.Context, in *AttachBatchRequest, opts ...grpc.CallOption) (*AttachBatchResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(AttachBatchResponse)
+	err := c.cc.Invoke(ctx, Bpfman_AttachBatch_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) DetachBatch(ctx context.Context, in *DetachBatchRequest, opts ...grpc.CallOption) (*DetachBatchResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(DetachBatchResponse)
+	err := c.cc.Invoke(ctx, Bpfman_DetachBatch_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) DisableLink(ctx context.Context, in *DisableLinkRequest, opts ...grpc.CallOption) (*DisableLinkResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(DisableLinkResponse)
+	err := c.cc.Invoke(ctx, Bpfman_DisableLink_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) EnableLink(ctx context.Context, in *EnableLinkRequest, opts ...grpc.CallOption) (*EnableLinkResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(EnableLinkResponse)
+	err := c.cc.Invoke(ctx, Bpfman_EnableLink_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) SetLinkPriority(ctx context.Context, in *SetLinkPriorityRequest, opts ...grpc.CallOption) (*SetLinkPriorityResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(SetLinkPriorityResponse)
+	err := c.cc.Invoke(ctx, Bpfman_SetLinkPriority_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) SetLinkReattachPolicy(ctx context.Context, in *SetLinkReattachPolicyRequest, opts ...grpc.CallOption) (*SetLinkReattachPolicyResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(SetLinkReattachPolicyResponse)
+	err := c.cc.Invoke(ctx, Bpfman_SetLinkReattachPolicy_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *bpfmanClient) List(ctx context.Context, in *ListRequest, opts ...grpc.CallOption) (*ListResponse, error) {
 	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
 	out := new(ListResponse)
@@ -119,6 +201,79 @@ func (c *bpfmanClient) Get(ctx context.Context, in *GetRequest, opts ...grpc.Cal
 	return out, nil
 }
 
+func (c *bpfmanClient) ListLinks(ctx context.Context, in *ListLinksRequest, opts ...grpc.CallOption) (*ListLinksResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ListLinksResponse)
+	err := c.cc.Invoke(ctx, Bpfman_ListLinks_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) GetLink(ctx context.Context, in *GetLinkRequest, opts ...grpc.CallOption) (*GetLinkResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(GetLinkResponse)
+	err := c.cc.Invoke(ctx, Bpfman_GetLink_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) GetLinkGroup(ctx context.Context, in *GetLinkGroupRequest, opts ...grpc.CallOption) (*GetLinkGroupResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(GetLinkGroupResponse)
+	err := c.cc.Invoke(ctx, Bpfman_GetLinkGroup_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) Verify(ctx context.Context, in *VerifyRequest, opts ...grpc.CallOption) (*VerifyResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(VerifyResponse)
+	err := c.cc.Invoke(ctx, Bpfman_Verify_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) Watch(ctx context.Context, in *WatchRequest, opts ...grpc.CallOption) (Bpfman_WatchClient, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	stream, err := c.cc.NewStream(ctx, &Bpfman_ServiceDesc.Streams[0], Bpfman_Watch_FullMethodName, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &bpfmanWatchClient{ClientStream: stream}
+	if err := x.ClientStream.SendMsg(in); err != nil {
+		return nil, err
+	}
+	if err := x.ClientStream.CloseSend(); err != nil {
+		return nil, err
+	}
+	return x, nil
+}
+
+type Bpfman_WatchClient interface {
+	Recv() (*WatchResponse, error)
+	grpc.ClientStream
+}
+
+type bpfmanWatchClient struct {
+	grpc.ClientStream
+}
+
+func (x *bpfmanWatchClient) Recv() (*WatchResponse, error) {
+	m := new(WatchResponse)
+	if err := x.ClientStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
 // BpfmanServer is the server API for Bpfman service.
 // All implementations must embed UnimplementedBpfmanServer
 // for forward compatibility
@@ -127,9 +282,20 @@ type BpfmanServer interface {
 	Unload(context.Context, *UnloadRequest) (*UnloadResponse, error)
 	Attach(context.Context, *AttachRequest) (*AttachResponse, error)
 	Detach(context.Context, *DetachRequest) (*DetachResponse, error)
+	AttachBatch(context.Context, *AttachBatchRequest) (*AttachBatchResponse, error)
+	DetachBatch(context.Context, *DetachBatchRequest) (*DetachBatchResponse, error)
+	DisableLink(context.Context, *DisableLinkRequest) (*DisableLinkResponse, error)
+	EnableLink(context.Context, *EnableLinkRequest) (*EnableLinkResponse, error)
+	SetLinkPriority(context.Context, *SetLinkPriorityRequest) (*SetLinkPriorityResponse, error)
+	SetLinkReattachPolicy(context.Context, *SetLinkReattachPolicyRequest) (*SetLinkReattachPolicyResponse, error)
 	List(context.Context, *ListRequest) (*ListResponse, error)
 	PullBytecode(context.Context, *PullBytecodeRequest) (*PullBytecodeResponse, error)
 	Get(context.Context, *GetRequest) (*GetResponse, error)
+	ListLinks(context.Context, *ListLinksRequest) (*ListLinksResponse, error)
+	GetLink(context.Context, *GetLinkRequest) (*GetLinkResponse, error)
+	GetLinkGroup(context.Context, *GetLinkGroupRequest) (*GetLinkGroupResponse, error)
+	Verify(context.Context, *VerifyRequest) (*VerifyResponse, error)
+	Watch(*WatchRequest, Bpfman_WatchServer) error
 	mustEmbedUnimplementedBpfmanServer()
 }
 
@@ -149,6 +315,24 @@ func (UnimplementedBpfmanServer) Attach(context.Context, *AttachRequest) (*Attac
 func (UnimplementedBpfmanServer) Detach(context.Context, *DetachRequest) (*DetachResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method Detach not implemented")
 }
+func (UnimplementedBpfmanServer) AttachBatch(context.Context, *AttachBatchRequest) (*AttachBatchResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method AttachBatch not implemented")
+}
+func (UnimplementedBpfmanServer) DetachBatch(context.Context, *DetachBatchRequest) (*DetachBatchResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DetachBatch not implemented")
+}
+func (UnimplementedBpfmanServer) DisableLink(context.Context, *DisableLinkRequest) (*DisableLinkResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DisableLink not implemented")
+}
+func (UnimplementedBpfmanServer) EnableLink(context.Context, *EnableLinkRequest) (*EnableLinkResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method EnableLink not implemented")
+}
+func (UnimplementedBpfmanServer) SetLinkPriority(context.Context, *SetLinkPriorityRequest) (*SetLinkPriorityResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method SetLinkPriority not implemented")
+}
+func (UnimplementedBpfmanServer) SetLinkReattachPolicy(context.Context, *SetLinkReattachPolicyRequest) (*SetLinkReattachPolicyResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method SetLinkReattachPolicy not implemented")
+}
 func (UnimplementedBpfmanServer) List(context.Context, *ListRequest) (*ListResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method List not implemented")
 }
@@ -158,6 +342,21 @@ func (UnimplementedBpfmanServer) PullBytecode(context.Context, *PullBytecodeRequ
 func (UnimplementedBpfmanServer) Get(context.Context, *GetRequest) (*GetResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method Get not implemented")
 }
+func (UnimplementedBpfmanServer) ListLinks(context.Context, *ListLinksRequest) (*ListLinksResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListLinks not implemented")
+}
+func (UnimplementedBpfmanServer) GetLink(context.Context, *GetLinkRequest) (*GetLinkResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetLink not implemented")
+}
+func (UnimplementedBpfmanServer) GetLinkGroup(context.Context, *GetLinkGroupRequest) (*GetLinkGroupResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetLinkGroup not implemented")
+}
+func (UnimplementedBpfmanServer) Verify(context.Context, *VerifyRequest) (*VerifyResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Verify not implemented")
+}
+func (UnimplementedBpfmanServer) Watch(*WatchRequest, Bpfman_WatchServer) error {
+	return status.Errorf(codes.Unimplemented, "method Watch not implemented")
+}
 func (UnimplementedBpfmanServer) mustEmbedUnimplementedBpfmanServer() {}
 
 // UnsafeBpfmanServer may be embedded to opt out of forward compatibility for this service.
@@ -243,6 +442,114 @@ func _Bpfman_Detach_Handler(srv interface{}, ctx context.Context, dec func(inter
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Bpfman_AttachBatch_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AttachBatchRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).AttachBatch(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_AttachBatch_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).AttachBatch(ctx, req.(*AttachBatchRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_DetachBatch_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DetachBatchRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).DetachBatch(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_DetachBatch_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).DetachBatch(ctx, req.(*DetachBatchRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_DisableLink_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DisableLinkRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).DisableLink(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_DisableLink_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).DisableLink(ctx, req.(*DisableLinkRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_EnableLink_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(EnableLinkRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).EnableLink(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_EnableLink_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).EnableLink(ctx, req.(*EnableLinkRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_SetLinkPriority_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SetLinkPriorityRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).SetLinkPriority(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_SetLinkPriority_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).SetLinkPriority(ctx, req.(*SetLinkPriorityRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_SetLinkReattachPolicy_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SetLinkReattachPolicyRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).SetLinkReattachPolicy(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_SetLinkReattachPolicy_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).SetLinkReattachPolicy(ctx, req.(*SetLinkReattachPolicyRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 func _Bpfman_List_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(ListRequest)
 	if err := dec(in); err != nil {
@@ -297,6 +604,99 @@ func _Bpfman_Get_Handler(srv interface{}, ctx context.Context, dec func(interfac
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Bpfman_ListLinks_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListLinksRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).ListLinks(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_ListLinks_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).ListLinks(ctx, req.(*ListLinksRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_GetLink_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetLinkRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).GetLink(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_GetLink_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).GetLink(ctx, req.(*GetLinkRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_GetLinkGroup_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetLinkGroupRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).GetLinkGroup(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_GetLinkGroup_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).GetLinkGroup(ctx, req.(*GetLinkGroupRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_Verify_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(VerifyRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).Verify(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_Verify_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).Verify(ctx, req.(*VerifyRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_Watch_Handler(srv interface{}, stream grpc.ServerStream) error {
+	m := new(WatchRequest)
+	if err := stream.RecvMsg(m); err != nil {
+		return err
+	}
+	return srv.(BpfmanServer).Watch(m, &bpfmanWatchServer{ServerStream: stream})
+}
+
+type Bpfman_WatchServer interface {
+	Send(*WatchResponse) error
+	grpc.ServerStream
+}
+
+type bpfmanWatchServer struct {
+	grpc.ServerStream
+}
+
+func (x *bpfmanWatchServer) Send(m *WatchResponse) error {
+	return x.ServerStream.SendMsg(m)
+}
+
 // Bpfman_ServiceDesc is the grpc.ServiceDesc for Bpfman service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -320,6 +720,30 @@ var Bpfman_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "Detach",
 			Handler:    _Bpfman_Detach_Handler,
 		},
+		{
+			MethodName: "AttachBatch",
+			Handler:    _Bpfman_AttachBatch_Handler,
+		},
+		{
+			MethodName: "DetachBatch",
+			Handler:    _Bpfman_DetachBatch_Handler,
+		},
+		{
+			MethodName: "DisableLink",
+			Handler:    _Bpfman_DisableLink_Handler,
+		},
+		{
+			MethodName: "EnableLink",
+			Handler:    _Bpfman_EnableLink_Handler,
+		},
+		{
+			MethodName: "SetLinkPriority",
+			Handler:    _Bpfman_SetLinkPriority_Handler,
+		},
+		{
+			MethodName: "SetLinkReattachPolicy",
+			Handler:    _Bpfman_SetLinkReattachPolicy_Handler,
+		},
 		{
 			MethodName: "List",
 			Handler:    _Bpfman_List_Handler,
@@ -332,7 +756,29 @@ var Bpfman_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "Get",
 			Handler:    _Bpfman_Get_Handler,
 		},
+		{
+			MethodName: "ListLinks",
+			Handler:    _Bpfman_ListLinks_Handler,
+		},
+		{
+			MethodName: "GetLink",
+			Handler:    _Bpfman_GetLink_Handler,
+		},
+		{
+			MethodName: "GetLinkGroup",
+			Handler:    _Bpfman_GetLinkGroup_Handler,
+		},
+		{
+			MethodName: "Verify",
+			Handler:    _Bpfman_Verify_Handler,
+		},
+	},
+	Streams: []grpc.StreamDesc{
+		{
+			StreamName:    "Watch",
+			Handler:       _Bpfman_Watch_Handler,
+			ServerStreams: true,
+		},
 	},
-	Streams:  []grpc.StreamDesc{},
 	Metadata: "bpfman.proto",
 }
@@ -0,0 +1,606 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// versions:
+// 	protoc-gen-go v1.36.3
+// 	protoc        v7.36.2
+// source: bpfman_v2.proto
+
+package v2
+
+import (
+	v1 "github.com/bpfman/clients/gobpfman/v1"
+	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
+	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
+	reflect "reflect"
+	sync "sync"
+)
+
+const (
+	// Verify that this generated code is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(20 - protoimpl.MinVersion)
+	// Verify that runtime/protoimpl is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
+)
+
+type ErrorReason int32
+
+const (
+	// The reason is not known [UNKNOWN].
+	ErrorReason_ERROR_REASON_UNSPECIFIED ErrorReason = 0
+	// A field of the request is missing or invalid [INVALID_ARGUMENT].
+	ErrorReason_ERROR_REASON_INVALID_ARGUMENT ErrorReason = 1
+	// A program, link, function or image doesn't exist [NOT_FOUND].
+	ErrorReason_ERROR_REASON_NOT_FOUND ErrorReason = 2
+	// The resource already exists [ALREADY_EXISTS].
+	ErrorReason_ERROR_REASON_ALREADY_EXISTS ErrorReason = 3
+	// The caller is not allowed to make the request [PERMISSION_DENIED].
+	ErrorReason_ERROR_REASON_PERMISSION_DENIED ErrorReason = 4
+	// The request is denied by the admission policy [FAILED_PRECONDITION].
+	ErrorReason_ERROR_REASON_POLICY_VIOLATION ErrorReason = 5
+	// The kernel verifier rejected the program [INVALID_ARGUMENT].
+	ErrorReason_ERROR_REASON_VERIFIER_REJECTED ErrorReason = 6
+	// The bytecode could not be parsed or loaded [INVALID_ARGUMENT].
+	ErrorReason_ERROR_REASON_BYTECODE_INVALID ErrorReason = 7
+	// The bytecode image could not be pulled [UNAVAILABLE].
+	ErrorReason_ERROR_REASON_IMAGE_PULL_FAILED ErrorReason = 8
+	// No more programs can be attached to the hook [RESOURCE_EXHAUSTED].
+	ErrorReason_ERROR_REASON_RESOURCE_EXHAUSTED ErrorReason = 9
+	// The database is in use, the request can be retried [UNAVAILABLE].
+	ErrorReason_ERROR_REASON_UNAVAILABLE ErrorReason = 10
+	// The requested revision is no longer in the event log [OUT_OF_RANGE].
+	ErrorReason_ERROR_REASON_OUT_OF_RANGE ErrorReason = 11
+	// The program was not loaded because another program of the same Load
+	// request failed [ABORTED].
+	ErrorReason_ERROR_REASON_ABORTED ErrorReason = 12
+	// bpfman or the kernel failed to carry out the request [INTERNAL].
+	ErrorReason_ERROR_REASON_INTERNAL ErrorReason = 13
+)
+
+// Enum value maps for ErrorReason.
+var (
+	ErrorReason_name = map[int32]string{
+		0:  "ERROR_REASON_UNSPECIFIED",
+		1:  "ERROR_REASON_INVALID_ARGUMENT",
+		2:  "ERROR_REASON_NOT_FOUND",
+		3:  "ERROR_REASON_ALREADY_EXISTS",
+		4:  "ERROR_REASON_PERMISSION_DENIED",
+		5:  "ERROR_REASON_POLICY_VIOLATION",
+		6:  "ERROR_REASON_VERIFIER_REJECTED",
+		7:  "ERROR_REASON_BYTECODE_INVALID",
+		8:  "ERROR_REASON_IMAGE_PULL_FAILED",
+		9:  "ERROR_REASON_RESOURCE_EXHAUSTED",
+		10: "ERROR_REASON_UNAVAILABLE",
+		11: "ERROR_REASON_OUT_OF_RANGE",
+		12: "ERROR_REASON_ABORTED",
+		13: "ERROR_REASON_INTERNAL",
+	}
+	ErrorReason_value = map[string]int32{
+		"ERROR_REASON_UNSPECIFIED":        0,
+		"ERROR_REASON_INVALID_ARGUMENT":   1,
+		"ERROR_REASON_NOT_FOUND":          2,
+		"ERROR_REASON_ALREADY_EXISTS":     3,
+		"ERROR_REASON_PERMISSION_DENIED":  4,
+		"ERROR_REASON_POLICY_VIOLATION":   5,
+		"ERROR_REASON_VERIFIER_REJECTED":  6,
+		"ERROR_REASON_BYTECODE_INVALID":   7,
+		"ERROR_REASON_IMAGE_PULL_FAILED":  8,
+		"ERROR_REASON_RESOURCE_EXHAUSTED": 9,
+		"ERROR_REASON_UNAVAILABLE":        10,
+		"ERROR_REASON_OUT_OF_RANGE":       11,
+		"ERROR_REASON_ABORTED":            12,
+		"ERROR_REASON_INTERNAL":           13,
+	}
+)
+
+func (x ErrorReason) Enum() *ErrorReason {
+	p := new(ErrorReason)
+	*p = x
+	return p
+}
+
+func (x ErrorReason) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (ErrorReason) Descriptor() protoreflect.EnumDescriptor {
+	return file_bpfman_v2_proto_enumTypes[0].Descriptor()
+}
+
+func (ErrorReason) Type() protoreflect.EnumType {
+	return &file_bpfman_v2_proto_enumTypes[0]
+}
+
+func (x ErrorReason) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use ErrorReason.Descriptor instead.
+func (ErrorReason) EnumDescriptor() ([]byte, []int) {
+	return file_bpfman_v2_proto_rawDescGZIP(), []int{0}
+}
+
+type ErrorDetail struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Reason        ErrorReason            `protobuf:"varint,1,opt,name=reason,proto3,enum=bpfman.v2.ErrorReason" json:"reason,omitempty"`
+	Message       string                 `protobuf:"bytes,2,opt,name=message,proto3" json:"message,omitempty"`
+	Field         *string                `protobuf:"bytes,3,opt,name=field,proto3,oneof" json:"field,omitempty"`
+	VerifierLog   *string                `protobuf:"bytes,4,opt,name=verifier_log,json=verifierLog,proto3,oneof" json:"verifier_log,omitempty"`
+	PolicyRule    *string                `protobuf:"bytes,5,opt,name=policy_rule,json=policyRule,proto3,oneof" json:"policy_rule,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ErrorDetail) Reset() {
+	*x = ErrorDetail{}
+	mi := &file_bpfman_v2_proto_msgTypes[0]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ErrorDetail) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ErrorDetail) ProtoMessage() {}
+
+func (x *ErrorDetail) ProtoReflect() protoreflect.Message {
+	mi := &file_bpfman_v2_proto_msgTypes[0]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ErrorDetail.ProtoReflect.Descriptor instead.
+func (*ErrorDetail) Descriptor() ([]byte, []int) {
+	return file_bpfman_v2_proto_rawDescGZIP(), []int{0}
+}
+
+func (x *ErrorDetail) GetReason() ErrorReason {
+	if x != nil {
+		return x.Reason
+	}
+	return ErrorReason_ERROR_REASON_UNSPECIFIED
+}
+
+func (x *ErrorDetail) GetMessage() string {
+	if x != nil {
+		return x.Message
+	}
+	return ""
+}
+
+func (x *ErrorDetail) GetField() string {
+	if x != nil && x.Field != nil {
+		return *x.Field
+	}
+	return ""
+}
+
+func (x *ErrorDetail) GetVerifierLog() string {
+	if x != nil && x.VerifierLog != nil {
+		return *x.VerifierLog
+	}
+	return ""
+}
+
+func (x *ErrorDetail) GetPolicyRule() string {
+	if x != nil && x.PolicyRule != nil {
+		return *x.PolicyRule
+	}
+	return ""
+}
+
+type LoadResponse struct {
+	state         protoimpl.MessageState        `protogen:"open.v1"`
+	Results       []*LoadResponse_ProgramResult `protobuf:"bytes,1,rep,name=results,proto3" json:"results,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *LoadResponse) Reset() {
+	*x = LoadResponse{}
+	mi := &file_bpfman_v2_proto_msgTypes[1]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *LoadResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*LoadResponse) ProtoMessage() {}
+
+func (x *LoadResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_bpfman_v2_proto_msgTypes[1]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use LoadResponse.ProtoReflect.Descriptor instead.
+func (*LoadResponse) Descriptor() ([]byte, []int) {
+	return file_bpfman_v2_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *LoadResponse) GetResults() []*LoadResponse_ProgramResult {
+	if x != nil {
+		return x.Results
+	}
+	return nil
+}
+
+type LoadResponse_ProgramResult struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	Name  string                 `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
+	// Types that are valid to be assigned to Outcome:
+	//
+	//	*LoadResponse_ProgramResult_Loaded
+	//	*LoadResponse_ProgramResult_Error
+	Outcome       isLoadResponse_ProgramResult_Outcome `protobuf_oneof:"outcome"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *LoadResponse_ProgramResult) Reset() {
+	*x = LoadResponse_ProgramResult{}
+	mi := &file_bpfman_v2_proto_msgTypes[2]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *LoadResponse_ProgramResult) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*LoadResponse_ProgramResult) ProtoMessage() {}
+
+func (x *LoadResponse_ProgramResult) ProtoReflect() protoreflect.Message {
+	mi := &file_bpfman_v2_proto_msgTypes[2]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use LoadResponse_ProgramResult.ProtoReflect.Descriptor instead.
+func (*LoadResponse_ProgramResult) Descriptor() ([]byte, []int) {
+	return file_bpfman_v2_proto_rawDescGZIP(), []int{1, 0}
+}
+
+func (x *LoadResponse_ProgramResult) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *LoadResponse_ProgramResult) GetOutcome() isLoadResponse_ProgramResult_Outcome {
+	if x != nil {
+		return x.Outcome
+	}
+	return nil
+}
+
+func (x *LoadResponse_ProgramResult) GetLoaded() *v1.LoadResponseInfo {
+	if x != nil {
+		if x, ok := x.Outcome.(*LoadResponse_ProgramResult_Loaded); ok {
+			return x.Loaded
+		}
+	}
+	return nil
+}
+
+func (x *LoadResponse_ProgramResult) GetError() *ErrorDetail {
+	if x != nil {
+		if x, ok := x.Outcome.(*LoadResponse_ProgramResult_Error); ok {
+			return x.Error
+		}
+	}
+	return nil
+}
+
+type isLoadResponse_ProgramResult_Outcome interface {
+	isLoadResponse_ProgramResult_Outcome()
+}
+
+type LoadResponse_ProgramResult_Loaded struct {
+	Loaded *v1.LoadResponseInfo `protobuf:"bytes,2,opt,name=loaded,proto3,oneof"`
+}
+
+type LoadResponse_ProgramResult_Error struct {
+	Error *ErrorDetail `protobuf:"bytes,3,opt,name=error,proto3,oneof"`
+}
+
+func (*LoadResponse_ProgramResult_Loaded) isLoadResponse_ProgramResult_Outcome() {}
+
+func (*LoadResponse_ProgramResult_Error) isLoadResponse_ProgramResult_Outcome() {}
+
+var File_bpfman_v2_proto protoreflect.FileDescriptor
+
+var file_bpfman_v2_proto_rawDesc = []byte{
+	0x0a, 0x0f, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x5f, 0x76, 0x32, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+	0x6f, 0x12, 0x09, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x32, 0x1a, 0x0c, 0x62, 0x70,
+	0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0xeb, 0x01, 0x0a, 0x0b, 0x45,
+	0x72, 0x72, 0x6f, 0x72, 0x44, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x12, 0x2e, 0x0a, 0x06, 0x72, 0x65,
+	0x61, 0x73, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x16, 0x2e, 0x62, 0x70, 0x66,
+	0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x32, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x52, 0x65, 0x61, 0x73,
+	0x6f, 0x6e, 0x52, 0x06, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x12, 0x18, 0x0a, 0x07, 0x6d, 0x65,
+	0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x6d, 0x65, 0x73,
+	0x73, 0x61, 0x67, 0x65, 0x12, 0x19, 0x0a, 0x05, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x05, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x88, 0x01, 0x01, 0x12,
+	0x26, 0x0a, 0x0c, 0x76, 0x65, 0x72, 0x69, 0x66, 0x69, 0x65, 0x72, 0x5f, 0x6c, 0x6f, 0x67, 0x18,
+	0x04, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x0b, 0x76, 0x65, 0x72, 0x69, 0x66, 0x69, 0x65,
+	0x72, 0x4c, 0x6f, 0x67, 0x88, 0x01, 0x01, 0x12, 0x24, 0x0a, 0x0b, 0x70, 0x6f, 0x6c, 0x69, 0x63,
+	0x79, 0x5f, 0x72, 0x75, 0x6c, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x48, 0x02, 0x52, 0x0a,
+	0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x75, 0x6c, 0x65, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a,
+	0x06, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x42, 0x0f, 0x0a, 0x0d, 0x5f, 0x76, 0x65, 0x72, 0x69,
+	0x66, 0x69, 0x65, 0x72, 0x5f, 0x6c, 0x6f, 0x67, 0x42, 0x0e, 0x0a, 0x0c, 0x5f, 0x70, 0x6f, 0x6c,
+	0x69, 0x63, 0x79, 0x5f, 0x72, 0x75, 0x6c, 0x65, 0x22, 0xe7, 0x01, 0x0a, 0x0c, 0x4c, 0x6f, 0x61,
+	0x64, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3f, 0x0a, 0x07, 0x72, 0x65, 0x73,
+	0x75, 0x6c, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x62, 0x70, 0x66,
+	0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x32, 0x2e, 0x4c, 0x6f, 0x61, 0x64, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x2e, 0x50, 0x72, 0x6f, 0x67, 0x72, 0x61, 0x6d, 0x52, 0x65, 0x73, 0x75, 0x6c,
+	0x74, 0x52, 0x07, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x1a, 0x95, 0x01, 0x0a, 0x0d, 0x50,
+	0x72, 0x6f, 0x67, 0x72, 0x61, 0x6d, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x12, 0x12, 0x0a, 0x04,
+	0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65,
+	0x12, 0x35, 0x0a, 0x06, 0x6c, 0x6f, 0x61, 0x64, 0x65, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x1b, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x6f, 0x61,
+	0x64, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x49, 0x6e, 0x66, 0x6f, 0x48, 0x00, 0x52,
+	0x06, 0x6c, 0x6f, 0x61, 0x64, 0x65, 0x64, 0x12, 0x2e, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
+	0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x16, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e,
+	0x76, 0x32, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x44, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x48, 0x00,
+	0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x42, 0x09, 0x0a, 0x07, 0x6f, 0x75, 0x74, 0x63, 0x6f,
+	0x6d, 0x65, 0x2a, 0xd4, 0x03, 0x0a, 0x0b, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x52, 0x65, 0x61, 0x73,
+	0x6f, 0x6e, 0x12, 0x1c, 0x0a, 0x18, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x52, 0x45, 0x41, 0x53,
+	0x4f, 0x4e, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00,
+	0x12, 0x21, 0x0a, 0x1d, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e,
+	0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x41, 0x52, 0x47, 0x55, 0x4d, 0x45, 0x4e,
+	0x54, 0x10, 0x01, 0x12, 0x1a, 0x0a, 0x16, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x52, 0x45, 0x41,
+	0x53, 0x4f, 0x4e, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x46, 0x4f, 0x55, 0x4e, 0x44, 0x10, 0x02, 0x12,
+	0x1f, 0x0a, 0x1b, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f,
+	0x41, 0x4c, 0x52, 0x45, 0x41, 0x44, 0x59, 0x5f, 0x45, 0x58, 0x49, 0x53, 0x54, 0x53, 0x10, 0x03,
+	0x12, 0x22, 0x0a, 0x1e, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e,
+	0x5f, 0x50, 0x45, 0x52, 0x4d, 0x49, 0x53, 0x53, 0x49, 0x4f, 0x4e, 0x5f, 0x44, 0x45, 0x4e, 0x49,
+	0x45, 0x44, 0x10, 0x04, 0x12, 0x21, 0x0a, 0x1d, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x52, 0x45,
+	0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x50, 0x4f, 0x4c, 0x49, 0x43, 0x59, 0x5f, 0x56, 0x49, 0x4f, 0x4c,
+	0x41, 0x54, 0x49, 0x4f, 0x4e, 0x10, 0x05, 0x12, 0x22, 0x0a, 0x1e, 0x45, 0x52, 0x52, 0x4f, 0x52,
+	0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x56, 0x45, 0x52, 0x49, 0x46, 0x49, 0x45, 0x52,
+	0x5f, 0x52, 0x45, 0x4a, 0x45, 0x43, 0x54, 0x45, 0x44, 0x10, 0x06, 0x12, 0x21, 0x0a, 0x1d, 0x45,
+	0x52, 0x52, 0x4f, 0x52, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x42, 0x59, 0x54, 0x45,
+	0x43, 0x4f, 0x44, 0x45, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x10, 0x07, 0x12, 0x22,
+	0x0a, 0x1e, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x49,
+	0x4d, 0x41, 0x47, 0x45, 0x5f, 0x50, 0x55, 0x4c, 0x4c, 0x5f, 0x46, 0x41, 0x49, 0x4c, 0x45, 0x44,
+	0x10, 0x08, 0x12, 0x23, 0x0a, 0x1f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x52, 0x45, 0x41, 0x53,
+	0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x53, 0x4f, 0x55, 0x52, 0x43, 0x45, 0x5f, 0x45, 0x58, 0x48, 0x41,
+	0x55, 0x53, 0x54, 0x45, 0x44, 0x10, 0x09, 0x12, 0x1c, 0x0a, 0x18, 0x45, 0x52, 0x52, 0x4f, 0x52,
+	0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x55, 0x4e, 0x41, 0x56, 0x41, 0x49, 0x4c, 0x41,
+	0x42, 0x4c, 0x45, 0x10, 0x0a, 0x12, 0x1d, 0x0a, 0x19, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x52,
+	0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x4f, 0x55, 0x54, 0x5f, 0x4f, 0x46, 0x5f, 0x52, 0x41, 0x4e,
+	0x47, 0x45, 0x10, 0x0b, 0x12, 0x18, 0x0a, 0x14, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x52, 0x45,
+	0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x41, 0x42, 0x4f, 0x52, 0x54, 0x45, 0x44, 0x10, 0x0c, 0x12, 0x19,
+	0x0a, 0x15, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x49,
+	0x4e, 0x54, 0x45, 0x52, 0x4e, 0x41, 0x4c, 0x10, 0x0d, 0x32, 0xc0, 0x09, 0x0a, 0x06, 0x42, 0x70,
+	0x66, 0x6d, 0x61, 0x6e, 0x12, 0x37, 0x0a, 0x04, 0x4c, 0x6f, 0x61, 0x64, 0x12, 0x16, 0x2e, 0x62,
+	0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x6f, 0x61, 0x64, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x1a, 0x17, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x32,
+	0x2e, 0x4c, 0x6f, 0x61, 0x64, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3d, 0x0a,
+	0x06, 0x55, 0x6e, 0x6c, 0x6f, 0x61, 0x64, 0x12, 0x18, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e,
+	0x2e, 0x76, 0x31, 0x2e, 0x55, 0x6e, 0x6c, 0x6f, 0x61, 0x64, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x1a, 0x19, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x55, 0x6e,
+	0x6c, 0x6f, 0x61, 0x64, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3d, 0x0a, 0x06,
+	0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x12, 0x18, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e,
+	0x76, 0x31, 0x2e, 0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x1a, 0x19, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x74, 0x74,
+	0x61, 0x63, 0x68, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3d, 0x0a, 0x06, 0x44,
+	0x65, 0x74, 0x61, 0x63, 0x68, 0x12, 0x18, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76,
+	0x31, 0x2e, 0x44, 0x65, 0x74, 0x61, 0x63, 0x68, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+	0x19, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x44, 0x65, 0x74, 0x61,
+	0x63, 0x68, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4c, 0x0a, 0x0b, 0x41, 0x74,
+	0x74, 0x61, 0x63, 0x68, 0x42, 0x61, 0x74, 0x63, 0x68, 0x12, 0x1d, 0x2e, 0x62, 0x70, 0x66, 0x6d,
+	0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x42, 0x61, 0x74, 0x63,
+	0x68, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61,
+	0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x42, 0x61, 0x74, 0x63, 0x68,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4c, 0x0a, 0x0b, 0x44, 0x65, 0x74, 0x61,
+	0x63, 0x68, 0x42, 0x61, 0x74, 0x63, 0x68, 0x12, 0x1d, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e,
+	0x2e, 0x76, 0x31, 0x2e, 0x44, 0x65, 0x74, 0x61, 0x63, 0x68, 0x42, 0x61, 0x74, 0x63, 0x68, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e,
+	0x76, 0x31, 0x2e, 0x44, 0x65, 0x74, 0x61, 0x63, 0x68, 0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4c, 0x0a, 0x0b, 0x44, 0x69, 0x73, 0x61, 0x62, 0x6c,
+	0x65, 0x4c, 0x69, 0x6e, 0x6b, 0x12, 0x1d, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76,
+	0x31, 0x2e, 0x44, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x4c, 0x69, 0x6e, 0x6b, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31,
+	0x2e, 0x44, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x4c, 0x69, 0x6e, 0x6b, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x49, 0x0a, 0x0a, 0x45, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x4c, 0x69,
+	0x6e, 0x6b, 0x12, 0x1c, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x45,
+	0x6e, 0x61, 0x62, 0x6c, 0x65, 0x4c, 0x69, 0x6e, 0x6b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x1a, 0x1d, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x45, 0x6e, 0x61,
+	0x62, 0x6c, 0x65, 0x4c, 0x69, 0x6e, 0x6b, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+	0x58, 0x0a, 0x0f, 0x53, 0x65, 0x74, 0x4c, 0x69, 0x6e, 0x6b, 0x50, 0x72, 0x69, 0x6f, 0x72, 0x69,
+	0x74, 0x79, 0x12, 0x21, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x53,
+	0x65, 0x74, 0x4c, 0x69, 0x6e, 0x6b, 0x50, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x22, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76,
+	0x31, 0x2e, 0x53, 0x65, 0x74, 0x4c, 0x69, 0x6e, 0x6b, 0x50, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74,
+	0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x6a, 0x0a, 0x15, 0x53, 0x65, 0x74,
+	0x4c, 0x69, 0x6e, 0x6b, 0x52, 0x65, 0x61, 0x74, 0x74, 0x61, 0x63, 0x68, 0x50, 0x6f, 0x6c, 0x69,
+	0x63, 0x79, 0x12, 0x27, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x53,
+	0x65, 0x74, 0x4c, 0x69, 0x6e, 0x6b, 0x52, 0x65, 0x61, 0x74, 0x74, 0x61, 0x63, 0x68, 0x50, 0x6f,
+	0x6c, 0x69, 0x63, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x28, 0x2e, 0x62, 0x70,
+	0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x65, 0x74, 0x4c, 0x69, 0x6e, 0x6b, 0x52,
+	0x65, 0x61, 0x74, 0x74, 0x61, 0x63, 0x68, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x37, 0x0a, 0x04, 0x4c, 0x69, 0x73, 0x74, 0x12, 0x16, 0x2e,
+	0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x17, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76,
+	0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4f,
+	0x0a, 0x0c, 0x50, 0x75, 0x6c, 0x6c, 0x42, 0x79, 0x74, 0x65, 0x63, 0x6f, 0x64, 0x65, 0x12, 0x1e,
+	0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x75, 0x6c, 0x6c, 0x42,
+	0x79, 0x74, 0x65, 0x63, 0x6f, 0x64, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1f,
+	0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x75, 0x6c, 0x6c, 0x42,
+	0x79, 0x74, 0x65, 0x63, 0x6f, 0x64, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+	0x34, 0x0a, 0x03, 0x47, 0x65, 0x74, 0x12, 0x15, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e,
+	0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x16, 0x2e,
+	0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x46, 0x0a, 0x09, 0x4c, 0x69, 0x73, 0x74, 0x4c, 0x69, 0x6e,
+	0x6b, 0x73, 0x12, 0x1b, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x4c,
+	0x69, 0x73, 0x74, 0x4c, 0x69, 0x6e, 0x6b, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+	0x1c, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74,
+	0x4c, 0x69, 0x6e, 0x6b, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x40, 0x0a,
+	0x07, 0x47, 0x65, 0x74, 0x4c, 0x69, 0x6e, 0x6b, 0x12, 0x19, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61,
+	0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x4c, 0x69, 0x6e, 0x6b, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x1a, 0x1a, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e,
+	0x47, 0x65, 0x74, 0x4c, 0x69, 0x6e, 0x6b, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+	0x3d, 0x0a, 0x06, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x12, 0x18, 0x2e, 0x62, 0x70, 0x66, 0x6d,
+	0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x1a, 0x19, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e,
+	0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3c,
+	0x0a, 0x05, 0x57, 0x61, 0x74, 0x63, 0x68, 0x12, 0x17, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e,
+	0x2e, 0x76, 0x31, 0x2e, 0x57, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x1a, 0x18, 0x2e, 0x62, 0x70, 0x66, 0x6d, 0x61, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x57, 0x61, 0x74,
+	0x63, 0x68, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x30, 0x01, 0x42, 0x2a, 0x5a, 0x28,
+	0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x62, 0x70, 0x66, 0x6d, 0x61,
+	0x6e, 0x2f, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x73, 0x2f, 0x67, 0x6f, 0x62, 0x70, 0x66, 0x6d,
+	0x61, 0x6e, 0x2f, 0x76, 0x32, 0x3b, 0x76, 0x32, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+}
+
+var (
+	file_bpfman_v2_proto_rawDescOnce sync.Once
+	file_bpfman_v2_proto_rawDescData = file_bpfman_v2_proto_rawDesc
+)
+
+func file_bpfman_v2_proto_rawDescGZIP() []byte {
+	file_bpfman_v2_proto_rawDescOnce.Do(func() {
+		file_bpfman_v2_proto_rawDescData = protoimpl.X.CompressGZIP(file_bpfman_v2_proto_rawDescData)
+	})
+	return file_bpfman_v2_proto_rawDescData
+}
+
+var file_bpfman_v2_proto_enumTypes = make([]protoimpl.EnumInfo, 1)
+var file_bpfman_v2_proto_msgTypes = make([]protoimpl.MessageInfo, 3)
+var file_bpfman_v2_proto_goTypes = []any{
+	(ErrorReason)(0),                         // 0: bpfman.v2.ErrorReason
+	(*ErrorDetail)(nil),                      // 1: bpfman.v2.ErrorDetail
+	(*LoadResponse)(nil),                     // 2: bpfman.v2.LoadResponse
+	(*LoadResponse_ProgramResult)(nil),       // 3: bpfman.v2.LoadResponse.ProgramResult
+	(*v1.LoadResponseInfo)(nil),              // 4: bpfman.v1.LoadResponseInfo
+	(*v1.LoadRequest)(nil),                   // 5: bpfman.v1.LoadRequest
+	(*v1.UnloadRequest)(nil),                 // 6: bpfman.v1.UnloadRequest
+	(*v1.AttachRequest)(nil),                 // 7: bpfman.v1.AttachRequest
+	(*v1.DetachRequest)(nil),                 // 8: bpfman.v1.DetachRequest
+	(*v1.AttachBatchRequest)(nil),            // 9: bpfman.v1.AttachBatchRequest
+	(*v1.DetachBatchRequest)(nil),            // 10: bpfman.v1.DetachBatchRequest
+	(*v1.DisableLinkRequest)(nil),            // 11: bpfman.v1.DisableLinkRequest
+	(*v1.EnableLinkRequest)(nil),             // 12: bpfman.v1.EnableLinkRequest
+	(*v1.SetLinkPriorityRequest)(nil),        // 13: bpfman.v1.SetLinkPriorityRequest
+	(*v1.SetLinkReattachPolicyRequest)(nil),  // 14: bpfman.v1.SetLinkReattachPolicyRequest
+	(*v1.ListRequest)(nil),                   // 15: bpfman.v1.ListRequest
+	(*v1.PullBytecodeRequest)(nil),           // 16: bpfman.v1.PullBytecodeRequest
+	(*v1.GetRequest)(nil),                    // 17: bpfman.v1.GetRequest
+	(*v1.ListLinksRequest)(nil),              // 18: bpfman.v1.ListLinksRequest
+	(*v1.GetLinkRequest)(nil),                // 19: bpfman.v1.GetLinkRequest
+	(*v1.VerifyRequest)(nil),                 // 20: bpfman.v1.VerifyRequest
+	(*v1.WatchRequest)(nil),                  // 21: bpfman.v1.WatchRequest
+	(*v1.UnloadResponse)(nil),                // 22: bpfman.v1.UnloadResponse
+	(*v1.AttachResponse)(nil),                // 23: bpfman.v1.AttachResponse
+	(*v1.DetachResponse)(nil),                // 24: bpfman.v1.DetachResponse
+	(*v1.AttachBatchResponse)(nil),           // 25: bpfman.v1.AttachBatchResponse
+	(*v1.DetachBatchResponse)(nil),           // 26: bpfman.v1.DetachBatchResponse
+	(*v1.DisableLinkResponse)(nil),           // 27: bpfman.v1.DisableLinkResponse
+	(*v1.EnableLinkResponse)(nil),            // 28: bpfman.v1.EnableLinkResponse
+	(*v1.SetLinkPriorityResponse)(nil),       // 29: bpfman.v1.SetLinkPriorityResponse
+	(*v1.SetLinkReattachPolicyResponse)(nil), // 30: bpfman.v1.SetLinkReattachPolicyResponse
+	(*v1.ListResponse)(nil),                  // 31: bpfman.v1.ListResponse
+	(*v1.PullBytecodeResponse)(nil),          // 32: bpfman.v1.PullBytecodeResponse
+	(*v1.GetResponse)(nil),                   // 33: bpfman.v1.GetResponse
+	(*v1.ListLinksResponse)(nil),             // 34: bpfman.v1.ListLinksResponse
+	(*v1.GetLinkResponse)(nil),               // 35: bpfman.v1.GetLinkResponse
+	(*v1.VerifyResponse)(nil),                // 36: bpfman.v1.VerifyResponse
+	(*v1.WatchResponse)(nil),                 // 37: bpfman.v1.WatchResponse
+}
+var file_bpfman_v2_proto_depIdxs = []int32{
+	0,  // 0: bpfman.v2.ErrorDetail.reason:type_name -> bpfman.v2.ErrorReason
+	3,  // 1: bpfman.v2.LoadResponse.results:type_name -> bpfman.v2.LoadResponse.ProgramResult
+	4,  // 2: bpfman.v2.LoadResponse.ProgramResult.loaded:type_name -> bpfman.v1.LoadResponseInfo
+	1,  // 3: bpfman.v2.LoadResponse.ProgramResult.error:type_name -> bpfman.v2.ErrorDetail
+	5,  // 4: bpfman.v2.Bpfman.Load:input_type -> bpfman.v1.LoadRequest
+	6,  // 5: bpfman.v2.Bpfman.Unload:input_type -> bpfman.v1.UnloadRequest
+	7,  // 6: bpfman.v2.Bpfman.Attach:input_type -> bpfman.v1.AttachRequest
+	8,  // 7: bpfman.v2.Bpfman.Detach:input_type -> bpfman.v1.DetachRequest
+	9,  // 8: bpfman.v2.Bpfman.AttachBatch:input_type -> bpfman.v1.AttachBatchRequest
+	10, // 9: bpfman.v2.Bpfman.DetachBatch:input_type -> bpfman.v1.DetachBatchRequest
+	11, // 10: bpfman.v2.Bpfman.DisableLink:input_type -> bpfman.v1.DisableLinkRequest
+	12, // 11: bpfman.v2.Bpfman.EnableLink:input_type -> bpfman.v1.EnableLinkRequest
+	13, // 12: bpfman.v2.Bpfman.SetLinkPriority:input_type -> bpfman.v1.SetLinkPriorityRequest
+	14, // 13: bpfman.v2.Bpfman.SetLinkReattachPolicy:input_type -> bpfman.v1.SetLinkReattachPolicyRequest
+	15, // 14: bpfman.v2.Bpfman.List:input_type -> bpfman.v1.ListRequest
+	16, // 15: bpfman.v2.Bpfman.PullBytecode:input_type -> bpfman.v1.PullBytecodeRequest
+	17, // 16: bpfman.v2.Bpfman.Get:input_type -> bpfman.v1.GetRequest
+	18, // 17: bpfman.v2.Bpfman.ListLinks:input_type -> bpfman.v1.ListLinksRequest
+	19, // 18: bpfman.v2.Bpfman.GetLink:input_type -> bpfman.v1.GetLinkRequest
+	20, // 19: bpfman.v2.Bpfman.Verify:input_type -> bpfman.v1.VerifyRequest
+	21, // 20: bpfman.v2.Bpfman.Watch:input_type -> bpfman.v1.WatchRequest
+	2,  // 21: bpfman.v2.Bpfman.Load:output_type -> bpfman.v2.LoadResponse
+	22, // 22: bpfman.v2.Bpfman.Unload:output_type -> bpfman.v1.UnloadResponse
+	23, // 23: bpfman.v2.Bpfman.Attach:output_type -> bpfman.v1.AttachResponse
+	24, // 24: bpfman.v2.Bpfman.Detach:output_type -> bpfman.v1.DetachResponse
+	25, // 25: bpfman.v2.Bpfman.AttachBatch:output_type -> bpfman.v1.AttachBatchResponse
+	26, // 26: bpfman.v2.Bpfman.DetachBatch:output_type -> bpfman.v1.DetachBatchResponse
+	27, // 27: bpfman.v2.Bpfman.DisableLink:output_type -> bpfman.v1.DisableLinkResponse
+	28, // 28: bpfman.v2.Bpfman.EnableLink:output_type -> bpfman.v1.EnableLinkResponse
+	29, // 29: bpfman.v2.Bpfman.SetLinkPriority:output_type -> bpfman.v1.SetLinkPriorityResponse
+	30, // 30: bpfman.v2.Bpfman.SetLinkReattachPolicy:output_type -> bpfman.v1.SetLinkReattachPolicyResponse
+	31, // 31: bpfman.v2.Bpfman.List:output_type -> bpfman.v1.ListResponse
+	32, // 32: bpfman.v2.Bpfman.PullBytecode:output_type -> bpfman.v1.PullBytecodeResponse
+	33, // 33: bpfman.v2.Bpfman.Get:output_type -> bpfman.v1.GetResponse
+	34, // 34: bpfman.v2.Bpfman.ListLinks:output_type -> bpfman.v1.ListLinksResponse
+	35, // 35: bpfman.v2.Bpfman.GetLink:output_type -> bpfman.v1.GetLinkResponse
+	36, // 36: bpfman.v2.Bpfman.Verify:output_type -> bpfman.v1.VerifyResponse
+	37, // 37: bpfman.v2.Bpfman.Watch:output_type -> bpfman.v1.WatchResponse
+	21, // [21:38] is the sub-list for method output_type
+	4,  // [4:21] is the sub-list for method input_type
+	4,  // [4:4] is the sub-list for extension type_name
+	4,  // [4:4] is the sub-list for extension extendee
+	0,  // [0:4] is the sub-list for field type_name
+}
+
+func init() { file_bpfman_v2_proto_init() }
+func file_bpfman_v2_proto_init() {
+	if File_bpfman_v2_proto != nil {
+		return
+	}
+	file_bpfman_v2_proto_msgTypes[0].OneofWrappers = []any{}
+	file_bpfman_v2_proto_msgTypes[2].OneofWrappers = []any{
+		(*LoadResponse_ProgramResult_Loaded)(nil),
+		(*LoadResponse_ProgramResult_Error)(nil),
+	}
+	type x struct{}
+	out := protoimpl.TypeBuilder{
+		File: protoimpl.DescBuilder{
+			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
+			RawDescriptor: file_bpfman_v2_proto_rawDesc,
+			NumEnums:      1,
+			NumMessages:   3,
+			NumExtensions: 0,
+			NumServices:   1,
+		},
+		GoTypes:           file_bpfman_v2_proto_goTypes,
+		DependencyIndexes: file_bpfman_v2_proto_depIdxs,
+		EnumInfos:         file_bpfman_v2_proto_enumTypes,
+		MessageInfos:      file_bpfman_v2_proto_msgTypes,
+	}.Build()
+	File_bpfman_v2_proto = out.File
+	file_bpfman_v2_proto_rawDesc = nil
+	file_bpfman_v2_proto_goTypes = nil
+	file_bpfman_v2_proto_depIdxs = nil
+}
@@ -0,0 +1,747 @@
+// Code generated by protoc-gen-go-grpc. DO NOT EDIT.
+// versions:
+// - protoc-gen-go-grpc v1.3.0
+// - protoc             v7.36.2
+// source: bpfman_v2.proto
+
+package v2
+
+import (
+	context "context"
+	v1 "github.com/bpfman/clients/gobpfman/v1"
+	grpc "google.golang.org/grpc"
+	codes "google.golang.org/grpc/codes"
+	status "google.golang.org/grpc/status"
+)
+
+// This is a compile-time assertion to ensure that this generated file
+// is compatible with the grpc package it is being compiled against.
+// Requires gRPC-Go v1.62.0 or later.
+const _ = grpc.SupportPackageIsVersion8
+
+const (
+	Bpfman_Load_FullMethodName                  = "/bpfman.v2.Bpfman/Load"
+	Bpfman_Unload_FullMethodName                = "/bpfman.v2.Bpfman/Unload"
+	Bpfman_Attach_FullMethodName                = "/bpfman.v2.Bpfman/Attach"
+	Bpfman_Detach_FullMethodName                = "/bpfman.v2.Bpfman/Detach"
+	Bpfman_AttachBatch_FullMethodName           = "/bpfman.v2.Bpfman/AttachBatch"
+	Bpfman_DetachBatch_FullMethodName           = "/bpfman.v2.Bpfman/DetachBatch"
+	Bpfman_DisableLink_FullMethodName           = "/bpfman.v2.Bpfman/DisableLink"
+	Bpfman_EnableLink_FullMethodName            = "/bpfman.v2.Bpfman/EnableLink"
+	Bpfman_SetLinkPriority_FullMethodName       = "/bpfman.v2.Bpfman/SetLinkPriority"
+	Bpfman_SetLinkReattachPolicy_FullMethodName = "/bpfman.v2.Bpfman/SetLinkReattachPolicy"
+	Bpfman_List_FullMethodName                  = "/bpfman.v2.Bpfman/List"
+	Bpfman_PullBytecode_FullMethodName          = "/bpfman.v2.Bpfman/PullBytecode"
+	Bpfman_Get_FullMethodName                   = "/bpfman.v2.Bpfman/Get"
+	Bpfman_ListLinks_FullMethodName             = "/bpfman.v2.Bpfman/ListLinks"
+	Bpfman_GetLink_FullMethodName               = "/bpfman.v2.Bpfman/GetLink"
+	Bpfman_Verify_FullMethodName                = "/bpfman.v2.Bpfman/Verify"
+	Bpfman_Watch_FullMethodName                 = "/bpfman.v2.Bpfman/Watch"
+)
+
+// BpfmanClient is the client API for Bpfman service.
+//
+// For semantics around ctx use and closing/ending streaming RPCs, please refer to https://pkg.go.dev/google.golang.org/grpc/?tab=doc#ClientConn.NewStream.
+type BpfmanClient interface {
+	Load(ctx context.Context, in *v1.LoadRequest, opts ...grpc.CallOption) (*LoadResponse, error)
+	Unload(ctx context.Context, in *v1.UnloadRequest, opts ...grpc.CallOption) (*v1.UnloadResponse, error)
+	Attach(ctx context.Context, in *v1.AttachRequest, opts ...grpc.CallOption) (*v1.AttachResponse, error)
+	Detach(ctx context.Context, in *v1.DetachRequest, opts ...grpc.CallOption) (*v1.DetachResponse, error)
+	AttachBatch(ctx context.Context, in *v1.AttachBatchRequest, opts ...grpc.CallOption) (*v1.AttachBatchResponse, error)
+	DetachBatch(ctx context.Context, in *v1.DetachBatchRequest, opts ...grpc.CallOption) (*v1.DetachBatchResponse, error)
+	DisableLink(ctx context.Context, in *v1.DisableLinkRequest, opts ...grpc.CallOption) (*v1.DisableLinkResponse, error)
+	EnableLink(ctx context.Context, in *v1.EnableLinkRequest, opts ...grpc.CallOption) (*v1.EnableLinkResponse, error)
+	SetLinkPriority(ctx context.Context, in *v1.SetLinkPriorityRequest, opts ...grpc.CallOption) (*v1.SetLinkPriorityResponse, error)
+	SetLinkReattachPolicy(ctx context.Context, in *v1.SetLinkReattachPolicyRequest, opts ...grpc.CallOption) (*v1.SetLinkReattachPolicyResponse, error)
+	List(ctx context.Context, in *v1.ListRequest, opts ...grpc.CallOption) (*v1.ListResponse, error)
+	PullBytecode(ctx context.Context, in *v1.PullBytecodeRequest, opts ...grpc.CallOption) (*v1.PullBytecodeResponse, error)
+	Get(ctx context.Context, in *v1.GetRequest, opts ...grpc.CallOption) (*v1.GetResponse, error)
+	ListLinks(ctx context.Context, in *v1.ListLinksRequest, opts ...grpc.CallOption) (*v1.ListLinksResponse, error)
+	GetLink(ctx context.Context, in *v1.GetLinkRequest, opts ...grpc.CallOption) (*v1.GetLinkResponse, error)
+	Verify(ctx context.Context, in *v1.VerifyRequest, opts ...grpc.CallOption) (*v1.VerifyResponse, error)
+	Watch(ctx context.Context, in *v1.WatchRequest, opts ...grpc.CallOption) (Bpfman_WatchClient, error)
+}
+
+type bpfmanClient struct {
+	cc grpc.ClientConnInterface
+}
+
+func NewBpfmanClient(cc grpc.ClientConnInterface) BpfmanClient {
+	return &bpfmanClient{cc}
+}
+
+func (c *bpfmanClient) Load(ctx context.Context, in *v1.LoadRequest, opts ...grpc.CallOption) (*LoadResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(LoadResponse)
+	err := c.cc.Invoke(ctx, Bpfman_Load_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) Unload(ctx context.Context, in *v1.UnloadRequest, opts ...grpc.CallOption) (*v1.UnloadResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(v1.UnloadResponse)
+	err := c.cc.Invoke(ctx, Bpfman_Unload_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) Attach(ctx context.Context, in *v1.AttachRequest, opts ...grpc.CallOption) (*v1.AttachResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(v1.AttachResponse)
+	err := c.cc.Invoke(ctx, Bpfman_Attach_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) Detach(ctx context.Context, in *v1.DetachRequest, opts ...grpc.CallOption) (*v1.DetachResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(v1.DetachResponse)
+	err := c.cc.Invoke(ctx, Bpfman_Detach_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) AttachBatch(ctx context.Context, in *v1.AttachBatchRequest, opts ...grpc.CallOption) (*v1.AttachBatchResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(v1.AttachBatchResponse)
+	err := c.cc.Invoke(ctx, Bpfman_AttachBatch_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) DetachBatch(ctx context.Context, in *v1.DetachBatchRequest, opts ...grpc.CallOption) (*v1.DetachBatchResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(v1.DetachBatchResponse)
+	err := c.cc.Invoke(ctx, Bpfman_DetachBatch_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) DisableLink(ctx context.Context, in *v1.DisableLinkRequest, opts ...grpc.CallOption) (*v1.DisableLinkResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(v1.DisableLinkResponse)
+	err := c.cc.Invoke(ctx, Bpfman_DisableLink_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) EnableLink(ctx context.Context, in *v1.EnableLinkRequest, opts ...grpc.CallOption) (*v1.EnableLinkResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(v1.EnableLinkResponse)
+	err := c.cc.Invoke(ctx, Bpfman_EnableLink_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) SetLinkPriority(ctx context.Context, in *v1.SetLinkPriorityRequest, opts ...grpc.CallOption) (*v1.SetLinkPriorityResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(v1.SetLinkPriorityResponse)
+	err := c.cc.Invoke(ctx, Bpfman_SetLinkPriority_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) SetLinkReattachPolicy(ctx context.Context, in *v1.SetLinkReattachPolicyRequest, opts ...grpc.CallOption) (*v1.SetLinkReattachPolicyResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(v1.SetLinkReattachPolicyResponse)
+	err := c.cc.Invoke(ctx, Bpfman_SetLinkReattachPolicy_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) List(ctx context.Context, in *v1.ListRequest, opts ...grpc.CallOption) (*v1.ListResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(v1.ListResponse)
+	err := c.cc.Invoke(ctx, Bpfman_List_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) PullBytecode(ctx context.Context, in *v1.PullBytecodeRequest, opts ...grpc.CallOption) (*v1.PullBytecodeResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(v1.PullBytecodeResponse)
+	err := c.cc.Invoke(ctx, Bpfman_PullBytecode_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) Get(ctx context.Context, in *v1.GetRequest, opts ...grpc.CallOption) (*v1.GetResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(v1.GetResponse)
+	err := c.cc.Invoke(ctx, Bpfman_Get_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) ListLinks(ctx context.Context, in *v1.ListLinksRequest, opts ...grpc.CallOption) (*v1.ListLinksResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(v1.ListLinksResponse)
+	err := c.cc.Invoke(ctx, Bpfman_ListLinks_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) GetLink(ctx context.Context, in *v1.GetLinkRequest, opts ...grpc.CallOption) (*v1.GetLinkResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(v1.GetLinkResponse)
+	err := c.cc.Invoke(ctx, Bpfman_GetLink_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) Verify(ctx context.Context, in *v1.VerifyRequest, opts ...grpc.CallOption) (*v1.VerifyResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(v1.VerifyResponse)
+	err := c.cc.Invoke(ctx, Bpfman_Verify_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) Watch(ctx context.Context, in *v1.WatchRequest, opts ...grpc.CallOption) (Bpfman_WatchClient, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	stream, err := c.cc.NewStream(ctx, &Bpfman_ServiceDesc.Streams[0], Bpfman_Watch_FullMethodName, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &bpfmanWatchClient{ClientStream: stream}
+	if err := x.ClientStream.SendMsg(in); err != nil {
+		return nil, err
+	}
+	if err := x.ClientStream.CloseSend(); err != nil {
+		return nil, err
+	}
+	return x, nil
+}
+
+type Bpfman_WatchClient interface {
+	Recv() (*v1.WatchResponse, error)
+	grpc.ClientStream
+}
+
+type bpfmanWatchClient struct {
+	grpc.ClientStream
+}
+
+func (x *bpfmanWatchClient) Recv() (*v1.WatchResponse, error) {
+	m := new(v1.WatchResponse)
+	if err := x.ClientStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
+// BpfmanServer is the server API for Bpfman service.
+// All implementations must embed UnimplementedBpfmanServer
+// for forward compatibility
+type BpfmanServer interface {
+	Load(context.Context, *v1.LoadRequest) (*LoadResponse, error)
+	Unload(context.Context, *v1.UnloadRequest) (*v1.UnloadResponse, error)
+	Attach(context.Context, *v1.AttachRequest) (*v1.AttachResponse, error)
+	Detach(context.Context, *v1.DetachRequest) (*v1.DetachResponse, error)
+	AttachBatch(context.Context, *v1.AttachBatchRequest) (*v1.AttachBatchResponse, error)
+	DetachBatch(context.Context, *v1.DetachBatchRequest) (*v1.DetachBatchResponse, error)
+	DisableLink(context.Context, *v1.DisableLinkRequest) (*v1.DisableLinkResponse, error)
+	EnableLink(context.Context, *v1.EnableLinkRequest) (*v1.EnableLinkResponse, error)
+	SetLinkPriority(context.Context, *v1.SetLinkPriorityRequest) (*v1.SetLinkPriorityResponse, error)
+	SetLinkReattachPolicy(context.Context, *v1.SetLinkReattachPolicyRequest) (*v1.SetLinkReattachPolicyResponse, error)
+	List(context.Context, *v1.ListRequest) (*v1.ListResponse, error)
+	PullBytecode(context.Context, *v1.PullBytecodeRequest) (*v1.PullBytecodeResponse, error)
+	Get(context.Context, *v1.GetRequest) (*v1.GetResponse, error)
+	ListLinks(context.Context, *v1.ListLinksRequest) (*v1.ListLinksResponse, error)
+	GetLink(context.Context, *v1.GetLinkRequest) (*v1.GetLinkResponse, error)
+	Verify(context.Context, *v1.VerifyRequest) (*v1.VerifyResponse, error)
+	Watch(*v1.WatchRequest, Bpfman_WatchServer) error
+	mustEmbedUnimplementedBpfmanServer()
+}
+
+// UnimplementedBpfmanServer must be embedded to have forward compatible implementations.
+type UnimplementedBpfmanServer struct {
+}
+
+func (UnimplementedBpfmanServer) Load(context.Context, *v1.LoadRequest) (*LoadResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Load not implemented")
+}
+func (UnimplementedBpfmanServer) Unload(context.Context, *v1.UnloadRequest) (*v1.UnloadResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Unload not implemented")
+}
+func (UnimplementedBpfmanServer) Attach(context.Context, *v1.AttachRequest) (*v1.AttachResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Attach not implemented")
+}
+func (UnimplementedBpfmanServer) Detach(context.Context, *v1.DetachRequest) (*v1.DetachResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Detach not implemented")
+}
+func (UnimplementedBpfmanServer) AttachBatch(context.Context, *v1.AttachBatchRequest) (*v1.AttachBatchResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method AttachBatch not implemented")
+}
+func (UnimplementedBpfmanServer) DetachBatch(context.Context, *v1.DetachBatchRequest) (*v1.DetachBatchResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DetachBatch not implemented")
+}
+func (UnimplementedBpfmanServer) DisableLink(context.Context, *v1.DisableLinkRequest) (*v1.DisableLinkResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DisableLink not implemented")
+}
+func (UnimplementedBpfmanServer) EnableLink(context.Context, *v1.EnableLinkRequest) (*v1.EnableLinkResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method EnableLink not implemented")
+}
+func (UnimplementedBpfmanServer) SetLinkPriority(context.Context, *v1.SetLinkPriorityRequest) (*v1.SetLinkPriorityResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method SetLinkPriority not implemented")
+}
+func (UnimplementedBpfmanServer) SetLinkReattachPolicy(context.Context, *v1.SetLinkReattachPolicyRequest) (*v1.SetLinkReattachPolicyResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method SetLinkReattachPolicy not implemented")
+}
+func (UnimplementedBpfmanServer) List(context.Context, *v1.ListRequest) (*v1.ListResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method List not implemented")
+}
+func (UnimplementedBpfmanServer) PullBytecode(context.Context, *v1.PullBytecodeRequest) (*v1.PullBytecodeResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method PullBytecode not implemented")
+}
+func (UnimplementedBpfmanServer) Get(context.Context, *v1.GetRequest) (*v1.GetResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Get not implemented")
+}
+func (UnimplementedBpfmanServer) ListLinks(context.Context, *v1.ListLinksRequest) (*v1.ListLinksResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListLinks not implemented")
+}
+func (UnimplementedBpfmanServer) GetLink(context.Context, *v1.GetLinkRequest) (*v1.GetLinkResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetLink not implemented")
+}
+func (UnimplementedBpfmanServer) Verify(context.Context, *v1.VerifyRequest) (*v1.VerifyResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Verify not implemented")
+}
+func (UnimplementedBpfmanServer) Watch(*v1.WatchRequest, Bpfman_WatchServer) error {
+	return status.Errorf(codes.Unimplemented, "method Watch not implemented")
+}
+func (UnimplementedBpfmanServer) mustEmbedUnimplementedBpfmanServer() {}
+
+// UnsafeBpfmanServer may be embedded to opt out of forward compatibility for this service.
+// Use of this interface is not recommended, as added methods to BpfmanServer will
+// result in compilation errors.
+type UnsafeBpfmanServer interface {
+	mustEmbedUnimplementedBpfmanServer()
+}
+
+func RegisterBpfmanServer(s grpc.ServiceRegistrar, srv BpfmanServer) {
+	s.RegisterService(&Bpfman_ServiceDesc, srv)
+}
+
+func _Bpfman_Load_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(v1.LoadRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).Load(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_Load_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).Load(ctx, req.(*v1.LoadRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_Unload_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(v1.UnloadRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).Unload(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_Unload_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).Unload(ctx, req.(*v1.UnloadRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_Attach_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(v1.AttachRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).Attach(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_Attach_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).Attach(ctx, req.(*v1.AttachRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_Detach_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(v1.DetachRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).Detach(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_Detach_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).Detach(ctx, req.(*v1.DetachRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_AttachBatch_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(v1.AttachBatchRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).AttachBatch(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_AttachBatch_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).AttachBatch(ctx, req.(*v1.AttachBatchRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_DetachBatch_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(v1.DetachBatchRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).DetachBatch(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_DetachBatch_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).DetachBatch(ctx, req.(*v1.DetachBatchRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_DisableLink_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(v1.DisableLinkRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).DisableLink(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_DisableLink_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).DisableLink(ctx, req.(*v1.DisableLinkRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_EnableLink_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(v1.EnableLinkRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).EnableLink(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_EnableLink_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).EnableLink(ctx, req.(*v1.EnableLinkRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_SetLinkPriority_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(v1.SetLinkPriorityRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).SetLinkPriority(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_SetLinkPriority_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).SetLinkPriority(ctx, req.(*v1.SetLinkPriorityRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_SetLinkReattachPolicy_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(v1.SetLinkReattachPolicyRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).SetLinkReattachPolicy(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_SetLinkReattachPolicy_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).SetLinkReattachPolicy(ctx, req.(*v1.SetLinkReattachPolicyRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_List_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(v1.ListRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).List(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_List_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).List(ctx, req.(*v1.ListRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_PullBytecode_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(v1.PullBytecodeRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).PullBytecode(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_PullBytecode_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).PullBytecode(ctx, req.(*v1.PullBytecodeRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_Get_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(v1.GetRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).Get(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_Get_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).Get(ctx, req.(*v1.GetRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_ListLinks_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(v1.ListLinksRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).ListLinks(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_ListLinks_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).ListLinks(ctx, req.(*v1.ListLinksRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_GetLink_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(v1.GetLinkRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).GetLink(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_GetLink_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).GetLink(ctx, req.(*v1.GetLinkRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_Verify_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(v1.VerifyRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).Verify(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Bpfman_Verify_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).Verify(ctx, req.(*v1.VerifyRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_Watch_Handler(srv interface{}, stream grpc.ServerStream) error {
+	m := new(v1.WatchRequest)
+	if err := stream.RecvMsg(m); err != nil {
+		return err
+	}
+	return srv.(BpfmanServer).Watch(m, &bpfmanWatchServer{ServerStream: stream})
+}
+
+type Bpfman_WatchServer interface {
+	Send(*v1.WatchResponse) error
+	grpc.ServerStream
+}
+
+type bpfmanWatchServer struct {
+	grpc.ServerStream
+}
+
+func (x *bpfmanWatchServer) Send(m *v1.WatchResponse) error {
+	return x.ServerStream.SendMsg(m)
+}
+
+// Bpfman_ServiceDesc is the grpc.ServiceDesc for Bpfman service.
+// It's only intended for direct use with grpc.RegisterService,
+// and not to be introspected or modified (even as a copy)
+var Bpfman_ServiceDesc = grpc.ServiceDesc{
+	ServiceName: "bpfman.v2.Bpfman",
+	HandlerType: (*BpfmanServer)(nil),
+	Methods: []grpc.MethodDesc{
+		{
+			MethodName: "Load",
+			Handler:    _Bpfman_Load_Handler,
+		},
+		{
+			MethodName: "Unload",
+			Handler:    _Bpfman_Unload_Handler,
+		},
+		{
+			MethodName: "Attach",
+			Handler:    _Bpfman_Attach_Handler,
+		},
+		{
+			MethodName: "Detach",
+			Handler:    _Bpfman_Detach_Handler,
+		},
+		{
+			MethodName: "AttachBatch",
+			Handler:    _Bpfman_AttachBatch_Handler,
+		},
+		{
+			MethodName: "DetachBatch",
+			Handler:    _Bpfman_DetachBatch_Handler,
+		},
+		{
+			MethodName: "DisableLink",
+			Handler:    _Bpfman_DisableLink_Handler,
+		},
+		{
+			MethodName: "EnableLink",
+			Handler:    _Bpfman_EnableLink_Handler,
+		},
+		{
+			MethodName: "SetLinkPriority",
+			Handler:    _Bpfman_SetLinkPriority_Handler,
+		},
+		{
+			MethodName: "SetLinkReattachPolicy",
+			Handler:    _Bpfman_SetLinkReattachPolicy_Handler,
+		},
+		{
+			MethodName: "List",
+			Handler:    _Bpfman_List_Handler,
+		},
+		{
+			MethodName: "PullBytecode",
+			Handler:    _Bpfman_PullBytecode_Handler,
+		},
+		{
+			MethodName: "Get",
+			Handler:    _Bpfman_Get_Handler,
+		},
+		{
+			MethodName: "ListLinks",
+			Handler:    _Bpfman_ListLinks_Handler,
+		},
+		{
+			MethodName: "GetLink",
+			Handler:    _Bpfman_GetLink_Handler,
+		},
+		{
+			MethodName: "Verify",
+			Handler:    _Bpfman_Verify_Handler,
+		},
+	},
+	Streams: []grpc.StreamDesc{
+		{
+			StreamName:    "Watch",
+			Handler:       _Bpfman_Watch_Handler,
+			ServerStreams: true,
+		},
+	},
+	Metadata: "bpfman_v2.proto",
+}
@@ -5,3 +5,7 @@
 #[rustfmt::skip]
 #[allow(clippy::all)]
 pub mod v1;
+
+/// The encoded protobuf descriptors of the `csi.v1` package, used for gRPC
+/// server reflection.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("csi_descriptor.bin");
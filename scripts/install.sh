@@ -77,7 +77,7 @@ copy_cli_tab_completion() {
     del_cli_tab_completion
     case $SHELL in
         "/bin/bash")
-            ${BIN_BPFMAN} completions --shell bash ${DST_CLI_TAB_COMPLETE_PATH}
+            ${BIN_BPFMAN} completions --dynamic --shell bash ${DST_CLI_TAB_COMPLETE_PATH}
             ;;
         *)
             echo "Currently only bash is supported by this script. For other shells, manually install."
@@ -1,8 +1,24 @@
 use std::{collections::HashMap, path::PathBuf, thread::sleep, time::Duration};
 
 use bpfman::{
-    remove_program, setup,
-    types::{AttachInfo, BytecodeImage, Location, TcProceedOn, XdpProceedOn},
+    add_programs, attach_link_group, attach_program, attach_program_batch,
+    bundle::{export_state, import_state},
+    detach_batch, detach_link_group, disable_link,
+    doctor::{CheckCategory, CheckStatus, diagnose},
+    enable_link,
+    errors::BpfmanError,
+    get_event_revision, get_events, get_kernel_link_info, get_link, get_link_group, get_program,
+    inspect::inspect_bytecode,
+    list_dispatchers, list_links, reconcile_interfaces, remove_program, resolve_link,
+    resolve_program, select_links, select_programs, set_link_priority, set_link_reattach_policy,
+    setup,
+    trace::{TraceRecord, trace_program},
+    types::{
+        AttachInfo, BpfProgType, BytecodeImage, DriftKind, EventKind, IdOrAlias, InterfaceSelector,
+        LinkListFilter, LinkType, Location, METADATA_ALIAS_TAG, METADATA_APPLICATION_TAG, Program,
+        ProgramData, ReattachPolicy, RepairStatus, TcProceedOn, XdpProceedOn, XdpProgram,
+    },
+    validate_attach, validate_programs, verify,
 };
 use procfs::sys::kernel::Version;
 
@@ -321,6 +337,759 @@ fn test_unload_xdp() {
     verify_and_delete_programs(&config, &root_db, progs);
 }
 
+#[test]
+fn test_disable_enable_xdp() {
+    init_logger();
+    // This test confirms that a disabled xdp link is skipped by the dispatcher
+    // while the remaining programs keep running, and that it runs again with
+    // the same link id once it is enabled.
+    let (config, root_db) = setup().unwrap();
+    let _namespace_guard = create_namespace().unwrap();
+    let _ping_guard = start_ping().unwrap();
+    let trace_guard = start_trace_pipe().unwrap();
+
+    assert!(iface_exists(DEFAULT_BPFMAN_IFACE));
+
+    let mut progs = vec![];
+
+    println!("Installing 1st xdp program");
+    let prog1 = add_xdp(
+        &config,
+        &root_db,
+        XDP_PASS_NAME.to_string(),
+        Location::File(XDP_PASS_FILE_LOC.to_string()),
+        HashMap::from([
+            (GLOBAL_U8.to_string(), vec![GLOBAL_1]),
+            (GLOBAL_U32.to_string(), vec![0x0A, 0x0B, 0x0C, 0x0D]),
+        ]),
+        HashMap::new(),
+        None,
+        AttachInfo::Xdp {
+            iface: DEFAULT_BPFMAN_IFACE.to_string(),
+            priority: 75,
+            proceed_on: XdpProceedOn::default(),
+            metadata: HashMap::new(),
+            netns: None,
+        },
+    );
+    progs.push(prog1);
+
+    println!("Installing 2nd xdp program");
+    let prog2 = add_xdp(
+        &config,
+        &root_db,
+        XDP_PASS_NAME.to_string(),
+        Location::File(XDP_PASS_FILE_LOC.to_string()),
+        HashMap::from([
+            (GLOBAL_U8.to_string(), vec![GLOBAL_2]),
+            (GLOBAL_U32.to_string(), vec![0x0A, 0x0B, 0x0C, 0x0D]),
+        ]),
+        HashMap::new(),
+        None,
+        AttachInfo::Xdp {
+            iface: DEFAULT_BPFMAN_IFACE.to_string(),
+            priority: 50,
+            proceed_on: XdpProceedOn::from_strings(vec![
+                "pass".to_string(),
+                "dispatcher_return".to_string(),
+            ])
+            .unwrap(),
+            metadata: HashMap::new(),
+            netns: None,
+        },
+    );
+    let link_id = prog2.get_data().get_link_ids().unwrap()[0];
+    progs.push(prog2);
+
+    println!("Disabling link {link_id}");
+    let link = disable_link(&config, &root_db, link_id).unwrap();
+    assert!(!link.get_enabled().unwrap());
+
+    println!("Clear the trace_pipe_log");
+    drop(trace_guard);
+    let trace_guard = start_trace_pipe().unwrap();
+
+    println!("wait for some traffic to generate logs...");
+    sleep(Duration::from_secs(2));
+
+    // Only the first program should be running.
+    let trace_pipe_log = read_trace_pipe_log().unwrap();
+    assert!(!trace_pipe_log.is_empty());
+    assert!(trace_pipe_log.contains(XDP_GLOBAL_1_LOG));
+    assert!(!trace_pipe_log.contains(XDP_GLOBAL_2_LOG));
+
+    println!("Enabling link {link_id}");
+    let link = enable_link(&config, &root_db, link_id).unwrap();
+    assert!(link.get_enabled().unwrap());
+    assert_eq!(link.get_id().unwrap(), link_id);
+
+    println!("Clear the trace_pipe_log");
+    drop(trace_guard);
+    let _trace_guard = start_trace_pipe().unwrap();
+
+    println!("wait for some traffic to generate logs...");
+    sleep(Duration::from_secs(2));
+
+    // Both programs should be running again.
+    let trace_pipe_log = read_trace_pipe_log().unwrap();
+    assert!(!trace_pipe_log.is_empty());
+    assert!(trace_pipe_log.contains(XDP_GLOBAL_1_LOG));
+    assert!(trace_pipe_log.contains(XDP_GLOBAL_2_LOG));
+    println!("Successfully completed the xdp disable/enable test");
+
+    verify_and_delete_programs(&config, &root_db, progs);
+}
+
+#[test]
+fn test_set_priority_xdp() {
+    init_logger();
+    // This test confirms that changing the priority of an xdp link reorders the
+    // programs on the dispatcher while keeping the link ids.
+    let (config, root_db) = setup().unwrap();
+    let _namespace_guard = create_namespace().unwrap();
+    let _ping_guard = start_ping().unwrap();
+    let trace_guard = start_trace_pipe().unwrap();
+
+    assert!(iface_exists(DEFAULT_BPFMAN_IFACE));
+
+    let mut progs = vec![];
+
+    println!("Installing 1st xdp program");
+    let prog1 = add_xdp(
+        &config,
+        &root_db,
+        XDP_PASS_NAME.to_string(),
+        Location::File(XDP_PASS_FILE_LOC.to_string()),
+        HashMap::from([
+            (GLOBAL_U8.to_string(), vec![GLOBAL_1]),
+            (GLOBAL_U32.to_string(), vec![0x0A, 0x0B, 0x0C, 0x0D]),
+        ]),
+        HashMap::new(),
+        None,
+        AttachInfo::Xdp {
+            iface: DEFAULT_BPFMAN_IFACE.to_string(),
+            priority: 50,
+            proceed_on: XdpProceedOn::from_strings(vec!["dispatcher_return".to_string()]).unwrap(),
+            metadata: HashMap::new(),
+            netns: None,
+        },
+    );
+    let link1_id = prog1.get_data().get_link_ids().unwrap()[0];
+    progs.push(prog1);
+
+    println!("Installing 2nd xdp program");
+    let prog2 = add_xdp(
+        &config,
+        &root_db,
+        XDP_PASS_NAME.to_string(),
+        Location::File(XDP_PASS_FILE_LOC.to_string()),
+        HashMap::from([
+            (GLOBAL_U8.to_string(), vec![GLOBAL_2]),
+            (GLOBAL_U32.to_string(), vec![0x0A, 0x0B, 0x0C, 0x0D]),
+        ]),
+        HashMap::new(),
+        None,
+        AttachInfo::Xdp {
+            iface: DEFAULT_BPFMAN_IFACE.to_string(),
+            priority: 75,
+            proceed_on: XdpProceedOn::default(),
+            metadata: HashMap::new(),
+            netns: None,
+        },
+    );
+    let link2_id = prog2.get_data().get_link_ids().unwrap()[0];
+    progs.push(prog2);
+
+    println!("Clear the trace_pipe_log");
+    drop(trace_guard);
+    let trace_guard = start_trace_pipe().unwrap();
+
+    println!("wait for some traffic to generate logs...");
+    sleep(Duration::from_secs(2));
+
+    // The 1st program runs first and does not proceed on pass.
+    let trace_pipe_log = read_trace_pipe_log().unwrap();
+    assert!(!trace_pipe_log.is_empty());
+    assert!(trace_pipe_log.contains(XDP_GLOBAL_1_LOG));
+    assert!(!trace_pipe_log.contains(XDP_GLOBAL_2_LOG));
+
+    println!("Setting priority of link {link2_id} to 25");
+    let link2 = set_link_priority(&config, &root_db, link2_id, 25).unwrap();
+    assert_eq!(link2.get_id().unwrap(), link2_id);
+    assert_eq!(link2.get_current_position().unwrap(), Some(0));
+    let link1 = get_link(&root_db, link1_id).unwrap();
+    assert_eq!(link1.get_current_position().unwrap(), Some(1));
+
+    println!("Clear the trace_pipe_log");
+    drop(trace_guard);
+    let _trace_guard = start_trace_pipe().unwrap();
+
+    println!("wait for some traffic to generate logs...");
+    sleep(Duration::from_secs(2));
+
+    // The 2nd program now runs first and proceeds on pass to the 1st.
+    let trace_pipe_log = read_trace_pipe_log().unwrap();
+    assert!(!trace_pipe_log.is_empty());
+    assert!(trace_pipe_log.contains(XDP_GLOBAL_1_LOG));
+    assert!(trace_pipe_log.contains(XDP_GLOBAL_2_LOG));
+    println!("Successfully completed the xdp set priority test");
+
+    verify_and_delete_programs(&config, &root_db, progs);
+}
+
+#[test]
+fn test_reattach_xdp() {
+    init_logger();
+    // This test confirms that an xdp link is marked as detached when its
+    // interface is removed, and is attached to a recreated interface with the
+    // same name when its reattach policy is by-name.
+    let (config, root_db) = setup().unwrap();
+    let namespace_guard = create_namespace().unwrap();
+
+    assert!(iface_exists(DEFAULT_BPFMAN_IFACE));
+
+    println!("Installing xdp program");
+    let prog = add_xdp(
+        &config,
+        &root_db,
+        XDP_PASS_NAME.to_string(),
+        Location::File(XDP_PASS_FILE_LOC.to_string()),
+        HashMap::from([
+            (GLOBAL_U8.to_string(), vec![GLOBAL_1]),
+            (GLOBAL_U32.to_string(), vec![0x0A, 0x0B, 0x0C, 0x0D]),
+        ]),
+        HashMap::new(),
+        None,
+        AttachInfo::Xdp {
+            iface: DEFAULT_BPFMAN_IFACE.to_string(),
+            priority: 50,
+            proceed_on: XdpProceedOn::default(),
+            metadata: HashMap::new(),
+            netns: None,
+        },
+    );
+    let link_id = prog.get_data().get_link_ids().unwrap()[0];
+
+    println!("Setting reattach policy of link {link_id} to by-name");
+    set_link_reattach_policy(&root_db, link_id, ReattachPolicy::ByName).unwrap();
+
+    println!("Removing interface {DEFAULT_BPFMAN_IFACE}");
+    drop(namespace_guard);
+    while iface_exists(DEFAULT_BPFMAN_IFACE) {
+        sleep(Duration::from_millis(100));
+    }
+
+    reconcile_interfaces(&config, &root_db, None).unwrap();
+    let link = get_link(&root_db, link_id).unwrap();
+    assert!(link.get_interface_missing().unwrap());
+
+    println!("Recreating interface {DEFAULT_BPFMAN_IFACE}");
+    let _namespace_guard = create_namespace().unwrap();
+    reconcile_interfaces(&config, &root_db, None).unwrap();
+    let link = get_link(&root_db, link_id).unwrap();
+    assert!(!link.get_interface_missing().unwrap());
+
+    let _ping_guard = start_ping().unwrap();
+    let _trace_guard = start_trace_pipe().unwrap();
+
+    println!("wait for some traffic to generate logs...");
+    sleep(Duration::from_secs(2));
+
+    let trace_pipe_log = read_trace_pipe_log().unwrap();
+    assert!(!trace_pipe_log.is_empty());
+    assert!(trace_pipe_log.contains(XDP_GLOBAL_1_LOG));
+    println!("Successfully completed the xdp reattach test");
+
+    verify_and_delete_programs(&config, &root_db, vec![prog]);
+}
+
+#[test]
+fn test_verify_xdp() {
+    init_logger();
+    // This test confirms that verify reports an xdp dispatcher that was
+    // detached behind bpfman's back, and attaches it again when asked to
+    // repair it.
+    let (config, root_db) = setup().unwrap();
+    let _namespace_guard = create_namespace().unwrap();
+
+    assert!(iface_exists(DEFAULT_BPFMAN_IFACE));
+
+    println!("Installing xdp program");
+    let prog = add_xdp(
+        &config,
+        &root_db,
+        XDP_PASS_NAME.to_string(),
+        Location::File(XDP_PASS_FILE_LOC.to_string()),
+        HashMap::from([
+            (GLOBAL_U8.to_string(), vec![GLOBAL_1]),
+            (GLOBAL_U32.to_string(), vec![0x0A, 0x0B, 0x0C, 0x0D]),
+        ]),
+        HashMap::new(),
+        None,
+        AttachInfo::Xdp {
+            iface: DEFAULT_BPFMAN_IFACE.to_string(),
+            priority: 50,
+            proceed_on: XdpProceedOn::default(),
+            metadata: HashMap::new(),
+            netns: None,
+        },
+    );
+    let prog_id = prog.get_data().get_id().unwrap();
+    let link_id = prog.get_data().get_link_ids().unwrap()[0];
+
+    let drifts = |repair| {
+        verify(&config, &root_db, repair)
+            .unwrap()
+            .into_iter()
+            .filter(|d| d.program_id == prog_id)
+            .collect::<Vec<_>>()
+    };
+    assert!(drifts(false).is_empty());
+
+    println!("Detaching the xdp dispatcher by removing its link pin");
+    for entry in std::fs::read_dir(RTDIR_FS_XDP).unwrap().flatten() {
+        if entry.file_name().to_string_lossy().ends_with("_link") {
+            std::fs::remove_file(entry.path()).unwrap();
+        }
+    }
+
+    let found = drifts(false);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].link_id, Some(link_id));
+    assert_eq!(
+        found[0].kind,
+        DriftKind::DispatcherDetached {
+            iface: DEFAULT_BPFMAN_IFACE.to_string()
+        }
+    );
+    assert_eq!(found[0].repair, RepairStatus::NotAttempted);
+
+    println!("Repairing the xdp dispatcher");
+    let repaired = drifts(true);
+    assert_eq!(repaired.len(), 1);
+    assert_eq!(repaired[0].repair, RepairStatus::Repaired);
+    assert!(drifts(false).is_empty());
+
+    let _ping_guard = start_ping().unwrap();
+    let _trace_guard = start_trace_pipe().unwrap();
+
+    println!("wait for some traffic to generate logs...");
+    sleep(Duration::from_secs(2));
+
+    let trace_pipe_log = read_trace_pipe_log().unwrap();
+    assert!(!trace_pipe_log.is_empty());
+    assert!(trace_pipe_log.contains(XDP_GLOBAL_1_LOG));
+    println!("Successfully completed the xdp verify test");
+
+    verify_and_delete_programs(&config, &root_db, vec![prog]);
+}
+
+#[test]
+fn test_events_xdp() {
+    init_logger();
+    // This test confirms that loading, attaching, detaching and unloading an
+    // xdp program is recorded in the event log in order.
+    let (config, root_db) = setup().unwrap();
+    let _namespace_guard = create_namespace().unwrap();
+    let revision = get_event_revision(&root_db).unwrap();
+
+    println!("Installing xdp program");
+    let prog = add_xdp(
+        &config,
+        &root_db,
+        XDP_PASS_NAME.to_string(),
+        Location::File(XDP_PASS_FILE_LOC.to_string()),
+        HashMap::new(),
+        HashMap::new(),
+        None,
+        AttachInfo::Xdp {
+            iface: DEFAULT_BPFMAN_IFACE.to_string(),
+            priority: 50,
+            proceed_on: XdpProceedOn::default(),
+            metadata: HashMap::new(),
+            netns: None,
+        },
+    );
+    let prog_id = prog.get_data().get_id().unwrap();
+    let link_id = prog.get_data().get_link_ids().unwrap()[0];
+    verify_and_delete_programs(&config, &root_db, vec![prog]);
+
+    let events = get_events(&root_db, revision).unwrap();
+    assert!(
+        events
+            .iter()
+            .any(|e| e.kind == EventKind::DispatcherRebuilt
+                && e.detail.contains(DEFAULT_BPFMAN_IFACE))
+    );
+    let kinds: Vec<_> = events
+        .iter()
+        .filter(|e| e.program_id == Some(prog_id))
+        .map(|e| (e.kind, e.link_id))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            (EventKind::ProgramLoaded, None),
+            (EventKind::LinkAttached, Some(link_id)),
+            (EventKind::LinkDetached, Some(link_id)),
+            (EventKind::ProgramUnloaded, None),
+        ]
+    );
+}
+
+#[test]
+fn test_attach_batch_xdp() {
+    init_logger();
+    // This test confirms that a batch of xdp links on the same interface is
+    // attached and detached with a single dispatcher rebuild each, and that a
+    // request that fails does not stop the rest of the batch.
+    let (config, root_db) = setup().unwrap();
+    let _namespace_guard = create_namespace().unwrap();
+
+    assert!(iface_exists(DEFAULT_BPFMAN_IFACE));
+
+    println!("Installing xdp program");
+    let prog = add_xdp(
+        &config,
+        &root_db,
+        XDP_PASS_NAME.to_string(),
+        Location::File(XDP_PASS_FILE_LOC.to_string()),
+        HashMap::new(),
+        HashMap::new(),
+        None,
+        AttachInfo::Xdp {
+            iface: DEFAULT_BPFMAN_IFACE.to_string(),
+            priority: 50,
+            proceed_on: XdpProceedOn::default(),
+            metadata: HashMap::new(),
+            netns: None,
+        },
+    );
+    let prog_id = prog.get_data().get_id().unwrap();
+    let attach_info = |priority| AttachInfo::Xdp {
+        iface: DEFAULT_BPFMAN_IFACE.to_string(),
+        priority,
+        proceed_on: XdpProceedOn::default(),
+        metadata: HashMap::new(),
+        netns: None,
+    };
+
+    println!("Attaching a batch of xdp links");
+    let revision = get_event_revision(&root_db).unwrap();
+    let results = attach_program_batch(
+        &config,
+        &root_db,
+        vec![
+            (prog_id, attach_info(10)),
+            (u32::MAX, attach_info(20)),
+            (prog_id, attach_info(30)),
+            (prog_id, attach_info(40)),
+        ],
+    );
+    assert_eq!(results.len(), 4);
+    assert!(results[1].is_err());
+    let link_ids: Vec<u32> = [&results[0], &results[2], &results[3]]
+        .into_iter()
+        .map(|r| r.as_ref().unwrap().get_id().unwrap())
+        .collect();
+    let positions: Vec<_> = link_ids
+        .iter()
+        .map(|id| {
+            get_link(&root_db, *id)
+                .unwrap()
+                .get_current_position()
+                .unwrap()
+        })
+        .collect();
+    assert_eq!(positions, vec![Some(0), Some(1), Some(2)]);
+
+    let rebuilt = |revision| {
+        get_events(&root_db, revision)
+            .unwrap()
+            .into_iter()
+            .filter(|e| e.kind == EventKind::DispatcherRebuilt)
+            .count()
+    };
+    assert_eq!(rebuilt(revision), 1);
+
+    println!("Detaching the batch of xdp links");
+    let revision = get_event_revision(&root_db).unwrap();
+    let mut ids = link_ids.clone();
+    ids.push(link_ids[0]);
+    let results = detach_batch(&config, &root_db, &ids);
+    assert!(results[..3].iter().all(|r| r.is_ok()));
+    assert!(results[3].is_err());
+    assert_eq!(rebuilt(revision), 1);
+    assert_eq!(prog.get_data().get_link_ids().unwrap().len(), 1);
+
+    verify_and_delete_programs(&config, &root_db, vec![prog]);
+}
+
+#[test]
+fn test_list_links_xdp() {
+    init_logger();
+    // This test confirms that an attached xdp link is listed by interface and
+    // link type, and that the kernel reports it as the link attaching the
+    // program to the dispatcher.
+    let (config, root_db) = setup().unwrap();
+    let _namespace_guard = create_namespace().unwrap();
+
+    assert!(iface_exists(DEFAULT_BPFMAN_IFACE));
+
+    println!("Installing xdp program");
+    let prog = add_xdp(
+        &config,
+        &root_db,
+        XDP_PASS_NAME.to_string(),
+        Location::File(XDP_PASS_FILE_LOC.to_string()),
+        HashMap::new(),
+        HashMap::new(),
+        None,
+        AttachInfo::Xdp {
+            iface: DEFAULT_BPFMAN_IFACE.to_string(),
+            priority: 50,
+            proceed_on: XdpProceedOn::default(),
+            metadata: HashMap::from([("owner".to_string(), "e2e".to_string())]),
+            netns: None,
+        },
+    );
+    let prog_id = prog.get_data().get_id().unwrap();
+    let link_id = prog.get_data().get_link_ids().unwrap()[0];
+
+    let filter = LinkListFilter::new(
+        Some(prog_id),
+        Some(LinkType::Xdp as u32),
+        Some(DEFAULT_BPFMAN_IFACE.to_string()),
+        HashMap::from([("owner".to_string(), "e2e".to_string())]),
+    );
+    let links = list_links(&root_db, filter).unwrap();
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].get_id().unwrap(), link_id);
+
+    let filter = LinkListFilter::new(
+        Some(prog_id),
+        Some(LinkType::Tc as u32),
+        None,
+        HashMap::new(),
+    );
+    assert!(list_links(&root_db, filter).unwrap().is_empty());
+
+    let link = get_link(&root_db, link_id).unwrap();
+    let kernel_info = get_kernel_link_info(&root_db, &link).unwrap().unwrap();
+    assert_eq!(kernel_info.program_id, prog_id);
+    assert_eq!(kernel_info.link_type, "tracing");
+
+    verify_and_delete_programs(&config, &root_db, vec![prog]);
+}
+
+#[test]
+fn test_export_import_xdp() {
+    init_logger();
+    // This test confirms that programs and links exported to a state bundle are
+    // recreated with new IDs by importing it, and that a program sharing the
+    // maps of another program shares the maps of its new copy.
+    let (config, root_db) = setup().unwrap();
+    let _namespace_guard = create_namespace().unwrap();
+
+    assert!(iface_exists(DEFAULT_BPFMAN_IFACE));
+
+    let attach_info = |priority| AttachInfo::Xdp {
+        iface: DEFAULT_BPFMAN_IFACE.to_string(),
+        priority,
+        proceed_on: XdpProceedOn::default(),
+        metadata: HashMap::new(),
+        netns: None,
+    };
+    println!("Installing xdp programs");
+    let owner = add_xdp(
+        &config,
+        &root_db,
+        XDP_PASS_NAME.to_string(),
+        Location::File(XDP_PASS_FILE_LOC.to_string()),
+        HashMap::new(),
+        HashMap::from([("owner".to_string(), "e2e".to_string())]),
+        None,
+        attach_info(50),
+    );
+    let owner_id = owner.get_data().get_id().unwrap();
+    let user = add_xdp(
+        &config,
+        &root_db,
+        XDP_PASS_NAME.to_string(),
+        Location::File(XDP_PASS_FILE_LOC.to_string()),
+        HashMap::new(),
+        HashMap::new(),
+        Some(owner_id),
+        attach_info(60),
+    );
+    let user_id = user.get_data().get_id().unwrap();
+
+    let mut bundle = export_state(&root_db).unwrap();
+    bundle
+        .programs
+        .retain(|p| p.id == owner_id || p.id == user_id);
+    bundle
+        .links
+        .retain(|l| l.program_id == owner_id || l.program_id == user_id);
+    bundle.link_groups.clear();
+    assert_eq!(bundle.programs.len(), 2);
+    assert_eq!(bundle.links.len(), 2);
+
+    verify_and_delete_programs(&config, &root_db, vec![owner, user]);
+
+    let imported = import_state(&config, &root_db, &bundle).unwrap();
+    assert_eq!(imported.programs.len(), 2);
+    assert_eq!(imported.links.len(), 2);
+
+    let new_id = |id| {
+        imported
+            .programs
+            .iter()
+            .find(|(old, _)| *old == id)
+            .unwrap()
+            .1
+    };
+    let owner = get_program(&root_db, new_id(owner_id)).unwrap();
+    let user = get_program(&root_db, new_id(user_id)).unwrap();
+    assert_eq!(
+        owner.get_data().get_metadata().unwrap()["owner"],
+        "e2e".to_string()
+    );
+    assert_eq!(
+        user.get_data().get_map_owner_id().unwrap(),
+        Some(new_id(owner_id))
+    );
+    for (_, link_id) in &imported.links {
+        let link = get_link(&root_db, *link_id).unwrap();
+        assert!(link.get_enabled().unwrap());
+    }
+
+    verify_and_delete_programs(&config, &root_db, vec![user, owner]);
+}
+
+#[test]
+fn test_list_dispatchers_xdp() {
+    init_logger();
+    // This test confirms that the xdp dispatcher on an interface is listed
+    // with the link and the program attached to it.
+    let (config, root_db) = setup().unwrap();
+    let _namespace_guard = create_namespace().unwrap();
+
+    assert!(iface_exists(DEFAULT_BPFMAN_IFACE));
+
+    println!("Installing xdp program");
+    let prog = add_xdp(
+        &config,
+        &root_db,
+        XDP_PASS_NAME.to_string(),
+        Location::File(XDP_PASS_FILE_LOC.to_string()),
+        HashMap::new(),
+        HashMap::new(),
+        None,
+        AttachInfo::Xdp {
+            iface: DEFAULT_BPFMAN_IFACE.to_string(),
+            priority: 50,
+            proceed_on: XdpProceedOn::default(),
+            metadata: HashMap::new(),
+            netns: None,
+        },
+    );
+    let prog_id = prog.get_data().get_id().unwrap();
+    let link_id = prog.get_data().get_link_ids().unwrap()[0];
+
+    let dispatchers = list_dispatchers(&root_db).unwrap();
+    let dispatcher = dispatchers
+        .iter()
+        .find(|d| matches!(d.link_type, LinkType::Xdp) && d.if_name == DEFAULT_BPFMAN_IFACE)
+        .unwrap();
+    assert_eq!(dispatcher.link_ids, vec![link_id]);
+    assert_eq!(dispatcher.program_ids, vec![prog_id]);
+    assert!(dispatcher.direction.is_none());
+
+    verify_and_delete_programs(&config, &root_db, vec![prog]);
+}
+
+#[test]
+fn test_link_group_xdp() {
+    init_logger();
+    // This test confirms that a link group attaches a program to the
+    // interfaces matched by a selector and is detached as a unit.
+    let (config, root_db) = setup().unwrap();
+    let _namespace_guard = create_namespace().unwrap();
+
+    assert!(iface_exists(DEFAULT_BPFMAN_IFACE));
+
+    println!("Installing xdp program");
+    let prog = add_xdp(
+        &config,
+        &root_db,
+        XDP_PASS_NAME.to_string(),
+        Location::File(XDP_PASS_FILE_LOC.to_string()),
+        HashMap::from([
+            (GLOBAL_U8.to_string(), vec![GLOBAL_1]),
+            (GLOBAL_U32.to_string(), vec![0x0A, 0x0B, 0x0C, 0x0D]),
+        ]),
+        HashMap::new(),
+        None,
+        AttachInfo::Xdp {
+            iface: DEFAULT_BPFMAN_IFACE.to_string(),
+            priority: 50,
+            proceed_on: XdpProceedOn::default(),
+            metadata: HashMap::new(),
+            netns: None,
+        },
+    );
+    let prog_id = prog.get_data().get_id().unwrap();
+    let attach_info = AttachInfo::Xdp {
+        iface: String::new(),
+        priority: 75,
+        proceed_on: XdpProceedOn::default(),
+        metadata: HashMap::new(),
+        netns: None,
+    };
+
+    println!("Attaching link group with a selector that matches nothing");
+    assert!(
+        attach_link_group(
+            &config,
+            &root_db,
+            prog_id,
+            "test-group",
+            InterfaceSelector::Glob("bpfman-no-such-iface*".to_string()),
+            attach_info.clone(),
+        )
+        .is_err()
+    );
+    assert!(get_link_group(&root_db, "test-group").is_err());
+
+    println!("Attaching link group");
+    let group = attach_link_group(
+        &config,
+        &root_db,
+        prog_id,
+        "test-group",
+        InterfaceSelector::Glob(DEFAULT_BPFMAN_IFACE.to_string()),
+        attach_info,
+    )
+    .unwrap();
+    let members = group.get_members().unwrap();
+    assert_eq!(members.len(), 1);
+    assert_eq!(members[0].iface, DEFAULT_BPFMAN_IFACE);
+    assert!(members[0].error.is_none());
+    let link_id = members[0].link_id.unwrap();
+    let link = get_link(&root_db, link_id).unwrap();
+    assert_eq!(link.get_group().unwrap(), Some("test-group".to_string()));
+    assert_eq!(prog.get_data().get_link_ids().unwrap().len(), 2);
+
+    println!("Detaching link group");
+    detach_link_group(&config, &root_db, "test-group").unwrap();
+    assert!(get_link_group(&root_db, "test-group").is_err());
+    assert_eq!(prog.get_data().get_link_ids().unwrap().len(), 1);
+    println!("Successfully completed the xdp link group test");
+
+    verify_and_delete_programs(&config, &root_db, vec![prog]);
+}
+
 #[test]
 fn test_proceed_on_tc() {
     init_logger();
@@ -1021,6 +1790,293 @@ fn test_program_execution_with_global_variables() {
     assert!(!bpffs_has_entries(RTDIR_FS_TC_EGRESS));
 }
 
+#[test]
+fn test_inspect_bytecode() {
+    init_logger();
+    // This test confirms that bytecode can be inspected without loading it,
+    // including the types of its global variables and the BTF-typed attach
+    // targets of its programs.
+    let info = inspect_bytecode(&std::fs::read(XDP_PASS_FILE_LOC).unwrap()).unwrap();
+    assert_eq!(info.license, "Dual BSD/GPL");
+    assert_eq!(info.programs.len(), 1);
+    assert_eq!(info.programs[0].name, XDP_PASS_NAME);
+    assert_eq!(info.programs[0].program_type, BpfProgType::Xdp);
+    let globals: Vec<(&str, Option<&str>)> = info
+        .globals
+        .iter()
+        .map(|g| (g.name.as_str(), g.type_name.as_deref()))
+        .collect();
+    assert_eq!(
+        globals,
+        vec![
+            (GLOBAL_U8, Some("volatile const __u8")),
+            (GLOBAL_U32, Some("volatile const __u32")),
+        ]
+    );
+
+    let info = inspect_bytecode(&std::fs::read(FENTRY_FILE_LOC).unwrap()).unwrap();
+    for (program, name) in info.programs.iter().zip([FENTRY_NAME, FEXIT_NAME]) {
+        assert_eq!(program.name, name);
+        assert_eq!(program.program_type, BpfProgType::Tracing);
+        assert_eq!(
+            program.attach_target.as_deref(),
+            Some(FENTRY_FEXIT_KERNEL_FUNCTION_NAME)
+        );
+        assert_eq!(program.attach_target_found, Some(true));
+    }
+    assert!(
+        info.required_features
+            .contains(&"BPF trampoline (Linux 5.5)".to_string())
+    );
+}
+
+#[test]
+fn test_verifier_log_level_xdp() {
+    init_logger();
+    let (config, root_db) = setup().unwrap();
+
+    // This test confirms that a program can be loaded with a verifier log
+    // level, and that a summary of the verification is recorded for it.
+    let mut data = ProgramData::new(
+        Location::File(XDP_PASS_FILE_LOC.to_string()),
+        XDP_PASS_NAME.to_string(),
+        HashMap::new(),
+        HashMap::new(),
+        None,
+    )
+    .unwrap();
+    data.set_verifier_log_level(1 | 4).unwrap();
+    let res = add_programs(
+        &config,
+        &root_db,
+        vec![Program::Xdp(XdpProgram::new(data).unwrap())],
+    )
+    .unwrap();
+
+    let summary = res[0]
+        .get_data()
+        .get_verifier_log_summary()
+        .unwrap()
+        .unwrap();
+    assert!(summary.starts_with("accepted"), "{summary}");
+
+    let id = res[0].get_data().get_id().unwrap();
+    remove_program(&config, &root_db, id).unwrap();
+}
+
+#[test]
+fn test_validate_xdp() {
+    init_logger();
+    let (config, root_db) = setup().unwrap();
+    let _namespace_guard = create_namespace().unwrap();
+
+    assert!(iface_exists(DEFAULT_BPFMAN_IFACE));
+
+    // This test confirms that validating a program runs the verifier without
+    // keeping the program, and that validating an attach checks the interface
+    // without creating a link.
+    let new_program = || {
+        let data = ProgramData::new(
+            Location::File(XDP_PASS_FILE_LOC.to_string()),
+            XDP_PASS_NAME.to_string(),
+            HashMap::new(),
+            HashMap::new(),
+            None,
+        )
+        .unwrap();
+        Program::Xdp(XdpProgram::new(data).unwrap())
+    };
+    let res = validate_programs(&config, &root_db, vec![new_program()]).unwrap();
+    let summary = res[0]
+        .get_data()
+        .get_verifier_log_summary()
+        .unwrap()
+        .unwrap();
+    assert!(summary.starts_with("accepted"), "{summary}");
+    let validated_id = res[0].get_data().get_id().unwrap();
+    assert!(get_program(&root_db, validated_id).is_err());
+    assert!(!PathBuf::from(format!("/run/bpfman/fs/prog_{validated_id}")).exists());
+
+    let res = add_programs(&config, &root_db, vec![new_program()]).unwrap();
+    let id = res[0].get_data().get_id().unwrap();
+    let attach_info = |iface: &str| AttachInfo::Xdp {
+        iface: iface.to_string(),
+        priority: 50,
+        proceed_on: XdpProceedOn::default(),
+        metadata: HashMap::new(),
+        netns: None,
+    };
+    validate_attach(&config, &root_db, id, attach_info(DEFAULT_BPFMAN_IFACE)).unwrap();
+    assert!(validate_attach(&config, &root_db, id, attach_info("bpfman-none0")).is_err());
+    let tracepoint = AttachInfo::Tracepoint {
+        tracepoint: "syscalls/sys_enter_kill".to_string(),
+        metadata: HashMap::new(),
+    };
+    assert!(validate_attach(&config, &root_db, id, tracepoint).is_err());
+    let program = get_program(&root_db, id).unwrap();
+    assert!(program.get_data().get_link_ids().unwrap().is_empty());
+
+    remove_program(&config, &root_db, id).unwrap();
+}
+
+#[test]
+fn test_alias_xdp() {
+    init_logger();
+    let (config, root_db) = setup().unwrap();
+    let _namespace_guard = create_namespace().unwrap();
+
+    assert!(iface_exists(DEFAULT_BPFMAN_IFACE));
+
+    // This test confirms that programs and links can be found by their alias,
+    // that an alias can only be used once, and that programs can be selected
+    // by the application they were loaded with.
+    let new_program = |alias: &str| {
+        let data = ProgramData::new(
+            Location::File(XDP_PASS_FILE_LOC.to_string()),
+            XDP_PASS_NAME.to_string(),
+            HashMap::from([
+                (
+                    METADATA_APPLICATION_TAG.to_string(),
+                    "AliasTestApp".to_string(),
+                ),
+                (METADATA_ALIAS_TAG.to_string(), alias.to_string()),
+            ]),
+            HashMap::new(),
+            None,
+        )
+        .unwrap();
+        Program::Xdp(XdpProgram::new(data).unwrap())
+    };
+    let res = add_programs(&config, &root_db, vec![new_program("alias-pass")]).unwrap();
+    let id = res[0].get_data().get_id().unwrap();
+    let program_alias = IdOrAlias::try_from("name:alias-pass".to_string()).unwrap();
+    assert_eq!(resolve_program(&root_db, &program_alias).unwrap(), id);
+    assert!(matches!(
+        add_programs(&config, &root_db, vec![new_program("alias-pass")]),
+        Err(BpfmanError::AliasInUse { .. })
+    ));
+
+    let link = attach_program(
+        &config,
+        &root_db,
+        id,
+        AttachInfo::Xdp {
+            iface: DEFAULT_BPFMAN_IFACE.to_string(),
+            priority: 50,
+            proceed_on: XdpProceedOn::default(),
+            metadata: HashMap::from([(
+                METADATA_ALIAS_TAG.to_string(),
+                "alias-pass-link".to_string(),
+            )]),
+            netns: None,
+        },
+    )
+    .unwrap();
+    let link_id = link.get_id().unwrap();
+    let link_alias = IdOrAlias::Alias("alias-pass-link".to_string());
+    assert_eq!(resolve_link(&root_db, &link_alias).unwrap(), link_id);
+    assert!(matches!(
+        resolve_link(&root_db, &IdOrAlias::Alias("alias-none".to_string())),
+        Err(BpfmanError::AliasNotFound { .. })
+    ));
+
+    let selected = select_programs(&root_db, "application=AliasTestApp").unwrap();
+    assert_eq!(selected.len(), 1);
+    assert_eq!(selected[0].get_data().get_id().unwrap(), id);
+    let selected = select_links(&root_db, "bpfman_alias in (alias-pass-link)").unwrap();
+    assert_eq!(selected.len(), 1);
+
+    remove_program(&config, &root_db, id).unwrap();
+    assert!(resolve_program(&root_db, &program_alias).is_err());
+}
+
+#[test]
+fn test_trace_xdp() {
+    init_logger();
+    let (config, root_db) = setup().unwrap();
+    let _namespace_guard = create_namespace().unwrap();
+    let _ping_guard = start_ping().unwrap();
+
+    assert!(iface_exists(DEFAULT_BPFMAN_IFACE));
+
+    // This test confirms that tracing a program returns the lines it prints
+    // with bpf_printk. Lines printed with the same format by other programs
+    // can't be told apart, so only the format is checked for all the lines.
+    let prog = add_xdp(
+        &config,
+        &root_db,
+        XDP_PASS_NAME.to_string(),
+        Location::File(XDP_PASS_FILE_LOC.to_string()),
+        HashMap::from([(GLOBAL_U8.to_string(), vec![GLOBAL_1])]),
+        HashMap::new(),
+        None,
+        AttachInfo::Xdp {
+            iface: DEFAULT_BPFMAN_IFACE.to_string(),
+            priority: 50,
+            proceed_on: XdpProceedOn::default(),
+            metadata: HashMap::new(),
+            netns: None,
+        },
+    );
+    let id = prog.get_data().get_id().unwrap();
+    let mut trace = trace_program(&root_db, id).unwrap();
+    assert!(trace.printk_formats() > 0);
+    assert!(!trace.has_log_map());
+
+    let mut records = vec![];
+    for _ in 0..50 {
+        records.extend(trace.read().unwrap());
+        if !records.is_empty() {
+            break;
+        }
+        sleep(Duration::from_millis(100));
+    }
+    assert!(
+        records
+            .iter()
+            .any(|r| r.to_string().contains(XDP_GLOBAL_1_LOG))
+    );
+    for record in &records {
+        match record {
+            TraceRecord::Printk(line) => assert!(line.contains("XDP: GLOBAL_u8: "), "{line}"),
+            _ => panic!("unexpected record {record:?}"),
+        }
+    }
+
+    verify_and_delete_programs(&config, &root_db, vec![prog]);
+}
+
+#[test]
+fn test_doctor() {
+    init_logger();
+    // This test confirms that the host the integration tests run on passes the
+    // checks that bpfman needs to run, and that the dispatchers can be loaded.
+    let report = diagnose();
+    for check in &report.checks {
+        println!(
+            "{} {} {}: {}",
+            check.category, check.name, check.status, check.message
+        );
+    }
+    // The bpffs is mounted by the check if no test mounted it before.
+    for name in ["kernel", "capabilities", "initialization", "bpffs"] {
+        let check = report
+            .checks
+            .iter()
+            .find(|c| c.category == CheckCategory::Environment && c.name == name)
+            .unwrap();
+        assert_ne!(check.status, CheckStatus::Fail, "{}", check.message);
+    }
+    for name in ["xdp", "tc"] {
+        let check = report
+            .checks
+            .iter()
+            .find(|c| c.category == CheckCategory::Program && c.name == name)
+            .unwrap();
+        assert_eq!(check.status, CheckStatus::Pass, "{}", check.message);
+    }
+}
+
 #[test]
 fn test_load_unload_xdp_maps() {
     init_logger();
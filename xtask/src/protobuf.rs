@@ -15,37 +15,39 @@ pub fn build(_opts: Options) -> anyhow::Result<()> {
 
 fn build_bpfman(_opts: &Options) -> anyhow::Result<()> {
     let root = PathBuf::from(WORKSPACE_ROOT.to_string());
-    let out_dir = root.join("bpfman-api/src");
+    let out_dir = root.join("bpfman-proto/src");
     let proto_dir = root.join("proto");
 
-    let protos = &["bpfman.proto"];
+    // google.rpc.Status carries the error details of the bpfman.v2 service.
+    let protos = &["bpfman.proto", "bpfman_v2.proto", "google/rpc/status.proto"];
     let includes = &[proto_dir.to_str().unwrap()];
     tonic_build::configure()
-        .out_dir(out_dir)
+        .out_dir(&out_dir)
+        .file_descriptor_set_path(out_dir.join("bpfman_descriptor.bin"))
         .compile(protos, includes)?;
 
     // protoc -I=./bpfman/proto --go_out=paths=source_relative:./clients/gobpfman ./bpfman/proto/bpfman.proto
-    let status = Command::new("protoc")
-        .current_dir(&root)
-        .args([
-            "-I=./proto",
-            "--go_out=paths=source_relative:./clients/gobpfman/v1",
-            "bpfman.proto",
-        ])
-        .status()
-        .expect("failed to build bpf program");
-    assert!(status.success());
-    let status = Command::new("protoc")
-        .current_dir(&root)
-        .args([
-            "-I=./proto",
-            "--go-grpc_out=./clients/gobpfman/v1",
-            "--go-grpc_opt=paths=source_relative",
-            "bpfman.proto",
-        ])
-        .status()
-        .expect("failed to build bpf program");
-    assert!(status.success());
+    for (proto, out) in [("bpfman.proto", "v1"), ("bpfman_v2.proto", "v2")] {
+        let go_out = format!("--go_out=paths=source_relative:./clients/gobpfman/{out}");
+        let go_grpc_out = format!("--go-grpc_out=./clients/gobpfman/{out}");
+        let status = Command::new("protoc")
+            .current_dir(&root)
+            .args(["-I=./proto", &go_out, proto])
+            .status()
+            .expect("failed to build bpf program");
+        assert!(status.success());
+        let status = Command::new("protoc")
+            .current_dir(&root)
+            .args([
+                "-I=./proto",
+                &go_grpc_out,
+                "--go-grpc_opt=paths=source_relative",
+                proto,
+            ])
+            .status()
+            .expect("failed to build bpf program");
+        assert!(status.success());
+    }
     Ok(())
 }
 
@@ -58,7 +60,8 @@ fn build_csi(_opts: &Options) -> anyhow::Result<()> {
     let includes = &[proto_dir.to_str().unwrap()];
 
     tonic_build::configure()
-        .out_dir(out_dir)
+        .out_dir(&out_dir)
+        .file_descriptor_set_path(out_dir.join("csi_descriptor.bin"))
         .compile(protos, includes)?;
     Ok(())
 }